@@ -81,7 +81,6 @@ impl DataFrameBuilder {
     /// if other does not match the schema of this builder.
     pub fn extend(&mut self, other: &DataFrame, share: ShareStrategy) {
         self.subslice_extend(other, 0, other.height(), share);
-        self.height += other.height();
     }
 
     /// Extends this builder with the contents of the given dataframe subslice.
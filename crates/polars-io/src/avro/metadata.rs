@@ -0,0 +1,200 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::avro::avro_schema::file::Block;
+use arrow::io::avro::avro_schema::schema::{Field as AvroField, Record, Schema as AvroSchema};
+use arrow::io::avro::read::{deserialize, infer_schema};
+use arrow::record_batch::RecordBatch;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+use super::AvroCodec;
+use super::write::{AVRO_MAGIC, SYNC_MARKER_LEN};
+
+/// A data block of an Avro object container file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AvroBlock {
+    /// Offset of the (possibly compressed) block data in the file.
+    pub offset: usize,
+    /// Length of the (possibly compressed) block data in bytes.
+    pub length: usize,
+    pub num_rows: usize,
+}
+
+/// Metadata of an Avro object container file, including the location of all of its data blocks.
+///
+/// The blocks are located by walking the block headers, which does not decompress or decode any
+/// of the data. This allows the blocks to be decoded independently and in parallel.
+#[derive(Clone, Debug)]
+pub struct AvroFileMetadata {
+    pub schema: ArrowSchemaRef,
+    pub avro_fields: Arc<[AvroField]>,
+    pub compression: Option<AvroCodec>,
+    pub blocks: Vec<AvroBlock>,
+}
+
+/// Reads a zigzag-encoded long, see
+/// <https://avro.apache.org/docs/1.11.1/specification/#binary-encoding>.
+fn read_long<R: Read>(reader: &mut R) -> PolarsResult<i64> {
+    let mut value = 0u64;
+    let mut byte = [0u8];
+    for i in 0..10 {
+        reader
+            .read_exact(&mut byte)
+            .map_err(|_| polars_err!(oos = "unexpected end of avro file"))?;
+        value |= u64::from(byte[0] & 0x7F) << (i * 7);
+        if byte[0] >> 7 == 0 {
+            return Ok((value >> 1) as i64 ^ -((value & 1) as i64));
+        }
+    }
+    polars_bail!(oos = "zigzag decoding failed - corrupt avro file")
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> PolarsResult<Vec<u8>> {
+    let len = read_long(reader)?;
+    polars_ensure!(len >= 0, oos = "avro bytes have a negative length");
+    let mut bytes = vec![];
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    polars_ensure!(
+        bytes.len() == len as usize,
+        oos = "unexpected end of avro file"
+    );
+    Ok(bytes)
+}
+
+/// The header of an Avro object container file.
+pub(super) struct AvroHeader {
+    pub record: Record,
+    pub compression: Option<AvroCodec>,
+    pub marker: [u8; SYNC_MARKER_LEN],
+}
+
+impl AvroHeader {
+    /// Reads the header, see
+    /// <https://avro.apache.org/docs/1.11.1/specification/#object-container-files>.
+    pub fn read<R: Read>(reader: &mut R) -> PolarsResult<Self> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        polars_ensure!(magic == AVRO_MAGIC, ComputeError: "not an avro file");
+
+        let mut schema = None;
+        let mut compression = None;
+        // The metadata is an Avro map of bytes, which is encoded as a series of blocks.
+        loop {
+            let mut len = read_long(reader)?;
+            if len == 0 {
+                break;
+            }
+            if len < 0 {
+                len = -len;
+                // Size of the block in bytes.
+                read_long(reader)?;
+            }
+            for _ in 0..len {
+                let key = read_bytes(reader)?;
+                let value = read_bytes(reader)?;
+                match key.as_slice() {
+                    b"avro.schema" => schema = Some(value),
+                    b"avro.codec" => compression = AvroCodec::from_codec_name(&value)?,
+                    _ => {},
+                }
+            }
+        }
+
+        let Some(schema) = schema else {
+            polars_bail!(ComputeError: "avro file has no schema");
+        };
+        let AvroSchema::Record(record) = serde_json::from_slice(&schema).map_err(to_compute_err)?
+        else {
+            polars_bail!(ComputeError: "the schema of an avro file must be a record");
+        };
+
+        let mut marker = [0u8; SYNC_MARKER_LEN];
+        reader.read_exact(&mut marker)?;
+
+        Ok(Self {
+            record,
+            compression,
+            marker,
+        })
+    }
+}
+
+impl AvroFileMetadata {
+    /// Reads the header of the Avro file in `bytes` and locates its data blocks.
+    pub fn read(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut rest = bytes;
+        let header = AvroHeader::read(&mut rest)?;
+        let schema = infer_schema(&header.record)?;
+
+        let mut blocks = Vec::new();
+        while !rest.is_empty() {
+            let num_rows = read_long(&mut rest)?;
+            let length = read_long(&mut rest)?;
+            polars_ensure!(
+                num_rows >= 0 && length >= 0,
+                ComputeError: "avro block has a negative number of rows or bytes"
+            );
+            let (num_rows, length) = (num_rows as usize, length as usize);
+
+            let offset = bytes.len() - rest.len();
+            polars_ensure!(
+                rest.len() >= length + SYNC_MARKER_LEN,
+                ComputeError: "avro block at offset {offset} is truncated"
+            );
+            polars_ensure!(
+                rest[length..length + SYNC_MARKER_LEN] == header.marker,
+                ComputeError: "avro block at offset {offset} does not end with the sync marker"
+            );
+            rest = &rest[length + SYNC_MARKER_LEN..];
+
+            blocks.push(AvroBlock {
+                offset,
+                length,
+                num_rows,
+            });
+        }
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            avro_fields: header.record.fields.into(),
+            compression: header.compression,
+            blocks,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.blocks.iter().map(|block| block.num_rows).sum()
+    }
+
+    /// Returns the mask of the columns of the file that are in `projection`.
+    pub fn projection_mask(&self, projection: &Schema) -> Vec<bool> {
+        self.schema
+            .iter_names()
+            .map(|name| projection.contains(name))
+            .collect()
+    }
+
+    /// Decompresses and decodes a single block of the file in `bytes`, only deserializing the
+    /// columns set in `projection`.
+    pub fn decode_block(
+        &self,
+        bytes: &[u8],
+        block: &AvroBlock,
+        projection: &[bool],
+    ) -> PolarsResult<RecordBatch> {
+        let data = &bytes[block.offset..block.offset + block.length];
+        let data = match self.compression {
+            None => data.to_vec(),
+            Some(compression) => compression.decompress(data)?,
+        };
+
+        deserialize(
+            &Block::new(block.num_rows, data),
+            &self.schema,
+            &self.avro_fields,
+            projection,
+        )
+    }
+}
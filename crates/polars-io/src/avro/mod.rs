@@ -1,5 +1,7 @@
+mod metadata;
 mod read;
 mod write;
 
+pub use metadata::*;
 pub use read::*;
 pub use write::*;
@@ -1,13 +1,22 @@
 use std::io::{Read, Seek};
 
-use arrow::io::avro::{self, read};
+use arrow::io::avro::read;
 use arrow::record_batch::RecordBatch;
-use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
+use super::metadata::AvroHeader;
+use super::{AvroBlock, AvroFileMetadata};
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+/// Options to scan Avro files with.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions {}
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -44,10 +53,8 @@ impl<R: Read + Seek> AvroReader<R> {
 
     /// Get arrow schema of the avro File, this is faster than a polars schema.
     pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
-        let metadata =
-            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
-        let schema = read::infer_schema(&metadata.record)?;
-        Ok(schema)
+        let header = AvroHeader::read(&mut self.reader)?;
+        read::infer_schema(&header.record)
     }
 
     /// Stop reading when `n` rows are read.
@@ -70,12 +77,23 @@ impl<R: Read + Seek> AvroReader<R> {
     }
 }
 
-impl<R> ArrowReader for read::Reader<R>
-where
-    R: Read + Seek,
-{
+/// Decodes the blocks of an Avro file in memory.
+struct BlockReader<'a> {
+    bytes: &'a [u8],
+    metadata: &'a AvroFileMetadata,
+    blocks: std::slice::Iter<'a, AvroBlock>,
+    projection: Vec<bool>,
+}
+
+impl ArrowReader for BlockReader<'_> {
     fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
-        self.next().map_or(Ok(None), |v| v.map(Some))
+        self.blocks
+            .next()
+            .map(|block| {
+                self.metadata
+                    .decode_block(self.bytes, block, &self.projection)
+            })
+            .transpose()
     }
 }
 
@@ -100,12 +118,14 @@ where
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
-        let metadata =
-            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
-        let schema = read::infer_schema(&metadata.record)?;
+        // The blocks are decoded from memory, see `AvroFileMetadata`.
+        let mut bytes = vec![];
+        self.reader.read_to_end(&mut bytes)?;
+        let metadata = AvroFileMetadata::read(&bytes)?;
+        let schema = metadata.schema.as_ref();
 
         if let Some(columns) = &self.columns {
-            self.projection = Some(columns_to_projection(columns, &schema)?);
+            self.projection = Some(columns_to_projection(columns, schema)?);
         }
 
         let (projection, projected_schema) = if let Some(projection) = self.projection {
@@ -113,15 +133,20 @@ where
             for &index in projection.iter() {
                 prj[index] = true;
             }
-            (Some(prj), apply_projection(&schema, &projection))
+            (prj, apply_projection(schema, &projection))
         } else {
-            (None, schema.clone())
+            (vec![true; schema.len()], schema.clone())
         };
 
-        let avro_reader = avro::read::Reader::new(&mut self.reader, metadata, schema, projection);
+        let block_reader = BlockReader {
+            bytes: &bytes,
+            metadata: &metadata,
+            blocks: metadata.blocks.iter(),
+            projection,
+        };
 
         finish_reader(
-            avro_reader,
+            block_reader,
             rechunk,
             self.n_rows,
             None,
@@ -1,14 +1,226 @@
-use std::io::Write;
+use std::io::{Read, Write};
 
 pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::{Record, Schema as AvroSchema};
+use arrow::io::avro::avro_schema::write::encode::zigzag_encode;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
+use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+pub(super) const AVRO_MAGIC: [u8; 4] = [b'O', b'b', b'j', 1];
+pub(super) const SYNC_MARKER_LEN: usize = 16;
+
+/// Compression codec of the data blocks of an Avro file.
+///
+/// Unlike [`Compression`], this includes the codecs that are not supported by `avro_schema`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    Deflate,
+    Snappy,
+    Zstd,
+}
+
+impl From<Compression> for AvroCodec {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::Deflate => Self::Deflate,
+            Compression::Snappy => Self::Snappy,
+        }
+    }
+}
+
+impl AvroCodec {
+    /// The name of the codec in the `avro.codec` metadata of a file.
+    pub fn codec_name(self) -> &'static str {
+        match self {
+            Self::Deflate => "deflate",
+            Self::Snappy => "snappy",
+            Self::Zstd => "zstandard",
+        }
+    }
+
+    pub fn from_codec_name(name: &[u8]) -> PolarsResult<Option<Self>> {
+        Ok(match name {
+            b"null" => None,
+            b"deflate" => Some(Self::Deflate),
+            b"snappy" => Some(Self::Snappy),
+            b"zstandard" => Some(Self::Zstd),
+            _ => polars_bail!(
+                ComputeError: "unsupported avro codec '{}'",
+                String::from_utf8_lossy(name)
+            ),
+        })
+    }
+
+    pub(super) fn compress(self, block: &mut Block) -> PolarsResult<Vec<u8>> {
+        let compression = match self {
+            Self::Deflate => Compression::Deflate,
+            Self::Snappy => Compression::Snappy,
+            Self::Zstd => return zstd::encode_all(block.data.as_slice(), 0).map_err(Into::into),
+        };
+        let mut compressed = CompressedBlock::default();
+        avro_schema::write::compress(block, &mut compressed, Some(compression))
+            .map_err(to_compute_err)?;
+        Ok(compressed.data)
+    }
+
+    pub(super) fn decompress(self, data: &[u8]) -> PolarsResult<Vec<u8>> {
+        let mut decompressed = vec![];
+        match self {
+            Self::Deflate => {
+                flate2::read::DeflateDecoder::new(data).read_to_end(&mut decompressed)?;
+            },
+            Self::Snappy => {
+                // The compressed data is followed by the big-endian CRC32 of the uncompressed data.
+                let Some((data, crc)) = data.split_last_chunk::<4>() else {
+                    polars_bail!(ComputeError: "avro snappy block is truncated");
+                };
+                decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(data)
+                    .map_err(to_compute_err)?;
+                let mut checksum = flate2::Crc::new();
+                checksum.update(&decompressed);
+                polars_ensure!(
+                    checksum.sum() == u32::from_be_bytes(*crc),
+                    ComputeError: "avro snappy block has an invalid checksum"
+                );
+            },
+            Self::Zstd => return zstd::decode_all(data).map_err(Into::into),
+        }
+        Ok(decompressed)
+    }
+}
+
+/// Returns a new random sync marker. The Avro specification requires every file to have its own
+/// randomly generated marker, which readers use to find the blocks when splitting a file.
+fn random_sync_marker() -> [u8; SYNC_MARKER_LEN] {
+    uuid::Uuid::new_v4().into_bytes()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Compression codec of the data blocks.
+    pub compression: Option<AvroCodec>,
+    /// Name of the record in the schema of the file.
+    pub name: PlSmallStr,
+    /// Maximum number of rows of each data block.
+    pub block_size: IdxSize,
+    /// Sync marker of the file. A random marker is generated for every file if this is `None`;
+    /// setting it makes the output deterministic.
+    pub sync_marker: Option<[u8; SYNC_MARKER_LEN]>,
+}
+
+impl Default for AvroWriterOptions {
+    fn default() -> Self {
+        Self {
+            compression: None,
+            name: PlSmallStr::EMPTY,
+            block_size: 1 << 14,
+            sync_marker: None,
+        }
+    }
+}
+
+impl AvroWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> AvroWriter<W> {
+        AvroWriter::new(writer)
+            .with_codec(self.compression)
+            .with_name(self.name.to_string())
+            .with_block_size(Some(self.block_size as usize))
+            .with_sync_marker(self.sync_marker)
+    }
+}
+
+/// Encodes the header and the data blocks of an Avro file.
+///
+/// The blocks are independent of each other, so they can be encoded in parallel and written in
+/// order after the header.
+#[derive(Clone, Debug)]
+pub struct AvroEncoder {
+    record: Record,
+    compression: Option<AvroCodec>,
+    sync_marker: [u8; SYNC_MARKER_LEN],
+}
+
+impl AvroEncoder {
+    /// Creates an encoder for a single file, with a random sync marker if `sync_marker` is `None`.
+    pub fn new(
+        schema: &Schema,
+        name: &str,
+        compression: Option<AvroCodec>,
+        sync_marker: Option<[u8; SYNC_MARKER_LEN]>,
+    ) -> PolarsResult<Self> {
+        let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let record = write::to_record(&schema, name.to_string())?;
+        Ok(Self {
+            record,
+            compression,
+            sync_marker: sync_marker.unwrap_or_else(random_sync_marker),
+        })
+    }
+
+    /// Encodes the header of the file into `buffer`.
+    pub fn encode_header(&self, buffer: &mut Vec<u8>) -> PolarsResult<()> {
+        let schema =
+            serde_json::to_vec(&AvroSchema::Record(self.record.clone())).map_err(to_compute_err)?;
+        let codec = self.compression.map_or("null", AvroCodec::codec_name);
+
+        buffer.extend_from_slice(&AVRO_MAGIC);
+        // The metadata is an Avro map of bytes.
+        zigzag_encode(2, buffer).map_err(to_compute_err)?;
+        for (key, value) in [
+            (b"avro.schema".as_slice(), schema.as_slice()),
+            (b"avro.codec", codec.as_bytes()),
+        ] {
+            encode_bytes(key, buffer)?;
+            encode_bytes(value, buffer)?;
+        }
+        zigzag_encode(0, buffer).map_err(to_compute_err)?;
+        buffer.extend_from_slice(&self.sync_marker);
+        Ok(())
+    }
+
+    /// Encodes `batch` as a single block into `buffer`.
+    pub fn encode_block(&self, batch: &RecordBatch, buffer: &mut Vec<u8>) -> PolarsResult<()> {
+        let mut serializers = batch
+            .arrays()
+            .iter()
+            .zip(self.record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        let mut block = Block::new(batch.height(), vec![]);
+        write::serialize(&mut serializers, &mut block);
+        let data = match self.compression {
+            None => block.data,
+            Some(compression) => compression.compress(&mut block)?,
+        };
+
+        zigzag_encode(batch.height() as i64, buffer).map_err(to_compute_err)?;
+        encode_bytes(&data, buffer)?;
+        buffer.extend_from_slice(&self.sync_marker);
+        Ok(())
+    }
+}
+
+fn encode_bytes(bytes: &[u8], buffer: &mut Vec<u8>) -> PolarsResult<()> {
+    zigzag_encode(bytes.len() as i64, buffer).map_err(to_compute_err)?;
+    buffer.extend_from_slice(bytes);
+    Ok(())
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -31,8 +243,10 @@ use crate::shared::{SerWriter, schema_to_arrow_checked};
 #[must_use]
 pub struct AvroWriter<W> {
     writer: W,
-    compression: Option<AvroCompression>,
+    compression: Option<AvroCodec>,
     name: String,
+    block_size: Option<usize>,
+    sync_marker: Option<[u8; SYNC_MARKER_LEN]>,
 }
 
 impl<W> AvroWriter<W>
@@ -41,7 +255,14 @@ where
 {
     /// Set the compression used. Defaults to None.
     pub fn with_compression(mut self, compression: Option<AvroCompression>) -> Self {
-        self.compression = compression;
+        self.compression = compression.map(Into::into);
+        self
+    }
+
+    /// Set the compression codec used, including the codecs that are not in [`Compression`].
+    /// Defaults to None.
+    pub fn with_codec(mut self, codec: Option<AvroCodec>) -> Self {
+        self.compression = codec;
         self
     }
 
@@ -49,6 +270,19 @@ where
         self.name = name;
         self
     }
+
+    /// Set the maximum number of rows of each block. Defaults to writing a block per chunk of the
+    /// [`DataFrame`].
+    pub fn with_block_size(mut self, block_size: Option<usize>) -> Self {
+        self.block_size = block_size;
+        self
+    }
+
+    /// Set the sync marker of the file. Defaults to a random marker.
+    pub fn with_sync_marker(mut self, sync_marker: Option<[u8; SYNC_MARKER_LEN]>) -> Self {
+        self.sync_marker = sync_marker;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -60,41 +294,38 @@ where
             writer,
             compression: None,
             name: "".to_string(),
+            block_size: None,
+            sync_marker: None,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
-
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
+        let encoder =
+            AvroEncoder::new(df.schema(), &self.name, self.compression, self.sync_marker)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
+        let mut buffer = vec![];
+        encoder.encode_header(&mut buffer)?;
+        self.writer.write_all(&buffer)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
+        let mut write_block = |batch: &RecordBatch| -> PolarsResult<()> {
+            buffer.clear();
+            encoder.encode_block(batch, &mut buffer)?;
+            self.writer.write_all(&buffer)?;
+            Ok(())
+        };
 
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+        match self.block_size {
+            Some(block_size) => {
+                for offset in (0..df.height()).step_by(block_size.max(1)) {
+                    let block = df.slice(offset as i64, block_size);
+                    write_block(&block.rechunk_to_record_batch(CompatLevel::oldest()))?;
+                }
+            },
+            None => {
+                for batch in df.iter_chunks(CompatLevel::oldest(), true) {
+                    write_block(&batch)?;
+                }
+            },
         }
 
         Ok(())
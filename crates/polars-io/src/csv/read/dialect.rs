@@ -0,0 +1,334 @@
+//! Rewriting of CSV dialects the parser cannot read directly to standard CSV.
+//!
+//! The parser splits fields and lines on single bytes and only knows doubled quotes as escapes.
+//! Files with a multi-byte separator or line terminator, or with an escape character, are
+//! rewritten to standard CSV before they are parsed, with the options of
+//! [`CsvParseOptions::to_standard_dialect`]. The rewrite runs over the whole file on a single
+//! thread, so it is avoided where possible: single byte separators and line terminators are
+//! parsed directly.
+use polars_core::prelude::*;
+
+use super::CsvParseOptions;
+use super::parser::is_comment_line;
+
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+
+impl CsvParseOptions {
+    /// Whether the file has to be rewritten to standard CSV before it can be parsed.
+    pub fn is_standard_dialect(&self) -> bool {
+        self.multi_char_separator.is_none()
+            && self.line_terminator.is_none()
+            && self.escape_char.is_none()
+    }
+
+    /// The options to parse the output of [`maybe_rewrite_dialect`] with.
+    pub fn to_standard_dialect(&self) -> CsvParseOptions {
+        if self.is_standard_dialect() {
+            return self.clone();
+        }
+
+        let mut options = self.clone();
+        if let Some(separator) = options.multi_char_separator.take() {
+            options.separator = separator
+                .as_bytes()
+                .first()
+                .copied()
+                .unwrap_or(self.separator);
+        }
+        if options.line_terminator.take().is_some() {
+            options.eol_char = b'\n';
+        }
+        options.escape_char = None;
+        options.quote_char = Some(self.quote_char.unwrap_or(b'"'));
+        options
+    }
+}
+
+/// The end of a field.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FieldEnd {
+    Separator,
+    LineTerminator,
+    Eof,
+}
+
+struct DialectRewriter<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    separator: &'a [u8],
+    line_terminator: &'a [u8],
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
+    /// The unescaped value of the current field.
+    value: Vec<u8>,
+}
+
+impl DialectRewriter<'_> {
+    fn at(&self, pattern: &[u8]) -> bool {
+        self.bytes[self.pos..].starts_with(pattern)
+    }
+
+    /// Pushes the byte after an escape character at the current position to the value.
+    fn push_escaped(&mut self) {
+        match self.bytes.get(self.pos + 1) {
+            Some(&b) => {
+                self.value.push(b);
+                self.pos += 2;
+            },
+            // A trailing escape character is taken literally.
+            None => {
+                self.value.push(self.bytes[self.pos]);
+                self.pos += 1;
+            },
+        }
+    }
+
+    /// Reads the quoted part of a field, the current position is at the opening quote.
+    fn read_quoted(&mut self, quote_char: u8) {
+        self.pos += 1;
+        let escape_char = self.escape_char.unwrap_or(quote_char);
+
+        while let Some(offset) = memchr::memchr2(quote_char, escape_char, &self.bytes[self.pos..]) {
+            self.value
+                .extend_from_slice(&self.bytes[self.pos..self.pos + offset]);
+            self.pos += offset;
+
+            if self.bytes[self.pos] == quote_char {
+                // Quotes within quoted fields can always be escaped by doubling them.
+                if self.bytes.get(self.pos + 1) == Some(&quote_char) {
+                    self.value.push(quote_char);
+                    self.pos += 2;
+                } else {
+                    self.pos += 1;
+                    return;
+                }
+            } else {
+                self.push_escaped();
+            }
+        }
+
+        // The quoted field runs until the end of the file.
+        self.value.extend_from_slice(&self.bytes[self.pos..]);
+        self.pos = self.bytes.len();
+    }
+
+    /// Reads the next field into `self.value`. Returns whether the field was quoted and how it
+    /// ended.
+    fn read_field(&mut self) -> (bool, FieldEnd) {
+        self.value.clear();
+
+        let quoted = match self.quote_char {
+            Some(quote_char) if self.bytes.get(self.pos) == Some(&quote_char) => {
+                self.read_quoted(quote_char);
+                true
+            },
+            _ => false,
+        };
+
+        let separator = self.separator[0];
+        let line_terminator = self.line_terminator[0];
+        let escape_char = self.escape_char.unwrap_or(separator);
+
+        while let Some(offset) = memchr::memchr3(
+            separator,
+            line_terminator,
+            escape_char,
+            &self.bytes[self.pos..],
+        ) {
+            self.value
+                .extend_from_slice(&self.bytes[self.pos..self.pos + offset]);
+            self.pos += offset;
+
+            if self.at(self.separator) {
+                self.pos += self.separator.len();
+                return (quoted, FieldEnd::Separator);
+            } else if self.at(self.line_terminator) {
+                self.pos += self.line_terminator.len();
+                return (quoted, FieldEnd::LineTerminator);
+            } else if Some(self.bytes[self.pos]) == self.escape_char {
+                self.push_escaped();
+            } else {
+                // The first byte of a multi-character separator or line terminator.
+                self.value.push(self.bytes[self.pos]);
+                self.pos += 1;
+            }
+        }
+
+        self.value.extend_from_slice(&self.bytes[self.pos..]);
+        self.pos = self.bytes.len();
+        (quoted, FieldEnd::Eof)
+    }
+
+    /// Skips past the line terminator of the current line.
+    fn skip_line(&mut self) {
+        self.pos = memchr::memmem::find(&self.bytes[self.pos..], self.line_terminator)
+            .map_or(self.bytes.len(), |offset| {
+                self.pos + offset + self.line_terminator.len()
+            });
+    }
+}
+
+/// Rewrites `bytes` to standard CSV if the options describe a dialect the parser cannot read
+/// directly. The rewritten bytes have to be parsed with [`CsvParseOptions::to_standard_dialect`].
+///
+/// Comment lines are removed, and fields are only quoted if they were quoted before or if their
+/// unescaped value requires it.
+///
+/// An `out` vec must be given for ownership of the rewritten data.
+pub fn maybe_rewrite_dialect<'a>(
+    bytes: &'a [u8],
+    parse_options: &CsvParseOptions,
+    out: &'a mut Vec<u8>,
+) -> PolarsResult<&'a [u8]> {
+    if parse_options.is_standard_dialect() {
+        return Ok(bytes);
+    }
+
+    let separator = match &parse_options.multi_char_separator {
+        Some(separator) => separator.as_bytes(),
+        None => std::slice::from_ref(&parse_options.separator),
+    };
+    let line_terminator = match &parse_options.line_terminator {
+        Some(line_terminator) => line_terminator.as_bytes(),
+        None => std::slice::from_ref(&parse_options.eol_char),
+    };
+    polars_ensure!(
+        !separator.is_empty() && !line_terminator.is_empty(),
+        InvalidOperation: "csv separator and line terminator must not be empty"
+    );
+    let escape_char = parse_options.escape_char;
+    polars_ensure!(
+        escape_char.is_none() || escape_char != parse_options.quote_char,
+        InvalidOperation: "csv escape char must differ from the quote char"
+    );
+
+    let standard = parse_options.to_standard_dialect();
+    let out_quote_char = standard.quote_char.unwrap();
+    let needs_quotes = |value: &[u8]| {
+        value
+            .iter()
+            .any(|&b| b == standard.separator || b == standard.eol_char || b == out_quote_char)
+    };
+
+    out.clear();
+    out.reserve(bytes.len());
+
+    let mut rewriter = DialectRewriter {
+        bytes,
+        pos: 0,
+        separator,
+        line_terminator,
+        quote_char: parse_options.quote_char,
+        escape_char: parse_options.escape_char,
+        value: vec![],
+    };
+
+    if rewriter.at(UTF8_BOM) {
+        out.extend_from_slice(UTF8_BOM);
+        rewriter.pos = UTF8_BOM.len();
+    }
+
+    while rewriter.pos < bytes.len() {
+        if is_comment_line(
+            &bytes[rewriter.pos..],
+            parse_options.comment_prefix.as_ref(),
+        ) {
+            rewriter.skip_line();
+            continue;
+        }
+
+        let line_start = out.len();
+        loop {
+            let (quoted, end) = rewriter.read_field();
+            let value = rewriter.value.as_slice();
+
+            // An unescaped comment prefix must not turn the line into a comment.
+            let is_comment = out.len() == line_start
+                && is_comment_line(value, parse_options.comment_prefix.as_ref());
+
+            if quoted || is_comment || needs_quotes(value) {
+                out.push(out_quote_char);
+                for &b in value {
+                    if b == out_quote_char {
+                        out.push(b);
+                    }
+                    out.push(b);
+                }
+                out.push(out_quote_char);
+            } else {
+                out.extend_from_slice(value);
+            }
+
+            match end {
+                FieldEnd::Separator => out.push(standard.separator),
+                FieldEnd::LineTerminator => {
+                    out.push(standard.eol_char);
+                    break;
+                },
+                FieldEnd::Eof => break,
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite(bytes: &str, parse_options: CsvParseOptions) -> String {
+        let mut out = vec![];
+        let out = maybe_rewrite_dialect(bytes.as_bytes(), &parse_options, &mut out).unwrap();
+        String::from_utf8(out.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_rewrite_dialect() {
+        let options = CsvParseOptions::default().with_multi_char_separator(Some("||".into()));
+        assert_eq!(
+            rewrite("a||b||c\n1,5||\"x||y\"||z|\n", options),
+            "a|b|c\n1,5|\"x||y\"|\"z|\"\n"
+        );
+
+        let options = CsvParseOptions::default()
+            .with_multi_char_separator(Some("\t|".into()))
+            .with_line_terminator(Some("\r\n".into()));
+        assert_eq!(
+            rewrite("a\t|b\r\nx\ny\t|\r\n\t|z", options),
+            "a\tb\n\"x\ny\"\t\n\tz"
+        );
+
+        let options = CsvParseOptions::default()
+            .with_escape_char(Some(b'\\'))
+            .with_comment_prefix(Some("#"));
+        assert_eq!(
+            rewrite(
+                "a,b\n# \"comment\n\\#x\\,y,\"say \\\"hi\\\" \"\"now\"\"\"\n\\\\,\\",
+                options
+            ),
+            "a,b\n\"#x,y\",\"say \"\"hi\"\" \"\"now\"\"\"\n\\,\\"
+        );
+
+        let options = CsvParseOptions::default()
+            .with_quote_char(None)
+            .with_line_terminator(Some(";;".into()));
+        assert_eq!(
+            rewrite("\u{feff}a,\"b;;c;,d;;", options),
+            "\u{feff}a,\"\"\"b\"\nc;,d\n"
+        );
+    }
+
+    #[test]
+    fn test_single_byte_dialect_is_not_rewritten() {
+        let options = CsvParseOptions::default()
+            .with_multi_char_separator(Some(";".into()))
+            .with_line_terminator(Some("\r".into()));
+        assert!(options.is_standard_dialect());
+        assert_eq!((options.separator, options.eol_char), (b';', b'\r'));
+
+        // Multi-byte UTF-8 characters still need the rewrite.
+        let options = CsvParseOptions::default().with_multi_char_separator(Some("§".into()));
+        assert!(!options.is_standard_dialect());
+    }
+}
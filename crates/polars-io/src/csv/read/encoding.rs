@@ -0,0 +1,318 @@
+//! Transcoding of CSV files in non-UTF-8 encodings to UTF-8 before they are parsed.
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+
+use super::CsvEncoding;
+
+/// Number of input bytes that are transcoded at once.
+const CHUNK_SIZE: usize = 1 << 16;
+
+const UTF8_BOM: &[u8] = b"\xef\xbb\xbf";
+const UTF16_LE_BOM: &[u8] = b"\xff\xfe";
+const UTF16_BE_BOM: &[u8] = b"\xfe\xff";
+
+/// The characters of the bytes `0x80..=0x9F` in Windows-1252. The bytes that are undefined in
+/// Windows-1252 map to the C1 control character of the same value, as in the WHATWG encoding
+/// standard.
+pub(crate) const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+impl CsvEncoding {
+    /// Whether the bytes of the file are parsed as they are.
+    pub fn is_utf8(&self) -> bool {
+        matches!(self, CsvEncoding::Utf8 | CsvEncoding::LossyUtf8)
+    }
+
+    /// Returns the encoding indicated by a byte order mark at the start of `bytes`, and the length
+    /// of the byte order mark.
+    fn detect_bom(bytes: &[u8]) -> Option<(CsvEncoding, usize)> {
+        if bytes.starts_with(UTF8_BOM) {
+            // The BOM is skipped by the parser.
+            Some((CsvEncoding::Utf8, 0))
+        } else if bytes.starts_with(UTF16_LE_BOM) {
+            Some((CsvEncoding::Utf16Le, UTF16_LE_BOM.len()))
+        } else if bytes.starts_with(UTF16_BE_BOM) {
+            Some((CsvEncoding::Utf16Be, UTF16_BE_BOM.len()))
+        } else {
+            None
+        }
+    }
+
+    /// The byte order mark that is written at the start of a file in this encoding.
+    pub(crate) fn bom(&self) -> PolarsResult<&'static [u8]> {
+        Ok(match self {
+            CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => UTF8_BOM,
+            CsvEncoding::Utf16Le => UTF16_LE_BOM,
+            CsvEncoding::Utf16Be => UTF16_BE_BOM,
+            CsvEncoding::Latin1 | CsvEncoding::Windows1252 => {
+                polars_bail!(InvalidOperation: "{:?} encoding has no byte order mark", self)
+            },
+        })
+    }
+}
+
+/// Transcodes a file to UTF-8, one chunk at a time.
+struct Utf8Transcoder {
+    encoding: CsvEncoding,
+    /// A trailing byte of a UTF-16 code unit that was split over two chunks.
+    pending_byte: Option<u8>,
+    /// A high surrogate of which the low surrogate is in the next chunk.
+    pending_surrogate: Option<u16>,
+}
+
+impl Utf8Transcoder {
+    fn new(encoding: CsvEncoding) -> Self {
+        Self {
+            encoding,
+            pending_byte: None,
+            pending_surrogate: None,
+        }
+    }
+
+    fn transcode_chunk(&mut self, mut chunk: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        // Copy the ASCII prefix as is, it is the same in all supported encodings but UTF-16.
+        if matches!(
+            self.encoding,
+            CsvEncoding::Latin1 | CsvEncoding::Windows1252
+        ) {
+            let n_ascii = chunk
+                .iter()
+                .position(|b| !b.is_ascii())
+                .unwrap_or(chunk.len());
+            out.extend_from_slice(&chunk[..n_ascii]);
+            chunk = &chunk[n_ascii..];
+        }
+
+        let mut buf = [0; 4];
+        let mut push_char =
+            |out: &mut Vec<u8>, c: char| out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+
+        match self.encoding {
+            CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => out.extend_from_slice(chunk),
+            CsvEncoding::Latin1 => {
+                for &b in chunk {
+                    push_char(out, b as char)
+                }
+            },
+            CsvEncoding::Windows1252 => {
+                for &b in chunk {
+                    match b {
+                        0x80..=0x9F => push_char(out, WINDOWS_1252_HIGH[(b - 0x80) as usize]),
+                        _ => push_char(out, b as char),
+                    }
+                }
+            },
+            CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => {
+                if chunk.is_empty() {
+                    return Ok(());
+                }
+                let from_bytes: fn([u8; 2]) -> u16 = if self.encoding == CsvEncoding::Utf16Le {
+                    u16::from_le_bytes
+                } else {
+                    u16::from_be_bytes
+                };
+
+                let mut units = Vec::with_capacity(chunk.len() / 2 + 2);
+                units.extend(self.pending_surrogate.take());
+                if let Some(first) = self.pending_byte.take() {
+                    units.push(from_bytes([first, chunk[0]]));
+                    chunk = &chunk[1..];
+                }
+                let mut pairs = chunk.chunks_exact(2);
+                units.extend(pairs.by_ref().map(|pair| from_bytes([pair[0], pair[1]])));
+                self.pending_byte = pairs.remainder().first().copied();
+
+                if units
+                    .last()
+                    .is_some_and(|unit| (0xD800..0xDC00).contains(unit))
+                {
+                    self.pending_surrogate = units.pop();
+                }
+
+                for c in char::decode_utf16(units) {
+                    let c = c.map_err(
+                        |_| polars_err!(ComputeError: "invalid utf-16 sequence in csv file"),
+                    )?;
+                    push_char(out, c);
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> PolarsResult<()> {
+        polars_ensure!(
+            self.pending_byte.is_none() && self.pending_surrogate.is_none(),
+            ComputeError: "invalid utf-16 sequence at the end of the csv file"
+        );
+        Ok(())
+    }
+}
+
+/// Transcodes `bytes` to UTF-8 if they are not in a UTF-8 encoding. A byte order mark at the
+/// start of `bytes` takes precedence over the given `encoding`.
+///
+/// The whole input is transcoded at once, the bytes are only borrowed if they are already UTF-8.
+/// Transcoded output can be empty, e.g. for a UTF-16 file that only holds a byte order mark.
+pub fn maybe_transcode_bytes(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<'_, [u8]>> {
+    let (encoding, bom_len) = CsvEncoding::detect_bom(bytes).unwrap_or((encoding, 0));
+
+    if encoding.is_utf8() {
+        return Ok(Cow::Borrowed(bytes));
+    }
+
+    // Most of the characters of typical CSV files are ASCII.
+    let mut out = Vec::with_capacity(match encoding {
+        CsvEncoding::Utf16Le | CsvEncoding::Utf16Be => bytes.len() / 2,
+        _ => bytes.len(),
+    });
+
+    let mut transcoder = Utf8Transcoder::new(encoding);
+    for chunk in bytes[bom_len..].chunks(CHUNK_SIZE) {
+        transcoder.transcode_chunk(chunk, &mut out)?;
+    }
+    transcoder.finish()?;
+
+    Ok(Cow::Owned(out))
+}
+
+/// Encodes the UTF-8 `bytes` in `encoding` and appends them to `out`.
+pub(crate) fn encode_utf8(
+    bytes: &[u8],
+    encoding: CsvEncoding,
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    if encoding.is_utf8() {
+        out.extend_from_slice(bytes);
+        return Ok(());
+    }
+
+    let text = std::str::from_utf8(bytes)
+        .map_err(|_| polars_err!(ComputeError: "cannot encode invalid utf-8 as {:?}", encoding))?;
+    let unencodable = |c: char| polars_err!(ComputeError: "cannot encode {c:?} as {encoding:?}");
+
+    match encoding {
+        CsvEncoding::Utf8 | CsvEncoding::LossyUtf8 => unreachable!(),
+        CsvEncoding::Latin1 => {
+            for c in text.chars() {
+                out.push(u8::try_from(c).map_err(|_| unencodable(c))?);
+            }
+        },
+        CsvEncoding::Windows1252 => {
+            for c in text.chars() {
+                let b = match u8::try_from(c) {
+                    Ok(b) if !(0x80..=0x9F).contains(&b) => b,
+                    _ => WINDOWS_1252_HIGH
+                        .iter()
+                        .position(|&high| high == c)
+                        .map(|i| 0x80 + i as u8)
+                        .ok_or_else(|| unencodable(c))?,
+                };
+                out.push(b);
+            }
+        },
+        CsvEncoding::Utf16Le => out.extend(text.encode_utf16().flat_map(u16::to_le_bytes)),
+        CsvEncoding::Utf16Be => out.extend(text.encode_utf16().flat_map(u16::to_be_bytes)),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcode(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<String> {
+        let out = maybe_transcode_bytes(bytes, encoding)?;
+        Ok(String::from_utf8(out.into_owned()).unwrap())
+    }
+
+    #[test]
+    fn test_transcode() {
+        assert_eq!(
+            transcode(b"a,b\ncaf\xe9,\xa3\x80\n", CsvEncoding::Latin1).unwrap(),
+            "a,b\ncafé,£\u{80}\n"
+        );
+        assert_eq!(
+            transcode(b"a,b\ncaf\xe9,\x80\x9c\x81\n", CsvEncoding::Windows1252).unwrap(),
+            "a,b\ncafé,€œ\u{81}\n"
+        );
+
+        let text = "a,b\ncafé,𝄞\n";
+        let le = text.encode_utf16().flat_map(u16::to_le_bytes);
+        let be = text.encode_utf16().flat_map(u16::to_be_bytes);
+        let bytes = le.clone().collect::<Vec<_>>();
+        assert_eq!(transcode(&bytes, CsvEncoding::Utf16Le).unwrap(), text);
+        let bytes = be.clone().collect::<Vec<_>>();
+        assert_eq!(transcode(&bytes, CsvEncoding::Utf16Be).unwrap(), text);
+
+        // The BOM takes precedence over the given encoding.
+        let bytes = [0xFF, 0xFE].into_iter().chain(le).collect::<Vec<_>>();
+        assert_eq!(transcode(&bytes, CsvEncoding::Utf8).unwrap(), text);
+        let bytes = [0xFE, 0xFF].into_iter().chain(be).collect::<Vec<_>>();
+        assert_eq!(transcode(&bytes, CsvEncoding::Utf16Le).unwrap(), text);
+        assert_eq!(
+            transcode("\u{feff}é".as_bytes(), CsvEncoding::Latin1).unwrap(),
+            "\u{feff}é"
+        );
+
+        // A file with only a BOM is transcoded to nothing, rather than left as is.
+        assert!(matches!(
+            maybe_transcode_bytes(&[0xFF, 0xFE], CsvEncoding::Utf8).unwrap(),
+            Cow::Owned(out) if out.is_empty()
+        ));
+        assert!(matches!(
+            maybe_transcode_bytes(b"a,b\n", CsvEncoding::Utf8).unwrap(),
+            Cow::Borrowed(b"a,b\n")
+        ));
+
+        assert!(transcode(&[0x61, 0x00, 0x62], CsvEncoding::Utf16Le).is_err());
+        assert!(transcode(&[0x00, 0xD8, 0x61, 0x00], CsvEncoding::Utf16Le).is_err());
+    }
+
+    #[test]
+    fn test_encode_utf8() {
+        let encode = |text: &str, encoding| {
+            let mut out = vec![];
+            encode_utf8(text.as_bytes(), encoding, &mut out).map(|_| out)
+        };
+
+        assert_eq!(
+            encode("a,b\ncafé,£\n", CsvEncoding::Latin1).unwrap(),
+            b"a,b\ncaf\xe9,\xa3\n"
+        );
+        assert_eq!(
+            encode("a,b\ncafé,€œ\u{81}\n", CsvEncoding::Windows1252).unwrap(),
+            b"a,b\ncaf\xe9,\x80\x9c\x81\n"
+        );
+        assert!(encode("€", CsvEncoding::Latin1).is_err());
+        assert!(encode("\u{80}", CsvEncoding::Windows1252).is_err());
+
+        // Round trip through the transcoding of the reader.
+        let text = "a,b\ncafé,𝄞\n";
+        for encoding in [CsvEncoding::Utf16Le, CsvEncoding::Utf16Be] {
+            let mut bytes = encoding.bom().unwrap().to_vec();
+            bytes.extend(encode(text, encoding).unwrap());
+            assert_eq!(transcode(&bytes, CsvEncoding::Utf8).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_transcode_utf16_chunk_boundaries() {
+        // Shift the surrogate pairs over the boundary between the first and second chunk.
+        for offset in 0..3 {
+            let text = "a".repeat(offset) + &"𝄞é".repeat(CHUNK_SIZE / 3);
+            let bytes = text
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes)
+                .collect::<Vec<_>>();
+            assert_eq!(transcode(&bytes, CsvEncoding::Utf16Le).unwrap(), text);
+        }
+    }
+}
@@ -17,6 +17,8 @@
 //! ```
 
 pub mod buffer;
+mod dialect;
+mod encoding;
 mod options;
 mod parser;
 mod read_impl;
@@ -25,6 +27,9 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
+pub use dialect::maybe_rewrite_dialect;
+pub(crate) use encoding::encode_utf8;
+pub use encoding::maybe_transcode_bytes;
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
@@ -49,8 +49,14 @@ pub struct CsvReadOptions {
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct CsvParseOptions {
     pub separator: u8,
+    /// A separator of more than one character. Takes precedence over `separator`.
+    pub multi_char_separator: Option<PlSmallStr>,
     pub quote_char: Option<u8>,
+    /// A character that escapes the character after it, e.g. `\` in `\"` and `\,`.
+    pub escape_char: Option<u8>,
     pub eol_char: u8,
+    /// A line terminator of more than one character. Takes precedence over `eol_char`.
+    pub line_terminator: Option<PlSmallStr>,
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
     pub missing_is_null: bool,
@@ -97,8 +103,11 @@ impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
             separator: b',',
+            multi_char_separator: None,
             quote_char: Some(b'"'),
+            escape_char: None,
             eol_char: b'\n',
+            line_terminator: None,
             encoding: Default::default(),
             null_values: None,
             missing_is_null: true,
@@ -266,6 +275,23 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set a separator of more than one character, e.g. `||`. Takes precedence
+    /// over the single byte separator.
+    ///
+    /// The parser only splits on single bytes, so with a multi-byte separator the
+    /// whole file is first rewritten to standard CSV on a single thread, in memory.
+    /// A single byte separator is used as `separator` instead.
+    pub fn with_multi_char_separator(mut self, separator: Option<PlSmallStr>) -> Self {
+        match separator {
+            Some(separator) if separator.len() == 1 => {
+                self.separator = separator.as_bytes()[0];
+                self.multi_char_separator = None;
+            },
+            separator => self.multi_char_separator = separator,
+        }
+        self
+    }
+
     /// Set the character used for field quoting. This is most often double
     /// quotes '"'. Set this to [None] to disable quote parsing.
     pub fn with_quote_char(mut self, quote_char: Option<u8>) -> Self {
@@ -273,13 +299,43 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set the character that escapes the character after it, both inside and
+    /// outside of quoted fields. This is most often a backslash '\'.
+    ///
+    /// Like a multi-byte separator, an escape char makes the whole file be rewritten
+    /// to standard CSV on a single thread before it is parsed.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Set the character used to indicate an end-of-line (eol).
     pub fn with_eol_char(mut self, eol_char: u8) -> Self {
         self.eol_char = eol_char;
         self
     }
 
+    /// Set a line terminator of more than one character, e.g. `\r\n`. Takes
+    /// precedence over the eol char.
+    ///
+    /// Like a multi-byte separator, a multi-byte line terminator makes the whole
+    /// file be rewritten to standard CSV on a single thread before it is parsed. A
+    /// single byte line terminator is used as `eol_char` instead.
+    pub fn with_line_terminator(mut self, line_terminator: Option<PlSmallStr>) -> Self {
+        match line_terminator {
+            Some(line_terminator) if line_terminator.len() == 1 => {
+                self.eol_char = line_terminator.as_bytes()[0];
+                self.line_terminator = None;
+            },
+            line_terminator => self.line_terminator = line_terminator,
+        }
+        self
+    }
+
     /// Set the encoding used by the file.
+    ///
+    /// Files that are not in a UTF-8 encoding are transcoded to UTF-8 as a whole before they are
+    /// parsed, so the transcoded file must fit in memory next to the original.
     pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
         self.encoding = encoding;
         self
@@ -339,6 +395,14 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1 (Latin-1) encoding.
+    Latin1,
+    /// Windows-1252 encoding.
+    Windows1252,
+    /// UTF-16 little endian encoding.
+    Utf16Le,
+    /// UTF-16 big endian encoding.
+    Utf16Be,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
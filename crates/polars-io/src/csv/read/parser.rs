@@ -10,6 +10,8 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::Buffer;
+use super::dialect::maybe_rewrite_dialect;
+use super::encoding::maybe_transcode_bytes;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
@@ -18,13 +20,9 @@ use crate::utils::compression::maybe_decompress_bytes;
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
-#[allow(clippy::too_many_arguments)]
 pub fn count_rows(
     addr: PlPathRef<'_>,
-    separator: u8,
-    quote_char: Option<u8>,
-    comment_prefix: Option<&CommentPrefix>,
-    eol_char: u8,
+    parse_options: &CsvParseOptions,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -47,13 +45,17 @@ pub fn count_rows(
     let mmap = MMapSemaphore::new_from_file(&file).unwrap();
     let owned = &mut vec![];
     let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+    let transcoded = maybe_transcode_bytes(reader_bytes, parse_options.encoding)?;
+    let rewritten = &mut vec![];
+    let reader_bytes = maybe_rewrite_dialect(&transcoded, parse_options, rewritten)?;
+    let parse_options = parse_options.to_standard_dialect();
 
     count_rows_from_slice_par(
         reader_bytes,
-        separator,
-        quote_char,
-        comment_prefix,
-        eol_char,
+        parse_options.separator,
+        parse_options.quote_char,
+        parse_options.comment_prefix.as_ref(),
+        parse_options.eol_char,
         has_header,
         skip_lines,
         skip_rows_before_header,
@@ -1,5 +1,6 @@
 pub(super) mod batched;
 
+use std::borrow::Cow;
 use std::fmt;
 use std::sync::Mutex;
 
@@ -13,6 +14,8 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::buffer::init_buffers;
+use super::dialect::maybe_rewrite_dialect;
+use super::encoding::maybe_transcode_bytes;
 use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::parser::{
     CountLines, SplitLines, is_comment_line, parse_lines, skip_bom, skip_line_ending,
@@ -137,7 +140,7 @@ impl<'a> CoreReader<'a> {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         reader_bytes: ReaderBytes<'a>,
-        parse_options: Arc<CsvParseOptions>,
+        mut parse_options: Arc<CsvParseOptions>,
         n_rows: Option<usize>,
         skip_rows: usize,
         skip_lines: usize,
@@ -159,7 +162,6 @@ impl<'a> CoreReader<'a> {
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
-        #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -173,8 +175,10 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // The lines of other dialects are only known after they have been rewritten.
+            let total_n_rows = n_rows
+                .filter(|_| parse_options.is_standard_dialect())
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -186,6 +190,19 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        if let Cow::Owned(transcoded) =
+            maybe_transcode_bytes(&reader_bytes, parse_options.encoding)?
+        {
+            reader_bytes = ReaderBytes::Owned(transcoded.into());
+        }
+
+        if !parse_options.is_standard_dialect() {
+            let mut rewritten = vec![];
+            maybe_rewrite_dialect(&reader_bytes, &parse_options, &mut rewritten)?;
+            reader_bytes = ReaderBytes::Owned(rewritten.into());
+            parse_options = Arc::new(parse_options.to_standard_dialect());
+        }
+
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
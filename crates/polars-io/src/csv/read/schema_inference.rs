@@ -7,6 +7,7 @@ use polars_time::chunkedarray::string::infer as date_infer;
 use polars_time::prelude::string::Pattern;
 use polars_utils::format_pl_smallstr;
 
+use super::dialect::maybe_rewrite_dialect;
 use super::parser::{SplitLines, is_comment_line, skip_bom, skip_line_ending};
 use super::splitfields::SplitFields;
 use super::{CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
@@ -30,6 +31,20 @@ impl SchemaInferenceResult {
     ) -> PolarsResult<Self> {
         let parse_options = options.get_parse_options();
 
+        let mut rewritten = vec![];
+        let rewritten_bytes;
+        let reader_bytes = if parse_options.is_standard_dialect() {
+            reader_bytes
+        } else {
+            rewritten_bytes = ReaderBytes::Borrowed(maybe_rewrite_dialect(
+                reader_bytes,
+                &parse_options,
+                &mut rewritten,
+            )?);
+            &rewritten_bytes
+        };
+        let parse_options = parse_options.to_standard_dialect();
+
         let infer_schema_length = options.infer_schema_length;
         let has_header = options.has_header;
         let schema_overwrite_arc = options.schema_overwrite.clone();
@@ -171,7 +186,12 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<'_, str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        // The other encodings are transcoded to utf8 before parsing.
+        CsvEncoding::Utf8
+        | CsvEncoding::Latin1
+        | CsvEncoding::Windows1252
+        | CsvEncoding::Utf16Le
+        | CsvEncoding::Utf16Be => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
         CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
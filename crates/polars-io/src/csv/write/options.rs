@@ -3,6 +3,8 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::csv::read::CsvEncoding;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -10,6 +12,7 @@ use serde::{Deserialize, Serialize};
 pub struct CsvWriterOptions {
     pub include_bom: bool,
     pub include_header: bool,
+    pub encoding: CsvEncoding,
     pub batch_size: NonZeroUsize,
     pub serialize_options: SerializeOptions,
 }
@@ -19,6 +22,7 @@ impl Default for CsvWriterOptions {
         Self {
             include_bom: false,
             include_header: true,
+            encoding: CsvEncoding::Utf8,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default(),
         }
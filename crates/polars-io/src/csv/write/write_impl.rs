@@ -10,6 +10,7 @@ use polars_error::polars_ensure;
 use rayon::prelude::*;
 use serializer::{serializer_for, string_serializer};
 
+use crate::csv::read::{CsvEncoding, encode_utf8};
 use crate::csv::write::SerializeOptions;
 
 pub(crate) fn write<W: Write>(
@@ -17,6 +18,7 @@ pub(crate) fn write<W: Write>(
     df: &DataFrame,
     chunk_size: usize,
     options: &SerializeOptions,
+    encoding: CsvEncoding,
     n_threads: usize,
 ) -> PolarsResult<()> {
     for s in df.get_columns() {
@@ -182,6 +184,11 @@ pub(crate) fn write<W: Write>(
                 write_buffer.extend_from_slice(options.line_terminator.as_bytes());
             }
 
+            if !encoding.is_utf8() {
+                let utf8 = std::mem::take(write_buffer);
+                encode_utf8(&utf8, encoding, write_buffer)?;
+            }
+
             Ok(())
         };
 
@@ -213,6 +220,7 @@ pub(crate) fn write_header<W: Write>(
     writer: &mut W,
     names: &[&str],
     options: &SerializeOptions,
+    encoding: CsvEncoding,
 ) -> PolarsResult<()> {
     let mut header = Vec::new();
 
@@ -231,13 +239,14 @@ pub(crate) fn write_header<W: Write>(
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
-    writer.write_all(&header)?;
+    let mut encoded = Vec::new();
+    encode_utf8(&header, encoding, &mut encoded)?;
+    writer.write_all(&encoded)?;
     Ok(())
 }
 
-/// Writes a UTF-8 BOM to `writer`.
-pub(crate) fn write_bom<W: Write>(writer: &mut W) -> PolarsResult<()> {
-    const BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
-    writer.write_all(&BOM)?;
+/// Writes the BOM of `encoding` to `writer`.
+pub(crate) fn write_bom<W: Write>(writer: &mut W, encoding: CsvEncoding) -> PolarsResult<()> {
+    writer.write_all(encoding.bom()?)?;
     Ok(())
 }
@@ -0,0 +1,467 @@
+//! Actions of the Delta Lake transaction log.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#actions>.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_err, to_compute_err};
+
+use crate::SerReader;
+use crate::parquet::read::ParquetReader;
+
+/// Location and size of the deletion vector of a data file.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    pub size_in_bytes: i64,
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Identifies the deletion vector within the table, together with the path of the data file
+    /// it uniquely identifies a logical file.
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAction {
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    #[serde(default)]
+    pub modification_time: i64,
+    /// JSON encoded file statistics.
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAction {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataAction {
+    /// Unique identifier of the table.
+    #[serde(default)]
+    pub id: String,
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: BTreeMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolAction {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub min_writer_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+    #[serde(default)]
+    pub writer_features: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitInfo {
+    #[serde(default)]
+    pub in_commit_timestamp: Option<i64>,
+}
+
+/// A single line of a commit file, or a single row of a checkpoint. Exactly one of the fields is
+/// expected to be set; actions this reader has no use for are ignored.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Action {
+    #[serde(default)]
+    pub add: Option<AddAction>,
+    #[serde(default)]
+    pub remove: Option<RemoveAction>,
+    #[serde(default)]
+    pub meta_data: Option<MetadataAction>,
+    #[serde(default)]
+    pub protocol: Option<ProtocolAction>,
+    #[serde(default)]
+    pub commit_info: Option<CommitInfo>,
+}
+
+/// Parses the newline delimited actions of a commit file.
+pub fn parse_commit(bytes: &[u8]) -> PolarsResult<Vec<Action>> {
+    bytes
+        .split(|b| *b == b'\n')
+        .filter(|line| !line.iter().all(u8::is_ascii_whitespace))
+        .map(|line| serde_json::from_slice::<Action>(line).map_err(to_compute_err))
+        .collect()
+}
+
+/// Parses the actions of a (part of a) checkpoint file.
+///
+/// Commit info actions are not stored in checkpoints.
+pub fn parse_checkpoint(bytes: &[u8]) -> PolarsResult<Vec<Action>> {
+    let df = ParquetReader::new(Cursor::new(bytes)).finish()?;
+    let height = df.height();
+    let mut actions = vec![Action::default(); height];
+
+    if let Some(add) = StructColumn::new(&df, "add")? {
+        let path = add.str_field("path")?;
+        let partition_values = add.field("partitionValues");
+        let size = add.i64_field("size")?;
+        let modification_time = add.i64_field("modificationTime")?;
+        let stats = add.str_field("stats")?;
+        let deletion_vector = add.field("deletionVector");
+
+        for (i, action) in actions.iter_mut().enumerate() {
+            if !add.is_valid(i) {
+                continue;
+            }
+
+            action.add = Some(AddAction {
+                path: required(path.as_ref().and_then(|ca| ca.get(i)), "add.path")?.to_string(),
+                partition_values: match &partition_values {
+                    Some(s) => string_map_at(s, i)?,
+                    None => BTreeMap::new(),
+                },
+                size: required(size.as_ref().and_then(|ca| ca.get(i)), "add.size")?,
+                modification_time: modification_time
+                    .as_ref()
+                    .and_then(|ca| ca.get(i))
+                    .unwrap_or(0),
+                stats: stats.as_ref().and_then(|ca| ca.get(i)).map(str::to_string),
+                deletion_vector: deletion_vector
+                    .as_ref()
+                    .map(|s| deletion_vector_at(s, i))
+                    .transpose()?
+                    .flatten(),
+            });
+        }
+    }
+
+    if let Some(remove) = StructColumn::new(&df, "remove")? {
+        let path = remove.str_field("path")?;
+        let deletion_vector = remove.field("deletionVector");
+
+        for (i, action) in actions.iter_mut().enumerate() {
+            if !remove.is_valid(i) {
+                continue;
+            }
+
+            action.remove = Some(RemoveAction {
+                path: required(path.as_ref().and_then(|ca| ca.get(i)), "remove.path")?.to_string(),
+                deletion_vector: deletion_vector
+                    .as_ref()
+                    .map(|s| deletion_vector_at(s, i))
+                    .transpose()?
+                    .flatten(),
+            });
+        }
+    }
+
+    if let Some(meta_data) = StructColumn::new(&df, "metaData")? {
+        let id = meta_data.str_field("id")?;
+        let schema_string = meta_data.str_field("schemaString")?;
+        let partition_columns = meta_data.field("partitionColumns");
+        let configuration = meta_data.field("configuration");
+
+        for (i, action) in actions.iter_mut().enumerate() {
+            if !meta_data.is_valid(i) {
+                continue;
+            }
+
+            action.meta_data = Some(MetadataAction {
+                id: id
+                    .as_ref()
+                    .and_then(|ca| ca.get(i))
+                    .unwrap_or_default()
+                    .to_string(),
+                schema_string: required(
+                    schema_string.as_ref().and_then(|ca| ca.get(i)),
+                    "metaData.schemaString",
+                )?
+                .to_string(),
+                partition_columns: match &partition_columns {
+                    Some(s) => string_list_at(s, i)?,
+                    None => vec![],
+                },
+                configuration: match &configuration {
+                    Some(s) => string_map_at(s, i)?,
+                    None => BTreeMap::new(),
+                },
+            });
+        }
+    }
+
+    if let Some(protocol) = StructColumn::new(&df, "protocol")? {
+        let min_reader_version = protocol.i64_field("minReaderVersion")?;
+        let min_writer_version = protocol.i64_field("minWriterVersion")?;
+        let reader_features = protocol.field("readerFeatures");
+        let writer_features = protocol.field("writerFeatures");
+
+        for (i, action) in actions.iter_mut().enumerate() {
+            if !protocol.is_valid(i) {
+                continue;
+            }
+
+            action.protocol = Some(ProtocolAction {
+                min_reader_version: required(
+                    min_reader_version.as_ref().and_then(|ca| ca.get(i)),
+                    "protocol.minReaderVersion",
+                )? as i32,
+                min_writer_version: min_writer_version
+                    .as_ref()
+                    .and_then(|ca| ca.get(i))
+                    .unwrap_or_default() as i32,
+                reader_features: match &reader_features {
+                    Some(s) if s.get(i)?.is_null() => None,
+                    Some(s) => Some(string_list_at(s, i)?),
+                    None => None,
+                },
+                writer_features: match &writer_features {
+                    Some(s) if s.get(i)?.is_null() => None,
+                    Some(s) => Some(string_list_at(s, i)?),
+                    None => None,
+                },
+            });
+        }
+    }
+
+    Ok(actions)
+}
+
+/// A struct column of a checkpoint, e.g. `add`.
+struct StructColumn {
+    validity: BooleanChunked,
+    fields: StructChunked,
+}
+
+impl StructColumn {
+    fn new(df: &DataFrame, name: &str) -> PolarsResult<Option<Self>> {
+        let Some(column) = df.column(name).ok() else {
+            return Ok(None);
+        };
+        let fields = column.struct_()?.clone();
+
+        Ok(Some(Self {
+            validity: column.is_not_null(),
+            fields,
+        }))
+    }
+
+    fn is_valid(&self, idx: usize) -> bool {
+        self.validity.get(idx).unwrap_or(false)
+    }
+
+    fn field(&self, name: &str) -> Option<Series> {
+        self.fields.field_by_name(name).ok()
+    }
+
+    fn str_field(&self, name: &str) -> PolarsResult<Option<StringChunked>> {
+        self.field(name).map(|s| s.str().cloned()).transpose()
+    }
+
+    fn i64_field(&self, name: &str) -> PolarsResult<Option<Int64Chunked>> {
+        self.field(name)
+            .map(|s| s.cast(&DataType::Int64).map(|s| s.i64().unwrap().clone()))
+            .transpose()
+    }
+}
+
+fn required<T>(value: Option<T>, name: &str) -> PolarsResult<T> {
+    value.ok_or_else(|| polars_err!(ComputeError: "missing {} in Delta checkpoint", name))
+}
+
+/// Reads a `map<string, string>` value, which is a list of key-value structs.
+fn string_map_at(s: &Series, idx: usize) -> PolarsResult<BTreeMap<String, Option<String>>> {
+    let Some(entries) = s.list()?.get_as_series(idx) else {
+        return Ok(BTreeMap::new());
+    };
+    let entries = entries.struct_()?;
+    let keys = entries.field_by_name("key")?;
+    let values = entries.field_by_name("value")?;
+
+    Ok(keys
+        .str()?
+        .iter()
+        .zip(values.str()?.iter())
+        .filter_map(|(k, v)| Some((k?.to_string(), v.map(str::to_string))))
+        .collect())
+}
+
+fn string_list_at(s: &Series, idx: usize) -> PolarsResult<Vec<String>> {
+    let Some(values) = s.list()?.get_as_series(idx) else {
+        return Ok(vec![]);
+    };
+
+    Ok(values.str()?.iter().flatten().map(str::to_string).collect())
+}
+
+fn deletion_vector_at(s: &Series, idx: usize) -> PolarsResult<Option<DeletionVectorDescriptor>> {
+    if s.get(idx)?.is_null() {
+        return Ok(None);
+    }
+
+    let ca = s.struct_()?;
+    let str_at = |name: &str| -> PolarsResult<Option<String>> {
+        Ok(ca.field_by_name(name)?.str()?.get(idx).map(str::to_string))
+    };
+    let i64_at = |name: &str| -> PolarsResult<Option<i64>> {
+        match ca.field_by_name(name) {
+            Ok(s) => Ok(s.cast(&DataType::Int64)?.i64()?.get(idx)),
+            Err(_) => Ok(None),
+        }
+    };
+
+    Ok(Some(DeletionVectorDescriptor {
+        storage_type: required(str_at("storageType")?, "deletionVector.storageType")?,
+        path_or_inline_dv: required(str_at("pathOrInlineDv")?, "deletionVector.pathOrInlineDv")?,
+        offset: i64_at("offset")?,
+        size_in_bytes: required(i64_at("sizeInBytes")?, "deletionVector.sizeInBytes")?,
+        cardinality: required(i64_at("cardinality")?, "deletionVector.cardinality")?,
+    }))
+}
+
+/// Names of the files in `_delta_log` that this reader uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogFileName {
+    Commit {
+        version: i64,
+    },
+    Checkpoint {
+        version: i64,
+        /// `(part, num_parts)` of a multi-part checkpoint.
+        part: Option<(u32, u32)>,
+    },
+}
+
+impl LogFileName {
+    /// Parses the name of a log file, returning `None` for files that are not commits or
+    /// checkpoints (e.g. `_last_checkpoint` or CRC files).
+    pub fn parse(name: &str) -> Option<Self> {
+        fn version(s: &str) -> Option<i64> {
+            (s.len() == 20 && s.bytes().all(|b| b.is_ascii_digit()))
+                .then(|| s.parse().ok())
+                .flatten()
+        }
+
+        if let Some(v) = name.strip_suffix(".json") {
+            return Some(Self::Commit {
+                version: version(v)?,
+            });
+        }
+
+        let rest = name.strip_suffix(".parquet")?;
+        let (v, rest) = rest.split_once(".checkpoint")?;
+        let version = version(v)?;
+
+        let part = match rest {
+            "" => None,
+            rest => {
+                // `.{part:010}.{num_parts:010}`
+                let (part, num_parts) = rest.strip_prefix('.')?.split_once('.')?;
+                Some((part.parse().ok()?, num_parts.parse().ok()?))
+            },
+        };
+
+        Some(Self::Checkpoint { version, part })
+    }
+}
+
+/// Parses a JSON object of per-file statistics into `(num_records, min, max, null_count)`, keyed
+/// by physical column name.
+#[allow(clippy::type_complexity)]
+pub fn parse_stats(
+    stats: &str,
+) -> PolarsResult<(
+    Option<i64>,
+    PlHashMap<String, serde_json::Value>,
+    PlHashMap<String, serde_json::Value>,
+    PlHashMap<String, serde_json::Value>,
+)> {
+    #[derive(serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Stats {
+        #[serde(default)]
+        num_records: Option<i64>,
+        #[serde(default)]
+        min_values: PlHashMap<String, serde_json::Value>,
+        #[serde(default)]
+        max_values: PlHashMap<String, serde_json::Value>,
+        #[serde(default)]
+        null_count: PlHashMap<String, serde_json::Value>,
+    }
+
+    let stats: Stats = serde_json::from_str(stats).map_err(to_compute_err)?;
+    Ok((
+        stats.num_records,
+        stats.min_values,
+        stats.max_values,
+        stats.null_count,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_file_name() {
+        assert_eq!(
+            LogFileName::parse("00000000000000000003.json"),
+            Some(LogFileName::Commit { version: 3 })
+        );
+        assert_eq!(
+            LogFileName::parse("00000000000000000010.checkpoint.parquet"),
+            Some(LogFileName::Checkpoint {
+                version: 10,
+                part: None
+            })
+        );
+        assert_eq!(
+            LogFileName::parse("00000000000000000010.checkpoint.0000000002.0000000003.parquet"),
+            Some(LogFileName::Checkpoint {
+                version: 10,
+                part: Some((2, 3))
+            })
+        );
+        assert_eq!(LogFileName::parse("_last_checkpoint"), None);
+        assert_eq!(LogFileName::parse("00000000000000000003.crc"), None);
+        assert_eq!(LogFileName::parse("3.json"), None);
+    }
+
+    #[test]
+    fn test_parse_commit() {
+        let commit = br#"{"commitInfo":{"timestamp":1,"operation":"WRITE"}}
+{"add":{"path":"a=1/part-0.parquet","partitionValues":{"a":"1"},"size":10,"modificationTime":1,"dataChange":true,"stats":"{\"numRecords\":2}"}}
+{"remove":{"path":"part-1.parquet","deletionTimestamp":1,"dataChange":true}}
+"#;
+        let actions = parse_commit(commit).unwrap();
+        assert_eq!(actions.len(), 3);
+
+        let add = actions[1].add.as_ref().unwrap();
+        assert_eq!(add.path, "a=1/part-0.parquet");
+        assert_eq!(add.partition_values["a"].as_deref(), Some("1"));
+        assert_eq!(add.stats.as_deref(), Some(r#"{"numRecords":2}"#));
+        assert_eq!(actions[2].remove.as_ref().unwrap().path, "part-1.parquet");
+    }
+}
@@ -0,0 +1,392 @@
+//! Decoding of Delta Lake deletion vectors.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vector-format>.
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+
+/// Magic number at the start of a serialized `RoaringBitmapArray`.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+const ROARING_SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const ROARING_SERIAL_COOKIE: u32 = 12347;
+const ROARING_NO_OFFSET_THRESHOLD: usize = 4;
+const ROARING_MAX_ARRAY_CONTAINER_CARDINALITY: usize = 4096;
+const ROARING_BITSET_CONTAINER_WORDS: usize = 1024;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Length of the Z85 encoded UUID at the end of a relative deletion vector path.
+const ENCODED_UUID_LEN: usize = 20;
+
+/// Decodes a Z85 encoded string, as used for inline deletion vectors and deletion vector UUIDs.
+pub fn z85_decode(encoded: &str) -> PolarsResult<Vec<u8>> {
+    let mut lookup = [u8::MAX; 256];
+    for (i, c) in Z85_ALPHABET.iter().enumerate() {
+        lookup[*c as usize] = i as u8;
+    }
+
+    let encoded = encoded.as_bytes();
+    polars_ensure!(
+        encoded.len() % 5 == 0,
+        ComputeError: "invalid Z85 data: length {} is not a multiple of 5", encoded.len()
+    );
+
+    let mut out = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks_exact(5) {
+        let mut value: u64 = 0;
+        for c in chunk {
+            let digit = lookup[*c as usize];
+            polars_ensure!(
+                digit != u8::MAX,
+                ComputeError: "invalid Z85 data: unexpected character {:?}", *c as char
+            );
+            value = value * 85 + digit as u64;
+        }
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid Z85 data: value out of range"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    Ok(out)
+}
+
+/// Resolves the path of a deletion vector stored relative to the table root (storage type `u`).
+///
+/// Such a path consists of an optional prefix directory followed by the Z85 encoded UUID of the
+/// file.
+pub fn relative_deletion_vector_path(
+    table_root: &str,
+    path_or_inline_dv: &str,
+) -> PolarsResult<String> {
+    let Some(prefix_len) = path_or_inline_dv.len().checked_sub(ENCODED_UUID_LEN) else {
+        polars_bail!(
+            ComputeError: "invalid deletion vector path: '{}' is too short", path_or_inline_dv
+        );
+    };
+    polars_ensure!(
+        path_or_inline_dv.is_char_boundary(prefix_len),
+        ComputeError: "invalid deletion vector path: '{}'", path_or_inline_dv
+    );
+    let (prefix, encoded_uuid) = path_or_inline_dv.split_at(prefix_len);
+    let uuid = z85_decode(encoded_uuid)?;
+
+    let hex = |bytes: &[u8]| bytes.iter().map(|b| format!("{b:02x}")).collect::<String>();
+    let file_name = format!(
+        "deletion_vector_{}-{}-{}-{}-{}.bin",
+        hex(&uuid[0..4]),
+        hex(&uuid[4..6]),
+        hex(&uuid[6..8]),
+        hex(&uuid[8..10]),
+        hex(&uuid[10..16]),
+    );
+
+    let table_root = table_root.trim_end_matches('/');
+    Ok(if prefix.is_empty() {
+        format!("{table_root}/{file_name}")
+    } else {
+        format!("{table_root}/{prefix}/{file_name}")
+    })
+}
+
+/// Returns the serialized deletion vector from a deletion vector file. `bytes` must start at the
+/// offset of the deletion vector, which is prefixed by its size and followed by its CRC32.
+pub fn deletion_vector_from_file_bytes(bytes: &[u8], size_in_bytes: usize) -> PolarsResult<&[u8]> {
+    let mut reader = ByteReader { bytes };
+    let size = u32::from_be_bytes(reader.take_array()?) as usize;
+    polars_ensure!(
+        size == size_in_bytes,
+        ComputeError: "invalid deletion vector: stored size {} does not match expected size {}",
+        size, size_in_bytes
+    );
+    let data = reader.take(size)?;
+    let checksum = u32::from_be_bytes(reader.take_array()?);
+
+    let mut crc = flate2::Crc::new();
+    crc.update(data);
+    polars_ensure!(
+        crc.sum() == checksum,
+        ComputeError: "invalid deletion vector: checksum mismatch"
+    );
+    Ok(data)
+}
+
+/// Decodes a serialized deletion vector into a mask in which the deleted rows are unset.
+///
+/// The mask ends at the last deleted row.
+pub fn deletion_vector_to_mask(bytes: &[u8], cardinality: usize) -> PolarsResult<Bitmap> {
+    let mut reader = ByteReader { bytes };
+    polars_ensure!(
+        reader.u32()? == DELETION_VECTOR_MAGIC,
+        ComputeError: "invalid deletion vector: unexpected magic number"
+    );
+
+    let mut deleted_rows = Vec::with_capacity(cardinality);
+    let num_bitmaps = reader.u64()?;
+    for _ in 0..num_bitmaps {
+        let high = (reader.u32()? as u64) << 32;
+        read_roaring_bitmap(&mut reader, |low| deleted_rows.push(high | low as u64))?;
+    }
+
+    polars_ensure!(
+        deleted_rows.len() == cardinality,
+        ComputeError: "invalid deletion vector: contains {} rows, expected {}",
+        deleted_rows.len(), cardinality
+    );
+
+    let Some(&last) = deleted_rows.last() else {
+        return Ok(Bitmap::new());
+    };
+    let len = usize::try_from(last)
+        .ok()
+        .and_then(|last| last.checked_add(1))
+        .ok_or_else(
+            || polars_err!(ComputeError: "invalid deletion vector: row index out of range"),
+        )?;
+
+    let mut mask = MutableBitmap::from_len_set(len);
+    for idx in deleted_rows {
+        mask.set(idx as usize, false);
+    }
+
+    Ok(mask.freeze())
+}
+
+/// Reads a 32-bit roaring bitmap in the portable serialization format, calling `f` for every value
+/// in ascending order.
+fn read_roaring_bitmap(reader: &mut ByteReader, mut f: impl FnMut(u32)) -> PolarsResult<()> {
+    let cookie = reader.u32()?;
+    let (num_containers, run_flags) = if cookie & 0xFFFF == ROARING_SERIAL_COOKIE {
+        let num_containers = (cookie >> 16) as usize + 1;
+        (
+            num_containers,
+            Some(reader.take(num_containers.div_ceil(8))?),
+        )
+    } else if cookie == ROARING_SERIAL_COOKIE_NO_RUNCONTAINER {
+        (reader.u32()? as usize, None)
+    } else {
+        polars_bail!(ComputeError: "invalid deletion vector: unexpected roaring bitmap cookie {}", cookie)
+    };
+
+    let header = reader.take(num_containers.checked_mul(4).ok_or_else(
+        || polars_err!(ComputeError: "invalid deletion vector: too many containers"),
+    )?)?;
+
+    if run_flags.is_none() || num_containers >= ROARING_NO_OFFSET_THRESHOLD {
+        // Offsets to the containers, which we don't need as we read them in order.
+        reader.take(num_containers * 4)?;
+    }
+
+    for (i, entry) in header.chunks_exact(4).enumerate() {
+        let key = (u16::from_le_bytes([entry[0], entry[1]]) as u32) << 16;
+        let cardinality = u16::from_le_bytes([entry[2], entry[3]]) as usize + 1;
+        let is_run = run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+        if is_run {
+            let num_runs = reader.u16()?;
+            for _ in 0..num_runs {
+                let start = reader.u16()? as u32;
+                let len = reader.u16()? as u32;
+                (start..=start + len).for_each(|low| f(key | low));
+            }
+        } else if cardinality > ROARING_MAX_ARRAY_CONTAINER_CARDINALITY {
+            for word_idx in 0..ROARING_BITSET_CONTAINER_WORDS {
+                let mut word = reader.u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros();
+                    f(key | (word_idx as u32 * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        } else {
+            for _ in 0..cardinality {
+                f(key | reader.u16()? as u32);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            n <= self.bytes.len(),
+            ComputeError: "invalid deletion vector: unexpected end of data"
+        );
+        let (out, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(out)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u16(&mut self) -> PolarsResult<u16> {
+        self.take_array().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        self.take_array().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> PolarsResult<u64> {
+        self.take_array().map(u64::from_le_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn z85_encode(bytes: &[u8]) -> String {
+        bytes
+            .chunks_exact(4)
+            .flat_map(|chunk| {
+                let mut value = u32::from_be_bytes(chunk.try_into().unwrap()) as u64;
+                let mut out = [0u8; 5];
+                for c in out.iter_mut().rev() {
+                    *c = Z85_ALPHABET[(value % 85) as usize];
+                    value /= 85;
+                }
+                out
+            })
+            .map(|c| c as char)
+            .collect()
+    }
+
+    /// Serializes a deletion vector with one bitmap using array containers.
+    fn serialize(rows: &[u32]) -> Vec<u8> {
+        let mut containers: Vec<(u16, Vec<u16>)> = vec![];
+        for row in rows {
+            let (key, low) = ((row >> 16) as u16, *row as u16);
+            match containers.last_mut() {
+                Some((k, values)) if *k == key => values.push(low),
+                _ => containers.push((key, vec![low])),
+            }
+        }
+
+        let mut out = vec![];
+        out.extend(DELETION_VECTOR_MAGIC.to_le_bytes());
+        out.extend(1u64.to_le_bytes());
+        out.extend(0u32.to_le_bytes());
+        out.extend(ROARING_SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        out.extend((containers.len() as u32).to_le_bytes());
+        for (key, values) in &containers {
+            out.extend(key.to_le_bytes());
+            out.extend((values.len() as u16 - 1).to_le_bytes());
+        }
+        // Offsets are not read.
+        out.extend(vec![0u8; containers.len() * 4]);
+        for (_, values) in &containers {
+            values.iter().for_each(|v| out.extend(v.to_le_bytes()));
+        }
+        out
+    }
+
+    fn unset_bits(mask: &Bitmap) -> Vec<usize> {
+        mask.iter()
+            .enumerate()
+            .filter_map(|(i, keep)| (!keep).then_some(i))
+            .collect()
+    }
+
+    #[test]
+    fn test_z85_roundtrip() {
+        let bytes = [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B];
+        assert_eq!(z85_encode(&bytes), "HelloWorld");
+        assert_eq!(z85_decode("HelloWorld").unwrap(), bytes);
+        assert!(z85_decode("Hello").is_ok());
+        assert!(z85_decode("Hell").is_err());
+        assert!(z85_decode("Hell~").is_err());
+    }
+
+    #[test]
+    fn test_relative_deletion_vector_path() {
+        let uuid: Vec<u8> = (0..16).collect();
+        let encoded = z85_encode(&uuid);
+        let file_name = "deletion_vector_00010203-0405-0607-0809-0a0b0c0d0e0f.bin";
+
+        assert_eq!(
+            relative_deletion_vector_path("s3://bucket/table/", &encoded).unwrap(),
+            format!("s3://bucket/table/{file_name}")
+        );
+        assert_eq!(
+            relative_deletion_vector_path("/table", &format!("ab{encoded}")).unwrap(),
+            format!("/table/ab/{file_name}")
+        );
+        assert!(relative_deletion_vector_path("/table", "abc").is_err());
+    }
+
+    #[test]
+    fn test_deletion_vector_to_mask() {
+        let rows = [1, 3, 4, 70_000];
+        let serialized = serialize(&rows);
+        let mask = deletion_vector_to_mask(&serialized, rows.len()).unwrap();
+        assert_eq!(mask.len(), 70_001);
+        assert_eq!(unset_bits(&mask), [1, 3, 4, 70_000]);
+
+        assert!(deletion_vector_to_mask(&serialized, 3).is_err());
+        assert!(deletion_vector_to_mask(&serialized[..serialized.len() - 1], 4).is_err());
+
+        let mut file = vec![1u8];
+        file.extend((serialized.len() as u32).to_be_bytes());
+        file.extend(&serialized);
+        let mut crc = flate2::Crc::new();
+        crc.update(&serialized);
+        file.extend(crc.sum().to_be_bytes());
+        assert_eq!(
+            deletion_vector_from_file_bytes(&file[1..], serialized.len()).unwrap(),
+            serialized
+        );
+
+        assert!(
+            deletion_vector_from_file_bytes(&file[1..file.len() - 1], serialized.len()).is_err()
+        );
+        *file.last_mut().unwrap() ^= 1;
+        assert!(deletion_vector_from_file_bytes(&file[1..], serialized.len()).is_err());
+    }
+
+    #[test]
+    fn test_deletion_vector_run_and_bitset_containers() {
+        let mut out = vec![];
+        out.extend(DELETION_VECTOR_MAGIC.to_le_bytes());
+        out.extend(2u64.to_le_bytes());
+        out.extend(0u32.to_le_bytes());
+        out.extend(ROARING_SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        out.extend(0u32.to_le_bytes());
+        out.extend(1u32.to_le_bytes());
+        // Two containers, the first is a run container.
+        out.extend((ROARING_SERIAL_COOKIE | (1 << 16)).to_le_bytes());
+        out.push(0b01);
+        out.extend(0u16.to_le_bytes());
+        out.extend(4u16.to_le_bytes());
+        out.extend(1u16.to_le_bytes());
+        out.extend(4999u16.to_le_bytes());
+        // Run container: [2, 6].
+        out.extend(1u16.to_le_bytes());
+        out.extend(2u16.to_le_bytes());
+        out.extend(4u16.to_le_bytes());
+        // Bitset container: the first 5000 values.
+        let mut words = [0u64; ROARING_BITSET_CONTAINER_WORDS];
+        (0..5000).for_each(|i| words[i / 64] |= 1 << (i % 64));
+        words.iter().for_each(|w| out.extend(w.to_le_bytes()));
+
+        // Only the low bits of the row index are checked, as the second bitmap starts at 2^32.
+        let mut deleted_rows = vec![];
+        let mut reader = ByteReader { bytes: &out[28..] };
+        read_roaring_bitmap(&mut reader, |low| deleted_rows.push(low)).unwrap();
+        let expected = (2..=6)
+            .chain((0..5000).map(|i| (1 << 16) | i))
+            .collect::<Vec<_>>();
+        assert_eq!(deleted_rows, expected);
+        assert!(reader.bytes.is_empty());
+        assert!(deletion_vector_to_mask(&out, 5004).is_err());
+    }
+}
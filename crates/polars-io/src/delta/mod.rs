@@ -0,0 +1,15 @@
+#[cfg(feature = "delta")]
+pub mod actions;
+#[cfg(feature = "delta")]
+pub mod deletion_vector;
+#[cfg(feature = "delta")]
+pub mod schema;
+#[cfg(feature = "delta")]
+mod snapshot;
+#[cfg(feature = "delta")]
+mod write;
+
+#[cfg(feature = "delta")]
+pub use snapshot::{DeltaDataFile, DeltaSnapshot, DeltaVersion};
+#[cfg(feature = "delta")]
+pub use write::{DeltaCommit, DeltaWriteMode};
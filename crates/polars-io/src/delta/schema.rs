@@ -0,0 +1,350 @@
+//! Conversion of the Delta Lake table schema to Polars data types.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#schema-serialization-format>.
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_err, to_compute_err};
+use polars_utils::pl_str::PlSmallStr;
+use serde_json::Value;
+
+const PHYSICAL_NAME_KEY: &str = "delta.columnMapping.physicalName";
+
+/// Schema of a Delta table.
+///
+/// With column mapping enabled, the columns of the data files are named by the physical names
+/// given in the field metadata, which may differ from the (logical) names of the table.
+#[derive(Debug, Clone)]
+pub struct DeltaSchema {
+    pub logical: SchemaRef,
+    pub physical: SchemaRef,
+}
+
+impl DeltaSchema {
+    /// Parses the `schemaString` of a metadata action.
+    pub fn parse(schema_string: &str, column_mapping: bool) -> PolarsResult<Self> {
+        let value: Value = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+        let fields = struct_fields(&value, column_mapping)?;
+
+        let logical = fields
+            .iter()
+            .map(|f| Field::new(f.logical_name.clone(), f.logical_dtype.clone()))
+            .collect::<Schema>();
+        let physical = fields
+            .iter()
+            .map(|f| Field::new(f.physical_name.clone(), f.physical_dtype.clone()))
+            .collect::<Schema>();
+
+        polars_ensure!(
+            logical.len() == fields.len() && physical.len() == fields.len(),
+            ComputeError: "duplicate column names in Delta table schema"
+        );
+
+        Ok(Self {
+            logical: Arc::new(logical),
+            physical: Arc::new(physical),
+        })
+    }
+
+    /// Logical name of the column with the given physical name.
+    pub fn logical_name(&self, physical_name: &str) -> Option<&PlSmallStr> {
+        let idx = self.physical.index_of(physical_name)?;
+        self.logical.get_at_index(idx).map(|(name, _)| name)
+    }
+
+    /// Physical name of the column with the given logical name.
+    pub fn physical_name(&self, logical_name: &str) -> Option<&PlSmallStr> {
+        let idx = self.logical.index_of(logical_name)?;
+        self.physical.get_at_index(idx).map(|(name, _)| name)
+    }
+}
+
+struct DeltaField {
+    logical_name: PlSmallStr,
+    physical_name: PlSmallStr,
+    logical_dtype: DataType,
+    physical_dtype: DataType,
+}
+
+fn struct_fields(value: &Value, column_mapping: bool) -> PolarsResult<Vec<DeltaField>> {
+    let fields = value
+        .get("fields")
+        .and_then(Value::as_array)
+        .ok_or_else(|| polars_err!(ComputeError: "missing fields of Delta struct type"))?;
+
+    fields
+        .iter()
+        .map(|field| {
+            let name = field
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| polars_err!(ComputeError: "missing name of Delta struct field"))?;
+            let physical_name = match column_mapping {
+                true => field
+                    .get("metadata")
+                    .and_then(|m| m.get(PHYSICAL_NAME_KEY))
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| {
+                        polars_err!(
+                            ComputeError:
+                            "missing physical name of column '{}' in Delta table with column mapping",
+                            name
+                        )
+                    })?,
+                false => name,
+            };
+            let ty = field
+                .get("type")
+                .ok_or_else(|| polars_err!(ComputeError: "missing type of Delta field '{}'", name))?;
+
+            Ok(DeltaField {
+                logical_name: name.into(),
+                physical_name: physical_name.into(),
+                logical_dtype: parse_type(ty, false)?,
+                physical_dtype: parse_type(ty, column_mapping)?,
+            })
+        })
+        .collect()
+}
+
+/// Parses a Delta type, which is either the name of a primitive type or an object for nested
+/// types. With `physical` set, the fields of nested structs use their physical names.
+fn parse_type(value: &Value, physical: bool) -> PolarsResult<DataType> {
+    let name = match value {
+        Value::String(name) => return parse_primitive_type(name),
+        Value::Object(obj) => obj
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid Delta type: {}", value))?,
+        _ => polars_bail!(ComputeError: "invalid Delta type: {}", value),
+    };
+
+    let get = |key: &str| {
+        value
+            .get(key)
+            .ok_or_else(|| polars_err!(ComputeError: "missing {} of Delta {} type", key, name))
+    };
+
+    Ok(match name {
+        "struct" => DataType::Struct(
+            struct_fields(value, physical)?
+                .into_iter()
+                .map(|f| match physical {
+                    true => Field::new(f.physical_name, f.physical_dtype),
+                    false => Field::new(f.logical_name, f.logical_dtype),
+                })
+                .collect(),
+        ),
+        "array" => DataType::List(Box::new(parse_type(get("elementType")?, physical)?)),
+        "map" => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(
+                PlSmallStr::from_static("key"),
+                parse_type(get("keyType")?, physical)?,
+            ),
+            Field::new(
+                PlSmallStr::from_static("value"),
+                parse_type(get("valueType")?, physical)?,
+            ),
+        ]))),
+        name => polars_bail!(ComputeError: "unsupported Delta type: {}", name),
+    })
+}
+
+fn parse_primitive_type(name: &str) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match name {
+        "string" => String,
+        "long" => Int64,
+        "integer" => Int32,
+        "short" => Int16,
+        "byte" => Int8,
+        "float" => Float32,
+        "double" => Float64,
+        "boolean" => Boolean,
+        "binary" => Binary,
+        "date" => Date,
+        "timestamp" => Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ntz" => Datetime(TimeUnit::Microseconds, None),
+        v if v.starts_with("decimal") => {
+            // e.g. decimal(10,2)
+            (|| {
+                let (precision, scale) = v
+                    .strip_prefix("decimal(")?
+                    .strip_suffix(')')?
+                    .split_once(',')?;
+                Some(Decimal(
+                    Some(precision.trim().parse().ok()?),
+                    Some(scale.trim().parse().ok()?),
+                ))
+            })()
+            .ok_or_else(|| polars_err!(ComputeError: "invalid Delta decimal type: {}", v))?
+        },
+        v => polars_bail!(ComputeError: "unsupported Delta type: {}", v),
+    })
+}
+
+/// Serializes a Polars schema to the `schemaString` of a metadata action, without column mapping.
+///
+/// Datetimes must use microseconds; they become `timestamp` with a time zone and `timestamp_ntz`
+/// without one.
+pub fn to_schema_string(schema: &Schema) -> PolarsResult<String> {
+    Ok(struct_type(schema.iter())?.to_string())
+}
+
+fn struct_type<'a>(
+    fields: impl Iterator<Item = (&'a PlSmallStr, &'a DataType)>,
+) -> PolarsResult<Value> {
+    let fields = fields
+        .map(|(name, dtype)| {
+            Ok(serde_json::json!({
+                "name": name.as_str(),
+                "type": to_delta_type(dtype)?,
+                "nullable": true,
+                "metadata": {},
+            }))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(serde_json::json!({"type": "struct", "fields": fields}))
+}
+
+fn to_delta_type(dtype: &DataType) -> PolarsResult<Value> {
+    use DataType::*;
+
+    let name = match dtype {
+        String => "string",
+        Int64 => "long",
+        Int32 => "integer",
+        Int16 => "short",
+        Int8 => "byte",
+        Float32 => "float",
+        Float64 => "double",
+        Boolean => "boolean",
+        Binary => "binary",
+        Date => "date",
+        Datetime(TimeUnit::Microseconds, Some(_)) => "timestamp",
+        Datetime(TimeUnit::Microseconds, None) => "timestamp_ntz",
+        Decimal(Some(precision), Some(scale)) => {
+            return Ok(Value::String(format!("decimal({precision},{scale})")));
+        },
+        List(inner) => {
+            return Ok(serde_json::json!({
+                "type": "array",
+                "elementType": to_delta_type(inner)?,
+                "containsNull": true,
+            }));
+        },
+        Struct(fields) => return struct_type(fields.iter().map(|f| (f.name(), f.dtype()))),
+        dt => polars_bail!(
+            ComputeError:
+            "data type {} cannot be written to a Delta table, cast it to a supported type",
+            dt
+        ),
+    };
+
+    Ok(Value::String(name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema() {
+        let schema_string = r#"{"type":"struct","fields":[
+            {"name":"id","type":"long","nullable":true,"metadata":{}},
+            {"name":"ts","type":"timestamp","nullable":true,"metadata":{}},
+            {"name":"amount","type":"decimal(10, 2)","nullable":true,"metadata":{}},
+            {"name":"tags","type":{"type":"map","keyType":"string","valueType":"integer",
+                "valueContainsNull":true},"nullable":true,"metadata":{}},
+            {"name":"point","type":{"type":"struct","fields":[
+                {"name":"x","type":"double","nullable":true,"metadata":{}}]},
+                "nullable":true,"metadata":{}}
+        ]}"#;
+
+        let schema = DeltaSchema::parse(schema_string, false).unwrap();
+        assert_eq!(schema.logical, schema.physical);
+        assert_eq!(
+            schema.logical.as_ref(),
+            &Schema::from_iter([
+                Field::new("id".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+                ),
+                Field::new("amount".into(), DataType::Decimal(Some(10), Some(2))),
+                Field::new(
+                    "tags".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+                Field::new(
+                    "point".into(),
+                    DataType::Struct(vec![Field::new("x".into(), DataType::Float64)])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_schema_column_mapping() {
+        let schema_string = r#"{"type":"struct","fields":[
+            {"name":"a","type":"long","nullable":true,
+                "metadata":{"delta.columnMapping.id":1,"delta.columnMapping.physicalName":"col-1"}},
+            {"name":"s","type":{"type":"struct","fields":[
+                {"name":"b","type":"string","nullable":true,
+                    "metadata":{"delta.columnMapping.id":3,"delta.columnMapping.physicalName":"col-3"}}]},
+                "nullable":true,
+                "metadata":{"delta.columnMapping.id":2,"delta.columnMapping.physicalName":"col-2"}}
+        ]}"#;
+
+        let schema = DeltaSchema::parse(schema_string, true).unwrap();
+        assert_eq!(
+            schema.physical.as_ref(),
+            &Schema::from_iter([
+                Field::new("col-1".into(), DataType::Int64),
+                Field::new(
+                    "col-2".into(),
+                    DataType::Struct(vec![Field::new("col-3".into(), DataType::String)])
+                ),
+            ])
+        );
+        assert_eq!(
+            schema.logical.get("s"),
+            Some(&DataType::Struct(vec![Field::new(
+                "b".into(),
+                DataType::String
+            )]))
+        );
+        assert_eq!(schema.logical_name("col-2").map(|s| s.as_str()), Some("s"));
+        assert_eq!(schema.physical_name("a").map(|s| s.as_str()), Some("col-1"));
+    }
+
+    #[test]
+    fn test_schema_string_roundtrip() {
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int16),
+            Field::new(
+                "ts".into(),
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            ),
+            Field::new("amount".into(), DataType::Decimal(Some(10), Some(2))),
+            Field::new(
+                "points".into(),
+                DataType::List(Box::new(DataType::Struct(vec![Field::new(
+                    "x".into(),
+                    DataType::Float64,
+                )]))),
+            ),
+        ]);
+
+        let schema_string = to_schema_string(&schema).unwrap();
+        let parsed = DeltaSchema::parse(&schema_string, false).unwrap();
+        assert_eq!(parsed.logical.as_ref(), &schema);
+
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::UInt32)]);
+        assert!(to_schema_string(&schema).is_err());
+    }
+}
@@ -0,0 +1,1043 @@
+//! Reconstruction of a Delta Lake table snapshot by replaying its transaction log.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#action-reconciliation>.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::plpath::{PlPath, PlPathRef};
+use serde_json::Value;
+
+use super::actions::{
+    Action, AddAction, DeletionVectorDescriptor, LogFileName, MetadataAction, ProtocolAction,
+    parse_checkpoint, parse_commit, parse_stats,
+};
+use super::schema::DeltaSchema;
+use crate::cloud::CloudOptions;
+use crate::predicates::SkipBatchPredicate;
+
+/// Highest reader protocol version that is supported.
+const MAX_READER_VERSION: i32 = 3;
+
+/// Reader features of protocol version 3 that are supported.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "vacuumProtocolCheck",
+];
+
+/// Version of a Delta table to load.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeltaVersion {
+    #[default]
+    Latest,
+    /// A specific version number.
+    Version(i64),
+    /// The latest version committed at or before this timestamp, in milliseconds since the
+    /// epoch.
+    Timestamp(i64),
+}
+
+/// An active data file of a snapshot.
+#[derive(Debug, Clone)]
+pub struct DeltaDataFile {
+    /// Resolved location of the file.
+    pub path: PlPath,
+    /// Path of the file as written in the log, which identifies the file in remove actions.
+    pub log_path: String,
+    /// Partition values by physical column name. `None` represents a null value.
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    /// JSON encoded statistics, if they were written.
+    pub stats: Option<String>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+/// The state of a Delta table at a specific version.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    table_root: PlPath,
+    version: i64,
+    table_id: String,
+    protocol: ProtocolAction,
+    schema: DeltaSchema,
+    /// Logical names of the partition columns.
+    partition_columns: Vec<PlSmallStr>,
+    configuration: BTreeMap<String, Option<String>>,
+    files: Vec<DeltaDataFile>,
+}
+
+impl DeltaSnapshot {
+    /// Loads the snapshot of the table at `table_root` by replaying the last checkpoint at or
+    /// before the requested version and the commits that follow it.
+    ///
+    /// Timestamps are resolved using the modification times of the commit files.
+    pub fn load(
+        table_root: PlPathRef<'_>,
+        version: DeltaVersion,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        Self::try_load(table_root, version, cloud_options)?.ok_or_else(|| {
+            polars_err!(
+                ComputeError:
+                "no Delta transaction log found at '{}'",
+                table_root.join("_delta_log").display()
+            )
+        })
+    }
+
+    /// Like [`DeltaSnapshot::load`], but returns `None` if there is no table at `table_root`.
+    pub fn try_load(
+        table_root: PlPathRef<'_>,
+        version: DeltaVersion,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Option<Self>> {
+        let store = LogStore::new(table_root, cloud_options)?;
+        let mut log_files = store.list()?;
+        log_files.sort_by(|a, b| a.name.version().cmp(&b.name.version()));
+
+        let commits: BTreeMap<i64, &LogFile> = log_files
+            .iter()
+            .filter_map(|f| match f.name {
+                LogFileName::Commit { version } => Some((version, f)),
+                _ => None,
+            })
+            .collect();
+
+        let checkpoints = complete_checkpoints(&log_files);
+
+        let Some(latest) = commits.keys().chain(checkpoints.keys()).copied().max() else {
+            return Ok(None);
+        };
+
+        let target = match version {
+            DeltaVersion::Latest => latest,
+            DeltaVersion::Version(v) => {
+                polars_ensure!(
+                    (0..=latest).contains(&v),
+                    ComputeError: "Delta table version {} does not exist, the latest version is {}",
+                    v, latest
+                );
+                v
+            },
+            DeltaVersion::Timestamp(ts) => commits
+                .iter()
+                .rev()
+                .find(|(_, f)| f.last_modified <= ts)
+                .map(|(v, _)| *v)
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "no Delta table version exists at or before timestamp {} ms",
+                        ts
+                    )
+                })?,
+        };
+
+        // Use the latest checkpoint that can be completed with the available commits.
+        let checkpoint = checkpoints
+            .range(..=target)
+            .rev()
+            .find(|(v, _)| (**v + 1..=target).all(|v| commits.contains_key(&v)));
+
+        let (start, mut replay) = match checkpoint {
+            Some((version, parts)) => {
+                let mut replay = LogReplay::default();
+                for part in parts {
+                    replay.apply(parse_checkpoint(&store.read(part)?)?);
+                }
+                (*version + 1, replay)
+            },
+            None => {
+                polars_ensure!(
+                    (0..=target).all(|v| commits.contains_key(&v)),
+                    ComputeError:
+                    "cannot reconstruct Delta table version {}: missing commits and no checkpoint",
+                    target
+                );
+                (0, LogReplay::default())
+            },
+        };
+
+        for version in start..=target {
+            replay.apply(parse_commit(&store.read(commits[&version])?)?);
+        }
+
+        replay.finish(table_root, target).map(Some)
+    }
+
+    pub fn table_root(&self) -> &PlPath {
+        &self.table_root
+    }
+
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// Unique identifier of the table, from its metadata.
+    pub fn table_id(&self) -> &str {
+        &self.table_id
+    }
+
+    pub fn protocol(&self) -> &ProtocolAction {
+        &self.protocol
+    }
+
+    /// Schema of the table, using the logical column names.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema.logical
+    }
+
+    /// Schema of the columns in the data files, using the physical column names. This includes
+    /// the partition columns.
+    pub fn physical_schema(&self) -> &SchemaRef {
+        &self.schema.physical
+    }
+
+    pub fn delta_schema(&self) -> &DeltaSchema {
+        &self.schema
+    }
+
+    /// Logical names of the partition columns.
+    pub fn partition_columns(&self) -> &[PlSmallStr] {
+        &self.partition_columns
+    }
+
+    pub fn configuration(&self) -> &BTreeMap<String, Option<String>> {
+        &self.configuration
+    }
+
+    /// Whether the physical column names differ from the logical column names.
+    pub fn has_column_mapping(&self) -> bool {
+        self.schema.logical != self.schema.physical
+    }
+
+    pub fn files(&self) -> &[DeltaDataFile] {
+        &self.files
+    }
+
+    /// Per-file statistics in the format expected by
+    /// [`SkipBatchPredicate::evaluate_with_stat_df`], with one row per file and the logical
+    /// column names.
+    ///
+    /// Statistics are missing (null) for files written without them and for column types whose
+    /// statistics are lossy in the log, such as timestamps.
+    pub fn statistics_df(&self) -> PolarsResult<DataFrame> {
+        let num_files = self.files.len();
+        let stats = self
+            .files
+            .iter()
+            .map(|f| f.stats.as_deref().map(parse_stats).transpose())
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut columns = Vec::with_capacity(1 + 3 * self.schema.logical.len());
+        columns.push(Column::new(
+            PlSmallStr::from_static("len"),
+            stats
+                .iter()
+                .map(|s| s.as_ref().and_then(|s| s.0).map(|n| n as IdxSize))
+                .collect::<IdxCa>()
+                .into_series(),
+        ));
+
+        for ((name, dtype), physical_name) in self
+            .schema
+            .logical
+            .iter()
+            .zip(self.schema.physical.iter_names())
+        {
+            let (min, max, nc): (Vec<_>, Vec<_>, Vec<_>) = if self.partition_columns.contains(name)
+            {
+                self.files
+                    .iter()
+                    .zip(&stats)
+                    .map(|(f, s)| {
+                        let value = f
+                            .partition_values
+                            .get(physical_name.as_str())
+                            .cloned()
+                            .flatten();
+                        let num_records = s.as_ref().and_then(|s| s.0);
+                        let nc = match value {
+                            Some(_) => Some(0),
+                            None => num_records,
+                        };
+                        let value = value.map(Value::String);
+                        (value.clone(), value, nc)
+                    })
+                    .collect()
+            } else {
+                stats
+                    .iter()
+                    .map(|s| match s {
+                        Some((_, min, max, nc)) => (
+                            min.get(physical_name.as_str()).cloned(),
+                            max.get(physical_name.as_str()).cloned(),
+                            nc.get(physical_name.as_str()).and_then(Value::as_i64),
+                        ),
+                        None => (None, None, None),
+                    })
+                    .collect()
+            };
+
+            columns.push(stat_column(format_pl_smallstr!("{name}_min"), &min, dtype)?);
+            columns.push(stat_column(format_pl_smallstr!("{name}_max"), &max, dtype)?);
+            columns.push(Column::new(
+                format_pl_smallstr!("{name}_nc"),
+                nc.iter()
+                    .map(|v| v.map(|v| v as IdxSize))
+                    .collect::<IdxCa>()
+                    .into_series(),
+            ));
+        }
+
+        DataFrame::new_with_height(num_files, columns)
+    }
+
+    /// Partition values of the files, with one row per file and the physical column names.
+    ///
+    /// The values are deserialized as described in
+    /// <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#partition-value-serialization>,
+    /// where both a missing value and an empty string are null.
+    pub fn partition_values_df(&self) -> PolarsResult<DataFrame> {
+        let columns = self
+            .partition_columns
+            .iter()
+            .map(|name| {
+                let physical_name = self.schema.physical_name(name).ok_or_else(|| {
+                    polars_err!(ComputeError: "partition column '{}' is not in the Delta table schema", name)
+                })?;
+                let values = self
+                    .files
+                    .iter()
+                    .map(|f| {
+                        f.partition_values
+                            .get(physical_name.as_str())
+                            .and_then(|v| v.as_deref())
+                            .filter(|v| !v.is_empty())
+                    })
+                    .collect::<Vec<_>>();
+                partition_value_column(
+                    physical_name.clone(),
+                    &values,
+                    self.schema.physical.get(physical_name).unwrap(),
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        DataFrame::new_with_height(self.files.len(), columns)
+    }
+
+    /// Removes the files that the predicate proves cannot contain matching rows, based on the
+    /// statistics of [`DeltaSnapshot::statistics_df`].
+    pub fn prune(&mut self, predicate: &dyn SkipBatchPredicate) -> PolarsResult<()> {
+        if self.files.is_empty() {
+            return Ok(());
+        }
+
+        let skip = predicate.evaluate_with_stat_df(&self.statistics_df()?)?;
+        let mut idx = 0;
+        self.files.retain(|_| {
+            let keep = !skip.get_bit(idx);
+            idx += 1;
+            keep
+        });
+
+        Ok(())
+    }
+}
+
+/// Builds a column of statistics from JSON values. Values that cannot be represented in `dtype`
+/// become null.
+fn stat_column(
+    name: PlSmallStr,
+    values: &[Option<Value>],
+    dtype: &DataType,
+) -> PolarsResult<Column> {
+    fn parse<T: std::str::FromStr>(v: &Value) -> Option<T> {
+        v.as_str()?.parse().ok()
+    }
+
+    let s = match dtype {
+        dt if dt.is_integer() => values
+            .iter()
+            .map(|v| v.as_ref().and_then(|v| v.as_i64().or_else(|| parse(v))))
+            .collect::<Int64Chunked>()
+            .into_series(),
+        dt if dt.is_float() => values
+            .iter()
+            .map(|v| v.as_ref().and_then(|v| v.as_f64().or_else(|| parse(v))))
+            .collect::<Float64Chunked>()
+            .into_series(),
+        DataType::Boolean => values
+            .iter()
+            .map(|v| v.as_ref().and_then(|v| v.as_bool().or_else(|| parse(v))))
+            .collect::<BooleanChunked>()
+            .into_series(),
+        DataType::String => values
+            .iter()
+            .map(|v| v.as_ref().and_then(Value::as_str))
+            .collect::<StringChunked>()
+            .into_series(),
+        DataType::Date => values
+            .iter()
+            .map(|v| {
+                let date =
+                    chrono::NaiveDate::parse_from_str(v.as_ref()?.as_str()?, "%Y-%m-%d").ok()?;
+                Some(
+                    date.signed_duration_since(chrono::NaiveDate::default())
+                        .num_days() as i32,
+                )
+            })
+            .collect::<Int32Chunked>()
+            .into_date()
+            .into_series(),
+        _ => Series::full_null(PlSmallStr::EMPTY, values.len(), dtype),
+    };
+
+    Ok(s.cast_with_options(dtype, CastOptions::NonStrict)?
+        .with_name(name)
+        .into_column())
+}
+
+/// Builds a column of partition values from their serialized form.
+fn partition_value_column(
+    name: PlSmallStr,
+    values: &[Option<&str>],
+    dtype: &DataType,
+) -> PolarsResult<Column> {
+    let invalid = |v: &str| {
+        polars_err!(
+            ComputeError: "invalid value '{}' of Delta partition column '{}' with type {}",
+            v, name, dtype
+        )
+    };
+
+    let s = match dtype {
+        DataType::Boolean => values
+            .iter()
+            .map(|v| {
+                v.map(|v| match v {
+                    "true" => Ok(true),
+                    "false" => Ok(false),
+                    _ => Err(invalid(v)),
+                })
+                .transpose()
+            })
+            .collect::<PolarsResult<BooleanChunked>>()?
+            .into_series(),
+        DataType::Binary => values
+            .iter()
+            .map(|v| v.map(str::as_bytes))
+            .collect::<BinaryChunked>()
+            .into_series(),
+        DataType::Date => values
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    let date = chrono::NaiveDate::parse_from_str(v, "%Y-%m-%d")
+                        .map_err(|_| invalid(v))?;
+                    Ok(date
+                        .signed_duration_since(chrono::NaiveDate::default())
+                        .num_days() as i32)
+                })
+                .transpose()
+            })
+            .collect::<PolarsResult<Int32Chunked>>()?
+            .into_date()
+            .into_series(),
+        // Timestamps are written as `2024-01-31 12:00:00[.123456]` in UTC, or in ISO 8601
+        // format.
+        DataType::Datetime(TimeUnit::Microseconds, tz) => values
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    let iso = v.strip_suffix('Z').unwrap_or(v);
+                    let datetime =
+                        chrono::NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S%.f")
+                            .or_else(|_| {
+                                chrono::NaiveDateTime::parse_from_str(iso, "%Y-%m-%dT%H:%M:%S%.f")
+                            })
+                            .map_err(|_| invalid(v))?;
+                    Ok(datetime.and_utc().timestamp_micros())
+                })
+                .transpose()
+            })
+            .collect::<PolarsResult<Int64Chunked>>()?
+            .into_datetime(TimeUnit::Microseconds, tz.clone())
+            .into_series(),
+        _ => values
+            .iter()
+            .copied()
+            .collect::<StringChunked>()
+            .with_name(name.clone())
+            .into_series()
+            .strict_cast(dtype)?,
+    };
+
+    Ok(s.with_name(name).into_column())
+}
+
+/// Applies actions in commit order.
+#[derive(Default)]
+struct LogReplay {
+    protocol: Option<ProtocolAction>,
+    metadata: Option<MetadataAction>,
+    /// Active files, keyed by path and deletion vector.
+    files: PlIndexMap<(String, Option<String>), AddAction>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, actions: Vec<Action>) {
+        for action in actions {
+            if let Some(protocol) = action.protocol {
+                self.protocol = Some(protocol);
+            }
+            if let Some(metadata) = action.meta_data {
+                self.metadata = Some(metadata);
+            }
+            if let Some(remove) = action.remove {
+                let dv_id = remove.deletion_vector.as_ref().map(|dv| dv.unique_id());
+                self.files.shift_remove(&(remove.path, dv_id));
+            }
+            if let Some(add) = action.add {
+                let dv_id = add.deletion_vector.as_ref().map(|dv| dv.unique_id());
+                self.files.insert((add.path.clone(), dv_id), add);
+            }
+        }
+    }
+
+    fn finish(self, table_root: PlPathRef<'_>, version: i64) -> PolarsResult<DeltaSnapshot> {
+        let protocol = self
+            .protocol
+            .ok_or_else(|| polars_err!(ComputeError: "missing protocol in Delta table log"))?;
+        let metadata = self
+            .metadata
+            .ok_or_else(|| polars_err!(ComputeError: "missing metadata in Delta table log"))?;
+
+        polars_ensure!(
+            protocol.min_reader_version <= MAX_READER_VERSION,
+            nyi = "Delta reader protocol version {}",
+            protocol.min_reader_version
+        );
+        if let Some(feature) = protocol
+            .reader_features
+            .iter()
+            .flatten()
+            .find(|f| !SUPPORTED_READER_FEATURES.contains(&f.as_str()))
+        {
+            polars_bail!(nyi = "Delta reader feature '{}'", feature);
+        }
+
+        let column_mapping = match metadata
+            .configuration
+            .get("delta.columnMapping.mode")
+            .cloned()
+            .flatten()
+            .as_deref()
+        {
+            None | Some("none") => false,
+            Some("name" | "id") => true,
+            Some(mode) => polars_bail!(ComputeError: "invalid Delta column mapping mode: {}", mode),
+        };
+
+        let schema = DeltaSchema::parse(&metadata.schema_string, column_mapping)?;
+
+        let partition_columns = metadata
+            .partition_columns
+            .iter()
+            .map(|name| {
+                polars_ensure!(
+                    schema.logical.contains(name),
+                    ComputeError: "Delta partition column '{}' not found in table schema", name
+                );
+                Ok(PlSmallStr::from_str(name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let files = self
+            .files
+            .into_values()
+            .map(|add| {
+                Ok(DeltaDataFile {
+                    path: resolve_path(table_root, &add.path)?,
+                    log_path: add.path,
+                    partition_values: add.partition_values,
+                    size: add.size,
+                    stats: add.stats,
+                    deletion_vector: add.deletion_vector,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(DeltaSnapshot {
+            table_root: table_root.into_owned(),
+            version,
+            table_id: metadata.id,
+            protocol,
+            schema,
+            partition_columns,
+            configuration: metadata.configuration,
+            files,
+        })
+    }
+}
+
+/// Resolves the (URI encoded) path of an add action, which is either relative to the table root
+/// or absolute.
+fn resolve_path(table_root: PlPathRef<'_>, path: &str) -> PolarsResult<PlPath> {
+    let decoded = percent_encoding::percent_decode_str(path)
+        .decode_utf8()
+        .map_err(|_| polars_err!(ComputeError: "invalid path in Delta table log: {}", path))?;
+
+    Ok(if path.contains("://") {
+        PlPath::new(&decoded)
+    } else {
+        table_root.join(decoded)
+    })
+}
+
+/// Groups the parts of the checkpoints by version, keeping only complete checkpoints.
+fn complete_checkpoints(log_files: &[LogFile]) -> BTreeMap<i64, Vec<&LogFile>> {
+    let mut parts: BTreeMap<i64, Vec<&LogFile>> = BTreeMap::new();
+    for f in log_files {
+        if let LogFileName::Checkpoint { version, .. } = f.name {
+            parts.entry(version).or_default().push(f);
+        }
+    }
+
+    parts.retain(|_, parts| {
+        // A single-part checkpoint takes precedence over multi-part checkpoints of the same
+        // version.
+        if let Some(idx) = parts
+            .iter()
+            .position(|f| matches!(f.name, LogFileName::Checkpoint { part: None, .. }))
+        {
+            *parts = vec![parts[idx]];
+            return true;
+        }
+
+        let Some(LogFileName::Checkpoint {
+            part: Some((_, num_parts)),
+            ..
+        }) = parts.first().map(|f| &f.name)
+        else {
+            return false;
+        };
+        let num_parts = *num_parts;
+
+        parts.retain(|f| {
+            matches!(f.name, LogFileName::Checkpoint { part: Some((_, n)), .. } if n == num_parts)
+        });
+        parts.sort_by_key(|f| match f.name {
+            LogFileName::Checkpoint { part, .. } => part,
+            _ => None,
+        });
+        parts.dedup_by_key(|f| f.name.clone());
+        parts.len() == num_parts as usize
+    });
+
+    parts
+}
+
+impl LogFileName {
+    fn version(&self) -> i64 {
+        match self {
+            Self::Commit { version } | Self::Checkpoint { version, .. } => *version,
+        }
+    }
+}
+
+struct LogFile {
+    name: LogFileName,
+    location: LogFileLocation,
+    /// Milliseconds since the epoch.
+    last_modified: i64,
+}
+
+enum LogFileLocation {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud {
+        path: object_store::path::Path,
+        size: usize,
+    },
+}
+
+/// Access to the `_delta_log` directory of a table.
+enum LogStore {
+    Local(PathBuf),
+    #[cfg(feature = "cloud")]
+    Cloud {
+        store: crate::cloud::PolarsObjectStore,
+        prefix: object_store::path::Path,
+    },
+}
+
+impl LogStore {
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+    fn new(table_root: PlPathRef<'_>, cloud_options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        match table_root {
+            PlPathRef::Local(path) => Ok(Self::Local(path.join("_delta_log"))),
+            PlPathRef::Cloud(_) => {
+                #[cfg(feature = "cloud")]
+                {
+                    let uri = table_root.to_str();
+                    let (location, store) = crate::pl_async::get_runtime().block_in_place_on(
+                        crate::cloud::build_object_store(uri, cloud_options, false),
+                    )?;
+                    let prefix = object_store::path::Path::from(format!(
+                        "{}/_delta_log",
+                        location.prefix.trim_end_matches('/')
+                    ));
+                    Ok(Self::Cloud { store, prefix })
+                }
+                #[cfg(not(feature = "cloud"))]
+                {
+                    polars_bail!(
+                        ComputeError:
+                        "Feature `cloud` must be enabled to read Delta tables from cloud urls"
+                    )
+                }
+            },
+        }
+    }
+
+    fn list(&self) -> PolarsResult<Vec<LogFile>> {
+        match self {
+            Self::Local(dir) => {
+                let entries = match std::fs::read_dir(dir) {
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+                    entries => entries,
+                };
+                let entries = entries.map_err(|err| {
+                    polars_err!(
+                        ComputeError:
+                        "failed to read Delta transaction log at '{}': {}",
+                        dir.display(), err
+                    )
+                })?;
+
+                let mut out = vec![];
+                for entry in entries {
+                    let entry = entry?;
+                    let Some(name) = entry.file_name().to_str().and_then(LogFileName::parse) else {
+                        continue;
+                    };
+                    let last_modified = entry
+                        .metadata()?
+                        .modified()?
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map_or(0, |d| d.as_millis() as i64);
+
+                    out.push(LogFile {
+                        name,
+                        location: LogFileLocation::Local(entry.path()),
+                        last_modified,
+                    });
+                }
+                Ok(out)
+            },
+            #[cfg(feature = "cloud")]
+            Self::Cloud { store, prefix } => {
+                let objects = crate::pl_async::get_runtime().block_in_place_on(
+                    store.try_exec_rebuild_on_err(|store| {
+                        let store = store.clone();
+                        async move {
+                            store
+                                .list_with_delimiter(Some(prefix))
+                                .await
+                                .map_err(polars_error::to_compute_err)
+                        }
+                    }),
+                )?;
+
+                Ok(objects
+                    .objects
+                    .into_iter()
+                    .filter_map(|meta| {
+                        let name = LogFileName::parse(meta.location.filename()?)?;
+                        Some(LogFile {
+                            name,
+                            last_modified: meta.last_modified.timestamp_millis(),
+                            location: LogFileLocation::Cloud {
+                                path: meta.location,
+                                size: meta.size as usize,
+                            },
+                        })
+                    })
+                    .collect())
+            },
+        }
+    }
+
+    fn read(&self, file: &LogFile) -> PolarsResult<Vec<u8>> {
+        match (self, &file.location) {
+            (_, LogFileLocation::Local(path)) => Ok(std::fs::read(path)?),
+            #[cfg(feature = "cloud")]
+            (Self::Cloud { store, .. }, LogFileLocation::Cloud { path, size }) => {
+                let bytes = crate::pl_async::get_runtime()
+                    .block_in_place_on(store.get_range(path, 0..*size))?;
+                Ok(bytes.to_vec())
+            },
+            #[cfg(feature = "cloud")]
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::bitmap::Bitmap;
+
+    use super::*;
+
+    fn write_commit(root: &std::path::Path, version: i64, actions: &[&str]) {
+        let log = root.join("_delta_log");
+        std::fs::create_dir_all(&log).unwrap();
+        std::fs::write(log.join(format!("{version:020}.json")), actions.join("\n")).unwrap();
+    }
+
+    const PROTOCOL: &str = r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#;
+    const METADATA: &str = r#"{"metaData":{"id":"1","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["p"],"configuration":{},"createdTime":0}}"#;
+
+    fn add(path: &str, p: &str, min: i64, max: i64) -> String {
+        format!(
+            r#"{{"add":{{"path":"{path}","partitionValues":{{"p":"{p}"}},"size":1,"modificationTime":0,"dataChange":true,"stats":"{{\"numRecords\":2,\"minValues\":{{\"a\":{min}}},\"maxValues\":{{\"a\":{max}}},\"nullCount\":{{\"a\":0}}}}"}}}}"#
+        )
+    }
+
+    fn file_names(snapshot: &DeltaSnapshot) -> Vec<String> {
+        snapshot
+            .files()
+            .iter()
+            .map(|f| {
+                f.path
+                    .as_ref()
+                    .to_str()
+                    .rsplit('/')
+                    .next()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_log_replay_and_time_travel() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        write_commit(
+            root,
+            0,
+            &[PROTOCOL, METADATA, &add("p=x/0.parquet", "x", 0, 9)],
+        );
+        write_commit(root, 1, &[&add("p=y/1.parquet", "y", 10, 19)]);
+        write_commit(
+            root,
+            2,
+            &[
+                r#"{"remove":{"path":"p=x/0.parquet","deletionTimestamp":0,"dataChange":true}}"#,
+                &add("p=x/2.parquet", "x", 20, 29),
+            ],
+        );
+
+        let root_path = PlPath::Local(root.into());
+
+        let snapshot = DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Latest, None).unwrap();
+        assert_eq!(snapshot.version(), 2);
+        assert_eq!(snapshot.partition_columns(), &["p"]);
+        assert_eq!(file_names(&snapshot), ["1.parquet", "2.parquet"]);
+
+        let snapshot =
+            DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Version(1), None).unwrap();
+        assert_eq!(snapshot.version(), 1);
+        assert_eq!(file_names(&snapshot), ["0.parquet", "1.parquet"]);
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+        let snapshot = DeltaSnapshot::load(
+            root_path.as_ref(),
+            DeltaVersion::Timestamp(now + 1000),
+            None,
+        )
+        .unwrap();
+        assert_eq!(snapshot.version(), 2);
+        assert!(DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Timestamp(0), None).is_err());
+        assert!(DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Version(3), None).is_err());
+
+        let stats = snapshot.statistics_df().unwrap();
+        assert_eq!(
+            stats.column("a_min").unwrap().as_materialized_series(),
+            &Series::new("a_min".into(), [10i64, 20])
+        );
+        assert_eq!(
+            stats.column("p_max").unwrap().as_materialized_series(),
+            &Series::new("p_max".into(), ["y", "x"])
+        );
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        use crate::parquet::write::ParquetWriter;
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        // Commits before the checkpoint may have been removed by log cleanup.
+        write_commit(root, 2, &[&add("p=x/2.parquet", "x", 20, 29)]);
+
+        let height = 3;
+        let outer_validity = |idx: usize| Some(Bitmap::from_iter((0..height).map(|i| i == idx)));
+
+        let protocol = StructChunked::from_series(
+            "protocol".into(),
+            height,
+            [Series::new(
+                "minReaderVersion".into(),
+                [Some(1i32), None, None],
+            )]
+            .iter(),
+        )
+        .unwrap()
+        .with_outer_validity(outer_validity(0));
+
+        let metadata: Value = serde_json::from_str(&METADATA[12..METADATA.len() - 1]).unwrap();
+        let partition_columns: ListChunked = [None, Some(Series::new("".into(), ["p"])), None]
+            .into_iter()
+            .collect();
+        let meta_data = StructChunked::from_series(
+            "metaData".into(),
+            height,
+            [
+                Series::new(
+                    "schemaString".into(),
+                    [None, metadata["schemaString"].as_str(), None],
+                ),
+                partition_columns
+                    .with_name("partitionColumns".into())
+                    .into_series(),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .with_outer_validity(outer_validity(1));
+
+        let partition_value = StructChunked::from_series(
+            "".into(),
+            1,
+            [
+                Series::new("key".into(), ["p"]),
+                Series::new("value".into(), ["y"]),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+        let partition_values: ListChunked =
+            [None, None, Some(partition_value)].into_iter().collect();
+        let add = StructChunked::from_series(
+            "add".into(),
+            height,
+            [
+                Series::new("path".into(), [None, None, Some("p=y/1.parquet")]),
+                partition_values
+                    .with_name("partitionValues".into())
+                    .into_series(),
+                Series::new("size".into(), [None, None, Some(1i64)]),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .with_outer_validity(outer_validity(2));
+
+        let mut df = DataFrame::new(vec![
+            protocol.into_column(),
+            meta_data.into_column(),
+            add.into_column(),
+        ])
+        .unwrap();
+        let file =
+            std::fs::File::create(root.join("_delta_log/00000000000000000001.checkpoint.parquet"))
+                .unwrap();
+        ParquetWriter::new(file).finish(&mut df).unwrap();
+
+        let root_path = PlPath::Local(root.into());
+        let snapshot = DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Latest, None).unwrap();
+        assert_eq!(snapshot.version(), 2);
+        assert_eq!(snapshot.partition_columns(), &["p"]);
+        assert_eq!(file_names(&snapshot), ["1.parquet", "2.parquet"]);
+        assert_eq!(
+            snapshot.files()[0].partition_values["p"].as_deref(),
+            Some("y")
+        );
+
+        // Versions before the checkpoint cannot be reconstructed.
+        assert!(DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Version(0), None).is_err());
+    }
+
+    #[test]
+    fn test_partition_value_column() {
+        let column = |values: &[Option<&str>], dtype: &DataType| {
+            partition_value_column("p".into(), values, dtype)
+                .map(|c| c.take_materialized_series())
+        };
+
+        assert_eq!(
+            column(&[Some("1"), None], &DataType::Int32).unwrap(),
+            Series::new("p".into(), [Some(1i32), None])
+        );
+        assert_eq!(
+            column(&[Some("2024-01-31")], &DataType::Date).unwrap(),
+            Series::new("p".into(), [19753i32]).cast(&DataType::Date).unwrap()
+        );
+        let datetime = DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC));
+        assert_eq!(
+            column(
+                &[
+                    Some("1970-01-01 00:00:01"),
+                    Some("1970-01-01 00:00:00.000002"),
+                    Some("1970-01-01T00:00:03.000000Z"),
+                ],
+                &datetime
+            )
+            .unwrap(),
+            Series::new("p".into(), [1_000_000i64, 2, 3_000_000])
+                .cast(&datetime)
+                .unwrap()
+        );
+        assert_eq!(
+            column(&[Some("true"), Some("false")], &DataType::Boolean).unwrap(),
+            Series::new("p".into(), [true, false])
+        );
+        assert!(column(&[Some("x")], &DataType::Int64).is_err());
+        assert!(column(&[Some("yes")], &DataType::Boolean).is_err());
+    }
+
+    #[test]
+    fn test_unsupported_reader_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        write_commit(
+            dir.path(),
+            0,
+            &[
+                r#"{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["v2Checkpoint"],"writerFeatures":[]}}"#,
+                METADATA,
+            ],
+        );
+
+        let root_path = PlPath::Local(dir.path().into());
+        let err = DeltaSnapshot::load(root_path.as_ref(), DeltaVersion::Latest, None).unwrap_err();
+        assert!(err.to_string().contains("v2Checkpoint"));
+    }
+}
@@ -0,0 +1,597 @@
+//! Committing data files written by Polars as a new version of a Delta Lake table.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#delta-log-entries>.
+
+use std::collections::BTreeMap;
+
+use arrow::temporal_conversions::{date32_to_date, timestamp_us_to_datetime};
+use percent_encoding::{AsciiSet, CONTROLS};
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::plpath::{PlPath, PlPathRef};
+use serde_json::{Map, Number, Value, json};
+
+use super::schema::{DeltaSchema, to_schema_string};
+use super::snapshot::{DeltaSnapshot, DeltaVersion};
+use crate::cloud::CloudOptions;
+use crate::partition::WrittenFile;
+use crate::utils::file::write_new_file;
+
+/// Highest writer protocol version without table features.
+const MAX_LEGACY_WRITER_VERSION: i32 = 2;
+
+/// Writer features of protocol version 7 that are supported. Files are added without deletion
+/// vectors, and column invariants are not checked.
+const SUPPORTED_WRITER_FEATURES: &[&str] = &[
+    "appendOnly",
+    "deletionVectors",
+    "invariants",
+    "timestampNtz",
+];
+
+/// Characters that are percent-encoded in the paths of added files, which are URIs.
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'%').add(b'#').add(b'?');
+
+/// How written files are committed to a Delta table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeltaWriteMode {
+    /// Add the files to the table, which is created if it does not exist yet.
+    #[default]
+    Append,
+    /// Replace all files of the table by the written files. The schema and partition columns of
+    /// the table are replaced as well.
+    Overwrite,
+}
+
+/// A write to a Delta table, which commits the files written by a partitioned Parquet sink as a
+/// new version of the table.
+///
+/// The table is checked when the write is created, so that incompatible writes fail before any
+/// data is written, and again when committing.
+#[derive(Debug, Clone)]
+pub struct DeltaCommit {
+    table_root: PlPath,
+    mode: DeltaWriteMode,
+    schema: SchemaRef,
+    schema_string: String,
+    partition_columns: Vec<PlSmallStr>,
+    /// Identifies the data files of this write.
+    write_id: uuid::Uuid,
+    cloud_options: Option<CloudOptions>,
+}
+
+impl DeltaCommit {
+    pub fn new(
+        table_root: PlPathRef<'_>,
+        mode: DeltaWriteMode,
+        schema: &Schema,
+        partition_columns: Vec<PlSmallStr>,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        for name in &partition_columns {
+            let dtype = schema.try_get(name)?;
+            polars_ensure!(
+                !dtype.is_nested(),
+                InvalidOperation: "cannot partition a Delta table by nested column '{}'", name
+            );
+        }
+        polars_ensure!(
+            partition_columns.len() < schema.len(),
+            InvalidOperation: "cannot partition a Delta table by all of its columns"
+        );
+
+        // Roundtrip the schema, so that it compares equal to the schema of a loaded snapshot.
+        let schema_string = to_schema_string(schema)?;
+        let schema = DeltaSchema::parse(&schema_string, false)?.logical;
+
+        let mut commit = Self {
+            table_root: table_root.into_owned(),
+            mode,
+            schema,
+            schema_string,
+            partition_columns,
+            write_id: uuid::Uuid::new_v4(),
+            cloud_options: cloud_options.cloned(),
+        };
+
+        let snapshot = DeltaSnapshot::try_load(table_root, DeltaVersion::Latest, cloud_options)?;
+        // An append with the same columns writes them with the types of the table, if they can be
+        // upcast to those.
+        if let Some(snapshot) = &snapshot {
+            if mode == DeltaWriteMode::Append
+                && snapshot
+                    .schema()
+                    .iter_names()
+                    .eq(commit.schema.iter_names())
+                && snapshot
+                    .schema()
+                    .iter_values()
+                    .zip(commit.schema.iter_values())
+                    .all(|(table_dtype, dtype)| {
+                        try_get_supertype(table_dtype, dtype).is_ok_and(|st| &st == table_dtype)
+                    })
+            {
+                commit.schema = snapshot.schema().clone();
+            }
+        }
+        commit.check(snapshot.as_ref())?;
+
+        Ok(commit)
+    }
+
+    pub fn table_root(&self) -> &PlPath {
+        &self.table_root
+    }
+
+    pub fn mode(&self) -> DeltaWriteMode {
+        self.mode
+    }
+
+    /// Schema of the table after the commit, which the written data must be cast to.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Name of the `file_idx`-th data file of this write, which is unique across writes.
+    pub fn data_file_name(&self, file_idx: usize) -> String {
+        format!("part-{file_idx:05}-{}.parquet", self.write_id)
+    }
+
+    pub fn partition_columns(&self) -> &[PlSmallStr] {
+        &self.partition_columns
+    }
+
+    /// Commits the files as the next version of the table, and returns that version.
+    ///
+    /// The commit file is written only if no commit with the same version exists. If another
+    /// writer committed first, an append is retried on top of the new version, whereas an
+    /// overwrite fails.
+    pub fn commit(&self, written_files: &[WrittenFile]) -> PolarsResult<i64> {
+        let now = now_ms();
+        let add_actions = written_files
+            .iter()
+            .map(|file| self.add_action(file, now))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        loop {
+            let snapshot = DeltaSnapshot::try_load(
+                self.table_root.as_ref(),
+                DeltaVersion::Latest,
+                self.cloud_options.as_ref(),
+            )?;
+            self.check(snapshot.as_ref())?;
+
+            let version = snapshot.as_ref().map_or(0, |s| s.version() + 1);
+
+            let mut bytes = Vec::new();
+            for action in self
+                .actions(snapshot.as_ref(), now)
+                .iter()
+                .chain(&add_actions)
+            {
+                serde_json::to_writer(&mut bytes, action).map_err(
+                    |e| polars_err!(ComputeError: "failed to write Delta commit: {}", e),
+                )?;
+                bytes.push(b'\n');
+            }
+
+            let path = self
+                .table_root
+                .as_ref()
+                .join("_delta_log")
+                .as_ref()
+                .join(format!("{version:020}.json"));
+
+            if write_new_file(path.as_ref(), &bytes, self.cloud_options.as_ref())? {
+                return Ok(version);
+            }
+
+            polars_ensure!(
+                self.mode == DeltaWriteMode::Append,
+                ComputeError: "version {} of the Delta table was committed concurrently", version
+            );
+        }
+    }
+
+    fn check(&self, snapshot: Option<&DeltaSnapshot>) -> PolarsResult<()> {
+        let Some(snapshot) = snapshot else {
+            return Ok(());
+        };
+
+        let protocol = snapshot.protocol();
+        match protocol.min_writer_version {
+            v if v <= MAX_LEGACY_WRITER_VERSION => {},
+            7 => {
+                if let Some(feature) = protocol
+                    .writer_features
+                    .iter()
+                    .flatten()
+                    .find(|f| !SUPPORTED_WRITER_FEATURES.contains(&f.as_str()))
+                {
+                    polars_bail!(
+                        nyi = "writing to Delta tables with writer feature '{}'",
+                        feature
+                    );
+                }
+            },
+            v => polars_bail!(
+                nyi = "writing to Delta tables of writer protocol version {}",
+                v
+            ),
+        }
+        polars_ensure!(
+            !snapshot.has_column_mapping(),
+            nyi = "writing to Delta tables with column mapping"
+        );
+
+        match self.mode {
+            DeltaWriteMode::Append => {
+                polars_ensure!(
+                    snapshot.schema() == &self.schema,
+                    SchemaMismatch: "cannot append to Delta table, schema {:?} does not match the table schema {:?}",
+                    self.schema, snapshot.schema()
+                );
+                polars_ensure!(
+                    snapshot.partition_columns() == self.partition_columns.as_slice(),
+                    SchemaMismatch: "cannot append to Delta table, partition columns {:?} do not match the table partition columns {:?}",
+                    self.partition_columns, snapshot.partition_columns()
+                );
+            },
+            DeltaWriteMode::Overwrite => {
+                let append_only = snapshot
+                    .configuration()
+                    .get("delta.appendOnly")
+                    .and_then(|v| v.as_deref());
+                polars_ensure!(
+                    !append_only.is_some_and(|v| v.eq_ignore_ascii_case("true")),
+                    InvalidOperation: "cannot overwrite an append-only Delta table"
+                );
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Actions other than the added files: the commit info, the protocol and metadata if the
+    /// table is created or changed, and the removal of all files on overwrite.
+    fn actions(&self, snapshot: Option<&DeltaSnapshot>, now: i64) -> Vec<Value> {
+        let partition_columns: Vec<&str> =
+            self.partition_columns.iter().map(|c| c.as_str()).collect();
+
+        let mode = match self.mode {
+            DeltaWriteMode::Append => "Append",
+            DeltaWriteMode::Overwrite => "Overwrite",
+        };
+        let mut actions = vec![json!({
+            "commitInfo": {
+                "timestamp": now,
+                "operation": "WRITE",
+                "operationParameters": {
+                    "mode": mode,
+                    "partitionBy": serde_json::to_string(&partition_columns).unwrap(),
+                },
+                "engineInfo": "polars",
+            }
+        })];
+
+        if let Some(protocol) = self.protocol_action(snapshot) {
+            actions.push(json!({ "protocol": protocol }));
+        }
+
+        let metadata_changed = snapshot.is_none_or(|s| {
+            s.schema() != &self.schema || s.partition_columns() != self.partition_columns.as_slice()
+        });
+        if metadata_changed {
+            let id = snapshot
+                .map(|s| s.table_id())
+                .filter(|id| !id.is_empty())
+                .map_or_else(|| uuid::Uuid::new_v4().to_string(), |id| id.to_string());
+            let configuration = snapshot
+                .map(|s| s.configuration().clone())
+                .unwrap_or_default();
+
+            actions.push(json!({
+                "metaData": {
+                    "id": id,
+                    "format": { "provider": "parquet", "options": {} },
+                    "schemaString": self.schema_string,
+                    "partitionColumns": partition_columns,
+                    "configuration": configuration,
+                    "createdTime": now,
+                }
+            }));
+        }
+
+        if let (DeltaWriteMode::Overwrite, Some(snapshot)) = (self.mode, snapshot) {
+            for file in snapshot.files() {
+                let mut remove = json!({
+                    "path": file.log_path,
+                    "deletionTimestamp": now,
+                    "dataChange": true,
+                    "extendedFileMetadata": true,
+                    "partitionValues": file.partition_values,
+                    "size": file.size,
+                });
+                if let Some(dv) = &file.deletion_vector {
+                    remove["deletionVector"] = serde_json::to_value(dv).unwrap();
+                }
+                actions.push(json!({ "remove": remove }));
+            }
+        }
+
+        actions
+    }
+
+    /// The protocol to commit if the table is created, or if its protocol must be upgraded.
+    /// Timestamps without a time zone require the `timestampNtz` table feature.
+    fn protocol_action(&self, snapshot: Option<&DeltaSnapshot>) -> Option<Value> {
+        let needs_timestamp_ntz = self.schema.iter_values().any(has_timestamp_ntz);
+
+        let Some(snapshot) = snapshot else {
+            return Some(if needs_timestamp_ntz {
+                json!({
+                    "minReaderVersion": 3,
+                    "minWriterVersion": 7,
+                    "readerFeatures": ["timestampNtz"],
+                    "writerFeatures": ["timestampNtz"],
+                })
+            } else {
+                json!({ "minReaderVersion": 1, "minWriterVersion": 2 })
+            });
+        };
+
+        let protocol = snapshot.protocol();
+        let has_timestamp_ntz = protocol
+            .writer_features
+            .iter()
+            .flatten()
+            .any(|f| f == "timestampNtz");
+        if !needs_timestamp_ntz || has_timestamp_ntz {
+            return None;
+        }
+
+        // Upgrade to table features, keeping the features implied by the legacy versions.
+        let mut reader_features = protocol.reader_features.clone().unwrap_or_default();
+        let mut writer_features = protocol.writer_features.clone().unwrap_or_else(|| {
+            let legacy: &[&str] = match protocol.min_writer_version {
+                2 => &["appendOnly", "invariants"],
+                _ => &[],
+            };
+            legacy.iter().map(|f| f.to_string()).collect()
+        });
+        reader_features.push("timestampNtz".to_string());
+        writer_features.push("timestampNtz".to_string());
+
+        Some(json!({
+            "minReaderVersion": 3,
+            "minWriterVersion": 7,
+            "readerFeatures": reader_features,
+            "writerFeatures": writer_features,
+        }))
+    }
+
+    fn add_action(&self, file: &WrittenFile, now: i64) -> PolarsResult<Value> {
+        let table_root = self.table_root.to_str().trim_end_matches('/');
+        let relative_path = file
+            .path
+            .strip_prefix(table_root)
+            .filter(|p| p.starts_with('/'))
+            .ok_or_else(|| {
+                polars_err!(
+                    ComputeError: "written file '{}' is not inside the Delta table at '{}'",
+                    file.path, table_root
+                )
+            })?
+            .trim_start_matches('/');
+
+        let partition_values = file
+            .keys
+            .iter()
+            .map(|(name, value)| Ok((name.to_string(), partition_value(value)?)))
+            .collect::<PolarsResult<BTreeMap<_, _>>>()?;
+
+        let mut min_values = Map::new();
+        let mut max_values = Map::new();
+        let mut null_count = Map::new();
+        for (name, stats) in &file.columns {
+            if self.schema.get(name).is_none_or(|dtype| dtype.is_nested()) {
+                continue;
+            }
+            null_count.insert(name.to_string(), json!(stats.null_count));
+
+            if stats.nan_count > 0 {
+                continue;
+            }
+            if let (Some(min), Some(max)) = (
+                stats_value(&stats.lower_bound),
+                stats_value(&stats.upper_bound),
+            ) {
+                min_values.insert(name.to_string(), min);
+                max_values.insert(name.to_string(), max);
+            }
+        }
+        let stats = json!({
+            "numRecords": file.num_rows,
+            "minValues": min_values,
+            "maxValues": max_values,
+            "nullCount": null_count,
+        });
+
+        Ok(json!({
+            "add": {
+                "path": percent_encoding::utf8_percent_encode(relative_path, PATH_ENCODE_SET).to_string(),
+                "partitionValues": partition_values,
+                "size": file.file_size,
+                "modificationTime": now,
+                "dataChange": true,
+                "stats": stats.to_string(),
+            }
+        }))
+    }
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+fn has_timestamp_ntz(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Datetime(_, None) => true,
+        DataType::List(inner) => has_timestamp_ntz(inner),
+        DataType::Struct(fields) => fields.iter().any(|f| has_timestamp_ntz(f.dtype())),
+        _ => false,
+    }
+}
+
+/// Serializes a partition value as described in
+/// <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#partition-value-serialization>.
+fn partition_value(value: &AnyValue<'_>) -> PolarsResult<Option<String>> {
+    Ok(Some(match value {
+        AnyValue::Null => return Ok(None),
+        AnyValue::Boolean(v) => v.to_string(),
+        AnyValue::String(v) => v.to_string(),
+        AnyValue::StringOwned(v) => v.to_string(),
+        AnyValue::Int8(v) => v.to_string(),
+        AnyValue::Int16(v) => v.to_string(),
+        AnyValue::Int32(v) => v.to_string(),
+        AnyValue::Int64(v) => v.to_string(),
+        AnyValue::Float32(v) => v.to_string(),
+        AnyValue::Float64(v) => v.to_string(),
+        AnyValue::Date(v) => date32_to_date(*v).format("%Y-%m-%d").to_string(),
+        AnyValue::Datetime(v, TimeUnit::Microseconds, _)
+        | AnyValue::DatetimeOwned(v, TimeUnit::Microseconds, _) => timestamp_us_to_datetime(*v)
+            .format("%Y-%m-%d %H:%M:%S%.6f")
+            .to_string(),
+        value => polars_bail!(
+            nyi = "writing Delta partition values of type {}",
+            value.dtype()
+        ),
+    }))
+}
+
+/// Serializes a minimum or maximum value of the file statistics, or returns `None` for types
+/// without statistics.
+fn stats_value(value: &AnyValue<'_>) -> Option<Value> {
+    Some(match value {
+        AnyValue::Int8(v) => json!(v),
+        AnyValue::Int16(v) => json!(v),
+        AnyValue::Int32(v) => json!(v),
+        AnyValue::Int64(v) => json!(v),
+        AnyValue::Float32(v) => Value::Number(Number::from_f64(*v as f64)?),
+        AnyValue::Float64(v) => Value::Number(Number::from_f64(*v)?),
+        AnyValue::String(v) => json!(v),
+        AnyValue::StringOwned(v) => json!(v.as_str()),
+        AnyValue::Date(v) => json!(date32_to_date(*v).format("%Y-%m-%d").to_string()),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::partition::WrittenColumnStats;
+
+    fn written_file(table_root: &str, name: &str, key: i64, lower: i32, upper: i32) -> WrittenFile {
+        WrittenFile {
+            path: format!("{table_root}/p={key}/{name}"),
+            num_rows: 2,
+            file_size: 100,
+            keys: vec![("p".into(), AnyValue::Int64(key))],
+            columns: vec![(
+                "a".into(),
+                WrittenColumnStats {
+                    null_count: 0,
+                    nan_count: 0,
+                    lower_bound: AnyValue::Int32(lower),
+                    upper_bound: AnyValue::Int32(upper),
+                },
+            )],
+        }
+    }
+
+    #[test]
+    fn test_commit_append_and_overwrite() -> PolarsResult<()> {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("table");
+        let table_root = PlPath::new(dir.to_str().unwrap());
+        let root = dir.to_str().unwrap();
+
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int32),
+            Field::new("p".into(), DataType::Int64),
+        ]);
+        let commit =
+            |mode| DeltaCommit::new(table_root.as_ref(), mode, &schema, vec!["p".into()], None);
+
+        let append = commit(DeltaWriteMode::Append)?;
+        assert_eq!(
+            append.commit(&[written_file(root, "0.parquet", 1, 0, 5)])?,
+            0
+        );
+        assert_eq!(
+            append.commit(&[written_file(root, "1.parquet", 2, 3, 9)])?,
+            1
+        );
+
+        let snapshot = DeltaSnapshot::load(table_root.as_ref(), DeltaVersion::Latest, None)?;
+        assert_eq!(snapshot.version(), 1);
+        assert_eq!(snapshot.schema().as_ref(), &schema);
+        assert_eq!(snapshot.partition_columns(), &["p"]);
+        assert_eq!(snapshot.files().len(), 2);
+        assert_eq!(
+            snapshot.files()[1].partition_values,
+            BTreeMap::from([("p".to_string(), Some("2".to_string()))])
+        );
+
+        let stats = snapshot.statistics_df()?;
+        assert_eq!(stats.column("a_min")?.i32()?.get(1), Some(3));
+        assert_eq!(stats.column("a_max")?.i32()?.get(1), Some(9));
+
+        // Appending columns which upcast to the table types writes those types.
+        let narrow_schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int16),
+            Field::new("p".into(), DataType::Int64),
+        ]);
+        let narrow = DeltaCommit::new(
+            table_root.as_ref(),
+            DeltaWriteMode::Append,
+            &narrow_schema,
+            vec!["p".into()],
+            None,
+        )?;
+        assert_eq!(narrow.schema().as_ref(), &schema);
+
+        // Appending with a different schema fails.
+        let other_schema = Schema::from_iter([
+            Field::new("a".into(), DataType::String),
+            Field::new("p".into(), DataType::Int64),
+        ]);
+        assert!(
+            DeltaCommit::new(
+                table_root.as_ref(),
+                DeltaWriteMode::Append,
+                &other_schema,
+                vec!["p".into()],
+                None
+            )
+            .is_err()
+        );
+
+        let overwrite = commit(DeltaWriteMode::Overwrite)?;
+        assert_eq!(
+            overwrite.commit(&[written_file(root, "2.parquet", 3, 1, 1)])?,
+            2
+        );
+
+        let snapshot = DeltaSnapshot::load(table_root.as_ref(), DeltaVersion::Latest, None)?;
+        assert_eq!(snapshot.files().len(), 1);
+        assert_eq!(snapshot.files()[0].log_path, "p=3/2.parquet");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,918 @@
+//! Reading and writing of Iceberg manifest lists and manifests, which are stored as Avro files.
+//!
+//! See <https://iceberg.apache.org/spec/#manifests>.
+
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use serde_json::{Value, json};
+
+use super::metadata::PartitionSpec;
+use crate::SerReader;
+use crate::avro::AvroReader;
+
+/// An entry of a manifest list.
+#[derive(Debug, Clone)]
+pub struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub content: ManifestContent,
+    /// Sequence number of the snapshot that added the manifest, 0 for format version 1.
+    pub sequence_number: i64,
+    /// Lowest data sequence number of the files in the manifest.
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+    /// Summaries of the partition fields, in the order of the partition spec.
+    pub partitions: Option<Vec<FieldSummary>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestContent {
+    Data,
+    Deletes,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldSummary {
+    pub contains_null: bool,
+    pub contains_nan: Option<bool>,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestEntryStatus {
+    Existing,
+    Added,
+    Deleted,
+}
+
+/// An entry of a manifest, tracking a single data or delete file.
+#[derive(Debug, Clone)]
+pub struct ManifestEntry {
+    pub status: ManifestEntryStatus,
+    /// Data sequence number, inherited from the manifest if not set explicitly.
+    pub sequence_number: i64,
+    pub data_file: DataFile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFileContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+#[derive(Debug, Clone)]
+pub struct DataFile {
+    pub content: DataFileContent,
+    pub file_path: String,
+    pub file_format: String,
+    /// Partition values, in the order of the fields of the partition spec. Temporal values are
+    /// stored as their physical representation.
+    pub partition: Vec<AnyValue<'static>>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub null_value_counts: PlHashMap<u32, i64>,
+    pub nan_value_counts: PlHashMap<u32, i64>,
+    pub lower_bounds: PlHashMap<u32, Vec<u8>>,
+    pub upper_bounds: PlHashMap<u32, Vec<u8>>,
+    pub equality_ids: Option<Vec<u32>>,
+}
+
+fn read_avro(bytes: &[u8]) -> PolarsResult<DataFrame> {
+    AvroReader::new(Cursor::new(bytes)).finish()
+}
+
+fn field(s: &Series, name: &str) -> Option<Series> {
+    s.struct_().ok()?.field_by_name(name).ok()
+}
+
+fn required<T>(value: Option<T>, name: &str) -> PolarsResult<T> {
+    value.ok_or_else(
+        || polars_err!(ComputeError: "missing required field '{}' in Iceberg manifest", name),
+    )
+}
+
+/// Reads an integer column of any width as `i64`.
+fn i64_values(s: Option<&Series>) -> PolarsResult<Option<Int64Chunked>> {
+    s.map(|s| Ok(s.cast(&DataType::Int64)?.i64()?.clone()))
+        .transpose()
+}
+
+fn str_values(s: Option<&Series>) -> PolarsResult<Option<StringChunked>> {
+    s.map(|s| Ok(s.str()?.clone())).transpose()
+}
+
+fn binary_at(s: &Series, idx: usize) -> PolarsResult<Option<Vec<u8>>> {
+    Ok(s.binary()?.get(idx).map(<[u8]>::to_vec))
+}
+
+/// Reads an Iceberg map stored as an array of key-value records, with integer field ID keys.
+fn id_map_at<T>(
+    s: Option<&Series>,
+    idx: usize,
+    values: impl Fn(&Series) -> PolarsResult<Vec<Option<T>>>,
+) -> PolarsResult<PlHashMap<u32, T>> {
+    let mut out = PlHashMap::new();
+    let Some(entries) = s
+        .map(|s| s.list())
+        .transpose()?
+        .and_then(|ca| ca.get_as_series(idx))
+    else {
+        return Ok(out);
+    };
+
+    let keys = i64_values(field(&entries, "key").as_ref())?;
+    let values = field(&entries, "value").map(|s| values(&s)).transpose()?;
+    let (Some(keys), Some(values)) = (keys, values) else {
+        return Ok(out);
+    };
+
+    for (key, value) in keys.iter().zip(values) {
+        if let (Some(key), Some(value)) = (key, value) {
+            out.insert(key as u32, value);
+        }
+    }
+
+    Ok(out)
+}
+
+fn binary_values(s: &Series) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    Ok(s.binary()?.iter().map(|v| v.map(<[u8]>::to_vec)).collect())
+}
+
+/// Reads the entries of a manifest list.
+pub fn read_manifest_list(bytes: &[u8]) -> PolarsResult<Vec<ManifestFile>> {
+    let df = read_avro(bytes)?;
+    let column = |name: &str| {
+        df.column(name)
+            .ok()
+            .map(|c| c.as_materialized_series().clone())
+    };
+
+    // Format version 1 names the file counts `*_data_files_count`.
+    let column_or = |name: &str, v1_name: &str| column(name).or_else(|| column(v1_name));
+
+    let manifest_path = str_values(column("manifest_path").as_ref())?;
+    let partition_spec_id = i64_values(column("partition_spec_id").as_ref())?;
+    let content = i64_values(column("content").as_ref())?;
+    let sequence_number = i64_values(column("sequence_number").as_ref())?;
+    let partitions = column("partitions");
+    let counts = [
+        column("manifest_length"),
+        column("min_sequence_number"),
+        column("added_snapshot_id"),
+        column_or("added_files_count", "added_data_files_count"),
+        column_or("existing_files_count", "existing_data_files_count"),
+        column_or("deleted_files_count", "deleted_data_files_count"),
+        column("added_rows_count"),
+        column("existing_rows_count"),
+        column("deleted_rows_count"),
+    ]
+    .iter()
+    .map(|s| i64_values(s.as_ref()))
+    .collect::<PolarsResult<Vec<_>>>()?;
+
+    (0..df.height())
+        .map(|i| {
+            let partitions = match &partitions {
+                Some(s) => s
+                    .list()?
+                    .get_as_series(i)
+                    .map(|summaries| read_field_summaries(&summaries))
+                    .transpose()?,
+                None => None,
+            };
+            let count = |idx: usize| counts[idx].as_ref().and_then(|ca| ca.get(i));
+            let sequence_number = sequence_number
+                .as_ref()
+                .and_then(|ca| ca.get(i))
+                .unwrap_or(0);
+
+            Ok(ManifestFile {
+                manifest_path: required(
+                    manifest_path.as_ref().and_then(|ca| ca.get(i)),
+                    "manifest_path",
+                )?
+                .to_string(),
+                manifest_length: count(0).unwrap_or(0),
+                partition_spec_id: required(
+                    partition_spec_id.as_ref().and_then(|ca| ca.get(i)),
+                    "partition_spec_id",
+                )? as i32,
+                content: match content.as_ref().and_then(|ca| ca.get(i)) {
+                    Some(1) => ManifestContent::Deletes,
+                    _ => ManifestContent::Data,
+                },
+                sequence_number,
+                min_sequence_number: count(1).unwrap_or(sequence_number),
+                added_snapshot_id: count(2).unwrap_or(0),
+                added_files_count: count(3).unwrap_or(0) as i32,
+                existing_files_count: count(4).unwrap_or(0) as i32,
+                deleted_files_count: count(5).unwrap_or(0) as i32,
+                added_rows_count: count(6).unwrap_or(0),
+                existing_rows_count: count(7).unwrap_or(0),
+                deleted_rows_count: count(8).unwrap_or(0),
+                partitions,
+            })
+        })
+        .collect()
+}
+
+fn read_field_summaries(summaries: &Series) -> PolarsResult<Vec<FieldSummary>> {
+    let contains_null = field(summaries, "contains_null");
+    let contains_nan = field(summaries, "contains_nan");
+    let lower_bound = field(summaries, "lower_bound");
+    let upper_bound = field(summaries, "upper_bound");
+
+    let contains_null = contains_null.as_ref().map(|s| s.bool()).transpose()?;
+    let contains_nan = contains_nan.as_ref().map(|s| s.bool()).transpose()?;
+
+    (0..summaries.len())
+        .map(|i| {
+            Ok(FieldSummary {
+                // Assume that there are nulls if this is not known.
+                contains_null: contains_null.and_then(|ca| ca.get(i)).unwrap_or(true),
+                contains_nan: contains_nan.and_then(|ca| ca.get(i)),
+                lower_bound: lower_bound
+                    .as_ref()
+                    .map(|s| binary_at(s, i))
+                    .transpose()?
+                    .flatten(),
+                upper_bound: upper_bound
+                    .as_ref()
+                    .map(|s| binary_at(s, i))
+                    .transpose()?
+                    .flatten(),
+            })
+        })
+        .collect()
+}
+
+/// Reads the entries of a manifest. The partition values are read for the fields of `spec`.
+pub fn read_manifest(
+    bytes: &[u8],
+    manifest: &ManifestFile,
+    spec: &PartitionSpec,
+) -> PolarsResult<Vec<ManifestEntry>> {
+    let df = read_avro(bytes)?;
+    let column = |name: &str| {
+        df.column(name)
+            .ok()
+            .map(|c| c.as_materialized_series().clone())
+    };
+
+    let status = i64_values(column("status").as_ref())?;
+    let sequence_number = i64_values(column("sequence_number").as_ref())?;
+    let data_file = required(column("data_file"), "data_file")?;
+    let data_file_field = |name: &str| field(&data_file, name);
+
+    let content = i64_values(data_file_field("content").as_ref())?;
+    let file_path = str_values(data_file_field("file_path").as_ref())?;
+    let file_format = str_values(data_file_field("file_format").as_ref())?;
+    let record_count = i64_values(data_file_field("record_count").as_ref())?;
+    let file_size_in_bytes = i64_values(data_file_field("file_size_in_bytes").as_ref())?;
+    let null_value_counts = data_file_field("null_value_counts");
+    let nan_value_counts = data_file_field("nan_value_counts");
+    let lower_bounds = data_file_field("lower_bounds");
+    let upper_bounds = data_file_field("upper_bounds");
+    let equality_ids = data_file_field("equality_ids");
+
+    // Temporal partition values are kept physical, as the Avro types do not carry the time zone
+    // of the table schema.
+    let partition = data_file_field("partition");
+    let partition_fields = spec
+        .fields
+        .iter()
+        .map(|pf| {
+            partition
+                .as_ref()
+                .and_then(|s| field(s, &pf.name))
+                .map(|s| s.to_physical_repr().into_owned())
+        })
+        .collect::<Vec<_>>();
+
+    (0..df.height())
+        .map(|i| {
+            let status = match required(status.as_ref().and_then(|ca| ca.get(i)), "status")? {
+                0 => ManifestEntryStatus::Existing,
+                1 => ManifestEntryStatus::Added,
+                _ => ManifestEntryStatus::Deleted,
+            };
+            // Added entries without an explicit sequence number inherit it from the manifest.
+            let sequence_number = sequence_number
+                .as_ref()
+                .and_then(|ca| ca.get(i))
+                .unwrap_or(manifest.sequence_number);
+
+            let partition = partition_fields
+                .iter()
+                .map(|s| match s {
+                    Some(s) => Ok(s.get(i)?.into_static()),
+                    None => Ok(AnyValue::Null),
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            let equality_ids = match &equality_ids {
+                Some(s) => s
+                    .list()?
+                    .get_as_series(i)
+                    .map(|ids| -> PolarsResult<_> {
+                        let ids = ids.cast(&DataType::UInt32)?;
+                        Ok(ids.u32()?.into_no_null_iter().collect())
+                    })
+                    .transpose()?,
+                None => None,
+            };
+
+            Ok(ManifestEntry {
+                status,
+                sequence_number,
+                data_file: DataFile {
+                    content: match content.as_ref().and_then(|ca| ca.get(i)) {
+                        Some(1) => DataFileContent::PositionDeletes,
+                        Some(2) => DataFileContent::EqualityDeletes,
+                        _ => DataFileContent::Data,
+                    },
+                    file_path: required(
+                        file_path.as_ref().and_then(|ca| ca.get(i)),
+                        "data_file.file_path",
+                    )?
+                    .to_string(),
+                    file_format: file_format
+                        .as_ref()
+                        .and_then(|ca| ca.get(i))
+                        .unwrap_or("PARQUET")
+                        .to_string(),
+                    partition,
+                    record_count: required(
+                        record_count.as_ref().and_then(|ca| ca.get(i)),
+                        "data_file.record_count",
+                    )?,
+                    file_size_in_bytes: file_size_in_bytes
+                        .as_ref()
+                        .and_then(|ca| ca.get(i))
+                        .unwrap_or(0),
+                    null_value_counts: id_map_at(null_value_counts.as_ref(), i, |s| {
+                        Ok(s.cast(&DataType::Int64)?.i64()?.iter().collect())
+                    })?,
+                    nan_value_counts: id_map_at(nan_value_counts.as_ref(), i, |s| {
+                        Ok(s.cast(&DataType::Int64)?.i64()?.iter().collect())
+                    })?,
+                    lower_bounds: id_map_at(lower_bounds.as_ref(), i, binary_values)?,
+                    upper_bounds: id_map_at(upper_bounds.as_ref(), i, binary_values)?,
+                    equality_ids,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Decodes a bound or partition summary value, stored with Iceberg's single-value binary
+/// serialization, into the physical representation of `dtype`.
+///
+/// Returns `None` for types whose bounds are not used for pruning.
+pub fn decode_single_value(bytes: &[u8], dtype: &DataType) -> Option<AnyValue<'static>> {
+    fn le<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+        bytes.try_into().ok()
+    }
+
+    Some(match dtype {
+        DataType::Boolean => AnyValue::Boolean(*bytes.first()? != 0),
+        DataType::Int32 | DataType::Date => AnyValue::Int32(i32::from_le_bytes(le(bytes)?)),
+        // Bounds of columns promoted from `int` are still stored as 4 bytes.
+        DataType::Int64 | DataType::Datetime(_, _) => AnyValue::Int64(match bytes.len() {
+            4 => i32::from_le_bytes(le(bytes)?) as i64,
+            _ => i64::from_le_bytes(le(bytes)?),
+        }),
+        // Iceberg stores microseconds since midnight.
+        DataType::Time => AnyValue::Int64(i64::from_le_bytes(le(bytes)?).checked_mul(1000)?),
+        DataType::Float32 => AnyValue::Float32(f32::from_le_bytes(le(bytes)?)),
+        DataType::Float64 => AnyValue::Float64(match bytes.len() {
+            4 => f32::from_le_bytes(le(bytes)?) as f64,
+            _ => f64::from_le_bytes(le(bytes)?),
+        }),
+        DataType::String => AnyValue::StringOwned(std::str::from_utf8(bytes).ok()?.into()),
+        DataType::Binary => AnyValue::BinaryOwned(bytes.to_vec()),
+        _ => return None,
+    })
+}
+
+/// Encodes a bound or partition summary value with Iceberg's single-value binary serialization.
+/// This is the inverse of [`decode_single_value`], but takes logical values.
+///
+/// Returns `None` for types whose bounds are not written.
+pub fn encode_single_value(value: &AnyValue<'_>) -> Option<Vec<u8>> {
+    Some(match value {
+        AnyValue::Boolean(v) => vec![*v as u8],
+        AnyValue::Int32(v) | AnyValue::Date(v) => v.to_le_bytes().to_vec(),
+        AnyValue::Int64(v)
+        | AnyValue::Datetime(v, TimeUnit::Microseconds, _)
+        | AnyValue::DatetimeOwned(v, TimeUnit::Microseconds, _) => v.to_le_bytes().to_vec(),
+        AnyValue::Float32(v) => v.to_le_bytes().to_vec(),
+        AnyValue::Float64(v) => v.to_le_bytes().to_vec(),
+        AnyValue::String(v) => v.as_bytes().to_vec(),
+        AnyValue::StringOwned(v) => v.as_bytes().to_vec(),
+        AnyValue::Binary(v) => v.to_vec(),
+        AnyValue::BinaryOwned(v) => v.clone(),
+        _ => return None,
+    })
+}
+
+/// Avro binary encoding of records, see
+/// <https://avro.apache.org/docs/1.11.1/specification/#binary-encoding>.
+///
+/// Manifests are written with this instead of the Avro writer, as Iceberg requires the field IDs
+/// in the Avro schema and metadata in the file header.
+#[derive(Default)]
+struct AvroEncoder(Vec<u8>);
+
+impl AvroEncoder {
+    fn long(&mut self, v: i64) {
+        let mut z = ((v << 1) ^ (v >> 63)) as u64;
+        while z > 0x7F {
+            self.0.push((z & 0x7F) as u8 | 0x80);
+            z >>= 7;
+        }
+        self.0.push(z as u8);
+    }
+
+    fn int(&mut self, v: i32) {
+        self.long(v as i64)
+    }
+
+    fn boolean(&mut self, v: bool) {
+        self.0.push(v as u8)
+    }
+
+    fn bytes(&mut self, v: &[u8]) {
+        self.long(v.len() as i64);
+        self.0.extend_from_slice(v)
+    }
+
+    fn string(&mut self, v: &str) {
+        self.bytes(v.as_bytes())
+    }
+
+    /// Encodes a `["null", T]` union.
+    fn optional<T>(&mut self, v: Option<T>, f: impl FnOnce(&mut Self, T)) {
+        match v {
+            None => self.long(0),
+            Some(v) => {
+                self.long(1);
+                f(self, v)
+            },
+        }
+    }
+
+    /// Encodes an array, or a map, as a single block.
+    fn array<I: ExactSizeIterator>(&mut self, items: I, mut f: impl FnMut(&mut Self, I::Item)) {
+        if items.len() > 0 {
+            self.long(items.len() as i64);
+            for item in items {
+                f(self, item)
+            }
+        }
+        self.long(0)
+    }
+
+    /// Encodes an Iceberg map with field ID keys, which is stored as an array of records.
+    fn id_map<T>(&mut self, map: &PlHashMap<u32, T>, mut f: impl FnMut(&mut Self, &T)) {
+        let mut entries = map.iter().collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(id, _)| **id);
+        self.array(entries.into_iter(), |e, (id, value)| {
+            e.int(*id as i32);
+            f(e, value)
+        })
+    }
+
+    /// Finishes an Avro object container file with `num_records` encoded records.
+    fn finish(self, schema: &Value, metadata: &[(&str, String)], num_records: usize) -> Vec<u8> {
+        let sync_marker = *uuid::Uuid::new_v4().as_bytes();
+        let schema = schema.to_string();
+        let header = [("avro.schema", schema.as_str()), ("avro.codec", "null")]
+            .into_iter()
+            .chain(metadata.iter().map(|(k, v)| (*k, v.as_str())))
+            .collect::<Vec<_>>();
+
+        let mut out = Self(b"Obj\x01".to_vec());
+        out.array(header.into_iter(), |out, (key, value)| {
+            out.string(key);
+            out.bytes(value.as_bytes())
+        });
+        out.0.extend_from_slice(&sync_marker);
+
+        if num_records > 0 {
+            out.long(num_records as i64);
+            out.bytes(&self.0);
+            out.0.extend_from_slice(&sync_marker);
+        }
+
+        out.0
+    }
+}
+
+fn avro_field(name: &str, ty: Value, field_id: u32) -> Value {
+    json!({ "name": name, "type": ty, "field-id": field_id })
+}
+
+fn optional_avro_field(name: &str, ty: Value, field_id: u32) -> Value {
+    json!({ "name": name, "type": ["null", ty], "default": null, "field-id": field_id })
+}
+
+fn id_map_avro_field(name: &str, field_id: u32, key_id: u32, value_id: u32, ty: &str) -> Value {
+    let entries = json!({
+        "type": "record",
+        "name": format!("k{key_id}_v{value_id}"),
+        "fields": [
+            avro_field("key", json!("int"), key_id),
+            avro_field("value", json!(ty), value_id),
+        ],
+    });
+    optional_avro_field(
+        name,
+        json!({ "type": "array", "logicalType": "map", "items": entries }),
+        field_id,
+    )
+}
+
+/// Avro type of the partition values of an Iceberg type.
+fn partition_avro_type(ty: &str) -> PolarsResult<Value> {
+    Ok(match ty {
+        "boolean" | "int" | "long" | "float" | "double" | "string" => json!(ty),
+        "binary" => json!("bytes"),
+        "date" => json!({ "type": "int", "logicalType": "date" }),
+        "timestamp" | "timestamptz" => json!({
+            "type": "long",
+            "logicalType": "timestamp-micros",
+            "adjust-to-utc": ty == "timestamptz",
+        }),
+        _ => polars_bail!(nyi = "writing Iceberg partition values of type {}", ty),
+    })
+}
+
+/// Encodes a partition value in its physical representation as a `["null", T]` union.
+fn encode_partition_value(e: &mut AvroEncoder, value: &AnyValue<'_>, ty: &str) -> PolarsResult<()> {
+    if value.is_null() {
+        e.long(0);
+        return Ok(());
+    }
+    e.long(1);
+
+    match (ty, value) {
+        ("boolean", AnyValue::Boolean(v)) => e.boolean(*v),
+        ("int" | "date", AnyValue::Int32(v)) => e.int(*v),
+        ("long" | "timestamp" | "timestamptz", AnyValue::Int64(v)) => e.long(*v),
+        ("float", AnyValue::Float32(v)) => e.0.extend_from_slice(&v.to_le_bytes()),
+        ("double", AnyValue::Float64(v)) => e.0.extend_from_slice(&v.to_le_bytes()),
+        ("string", AnyValue::String(v)) => e.string(v),
+        ("string", AnyValue::StringOwned(v)) => e.string(v),
+        ("binary", AnyValue::Binary(v)) => e.bytes(v),
+        ("binary", AnyValue::BinaryOwned(v)) => e.bytes(v),
+        _ => polars_bail!(
+            ComputeError: "invalid value {} for Iceberg partition field of type {}", value, ty
+        ),
+    }
+
+    Ok(())
+}
+
+/// Writes a manifest of added data files, whose snapshot ID and sequence numbers are inherited
+/// from the manifest list.
+///
+/// `schema` is the table schema as stored in the table metadata, and `partition_types` are the
+/// Iceberg types of the fields of `spec`. Partition values are taken in their physical
+/// representation.
+pub fn write_manifest(
+    files: &[DataFile],
+    schema: &Value,
+    spec: &PartitionSpec,
+    partition_types: &[&str],
+) -> PolarsResult<Vec<u8>> {
+    let partition_fields = spec
+        .fields
+        .iter()
+        .zip(partition_types)
+        .map(|(field, ty)| {
+            let field_id = required(field.field_id, "partition field-id")?;
+            Ok(optional_avro_field(
+                &field.name,
+                partition_avro_type(ty)?,
+                field_id,
+            ))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let data_file = json!({
+        "type": "record",
+        "name": "r2",
+        "fields": [
+            avro_field("content", json!("int"), 134),
+            avro_field("file_path", json!("string"), 100),
+            avro_field("file_format", json!("string"), 101),
+            avro_field(
+                "partition",
+                json!({ "type": "record", "name": "r102", "fields": partition_fields }),
+                102,
+            ),
+            avro_field("record_count", json!("long"), 103),
+            avro_field("file_size_in_bytes", json!("long"), 104),
+            id_map_avro_field("null_value_counts", 110, 121, 122, "long"),
+            id_map_avro_field("nan_value_counts", 137, 138, 139, "long"),
+            id_map_avro_field("lower_bounds", 125, 126, 127, "bytes"),
+            id_map_avro_field("upper_bounds", 128, 129, 130, "bytes"),
+            optional_avro_field(
+                "equality_ids",
+                json!({ "type": "array", "items": "int", "element-id": 136 }),
+                135,
+            ),
+        ],
+    });
+    let avro_schema = json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            avro_field("status", json!("int"), 0),
+            optional_avro_field("snapshot_id", json!("long"), 1),
+            optional_avro_field("sequence_number", json!("long"), 3),
+            optional_avro_field("file_sequence_number", json!("long"), 4),
+            avro_field("data_file", data_file, 2),
+        ],
+    });
+
+    let mut e = AvroEncoder::default();
+    for file in files {
+        polars_ensure!(
+            file.content == DataFileContent::Data && file.partition.len() == partition_types.len(),
+            ComputeError: "invalid data file '{}' for Iceberg manifest", file.file_path
+        );
+
+        // Added, with an inherited snapshot ID and sequence numbers.
+        e.int(1);
+        e.optional(None, AvroEncoder::long);
+        e.optional(None, AvroEncoder::long);
+        e.optional(None, AvroEncoder::long);
+
+        e.int(0);
+        e.string(&file.file_path);
+        e.string(&file.file_format);
+        for (value, ty) in file.partition.iter().zip(partition_types) {
+            encode_partition_value(&mut e, value, ty)?;
+        }
+        e.long(file.record_count);
+        e.long(file.file_size_in_bytes);
+        e.optional(Some(&file.null_value_counts), |e, m| {
+            e.id_map(m, |e, v| e.long(*v))
+        });
+        e.optional(Some(&file.nan_value_counts), |e, m| {
+            e.id_map(m, |e, v| e.long(*v))
+        });
+        e.optional(Some(&file.lower_bounds), |e, m| {
+            e.id_map(m, |e, v| e.bytes(v))
+        });
+        e.optional(Some(&file.upper_bounds), |e, m| {
+            e.id_map(m, |e, v| e.bytes(v))
+        });
+        e.optional(file.equality_ids.as_ref(), |e, ids| {
+            e.array(ids.iter(), |e, id| e.int(*id as i32))
+        });
+    }
+
+    let metadata = [
+        ("schema", schema.to_string()),
+        ("schema-id", schema["schema-id"].to_string()),
+        (
+            "partition-spec",
+            serde_json::to_string(&spec.fields).unwrap(),
+        ),
+        ("partition-spec-id", spec.spec_id.to_string()),
+        ("format-version", "2".to_string()),
+        ("content", "data".to_string()),
+    ];
+
+    Ok(e.finish(&avro_schema, &metadata, files.len()))
+}
+
+/// Writes the manifest list of a new snapshot.
+pub fn write_manifest_list(
+    manifests: &[ManifestFile],
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+) -> Vec<u8> {
+    let field_summary = json!({
+        "type": "record",
+        "name": "r508",
+        "fields": [
+            avro_field("contains_null", json!("boolean"), 509),
+            optional_avro_field("contains_nan", json!("boolean"), 518),
+            optional_avro_field("lower_bound", json!("bytes"), 510),
+            optional_avro_field("upper_bound", json!("bytes"), 511),
+        ],
+    });
+    let avro_schema = json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": [
+            avro_field("manifest_path", json!("string"), 500),
+            avro_field("manifest_length", json!("long"), 501),
+            avro_field("partition_spec_id", json!("int"), 502),
+            avro_field("content", json!("int"), 517),
+            avro_field("sequence_number", json!("long"), 515),
+            avro_field("min_sequence_number", json!("long"), 516),
+            avro_field("added_snapshot_id", json!("long"), 503),
+            avro_field("added_files_count", json!("int"), 504),
+            avro_field("existing_files_count", json!("int"), 505),
+            avro_field("deleted_files_count", json!("int"), 506),
+            avro_field("added_rows_count", json!("long"), 512),
+            avro_field("existing_rows_count", json!("long"), 513),
+            avro_field("deleted_rows_count", json!("long"), 514),
+            optional_avro_field(
+                "partitions",
+                json!({ "type": "array", "items": field_summary, "element-id": 508 }),
+                507,
+            ),
+        ],
+    });
+
+    let mut e = AvroEncoder::default();
+    for m in manifests {
+        e.string(&m.manifest_path);
+        e.long(m.manifest_length);
+        e.int(m.partition_spec_id);
+        e.int(match m.content {
+            ManifestContent::Data => 0,
+            ManifestContent::Deletes => 1,
+        });
+        e.long(m.sequence_number);
+        e.long(m.min_sequence_number);
+        e.long(m.added_snapshot_id);
+        e.int(m.added_files_count);
+        e.int(m.existing_files_count);
+        e.int(m.deleted_files_count);
+        e.long(m.added_rows_count);
+        e.long(m.existing_rows_count);
+        e.long(m.deleted_rows_count);
+        e.optional(m.partitions.as_ref(), |e, summaries| {
+            e.array(summaries.iter(), |e, s| {
+                e.boolean(s.contains_null);
+                e.optional(s.contains_nan, AvroEncoder::boolean);
+                e.optional(s.lower_bound.as_deref(), AvroEncoder::bytes);
+                e.optional(s.upper_bound.as_deref(), AvroEncoder::bytes);
+            })
+        });
+    }
+
+    let metadata = [
+        ("snapshot-id", snapshot_id.to_string()),
+        (
+            "parent-snapshot-id",
+            parent_snapshot_id.map_or_else(|| "null".to_string(), |id| id.to_string()),
+        ),
+        ("sequence-number", sequence_number.to_string()),
+        ("format-version", "2".to_string()),
+    ];
+
+    e.finish(&avro_schema, &metadata, manifests.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_single_value() {
+        assert_eq!(
+            decode_single_value(&[1], &DataType::Boolean),
+            Some(AnyValue::Boolean(true))
+        );
+        assert_eq!(
+            decode_single_value(&5i32.to_le_bytes(), &DataType::Int64),
+            Some(AnyValue::Int64(5))
+        );
+        assert_eq!(
+            decode_single_value(&(-5i64).to_le_bytes(), &DataType::Int64),
+            Some(AnyValue::Int64(-5))
+        );
+        assert_eq!(
+            decode_single_value(&1.5f32.to_le_bytes(), &DataType::Float64),
+            Some(AnyValue::Float64(1.5))
+        );
+        assert_eq!(
+            decode_single_value(&2i64.to_le_bytes(), &DataType::Time),
+            Some(AnyValue::Int64(2000))
+        );
+        assert_eq!(
+            decode_single_value(b"abc", &DataType::String),
+            Some(AnyValue::StringOwned("abc".into()))
+        );
+        assert_eq!(
+            decode_single_value(&[0; 16], &DataType::Decimal(Some(38), Some(2))),
+            None
+        );
+        // Truncated or corrupt bounds are ignored.
+        assert_eq!(decode_single_value(&[0; 3], &DataType::Int32), None);
+    }
+
+    #[test]
+    fn test_write_manifest_roundtrip() {
+        use super::super::metadata::PartitionField;
+
+        let spec = PartitionSpec {
+            spec_id: 1,
+            fields: vec![PartitionField {
+                source_id: 2,
+                field_id: Some(1000),
+                name: "d".into(),
+                transform: "identity".into(),
+            }],
+        };
+        let schema = json!({"type": "struct", "schema-id": 0, "fields": []});
+        let data_file = |path: &str, d: Option<i32>| DataFile {
+            content: DataFileContent::Data,
+            file_path: path.into(),
+            file_format: "PARQUET".into(),
+            partition: vec![d.map_or(AnyValue::Null, AnyValue::Int32)],
+            record_count: 3,
+            file_size_in_bytes: 100,
+            null_value_counts: PlHashMap::from_iter([(1, 1), (2, 0)]),
+            nan_value_counts: PlHashMap::new(),
+            lower_bounds: PlHashMap::from_iter([(
+                1,
+                encode_single_value(&AnyValue::Int64(-1)).unwrap(),
+            )]),
+            upper_bounds: PlHashMap::from_iter([(
+                1,
+                encode_single_value(&AnyValue::Int64(7)).unwrap(),
+            )]),
+            equality_ids: None,
+        };
+
+        let bytes = write_manifest(
+            &[
+                data_file("a.parquet", Some(5)),
+                data_file("b.parquet", None),
+            ],
+            &schema,
+            &spec,
+            &["date"],
+        )
+        .unwrap();
+
+        let manifest = ManifestFile {
+            manifest_path: "m.avro".into(),
+            manifest_length: bytes.len() as i64,
+            partition_spec_id: 1,
+            content: ManifestContent::Data,
+            sequence_number: 4,
+            min_sequence_number: 4,
+            added_snapshot_id: 10,
+            added_files_count: 2,
+            existing_files_count: 0,
+            deleted_files_count: 0,
+            added_rows_count: 6,
+            existing_rows_count: 0,
+            deleted_rows_count: 0,
+            partitions: Some(vec![FieldSummary {
+                contains_null: true,
+                contains_nan: None,
+                lower_bound: Some(5i32.to_le_bytes().to_vec()),
+                upper_bound: Some(5i32.to_le_bytes().to_vec()),
+            }]),
+        };
+        let list = read_manifest_list(&write_manifest_list(&[manifest], 10, None, 4)).unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].sequence_number, 4);
+        assert_eq!(list[0].added_rows_count, 6);
+        let summary = &list[0].partitions.as_ref().unwrap()[0];
+        assert!(summary.contains_null);
+        assert_eq!(summary.upper_bound, Some(5i32.to_le_bytes().to_vec()));
+
+        let entries = read_manifest(&bytes, &list[0], &spec).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].status, ManifestEntryStatus::Added);
+        // Inherited from the manifest list.
+        assert_eq!(entries[0].sequence_number, 4);
+        assert_eq!(entries[0].data_file.file_path, "a.parquet");
+        assert_eq!(entries[0].data_file.partition, [AnyValue::Int32(5)]);
+        assert_eq!(entries[1].data_file.partition, [AnyValue::Null]);
+        assert_eq!(entries[0].data_file.null_value_counts.get(&1), Some(&1));
+        assert_eq!(
+            entries[0]
+                .data_file
+                .upper_bounds
+                .get(&1)
+                .and_then(|b| decode_single_value(b, &DataType::Int64)),
+            Some(AnyValue::Int64(7))
+        );
+    }
+}
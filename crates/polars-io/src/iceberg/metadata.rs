@@ -0,0 +1,378 @@
+//! Iceberg table metadata, as stored in the `metadata.json` files of a table.
+//!
+//! See <https://iceberg.apache.org/spec/#table-metadata-fields>.
+
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{IcebergColumn, IcebergColumnType, IcebergSchema};
+use polars_error::{PolarsResult, polars_bail, polars_err, to_compute_err};
+use polars_utils::pl_str::PlSmallStr;
+use serde_json::Value;
+
+/// Physical ID used for the entries of a map, which do not have an ID in Iceberg.
+const MAP_ENTRIES_ID: u32 = u32::MAX;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableMetadata {
+    pub format_version: i32,
+    pub location: String,
+    #[serde(default)]
+    pub last_updated_ms: i64,
+    #[serde(default)]
+    pub last_sequence_number: i64,
+    #[serde(default)]
+    pub last_column_id: u32,
+    /// Highest assigned partition field ID, not set by some format version 1 writers.
+    #[serde(default)]
+    pub last_partition_id: Option<u32>,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    #[serde(default)]
+    pub schemas: Vec<TableSchema>,
+    /// Only set by format version 1.
+    #[serde(default)]
+    pub schema: Option<TableSchema>,
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<Snapshot>,
+    #[serde(default)]
+    pub default_spec_id: Option<i32>,
+    #[serde(default)]
+    pub partition_specs: Vec<PartitionSpec>,
+    /// Only set by format version 1.
+    #[serde(default)]
+    pub partition_spec: Option<Vec<PartitionField>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct TableSchema {
+    #[serde(default)]
+    pub schema_id: i32,
+    pub fields: Vec<Value>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Snapshot {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+    #[serde(default)]
+    pub sequence_number: i64,
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartitionField {
+    pub source_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_id: Option<u32>,
+    pub name: String,
+    pub transform: String,
+}
+
+impl PartitionField {
+    pub fn is_identity(&self) -> bool {
+        self.transform == "identity"
+    }
+}
+
+impl TableMetadata {
+    pub fn parse(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut metadata: Self = serde_json::from_slice(bytes).map_err(to_compute_err)?;
+
+        // Normalize format version 1 metadata.
+        if metadata.schemas.is_empty() {
+            metadata.schemas.extend(metadata.schema.take());
+        }
+        if metadata.partition_specs.is_empty() {
+            metadata.partition_specs.push(PartitionSpec {
+                spec_id: 0,
+                fields: metadata.partition_spec.take().unwrap_or_default(),
+            });
+        }
+        // `-1` is used by some writers to indicate that there is no snapshot.
+        if metadata.current_snapshot_id == Some(-1) {
+            metadata.current_snapshot_id = None;
+        }
+
+        Ok(metadata)
+    }
+
+    pub fn snapshot(&self, snapshot_id: i64) -> Option<&Snapshot> {
+        self.snapshots.iter().find(|s| s.snapshot_id == snapshot_id)
+    }
+
+    pub fn current_snapshot(&self) -> Option<&Snapshot> {
+        self.snapshot(self.current_snapshot_id?)
+    }
+
+    pub fn schema_by_id(&self, schema_id: i32) -> PolarsResult<&TableSchema> {
+        self.schemas
+            .iter()
+            .find(|s| s.schema_id == schema_id)
+            .ok_or_else(|| polars_err!(ComputeError: "Iceberg schema ID not found: {}", schema_id))
+    }
+
+    pub fn current_schema(&self) -> PolarsResult<&TableSchema> {
+        match self.current_schema_id {
+            Some(schema_id) => self.schema_by_id(schema_id),
+            None => self
+                .schemas
+                .last()
+                .ok_or_else(|| polars_err!(ComputeError: "Iceberg table metadata has no schema")),
+        }
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> PolarsResult<&PartitionSpec> {
+        self.partition_specs
+            .iter()
+            .find(|s| s.spec_id == spec_id)
+            .ok_or_else(
+                || polars_err!(ComputeError: "Iceberg partition spec ID not found: {}", spec_id),
+            )
+    }
+}
+
+impl TableSchema {
+    /// Converts the schema to an [`IcebergSchema`], which maps the field IDs of the data files to
+    /// the output columns.
+    pub fn to_iceberg_schema(&self) -> PolarsResult<IcebergSchema> {
+        struct_fields(&self.fields)
+    }
+
+    pub fn to_polars_schema(&self) -> PolarsResult<Schema> {
+        Ok(self
+            .to_iceberg_schema()?
+            .values()
+            .map(|col| Field::new(col.name.clone(), col.type_.to_polars_dtype()))
+            .collect())
+    }
+
+    /// Finds the top-level field with the given ID.
+    pub fn field_by_id(&self, field_id: u32) -> Option<(PlSmallStr, DataType)> {
+        let schema = self.to_iceberg_schema().ok()?;
+        let col = schema.get(&field_id)?;
+        Some((col.name.clone(), col.type_.to_polars_dtype()))
+    }
+}
+
+fn struct_fields(fields: &[Value]) -> PolarsResult<IcebergSchema> {
+    fields
+        .iter()
+        .map(|field| {
+            let id = field_id(field, "id")?;
+            let name = field
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| polars_err!(ComputeError: "missing name of Iceberg field {}", id))?;
+            let ty = field
+                .get("type")
+                .ok_or_else(|| polars_err!(ComputeError: "missing type of Iceberg field {}", id))?;
+
+            Ok((id, column(name.into(), id, ty)?))
+        })
+        .collect()
+}
+
+fn field_id(value: &Value, key: &str) -> PolarsResult<u32> {
+    value
+        .get(key)
+        .and_then(Value::as_u64)
+        .and_then(|id| u32::try_from(id).ok())
+        .ok_or_else(|| polars_err!(ComputeError: "missing or invalid Iceberg field ID '{}'", key))
+}
+
+fn column(name: PlSmallStr, physical_id: u32, ty: &Value) -> PolarsResult<IcebergColumn> {
+    let type_ = match ty {
+        Value::String(name) => IcebergColumnType::Primitive {
+            dtype: parse_primitive_type(name)?,
+        },
+        Value::Object(obj) => {
+            let get = |key: &str| {
+                obj.get(key)
+                    .ok_or_else(|| polars_err!(ComputeError: "missing {} of Iceberg type", key))
+            };
+
+            match obj.get("type").and_then(Value::as_str) {
+                Some("struct") => IcebergColumnType::Struct(struct_fields(
+                    get("fields")?
+                        .as_array()
+                        .map(Vec::as_slice)
+                        .unwrap_or_default(),
+                )?),
+                Some("list") => IcebergColumnType::List(Box::new(column(
+                    PlSmallStr::from_static("element"),
+                    field_id(ty, "element-id")?,
+                    get("element")?,
+                )?)),
+                Some("map") => {
+                    let key_id = field_id(ty, "key-id")?;
+                    let value_id = field_id(ty, "value-id")?;
+                    let entries = [
+                        (
+                            key_id,
+                            column(PlSmallStr::from_static("key"), key_id, get("key")?)?,
+                        ),
+                        (
+                            value_id,
+                            column(PlSmallStr::from_static("value"), value_id, get("value")?)?,
+                        ),
+                    ];
+
+                    IcebergColumnType::List(Box::new(IcebergColumn {
+                        name: PlSmallStr::from_static("entries"),
+                        physical_id: MAP_ENTRIES_ID,
+                        type_: IcebergColumnType::Struct(entries.into_iter().collect()),
+                    }))
+                },
+                _ => polars_bail!(ComputeError: "invalid Iceberg type: {}", ty),
+            }
+        },
+        _ => polars_bail!(ComputeError: "invalid Iceberg type: {}", ty),
+    };
+
+    Ok(IcebergColumn {
+        name,
+        physical_id,
+        type_,
+    })
+}
+
+pub(super) fn parse_primitive_type(name: &str) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match name {
+        "boolean" => Boolean,
+        "int" => Int32,
+        "long" => Int64,
+        "float" => Float32,
+        "double" => Float64,
+        "date" => Date,
+        "time" => Time,
+        "timestamp" => Datetime(TimeUnit::Microseconds, None),
+        "timestamptz" => Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ns" => Datetime(TimeUnit::Nanoseconds, None),
+        "timestamptz_ns" => Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+        "string" => String,
+        "uuid" | "binary" => Binary,
+        v if v.starts_with("fixed[") => Binary,
+        v if v.starts_with("decimal(") => {
+            // e.g. decimal(10, 2)
+            (|| {
+                let (precision, scale) = v
+                    .strip_prefix("decimal(")?
+                    .strip_suffix(')')?
+                    .split_once(',')?;
+                Some(Decimal(
+                    Some(precision.trim().parse().ok()?),
+                    Some(scale.trim().parse().ok()?),
+                ))
+            })()
+            .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg decimal type: {}", v))?
+        },
+        v => polars_bail!(ComputeError: "unsupported Iceberg type: {}", v),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table_metadata() {
+        let metadata = br#"{
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "s3://bucket/table",
+            "last-sequence-number": 2,
+            "current-schema-id": 1,
+            "schemas": [
+                {"type": "struct", "schema-id": 0, "fields": [
+                    {"id": 1, "name": "a", "required": false, "type": "int"}
+                ]},
+                {"type": "struct", "schema-id": 1, "fields": [
+                    {"id": 1, "name": "a", "required": false, "type": "long"},
+                    {"id": 2, "name": "ts", "required": false, "type": "timestamptz"},
+                    {"id": 3, "name": "tags", "required": false, "type": {
+                        "type": "map", "key-id": 4, "key": "string",
+                        "value-id": 5, "value": "int", "value-required": false
+                    }},
+                    {"id": 6, "name": "points", "required": false, "type": {
+                        "type": "list", "element-id": 7, "element-required": false,
+                        "element": {"type": "struct", "fields": [
+                            {"id": 8, "name": "x", "required": false, "type": "decimal(10, 2)"}
+                        ]}
+                    }}
+                ]}
+            ],
+            "default-spec-id": 0,
+            "partition-specs": [
+                {"spec-id": 0, "fields": [
+                    {"source-id": 1, "field-id": 1000, "name": "a", "transform": "identity"}
+                ]}
+            ],
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {"snapshot-id": 1, "timestamp-ms": 1, "sequence-number": 1,
+                    "manifest-list": "s3://bucket/table/metadata/snap-1.avro", "schema-id": 0},
+                {"snapshot-id": 2, "timestamp-ms": 2, "sequence-number": 2,
+                    "manifest-list": "s3://bucket/table/metadata/snap-2.avro", "schema-id": 1}
+            ]
+        }"#;
+
+        let metadata = TableMetadata::parse(metadata).unwrap();
+        assert_eq!(metadata.current_snapshot().unwrap().snapshot_id, 2);
+        assert!(metadata.partition_spec(0).unwrap().fields[0].is_identity());
+
+        let schema = metadata.current_schema().unwrap();
+        assert_eq!(
+            schema.to_polars_schema().unwrap(),
+            Schema::from_iter([
+                Field::new("a".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+                ),
+                Field::new(
+                    "tags".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+                Field::new(
+                    "points".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![Field::new(
+                        "x".into(),
+                        DataType::Decimal(Some(10), Some(2))
+                    )])))
+                ),
+            ])
+        );
+
+        let iceberg_schema = schema.to_iceberg_schema().unwrap();
+        assert_eq!(
+            iceberg_schema.keys().copied().collect::<Vec<_>>(),
+            [1, 2, 3, 6]
+        );
+
+        let snapshot_schema = metadata.schema_by_id(0).unwrap();
+        assert_eq!(
+            snapshot_schema.field_by_id(1),
+            Some(("a".into(), DataType::Int32))
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod manifest;
+pub mod metadata;
+mod table;
+mod write;
+
+pub use table::{IcebergDataFile, IcebergEqualityDelete, IcebergScan, IcebergTable};
+pub use write::{IcebergCommit, IcebergWriteMode};
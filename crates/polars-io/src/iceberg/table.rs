@@ -0,0 +1,1144 @@
+//! Planning of Iceberg table scans from the table metadata, manifest lists and manifests.
+//!
+//! See <https://iceberg.apache.org/spec/#scan-planning>.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{IcebergColumn, IcebergColumnType, IcebergSchema};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::format_pl_smallstr;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::plpath::{PlPath, PlPathRef};
+
+use super::manifest::{
+    DataFile, DataFileContent, ManifestContent, ManifestEntry, ManifestEntryStatus, ManifestFile,
+    decode_single_value, read_manifest, read_manifest_list,
+};
+use super::metadata::{TableMetadata, TableSchema};
+use crate::cloud::CloudOptions;
+use crate::predicates::SkipBatchPredicate;
+
+/// Field ID of the `file_path` column of position delete files.
+const DELETE_FILE_PATH_ID: u32 = 2147483546;
+
+/// An Iceberg table, loaded from one of its `metadata.json` files.
+#[derive(Debug, Clone)]
+pub struct IcebergTable {
+    metadata_location: PlPath,
+    metadata: Arc<TableMetadata>,
+    cloud_options: Option<CloudOptions>,
+}
+
+/// An equality delete file that applies to a data file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcebergEqualityDelete {
+    pub path: String,
+    /// Field IDs of the columns used to match deleted rows.
+    pub equality_ids: Vec<u32>,
+}
+
+/// A data file to scan, together with the delete files that apply to it.
+#[derive(Debug, Clone)]
+pub struct IcebergDataFile {
+    pub path: PlPath,
+    pub record_count: i64,
+    pub spec_id: i32,
+    /// Partition values, in the order of the fields of the partition spec.
+    pub partition: Vec<AnyValue<'static>>,
+    pub position_deletes: Vec<String>,
+    pub equality_deletes: Vec<IcebergEqualityDelete>,
+    null_value_counts: PlHashMap<u32, i64>,
+    nan_value_counts: PlHashMap<u32, i64>,
+    lower_bounds: PlHashMap<u32, Vec<u8>>,
+    upper_bounds: PlHashMap<u32, Vec<u8>>,
+}
+
+/// The data files of a snapshot of an Iceberg table.
+#[derive(Debug, Clone)]
+pub struct IcebergScan {
+    metadata: Arc<TableMetadata>,
+    snapshot_id: Option<i64>,
+    schema: SchemaRef,
+    iceberg_schema: Arc<IcebergSchema>,
+    files: Vec<IcebergDataFile>,
+}
+
+impl IcebergTable {
+    /// Loads the table metadata at `metadata_location`, e.g.
+    /// `s3://bucket/table/metadata/00001-<uuid>.metadata.json`.
+    pub fn load(
+        metadata_location: PlPathRef<'_>,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let metadata = TableMetadata::parse(&read_file(metadata_location, cloud_options)?)?;
+
+        polars_ensure!(
+            metadata.format_version <= 2,
+            nyi = "reading Iceberg tables of format version {}",
+            metadata.format_version
+        );
+
+        Ok(Self {
+            metadata_location: metadata_location.into_owned(),
+            metadata: Arc::new(metadata),
+            cloud_options: cloud_options.cloned(),
+        })
+    }
+
+    pub fn metadata_location(&self) -> &PlPath {
+        &self.metadata_location
+    }
+
+    pub fn metadata(&self) -> &TableMetadata {
+        &self.metadata
+    }
+
+    /// Plans a scan of the snapshot with the given ID, or of the current snapshot if `None`.
+    ///
+    /// With a `predicate`, manifests are skipped using the partition summaries of the manifest
+    /// list, and data files are skipped using their column bounds and partition values.
+    pub fn scan(
+        &self,
+        snapshot_id: Option<i64>,
+        predicate: Option<&dyn SkipBatchPredicate>,
+    ) -> PolarsResult<IcebergScan> {
+        let metadata = &self.metadata;
+
+        let (snapshot, table_schema) = match snapshot_id {
+            Some(snapshot_id) => {
+                let snapshot = metadata.snapshot(snapshot_id).ok_or_else(
+                    || polars_err!(ComputeError: "Iceberg snapshot ID not found: {}", snapshot_id),
+                )?;
+                let schema = match snapshot.schema_id {
+                    Some(schema_id) => metadata.schema_by_id(schema_id)?,
+                    None => metadata.current_schema()?,
+                };
+                (Some(snapshot), schema)
+            },
+            None => (metadata.current_snapshot(), metadata.current_schema()?),
+        };
+
+        let mut scan = IcebergScan {
+            metadata: metadata.clone(),
+            snapshot_id: snapshot.map(|s| s.snapshot_id),
+            schema: Arc::new(table_schema.to_polars_schema()?),
+            iceberg_schema: Arc::new(table_schema.to_iceberg_schema()?),
+            files: vec![],
+        };
+
+        let Some(manifest_list) = snapshot.and_then(|s| s.manifest_list.as_deref()) else {
+            return Ok(scan);
+        };
+
+        let mut manifests = read_manifest_list(&read_file(
+            resolve_path(manifest_list).as_ref(),
+            self.cloud_options.as_ref(),
+        )?)?;
+
+        if let Some(predicate) = predicate {
+            let stats = manifest_statistics_df(&manifests, metadata, table_schema)?;
+            let skip = predicate.evaluate_with_stat_df(&stats)?;
+            let mut idx = 0;
+            manifests.retain(|m| {
+                // Delete manifests are only pruned through the data files they apply to.
+                let keep = m.content == ManifestContent::Deletes || !skip.get_bit(idx);
+                idx += 1;
+                keep
+            });
+        }
+
+        let mut data_files = vec![];
+        let mut delete_files = vec![];
+
+        for manifest in &manifests {
+            let spec = metadata.partition_spec(manifest.partition_spec_id)?;
+            let bytes = read_file(
+                resolve_path(&manifest.manifest_path).as_ref(),
+                self.cloud_options.as_ref(),
+            )?;
+
+            for entry in read_manifest(&bytes, manifest, spec)? {
+                if entry.status == ManifestEntryStatus::Deleted {
+                    continue;
+                }
+
+                polars_ensure!(
+                    entry.data_file.file_format.eq_ignore_ascii_case("parquet"),
+                    nyi = "reading Iceberg {} files: '{}'",
+                    entry.data_file.file_format,
+                    entry.data_file.file_path
+                );
+
+                match entry.data_file.content {
+                    DataFileContent::Data => data_files.push((manifest.partition_spec_id, entry)),
+                    _ => delete_files.push((manifest.partition_spec_id, entry)),
+                }
+            }
+        }
+
+        scan.files = data_files
+            .into_iter()
+            .map(|(spec_id, entry)| {
+                let (position_deletes, equality_deletes) =
+                    applicable_deletes(spec_id, &entry, &delete_files, metadata)?;
+                let ManifestEntry {
+                    data_file:
+                        DataFile {
+                            file_path,
+                            partition,
+                            record_count,
+                            null_value_counts,
+                            nan_value_counts,
+                            lower_bounds,
+                            upper_bounds,
+                            ..
+                        },
+                    ..
+                } = entry;
+
+                Ok(IcebergDataFile {
+                    path: resolve_path(&file_path),
+                    record_count,
+                    spec_id,
+                    partition,
+                    position_deletes,
+                    equality_deletes,
+                    null_value_counts,
+                    nan_value_counts,
+                    lower_bounds,
+                    upper_bounds,
+                })
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        if let Some(predicate) = predicate {
+            scan.prune(predicate)?;
+        }
+
+        Ok(scan)
+    }
+}
+
+impl IcebergScan {
+    /// ID of the scanned snapshot, `None` if the table has no snapshots.
+    pub fn snapshot_id(&self) -> Option<i64> {
+        self.snapshot_id
+    }
+
+    /// Schema of the table at the scanned snapshot.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Maps the field IDs of the data files to the columns of [`IcebergScan::schema`].
+    pub fn iceberg_schema(&self) -> &Arc<IcebergSchema> {
+        &self.iceberg_schema
+    }
+
+    pub fn files(&self) -> &[IcebergDataFile] {
+        &self.files
+    }
+
+    /// Builds a DataFrame of statistics in the format expected by
+    /// [`SkipBatchPredicate::evaluate_with_stat_df`], with one row per data file.
+    ///
+    /// Statistics are taken from the column bounds of the manifests, or from the partition value
+    /// for identity-partitioned columns. They are missing (null) for nested columns and for types
+    /// whose bounds are not decoded, such as decimals. Float bounds are also missing for files
+    /// that may contain NaN, as the bounds exclude NaN while Polars orders it above all values.
+    pub fn statistics_df(&self) -> PolarsResult<DataFrame> {
+        let num_files = self.files.len();
+
+        let mut columns = Vec::with_capacity(1 + 3 * self.schema.len());
+        columns.push(Column::new(
+            PlSmallStr::from_static("len"),
+            self.files
+                .iter()
+                .map(|f| Some(f.record_count as IdxSize))
+                .collect::<IdxCa>()
+                .into_series(),
+        ));
+
+        for (&field_id, col) in self.iceberg_schema.iter() {
+            let dtype = col.type_.to_polars_dtype();
+            let (mut min, mut max, mut nc) = (
+                Vec::with_capacity(num_files),
+                Vec::with_capacity(num_files),
+                Vec::with_capacity(num_files),
+            );
+
+            for file in &self.files {
+                let partition_value = match col.type_.is_nested() {
+                    true => None,
+                    false => self.identity_partition_value(file, field_id),
+                };
+
+                match partition_value {
+                    Some(value) => {
+                        nc.push(Some(match value.is_null() {
+                            true => file.record_count,
+                            false => 0,
+                        }));
+                        min.push(value.clone());
+                        max.push(value);
+                    },
+                    None => {
+                        let may_contain_nan = dtype.is_float()
+                            && file
+                                .nan_value_counts
+                                .get(&field_id)
+                                .is_none_or(|&count| count > 0);
+                        let bound = |bounds: &PlHashMap<u32, Vec<u8>>| {
+                            bounds
+                                .get(&field_id)
+                                .filter(|_| !col.type_.is_nested() && !may_contain_nan)
+                                .and_then(|b| decode_single_value(b, &dtype))
+                                .unwrap_or(AnyValue::Null)
+                        };
+                        min.push(bound(&file.lower_bounds));
+                        max.push(bound(&file.upper_bounds));
+                        nc.push(file.null_value_counts.get(&field_id).copied());
+                    },
+                }
+            }
+
+            columns.push(stat_column(
+                format_pl_smallstr!("{}_min", col.name),
+                &min,
+                &dtype,
+            )?);
+            columns.push(stat_column(
+                format_pl_smallstr!("{}_max", col.name),
+                &max,
+                &dtype,
+            )?);
+            columns.push(Column::new(
+                format_pl_smallstr!("{}_nc", col.name),
+                nc.iter()
+                    .map(|v| v.map(|v| v as IdxSize))
+                    .collect::<IdxCa>()
+                    .into_series(),
+            ));
+        }
+
+        DataFrame::new_with_height(num_files, columns)
+    }
+
+    /// Removes the files that the predicate proves cannot contain matching rows, based on the
+    /// statistics of [`IcebergScan::statistics_df`].
+    pub fn prune(&mut self, predicate: &dyn SkipBatchPredicate) -> PolarsResult<()> {
+        if self.files.is_empty() {
+            return Ok(());
+        }
+
+        let skip = predicate.evaluate_with_stat_df(&self.statistics_df()?)?;
+        let mut idx = 0;
+        self.files.retain(|_| {
+            let keep = !skip.get_bit(idx);
+            idx += 1;
+            keep
+        });
+
+        Ok(())
+    }
+
+    /// Values of identity-partitioned columns for every file, keyed by the source field ID.
+    ///
+    /// These provide the values of partition columns that are not stored in the data files. An
+    /// error message is given instead for columns whose values cannot be used, e.g. because
+    /// their type changed in an unsupported way.
+    pub fn identity_partition_values(&self) -> PlIndexMap<u32, Result<Column, String>> {
+        // {source_field_id: [(spec_id, partition_field_index)]}
+        let mut fields: PlIndexMap<u32, Vec<(i32, usize)>> = PlIndexMap::new();
+        for spec in &self.metadata.partition_specs {
+            for (idx, pf) in spec.fields.iter().enumerate() {
+                if pf.is_identity() && find_column(&self.iceberg_schema, pf.source_id).is_some() {
+                    fields
+                        .entry(pf.source_id)
+                        .or_default()
+                        .push((spec.spec_id, idx));
+                }
+            }
+        }
+
+        fields
+            .into_iter()
+            .map(|(source_id, spec_fields)| {
+                let values = (|| {
+                    let col = find_column(&self.iceberg_schema, source_id).unwrap();
+                    let dtype = col.type_.to_polars_dtype();
+
+                    if col.type_.is_nested() {
+                        return Err(format!("non-primitive type: {dtype}"));
+                    }
+
+                    for schema in &self.metadata.schemas {
+                        let Some(old) = schema
+                            .to_iceberg_schema()
+                            .ok()
+                            .and_then(|s| find_column(&s, source_id).cloned())
+                        else {
+                            continue;
+                        };
+                        let old_dtype = old.type_.to_polars_dtype();
+
+                        if !(old_dtype == dtype
+                            || (old_dtype == DataType::Int32 && dtype == DataType::Int64)
+                            || (old_dtype.is_float() && dtype.is_float()))
+                        {
+                            return Err(format!(
+                                "unsupported type change: from: {old_dtype}, to: {dtype}"
+                            ));
+                        }
+                    }
+
+                    let values = self
+                        .files
+                        .iter()
+                        .map(|file| {
+                            spec_fields
+                                .iter()
+                                .find(|(spec_id, _)| *spec_id == file.spec_id)
+                                .and_then(|(_, idx)| file.partition.get(*idx).cloned())
+                                .unwrap_or(AnyValue::Null)
+                        })
+                        .collect::<Vec<_>>();
+
+                    stat_column(col.name.clone(), &values, &dtype).map_err(|e| e.to_string())
+                })();
+
+                (source_id, values)
+            })
+            .collect()
+    }
+
+    /// Value of the identity partition of `file` on the given top-level column.
+    fn identity_partition_value(
+        &self,
+        file: &IcebergDataFile,
+        source_id: u32,
+    ) -> Option<AnyValue<'static>> {
+        let spec = self.metadata.partition_spec(file.spec_id).ok()?;
+        let idx = spec
+            .fields
+            .iter()
+            .position(|pf| pf.is_identity() && pf.source_id == source_id)?;
+        file.partition.get(idx).cloned()
+    }
+}
+
+/// Builds the statistics of the manifests of the manifest list, with one row per manifest.
+///
+/// Only identity-partitioned columns have statistics, taken from the partition summaries. As for
+/// data files, float bounds are missing for manifests that may contain NaN.
+fn manifest_statistics_df(
+    manifests: &[ManifestFile],
+    metadata: &TableMetadata,
+    schema: &TableSchema,
+) -> PolarsResult<DataFrame> {
+    let num_manifests = manifests.len();
+    let iceberg_schema = schema.to_iceberg_schema()?;
+
+    let mut columns = Vec::with_capacity(1 + 3 * iceberg_schema.len());
+    columns.push(Column::full_null(
+        PlSmallStr::from_static("len"),
+        num_manifests,
+        &IDX_DTYPE,
+    ));
+
+    for (&field_id, col) in iceberg_schema.iter() {
+        let dtype = col.type_.to_polars_dtype();
+        let (mut min, mut max, mut nc) = (
+            Vec::with_capacity(num_manifests),
+            Vec::with_capacity(num_manifests),
+            Vec::with_capacity(num_manifests),
+        );
+
+        for manifest in manifests {
+            let summary = (!col.type_.is_nested())
+                .then(|| metadata.partition_spec(manifest.partition_spec_id).ok())
+                .flatten()
+                .and_then(|spec| {
+                    spec.fields
+                        .iter()
+                        .position(|pf| pf.is_identity() && pf.source_id == field_id)
+                })
+                .and_then(|idx| manifest.partitions.as_ref()?.get(idx));
+            let may_contain_nan =
+                dtype.is_float() && summary.is_none_or(|s| s.contains_nan != Some(false));
+
+            let decode = |bound: Option<&Vec<u8>>| {
+                bound
+                    .filter(|_| !may_contain_nan)
+                    .and_then(|b| decode_single_value(b, &dtype))
+                    .unwrap_or(AnyValue::Null)
+            };
+
+            min.push(decode(summary.and_then(|s| s.lower_bound.as_ref())));
+            max.push(decode(summary.and_then(|s| s.upper_bound.as_ref())));
+            nc.push(summary.and_then(|s| (!s.contains_null).then_some(0 as IdxSize)));
+        }
+
+        columns.push(stat_column(
+            format_pl_smallstr!("{}_min", col.name),
+            &min,
+            &dtype,
+        )?);
+        columns.push(stat_column(
+            format_pl_smallstr!("{}_max", col.name),
+            &max,
+            &dtype,
+        )?);
+        columns.push(Column::new(
+            format_pl_smallstr!("{}_nc", col.name),
+            nc.into_iter().collect::<IdxCa>().into_series(),
+        ));
+    }
+
+    DataFrame::new_with_height(num_manifests, columns)
+}
+
+/// Builds a column from values in the physical representation of `dtype`.
+fn stat_column(
+    name: PlSmallStr,
+    values: &[AnyValue<'static>],
+    dtype: &DataType,
+) -> PolarsResult<Column> {
+    if dtype.is_nested() || values.iter().all(AnyValue::is_null) {
+        return Ok(Column::full_null(name, values.len(), dtype));
+    }
+
+    // Temporal values are given as their physical representation.
+    let physical = match dtype.is_temporal() {
+        true => dtype.to_physical(),
+        false => dtype.clone(),
+    };
+    let s = Series::from_any_values_and_dtype(name, values, &physical, false)?;
+    Ok(s.cast(dtype)?.into_column())
+}
+
+/// Finds the delete files that apply to a data file.
+fn applicable_deletes(
+    spec_id: i32,
+    entry: &ManifestEntry,
+    delete_files: &[(i32, ManifestEntry)],
+    metadata: &TableMetadata,
+) -> PolarsResult<(Vec<String>, Vec<IcebergEqualityDelete>)> {
+    let data_file = &entry.data_file;
+    let mut position_deletes = vec![];
+    let mut equality_deletes = vec![];
+
+    for (delete_spec_id, delete) in delete_files {
+        let same_partition =
+            *delete_spec_id == spec_id && delete.data_file.partition == data_file.partition;
+
+        match delete.data_file.content {
+            DataFileContent::PositionDeletes => {
+                // Skip delete files whose `file_path` bounds exclude the data file.
+                let path = data_file.file_path.as_bytes();
+                let may_reference = delete
+                    .data_file
+                    .lower_bounds
+                    .get(&DELETE_FILE_PATH_ID)
+                    .is_none_or(|lower| lower.as_slice() <= path)
+                    && delete
+                        .data_file
+                        .upper_bounds
+                        .get(&DELETE_FILE_PATH_ID)
+                        .is_none_or(|upper| path <= upper.as_slice());
+
+                if entry.sequence_number <= delete.sequence_number
+                    && same_partition
+                    && may_reference
+                {
+                    position_deletes.push(delete.data_file.file_path.clone());
+                }
+            },
+            DataFileContent::EqualityDeletes => {
+                // Equality deletes of an unpartitioned spec apply to all partitions.
+                let global = metadata.partition_spec(*delete_spec_id)?.fields.is_empty();
+
+                if entry.sequence_number < delete.sequence_number && (global || same_partition) {
+                    let Some(equality_ids) = delete.data_file.equality_ids.clone() else {
+                        polars_bail!(
+                            ComputeError:
+                            "Iceberg equality delete file has no equality IDs: '{}'",
+                            delete.data_file.file_path
+                        )
+                    };
+
+                    equality_deletes.push(IcebergEqualityDelete {
+                        path: delete.data_file.file_path.clone(),
+                        equality_ids,
+                    });
+                }
+            },
+            DataFileContent::Data => unreachable!(),
+        }
+    }
+
+    Ok((position_deletes, equality_deletes))
+}
+
+/// Finds the column with the given field ID, which may be nested.
+fn find_column(schema: &IcebergSchema, field_id: u32) -> Option<&IcebergColumn> {
+    if let Some(col) = schema.get(&field_id) {
+        return Some(col);
+    }
+
+    schema.values().find_map(|col| {
+        let mut type_ = &col.type_;
+        while let IcebergColumnType::List(inner) | IcebergColumnType::FixedSizeList(inner, _) =
+            type_
+        {
+            if inner.physical_id == field_id {
+                return Some(inner.as_ref());
+            }
+            type_ = &inner.type_;
+        }
+
+        match type_ {
+            IcebergColumnType::Struct(fields) => find_column(fields, field_id),
+            _ => None,
+        }
+    })
+}
+
+/// Converts a path from the table metadata to a [`PlPath`]. Local paths may be written as `file:`
+/// URIs.
+pub(super) fn resolve_path(path: &str) -> PlPath {
+    match path.strip_prefix("file:") {
+        Some(local) if local.starts_with('/') => PlPath::new(local.trim_start_matches("//")),
+        _ => PlPath::new(path),
+    }
+}
+
+#[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+pub(super) fn read_file(
+    path: PlPathRef<'_>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<u8>> {
+    match path {
+        PlPathRef::Local(local) => std::fs::read(local).map_err(|err| {
+            polars_err!(
+                ComputeError:
+                "failed to read Iceberg file '{}': {}",
+                local.display(), err
+            )
+        }),
+        PlPathRef::Cloud(_) => {
+            #[cfg(feature = "cloud")]
+            {
+                use crate::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+
+                let uri = path.to_str();
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let source = DynByteSourceBuilder::ObjectStore
+                        .try_build_from_path(uri, cloud_options)
+                        .await?;
+                    let size = source.get_size().await?;
+                    Ok(source.get_range(0..size).await?.to_vec())
+                })
+            }
+            #[cfg(not(feature = "cloud"))]
+            {
+                polars_bail!(
+                    ComputeError:
+                    "Feature `cloud` must be enabled to read Iceberg tables from cloud urls"
+                )
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use arrow::bitmap::Bitmap;
+
+    use super::super::manifest::FieldSummary;
+    use super::*;
+    use crate::SerWriter;
+    use crate::avro::AvroWriter;
+
+    struct Entry {
+        status: i32,
+        sequence_number: Option<i64>,
+        content: i32,
+        path: &'static str,
+        p: &'static str,
+        a_bounds: Option<(i64, i64)>,
+        equality_ids: Option<Vec<i32>>,
+    }
+
+    fn write_avro(path: &Path, mut df: DataFrame) {
+        AvroWriter::new(std::fs::File::create(path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+    }
+
+    /// Builds an Iceberg map column, stored as a list of key-value records.
+    fn id_map(
+        name: &str,
+        value_dtype: DataType,
+        values: impl Iterator<Item = Option<Series>>,
+    ) -> Series {
+        let dtype = DataType::List(Box::new(DataType::Struct(vec![
+            Field::new("key".into(), DataType::Int32),
+            Field::new("value".into(), value_dtype),
+        ])));
+
+        values
+            .map(|value| {
+                value.map(|value| {
+                    StructChunked::from_series(
+                        "".into(),
+                        1,
+                        [Series::new("key".into(), [1i32]), value].iter(),
+                    )
+                    .unwrap()
+                    .into_series()
+                })
+            })
+            .collect::<ListChunked>()
+            .with_name(name.into())
+            .into_series()
+            .cast(&dtype)
+            .unwrap()
+    }
+
+    fn write_manifest(path: &Path, entries: &[Entry]) {
+        let height = entries.len();
+        let bound = |name: &str, idx: usize| {
+            id_map(
+                name,
+                DataType::Binary,
+                entries.iter().map(|e| {
+                    let (lower, upper) = e.a_bounds?;
+                    let value = [lower, upper][idx].to_le_bytes();
+                    Some(
+                        BinaryChunked::from_slice("value".into(), &[value.as_slice()])
+                            .into_series(),
+                    )
+                }),
+            )
+        };
+
+        let data_file = StructChunked::from_series(
+            "data_file".into(),
+            height,
+            [
+                Series::new(
+                    "content".into(),
+                    entries.iter().map(|e| e.content).collect::<Vec<_>>(),
+                ),
+                Series::new(
+                    "file_path".into(),
+                    entries.iter().map(|e| e.path).collect::<Vec<_>>(),
+                ),
+                Series::new("file_format".into(), vec!["PARQUET"; height]),
+                StructChunked::from_series(
+                    "partition".into(),
+                    height,
+                    [Series::new(
+                        "p".into(),
+                        entries.iter().map(|e| e.p).collect::<Vec<_>>(),
+                    )]
+                    .iter(),
+                )
+                .unwrap()
+                .into_series(),
+                Series::new("record_count".into(), vec![2i64; height]),
+                Series::new("file_size_in_bytes".into(), vec![1i64; height]),
+                id_map(
+                    "null_value_counts",
+                    DataType::Int64,
+                    entries
+                        .iter()
+                        .map(|_| Some(Series::new("value".into(), [0i64]))),
+                ),
+                bound("lower_bounds", 0),
+                bound("upper_bounds", 1),
+                entries
+                    .iter()
+                    .map(|e| {
+                        e.equality_ids
+                            .as_ref()
+                            .map(|ids| Series::new("".into(), ids))
+                    })
+                    .collect::<ListChunked>()
+                    .with_name("equality_ids".into())
+                    .into_series()
+                    .cast(&DataType::List(Box::new(DataType::Int32)))
+                    .unwrap(),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+
+        let df = DataFrame::new(vec![
+            Column::new(
+                "status".into(),
+                entries.iter().map(|e| e.status).collect::<Vec<_>>(),
+            ),
+            Column::new("snapshot_id".into(), vec![1i64; height]),
+            Column::new(
+                "sequence_number".into(),
+                entries
+                    .iter()
+                    .map(|e| e.sequence_number)
+                    .collect::<Vec<_>>(),
+            ),
+            data_file.into_column(),
+        ])
+        .unwrap();
+
+        write_avro(path, df);
+    }
+
+    /// Writes a manifest list. Every manifest has a summary of the `p` partition field.
+    fn write_manifest_list(path: &Path, manifests: &[(String, i32, i64, &str)]) {
+        let partitions = manifests
+            .iter()
+            .map(|(_, _, _, p)| {
+                Some(
+                    StructChunked::from_series(
+                        "".into(),
+                        1,
+                        [
+                            Series::new("contains_null".into(), [false]),
+                            BinaryChunked::from_slice("lower_bound".into(), &[p.as_bytes()])
+                                .into_series(),
+                            BinaryChunked::from_slice("upper_bound".into(), &[p.as_bytes()])
+                                .into_series(),
+                        ]
+                        .iter(),
+                    )
+                    .unwrap()
+                    .into_series(),
+                )
+            })
+            .collect::<ListChunked>()
+            .with_name("partitions".into());
+
+        let df = DataFrame::new(vec![
+            Column::new(
+                "manifest_path".into(),
+                manifests.iter().map(|m| m.0.as_str()).collect::<Vec<_>>(),
+            ),
+            Column::new("manifest_length".into(), vec![1i64; manifests.len()]),
+            Column::new("partition_spec_id".into(), vec![0i32; manifests.len()]),
+            Column::new(
+                "content".into(),
+                manifests.iter().map(|m| m.1).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "sequence_number".into(),
+                manifests.iter().map(|m| m.2).collect::<Vec<_>>(),
+            ),
+            partitions.into_series().into_column(),
+        ])
+        .unwrap();
+
+        write_avro(path, df);
+    }
+
+    fn data_entry(path: &'static str, p: &'static str, a_bounds: (i64, i64)) -> Entry {
+        Entry {
+            status: 1,
+            sequence_number: None,
+            content: 0,
+            path,
+            p,
+            a_bounds: Some(a_bounds),
+            equality_ids: None,
+        }
+    }
+
+    /// Writes a table with the data files `x.parquet` (p = "x") and `y.parquet` (p = "y") in
+    /// snapshot 1, and delete files for both in snapshot 2.
+    fn write_table(root: &Path) -> PlPath {
+        let path = |name: &str| root.join(name).to_str().unwrap().to_string();
+
+        write_manifest(
+            &root.join("m-x.avro"),
+            &[data_entry("/data/x.parquet", "x", (0, 9))],
+        );
+        write_manifest(
+            &root.join("m-y.avro"),
+            &[
+                data_entry("/data/y.parquet", "y", (10, 19)),
+                Entry {
+                    status: 2,
+                    sequence_number: Some(1),
+                    ..data_entry("/data/z.parquet", "y", (20, 29))
+                },
+            ],
+        );
+        write_manifest(
+            &root.join("m-deletes.avro"),
+            &[
+                Entry {
+                    content: 1,
+                    a_bounds: None,
+                    ..data_entry("/data/x-pos-deletes.parquet", "x", (0, 0))
+                },
+                Entry {
+                    content: 2,
+                    a_bounds: None,
+                    equality_ids: Some(vec![1]),
+                    ..data_entry("/data/y-eq-deletes.parquet", "y", (0, 0))
+                },
+            ],
+        );
+
+        let data_manifests = [
+            (format!("file://{}", path("m-x.avro")), 0, 1, "x"),
+            (path("m-y.avro"), 0, 1, "y"),
+        ];
+        write_manifest_list(&root.join("snap-1.avro"), &data_manifests);
+        let mut manifests = data_manifests.to_vec();
+        manifests.push((path("m-deletes.avro"), 1, 2, "x"));
+        write_manifest_list(&root.join("snap-2.avro"), &manifests);
+
+        let metadata = format!(
+            r#"{{
+                "format-version": 2,
+                "location": "{root}",
+                "current-schema-id": 0,
+                "schemas": [{{"type": "struct", "schema-id": 0, "fields": [
+                    {{"id": 1, "name": "a", "required": false, "type": "long"}},
+                    {{"id": 2, "name": "p", "required": false, "type": "string"}}
+                ]}}],
+                "default-spec-id": 0,
+                "partition-specs": [{{"spec-id": 0, "fields": [
+                    {{"source-id": 2, "field-id": 1000, "name": "p", "transform": "identity"}}
+                ]}}],
+                "current-snapshot-id": 2,
+                "snapshots": [
+                    {{"snapshot-id": 1, "timestamp-ms": 1, "sequence-number": 1,
+                        "manifest-list": "{snap_1}", "schema-id": 0}},
+                    {{"snapshot-id": 2, "timestamp-ms": 2, "sequence-number": 2,
+                        "manifest-list": "{snap_2}", "schema-id": 0}}
+                ]
+            }}"#,
+            root = root.display(),
+            snap_1 = path("snap-1.avro"),
+            snap_2 = path("snap-2.avro"),
+        );
+        std::fs::write(root.join("v1.metadata.json"), metadata).unwrap();
+
+        PlPath::Local(root.join("v1.metadata.json").into())
+    }
+
+    fn file_names(scan: &IcebergScan) -> Vec<&str> {
+        scan.files()
+            .iter()
+            .map(|f| f.path.as_ref().to_str().rsplit('/').next().unwrap())
+            .collect()
+    }
+
+    struct SkipIfMaxBelow {
+        schema: SchemaRef,
+        column: &'static str,
+        value: &'static str,
+    }
+
+    impl SkipBatchPredicate for SkipIfMaxBelow {
+        fn schema(&self) -> &SchemaRef {
+            &self.schema
+        }
+
+        fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+            let max = df.column(&format!("{}_max", self.column))?.str()?;
+            Ok(max
+                .iter()
+                .map(|v| v.is_some_and(|v| v < self.value))
+                .collect())
+        }
+    }
+
+    #[test]
+    fn test_scan_planning() {
+        let dir = tempfile::tempdir().unwrap();
+        let table = IcebergTable::load(write_table(dir.path()).as_ref(), None).unwrap();
+
+        let scan = table.scan(None, None).unwrap();
+        assert_eq!(scan.snapshot_id(), Some(2));
+        assert_eq!(file_names(&scan), ["x.parquet", "y.parquet"]);
+
+        let [x, y] = scan.files() else { panic!() };
+        assert_eq!(x.position_deletes, ["/data/x-pos-deletes.parquet"]);
+        assert!(x.equality_deletes.is_empty());
+        assert!(y.position_deletes.is_empty());
+        assert_eq!(
+            y.equality_deletes,
+            [IcebergEqualityDelete {
+                path: "/data/y-eq-deletes.parquet".into(),
+                equality_ids: vec![1],
+            }]
+        );
+
+        let stats = scan.statistics_df().unwrap();
+        assert_eq!(
+            stats.column("a_min").unwrap().as_materialized_series(),
+            &Series::new("a_min".into(), [0i64, 10])
+        );
+        assert_eq!(
+            stats.column("p_max").unwrap().as_materialized_series(),
+            &Series::new("p_max".into(), ["x", "y"])
+        );
+
+        let partition_values = scan.identity_partition_values();
+        assert_eq!(
+            partition_values[&2]
+                .as_ref()
+                .unwrap()
+                .as_materialized_series(),
+            &Series::new("p".into(), ["x", "y"])
+        );
+
+        // Time travel to the snapshot before the deletes.
+        let scan = table.scan(Some(1), None).unwrap();
+        assert_eq!(file_names(&scan), ["x.parquet", "y.parquet"]);
+        assert!(scan.files().iter().all(|f| f.position_deletes.is_empty()));
+        assert!(table.scan(Some(3), None).is_err());
+    }
+
+    #[test]
+    fn test_scan_pruning() {
+        let dir = tempfile::tempdir().unwrap();
+        let table = IcebergTable::load(write_table(dir.path()).as_ref(), None).unwrap();
+        let metadata = table.metadata();
+
+        let manifests =
+            read_manifest_list(&std::fs::read(dir.path().join("snap-2.avro")).unwrap()).unwrap();
+        let stats =
+            manifest_statistics_df(&manifests, metadata, metadata.current_schema().unwrap())
+                .unwrap();
+        assert_eq!(
+            stats.column("p_min").unwrap().as_materialized_series(),
+            &Series::new("p_min".into(), ["x", "y", "x"])
+        );
+        assert_eq!(stats.column("a_min").unwrap().null_count(), 3);
+
+        let schema = metadata
+            .current_schema()
+            .unwrap()
+            .to_polars_schema()
+            .unwrap();
+        let predicate = SkipIfMaxBelow {
+            schema: Arc::new(schema),
+            column: "p",
+            value: "y",
+        };
+        let scan = table.scan(None, Some(&predicate)).unwrap();
+        assert_eq!(file_names(&scan), ["y.parquet"]);
+        assert_eq!(scan.files()[0].equality_deletes.len(), 1);
+    }
+
+    #[test]
+    fn test_nan_bounds() {
+        let metadata = TableMetadata::parse(
+            br#"{
+                "format-version": 2,
+                "location": "/table",
+                "current-schema-id": 0,
+                "schemas": [{"type": "struct", "schema-id": 0, "fields": [
+                    {"id": 1, "name": "f", "required": false, "type": "double"},
+                    {"id": 2, "name": "g", "required": false, "type": "double"}
+                ]}],
+                "default-spec-id": 0,
+                "partition-specs": [{"spec-id": 0, "fields": [
+                    {"source-id": 2, "field-id": 1000, "name": "g", "transform": "identity"}
+                ]}]
+            }"#,
+        )
+        .unwrap();
+        let table_schema = metadata.current_schema().unwrap();
+
+        // Files with no NaNs, with NaNs, and without a NaN count for `f`.
+        let data_file = |path: &str, nan_count: Option<i64>| IcebergDataFile {
+            path: PlPath::new(path),
+            record_count: 2,
+            spec_id: 0,
+            partition: vec![AnyValue::Float64(0.5)],
+            position_deletes: vec![],
+            equality_deletes: vec![],
+            null_value_counts: PlHashMap::from_iter([(1, 0)]),
+            nan_value_counts: nan_count.map(|n| (1, n)).into_iter().collect(),
+            lower_bounds: PlHashMap::from_iter([(1, 1.0f64.to_le_bytes().to_vec())]),
+            upper_bounds: PlHashMap::from_iter([(1, 2.0f64.to_le_bytes().to_vec())]),
+        };
+        let mut scan = IcebergScan {
+            metadata: Arc::new(metadata.clone()),
+            snapshot_id: None,
+            schema: Arc::new(table_schema.to_polars_schema().unwrap()),
+            iceberg_schema: Arc::new(table_schema.to_iceberg_schema().unwrap()),
+            files: vec![
+                data_file("/data/a.parquet", Some(0)),
+                data_file("/data/b.parquet", Some(1)),
+                data_file("/data/c.parquet", None),
+            ],
+        };
+
+        let stats = scan.statistics_df().unwrap();
+        for (name, bound) in [("f_min", 1.0), ("f_max", 2.0)] {
+            assert_eq!(
+                stats.column(name).unwrap().as_materialized_series(),
+                &Series::new(name.into(), [Some(bound), None, None])
+            );
+        }
+        // Partition values are exact, NaN or not.
+        assert_eq!(stats.column("g_max").unwrap().null_count(), 0);
+
+        // `f > 5` can only skip the file without NaNs.
+        struct SkipIfFloatMaxBelow(SchemaRef);
+
+        impl SkipBatchPredicate for SkipIfFloatMaxBelow {
+            fn schema(&self) -> &SchemaRef {
+                &self.0
+            }
+
+            fn evaluate_with_stat_df(&self, df: &DataFrame) -> PolarsResult<Bitmap> {
+                let max = df.column("f_max")?.f64()?;
+                Ok(max.iter().map(|v| v.is_some_and(|v| v < 5.0)).collect())
+            }
+        }
+
+        scan.prune(&SkipIfFloatMaxBelow(scan.schema.clone()))
+            .unwrap();
+        assert_eq!(file_names(&scan), ["b.parquet", "c.parquet"]);
+
+        let manifest = |contains_nan: Option<bool>| ManifestFile {
+            manifest_path: "/m.avro".to_string(),
+            manifest_length: 1,
+            partition_spec_id: 0,
+            content: ManifestContent::Data,
+            sequence_number: 1,
+            min_sequence_number: 1,
+            added_snapshot_id: 1,
+            added_files_count: 1,
+            existing_files_count: 0,
+            deleted_files_count: 0,
+            added_rows_count: 2,
+            existing_rows_count: 0,
+            deleted_rows_count: 0,
+            partitions: Some(vec![FieldSummary {
+                contains_null: false,
+                contains_nan,
+                lower_bound: Some(1.0f64.to_le_bytes().to_vec()),
+                upper_bound: Some(2.0f64.to_le_bytes().to_vec()),
+            }]),
+        };
+        let manifests = [manifest(Some(false)), manifest(Some(true)), manifest(None)];
+        let stats = manifest_statistics_df(&manifests, &metadata, table_schema).unwrap();
+        assert_eq!(
+            stats.column("g_max").unwrap().as_materialized_series(),
+            &Series::new("g_max".into(), [Some(2.0), None, None])
+        );
+    }
+}
@@ -0,0 +1,979 @@
+//! Committing data files written by Polars as a new snapshot of an Iceberg table.
+//!
+//! Tables are tracked without a catalog, in the layout of file system tables: version `N` of the
+//! table metadata is written to `{location}/metadata/v{N}.metadata.json`, and the latest version
+//! is recorded in `{location}/metadata/version-hint.text`.
+//!
+//! See <https://iceberg.apache.org/spec/#file-system-tables>.
+
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{IcebergColumn, IcebergColumnType, IcebergSchema};
+use polars_core::utils::try_get_supertype;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err, to_compute_err};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::plpath::{PlPath, PlPathRef};
+use serde_json::{Value, json};
+
+use super::manifest::{
+    DataFile, DataFileContent, FieldSummary, ManifestContent, ManifestFile, encode_single_value,
+    read_manifest_list, write_manifest, write_manifest_list,
+};
+use super::metadata::{PartitionField, PartitionSpec, TableMetadata, TableSchema};
+use super::table::{read_file, resolve_path};
+use crate::cloud::CloudOptions;
+use crate::parquet::write::{ChildFieldOverwrites, ParquetFieldOverwrites};
+use crate::partition::WrittenFile;
+use crate::utils::file::{Writeable, read_file_if_exists, write_new_file};
+
+/// First ID of partition fields, which have their own ID space.
+const FIRST_PARTITION_FIELD_ID: u32 = 1000;
+
+/// How written files are committed to an Iceberg table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IcebergWriteMode {
+    /// Add the files to the current snapshot, creating the table if it does not exist yet.
+    #[default]
+    Append,
+    /// Replace all files of the current snapshot by the written files. The schema and partition
+    /// spec of the table are replaced as well.
+    ///
+    /// The replaced files are not listed as deleted in the new snapshot, they can be removed once
+    /// the snapshots that reference them are expired.
+    Overwrite,
+}
+
+/// A write to an Iceberg table, which commits the files written by a partitioned Parquet sink as
+/// a new snapshot. Partition columns are written with identity transforms.
+///
+/// The schema and partition spec that the data files are written with are fixed when the write
+/// is created, and committing fails if the table was changed incompatibly in the meantime.
+#[derive(Debug, Clone)]
+pub struct IcebergCommit {
+    location: PlPath,
+    mode: IcebergWriteMode,
+    schema: SchemaRef,
+    /// The schema as stored in the table metadata.
+    table_schema: Value,
+    iceberg_schema: IcebergSchema,
+    last_column_id: u32,
+    spec: PartitionSpec,
+    /// Iceberg types of the partition fields.
+    partition_types: Vec<String>,
+    /// Version of the table when the write was created.
+    base_version: Option<u64>,
+    /// Identifies the data files of this write.
+    write_id: uuid::Uuid,
+    cloud_options: Option<CloudOptions>,
+}
+
+/// The latest version of a table.
+struct CurrentTable {
+    version: u64,
+    metadata_location: PlPath,
+    raw: Value,
+    metadata: TableMetadata,
+}
+
+impl IcebergCommit {
+    /// Prepares a write to the table at `location`. The schema and partition spec of the table
+    /// are kept if they match `schema` and `partition_columns`, otherwise appending fails.
+    pub fn new(
+        location: PlPathRef<'_>,
+        mode: IcebergWriteMode,
+        schema: &Schema,
+        partition_columns: &[PlSmallStr],
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let location = location.into_owned();
+        let current = load_current(location.as_ref(), cloud_options)?;
+        let current_metadata = current.as_ref().map(|c| &c.metadata);
+        let current_schema = current_metadata.map(|m| m.current_schema()).transpose()?;
+
+        // Assign fresh IDs, after those that were used before.
+        let mut last_column_id = current_metadata.map_or(0, |m| m.last_column_id);
+        let fields = struct_fields(schema.iter(), &mut last_column_id)?;
+        let schema_id = current_metadata.map_or(0, |m| {
+            m.schemas.iter().map(|s| s.schema_id).max().unwrap_or(-1) + 1
+        });
+        let mut table_schema = TableSchema { schema_id, fields };
+
+        if let Some(current_schema) = current_schema {
+            let current_polars_schema = current_schema.to_polars_schema()?;
+            let new_polars_schema = table_schema.to_polars_schema()?;
+            // An append with the same columns writes them with the types of the table, if they can
+            // be upcast to those.
+            let same_schema = current_polars_schema == new_polars_schema
+                || (mode == IcebergWriteMode::Append
+                    && current_polars_schema
+                        .iter_names()
+                        .eq(new_polars_schema.iter_names())
+                    && current_polars_schema
+                        .iter_values()
+                        .zip(new_polars_schema.iter_values())
+                        .all(|(table_dtype, dtype)| {
+                            try_get_supertype(table_dtype, dtype).is_ok_and(|st| &st == table_dtype)
+                        }));
+            if same_schema {
+                polars_ensure!(
+                    !current_schema
+                        .fields
+                        .iter()
+                        .any(|f| has_unwritable_type(&f["type"])),
+                    nyi = "writing to Iceberg tables with map, uuid or fixed columns"
+                );
+                table_schema = current_schema.clone();
+                last_column_id = current_metadata.unwrap().last_column_id;
+            } else {
+                polars_ensure!(
+                    mode == IcebergWriteMode::Overwrite,
+                    SchemaMismatch: "cannot append to Iceberg table, schema {:?} does not match the table schema {:?}",
+                    schema, current_polars_schema
+                );
+            }
+        }
+
+        let iceberg_schema = table_schema.to_iceberg_schema()?;
+        let source = |name: &PlSmallStr| {
+            iceberg_schema
+                .values()
+                .find(|col| col.name == name)
+                .filter(|col| !col.type_.is_nested())
+                .ok_or_else(
+                    || polars_err!(InvalidOperation: "cannot partition an Iceberg table by column '{}'", name),
+                )
+        };
+
+        let mut last_partition_id = current_metadata
+            .and_then(|m| m.last_partition_id)
+            .unwrap_or(FIRST_PARTITION_FIELD_ID - 1);
+        let mut spec = PartitionSpec {
+            spec_id: current_metadata.map_or(0, |m| {
+                m.partition_specs
+                    .iter()
+                    .map(|s| s.spec_id)
+                    .max()
+                    .unwrap_or(-1)
+                    + 1
+            }),
+            fields: partition_columns
+                .iter()
+                .map(|name| {
+                    last_partition_id += 1;
+                    Ok(PartitionField {
+                        source_id: source(name)?.physical_id,
+                        field_id: Some(last_partition_id),
+                        name: name.to_string(),
+                        transform: "identity".to_string(),
+                    })
+                })
+                .collect::<PolarsResult<_>>()?,
+        };
+
+        if let Some(metadata) = current_metadata {
+            let current_spec = metadata.partition_spec(metadata.default_spec_id.unwrap_or(0))?;
+            let same_partitioning = current_spec.fields.len() == spec.fields.len()
+                && current_spec.fields.iter().zip(&spec.fields).all(|(a, b)| {
+                    a.is_identity() && a.source_id == b.source_id && a.field_id.is_some()
+                });
+
+            if same_partitioning {
+                spec = current_spec.clone();
+            } else {
+                polars_ensure!(
+                    mode == IcebergWriteMode::Overwrite,
+                    SchemaMismatch: "cannot append to Iceberg table, partition columns {:?} do not match the partition spec of the table",
+                    partition_columns
+                );
+            }
+        }
+
+        let partition_types = spec
+            .fields
+            .iter()
+            .map(|field| {
+                let ty = table_schema
+                    .fields
+                    .iter()
+                    .find(|f| f["id"].as_u64() == Some(field.source_id as u64))
+                    .and_then(|f| f["type"].as_str());
+                Ok(ty.ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg partition field '{}'", field.name))?
+                    .to_string())
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        Ok(Self {
+            location,
+            mode,
+            schema: Arc::new(table_schema.to_polars_schema()?),
+            table_schema: json!({
+                "type": "struct",
+                "schema-id": table_schema.schema_id,
+                "fields": table_schema.fields,
+            }),
+            iceberg_schema,
+            last_column_id,
+            spec,
+            partition_types,
+            base_version: current.map(|c| c.version),
+            write_id: uuid::Uuid::new_v4(),
+            cloud_options: cloud_options.cloned(),
+        })
+    }
+
+    pub fn location(&self) -> &PlPath {
+        &self.location
+    }
+
+    pub fn mode(&self) -> IcebergWriteMode {
+        self.mode
+    }
+
+    /// Schema of the table after the commit, which the written data must be cast to.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Location of the data files.
+    pub fn data_location(&self) -> PlPath {
+        self.location.as_ref().join("data")
+    }
+
+    /// Name of the `file_idx`-th data file of this write, which is unique across writes.
+    pub fn data_file_name(&self, file_idx: usize) -> String {
+        format!("{file_idx:05}-{}.parquet", self.write_id)
+    }
+
+    /// The field IDs that the columns of the data files must be written with.
+    pub fn field_overwrites(&self) -> Vec<ParquetFieldOverwrites> {
+        fn field_overwrite(
+            col: &IcebergColumn,
+            name: Option<PlSmallStr>,
+        ) -> ParquetFieldOverwrites {
+            ParquetFieldOverwrites {
+                name,
+                children: match &col.type_ {
+                    IcebergColumnType::Primitive { .. } => ChildFieldOverwrites::None,
+                    IcebergColumnType::List(inner) | IcebergColumnType::FixedSizeList(inner, _) => {
+                        ChildFieldOverwrites::ListLike(Box::new(field_overwrite(inner, None)))
+                    },
+                    IcebergColumnType::Struct(fields) => ChildFieldOverwrites::Struct(
+                        fields
+                            .values()
+                            .map(|f| field_overwrite(f, Some(f.name.clone())))
+                            .collect(),
+                    ),
+                },
+                required: None,
+                field_id: Some(col.physical_id as i32),
+                metadata: None,
+                bloom_filter: None,
+                encoding: None,
+            }
+        }
+
+        self.iceberg_schema
+            .values()
+            .map(|col| field_overwrite(col, Some(col.name.clone())))
+            .collect()
+    }
+
+    /// Commits the files as a new snapshot, and returns the location of the new table metadata.
+    ///
+    /// The metadata file is written only if no metadata with the same version exists. If another
+    /// writer committed first, an append is retried on top of the new version, whereas an
+    /// overwrite fails.
+    pub fn commit(&self, written_files: &[WrittenFile]) -> PolarsResult<PlPath> {
+        let now = now_ms();
+        let metadata_dir = self.location.as_ref().join("metadata");
+
+        // The manifest does not depend on the snapshot, as the snapshot ID and sequence numbers
+        // of its entries are inherited from the manifest list.
+        let manifest = if written_files.is_empty() {
+            None
+        } else {
+            let files = written_files
+                .iter()
+                .map(|file| self.data_file(file))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let partition_types = self
+                .partition_types
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            let bytes = write_manifest(&files, &self.table_schema, &self.spec, &partition_types)?;
+            let path = metadata_dir
+                .as_ref()
+                .join(format!("{}-m0.avro", uuid::Uuid::new_v4()));
+            self.put(path.as_ref(), &bytes)?;
+
+            Some(ManifestFile {
+                manifest_path: path.to_str().to_string(),
+                manifest_length: bytes.len() as i64,
+                partition_spec_id: self.spec.spec_id,
+                content: ManifestContent::Data,
+                sequence_number: 0,
+                min_sequence_number: 0,
+                added_snapshot_id: 0,
+                added_files_count: files.len() as i32,
+                existing_files_count: 0,
+                deleted_files_count: 0,
+                added_rows_count: files.iter().map(|f| f.record_count).sum(),
+                existing_rows_count: 0,
+                deleted_rows_count: 0,
+                partitions: Some(self.partition_summaries(written_files)?),
+            })
+        };
+
+        loop {
+            let current = load_current(self.location.as_ref(), self.cloud_options.as_ref())?;
+            self.check(current.as_ref())?;
+
+            let mut metadata = match &current {
+                Some(current) => current.raw.clone(),
+                None => self.new_table_metadata(now),
+            };
+            let current_metadata = current.as_ref().map(|c| &c.metadata);
+            let parent_snapshot_id = current_metadata.and_then(|m| m.current_snapshot_id);
+            let sequence_number = current_metadata.map_or(0, |m| m.last_sequence_number) + 1;
+            let snapshot_id = loop {
+                let id = (uuid::Uuid::new_v4().as_u64_pair().0 & i64::MAX as u64) as i64;
+                if id != 0 && current_metadata.is_none_or(|m| m.snapshot(id).is_none()) {
+                    break id;
+                }
+            };
+
+            let mut manifests = Vec::new();
+            if let Some(mut manifest) = manifest.clone() {
+                manifest.sequence_number = sequence_number;
+                manifest.min_sequence_number = sequence_number;
+                manifest.added_snapshot_id = snapshot_id;
+                manifests.push(manifest);
+            }
+            if self.mode == IcebergWriteMode::Append {
+                let manifest_list = current_metadata
+                    .and_then(|m| m.current_snapshot())
+                    .and_then(|s| s.manifest_list.as_deref());
+                if let Some(manifest_list) = manifest_list {
+                    let bytes = read_file(
+                        resolve_path(manifest_list).as_ref(),
+                        self.cloud_options.as_ref(),
+                    )?;
+                    manifests.extend(read_manifest_list(&bytes)?);
+                }
+            }
+
+            let manifest_list = metadata_dir.as_ref().join(format!(
+                "snap-{snapshot_id}-1-{}.avro",
+                uuid::Uuid::new_v4()
+            ));
+            self.put(
+                manifest_list.as_ref(),
+                &write_manifest_list(&manifests, snapshot_id, parent_snapshot_id, sequence_number),
+            )?;
+
+            let added_files = manifest.as_ref().map_or(0, |m| m.added_files_count);
+            let added_records = manifest.as_ref().map_or(0, |m| m.added_rows_count);
+            let added_size: u64 = written_files.iter().map(|f| f.file_size).sum();
+            let mut snapshot = json!({
+                "snapshot-id": snapshot_id,
+                "sequence-number": sequence_number,
+                "timestamp-ms": now,
+                "manifest-list": manifest_list.to_str(),
+                "summary": {
+                    "operation": match self.mode {
+                        IcebergWriteMode::Append => "append",
+                        IcebergWriteMode::Overwrite => "overwrite",
+                    },
+                    "added-data-files": added_files.to_string(),
+                    "added-records": added_records.to_string(),
+                    "added-files-size": added_size.to_string(),
+                },
+                "schema-id": self.table_schema["schema-id"],
+            });
+            if let Some(parent_snapshot_id) = parent_snapshot_id {
+                snapshot["parent-snapshot-id"] = json!(parent_snapshot_id);
+            }
+
+            self.update_metadata(&mut metadata, current.as_ref(), snapshot, now)?;
+
+            let version = current.as_ref().map_or(1, |c| c.version + 1);
+            let location = metadata_location(self.location.as_ref(), version);
+            let bytes = serde_json::to_vec(&metadata).map_err(to_compute_err)?;
+
+            if write_new_file(location.as_ref(), &bytes, self.cloud_options.as_ref())? {
+                // The hint only speeds up finding the latest version, so it does not need to be
+                // replaced atomically.
+                let mut hint = Writeable::try_new(
+                    metadata_dir.as_ref().join("version-hint.text").as_ref(),
+                    self.cloud_options.as_ref(),
+                )?;
+                hint.write_all(version.to_string().as_bytes())?;
+                hint.close()?;
+
+                return Ok(location);
+            }
+
+            polars_ensure!(
+                self.mode == IcebergWriteMode::Append,
+                ComputeError: "version {} of the Iceberg table was committed concurrently", version
+            );
+        }
+    }
+
+    /// Checks that the schema and partition spec that the files were written with can still be
+    /// committed to the latest version of the table.
+    fn check(&self, current: Option<&CurrentTable>) -> PolarsResult<()> {
+        match self.mode {
+            IcebergWriteMode::Append => {
+                let Some(current) = current else {
+                    return Ok(());
+                };
+                let metadata = &current.metadata;
+                let schema = metadata.current_schema()?;
+                let spec = metadata.partition_spec(metadata.default_spec_id.unwrap_or(0))?;
+
+                polars_ensure!(
+                    json!(schema.schema_id) == self.table_schema["schema-id"]
+                        && Value::Array(schema.fields.clone()) == self.table_schema["fields"]
+                        && spec == &self.spec,
+                    ComputeError: "the schema or partition spec of the Iceberg table was changed concurrently"
+                );
+            },
+            IcebergWriteMode::Overwrite => {
+                polars_ensure!(
+                    current.map(|c| c.version) == self.base_version,
+                    ComputeError: "the Iceberg table was changed concurrently"
+                );
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Adds the snapshot to the metadata and makes it current, together with the schema and
+    /// partition spec if they are new.
+    fn update_metadata(
+        &self,
+        metadata: &mut Value,
+        current: Option<&CurrentTable>,
+        snapshot: Value,
+        now: i64,
+    ) -> PolarsResult<()> {
+        let obj = metadata
+            .as_object_mut()
+            .ok_or_else(|| polars_err!(ComputeError: "invalid Iceberg table metadata"))?;
+        let schema_id = self.table_schema["schema-id"].clone();
+        let metadata_ref = current.map(|c| &c.metadata);
+        if metadata_ref.is_none_or(|m| m.schemas.iter().all(|s| json!(s.schema_id) != schema_id)) {
+            push(obj, "schemas", self.table_schema.clone())?;
+        }
+        if metadata_ref.is_none_or(|m| m.partition_spec(self.spec.spec_id).is_err()) {
+            push(
+                obj,
+                "partition-specs",
+                serde_json::to_value(&self.spec).unwrap(),
+            )?;
+        }
+        push(
+            obj,
+            "snapshot-log",
+            json!({ "snapshot-id": snapshot["snapshot-id"], "timestamp-ms": now }),
+        )?;
+        if let Some(current) = current {
+            push(
+                obj,
+                "metadata-log",
+                json!({
+                    "metadata-file": current.metadata_location.to_str(),
+                    "timestamp-ms": current.metadata.last_updated_ms,
+                }),
+            )?;
+        }
+
+        let last_partition_id = self
+            .spec
+            .fields
+            .iter()
+            .filter_map(|f| f.field_id)
+            .chain(metadata_ref.and_then(|m| m.last_partition_id))
+            .max()
+            .unwrap_or(FIRST_PARTITION_FIELD_ID - 1);
+
+        obj.insert("last-updated-ms".into(), json!(now));
+        obj.insert(
+            "last-column-id".into(),
+            json!(
+                self.last_column_id
+                    .max(metadata_ref.map_or(0, |m| m.last_column_id))
+            ),
+        );
+        obj.insert("current-schema-id".into(), schema_id);
+        obj.insert("default-spec-id".into(), json!(self.spec.spec_id));
+        obj.insert("last-partition-id".into(), json!(last_partition_id));
+        obj.insert(
+            "last-sequence-number".into(),
+            snapshot["sequence-number"].clone(),
+        );
+        obj.insert(
+            "current-snapshot-id".into(),
+            snapshot["snapshot-id"].clone(),
+        );
+        obj.entry("refs").or_insert_with(|| json!({}))["main"] = json!({
+            "snapshot-id": snapshot["snapshot-id"],
+            "type": "branch",
+        });
+        push(obj, "snapshots", snapshot)
+    }
+
+    fn new_table_metadata(&self, now: i64) -> Value {
+        json!({
+            "format-version": 2,
+            "table-uuid": uuid::Uuid::new_v4().to_string(),
+            "location": self.location.to_str(),
+            "last-sequence-number": 0,
+            "last-updated-ms": now,
+            "last-column-id": 0,
+            "current-schema-id": 0,
+            "schemas": [],
+            "default-spec-id": 0,
+            "partition-specs": [],
+            "last-partition-id": FIRST_PARTITION_FIELD_ID - 1,
+            "default-sort-order-id": 0,
+            "sort-orders": [{ "order-id": 0, "fields": [] }],
+            "properties": {},
+            "refs": {},
+            "snapshots": [],
+            "snapshot-log": [],
+            "metadata-log": [],
+        })
+    }
+
+    fn data_file(&self, file: &WrittenFile) -> PolarsResult<DataFile> {
+        let partition = self
+            .spec
+            .fields
+            .iter()
+            .map(|field| {
+                let value = file
+                    .keys
+                    .iter()
+                    .find(|(name, _)| name == field.name.as_str())
+                    .map(|(_, value)| value.clone().to_physical())
+                    .ok_or_else(|| {
+                        polars_err!(ComputeError: "missing partition value '{}' of written file '{}'", field.name, file.path)
+                    })?;
+                Ok(value)
+            })
+            .collect::<PolarsResult<_>>()?;
+
+        let mut null_value_counts = PlHashMap::new();
+        let mut nan_value_counts = PlHashMap::new();
+        let mut lower_bounds = PlHashMap::new();
+        let mut upper_bounds = PlHashMap::new();
+        for (name, stats) in &file.columns {
+            let Some(col) = self
+                .iceberg_schema
+                .values()
+                .find(|col| col.name == name && !col.type_.is_nested())
+            else {
+                continue;
+            };
+            let id = col.physical_id;
+
+            null_value_counts.insert(id, stats.null_count as i64);
+            if col.type_.to_polars_dtype().is_float() {
+                nan_value_counts.insert(id, stats.nan_count as i64);
+            }
+            if let Some(bound) = encode_single_value(&stats.lower_bound) {
+                lower_bounds.insert(id, bound);
+            }
+            if let Some(bound) = encode_single_value(&stats.upper_bound) {
+                upper_bounds.insert(id, bound);
+            }
+        }
+
+        Ok(DataFile {
+            content: DataFileContent::Data,
+            file_path: file.path.clone(),
+            file_format: "PARQUET".to_string(),
+            partition,
+            record_count: file.num_rows as i64,
+            file_size_in_bytes: file.file_size as i64,
+            null_value_counts,
+            nan_value_counts,
+            lower_bounds,
+            upper_bounds,
+            equality_ids: None,
+        })
+    }
+
+    /// Summaries of the partition values of the files, which are used to skip manifests.
+    fn partition_summaries(&self, files: &[WrittenFile]) -> PolarsResult<Vec<FieldSummary>> {
+        self.spec
+            .fields
+            .iter()
+            .map(|field| {
+                let values = files
+                    .iter()
+                    .map(|f| {
+                        f.keys
+                            .iter()
+                            .find(|(name, _)| name == field.name.as_str())
+                            .map_or(AnyValue::Null, |(_, v)| v.clone())
+                    })
+                    .collect::<Vec<_>>();
+                let s = Series::from_any_values(field.name.as_str().into(), &values, false)?;
+
+                Ok(FieldSummary {
+                    contains_null: s.null_count() > 0,
+                    contains_nan: s
+                        .dtype()
+                        .is_float()
+                        .then(|| PolarsResult::Ok(s.is_nan()?.any()))
+                        .transpose()?,
+                    lower_bound: encode_single_value(s.min_reduce()?.value()),
+                    upper_bound: encode_single_value(s.max_reduce()?.value()),
+                })
+            })
+            .collect()
+    }
+
+    fn put(&self, path: PlPathRef<'_>, bytes: &[u8]) -> PolarsResult<()> {
+        polars_ensure!(
+            write_new_file(path, bytes, self.cloud_options.as_ref())?,
+            ComputeError: "Iceberg file '{}' already exists", path.to_str()
+        );
+        Ok(())
+    }
+}
+
+/// Appends to an array of the table metadata.
+fn push(obj: &mut serde_json::Map<String, Value>, key: &str, value: Value) -> PolarsResult<()> {
+    let array = obj
+        .entry(key)
+        .or_insert_with(|| json!([]))
+        .as_array_mut()
+        .ok_or_else(|| polars_err!(ComputeError: "invalid '{}' in Iceberg table metadata", key))?;
+    array.push(value);
+    Ok(())
+}
+
+fn metadata_location(location: PlPathRef<'_>, version: u64) -> PlPath {
+    location
+        .join("metadata")
+        .as_ref()
+        .join(format!("v{version}.metadata.json"))
+}
+
+/// Loads the latest metadata of the table, starting from the version hint, which may lag behind.
+fn load_current(
+    location: PlPathRef<'_>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Option<CurrentTable>> {
+    let hint_location = location.join("metadata").as_ref().join("version-hint.text");
+    let hint = match read_file_if_exists(hint_location.as_ref(), cloud_options)? {
+        Some(bytes) => std::str::from_utf8(&bytes)
+            .ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .ok_or_else(|| {
+                polars_err!(ComputeError: "invalid Iceberg version hint '{}'", hint_location.to_str())
+            })?,
+        None => 0,
+    };
+
+    let mut current = None;
+    let mut version = hint.max(1);
+    while let Some(bytes) =
+        read_file_if_exists(metadata_location(location, version).as_ref(), cloud_options)?
+    {
+        current = Some((version, bytes));
+        version += 1;
+    }
+
+    let Some((version, bytes)) = current else {
+        polars_ensure!(
+            hint == 0,
+            ComputeError: "Iceberg metadata of version {} not found at '{}'", hint, location.to_str()
+        );
+        return Ok(None);
+    };
+
+    let metadata = TableMetadata::parse(&bytes)?;
+    polars_ensure!(
+        metadata.format_version == 2,
+        nyi = "writing to Iceberg tables of format version {}",
+        metadata.format_version
+    );
+
+    Ok(Some(CurrentTable {
+        version,
+        metadata_location: metadata_location(location, version),
+        raw: serde_json::from_slice(&bytes).map_err(to_compute_err)?,
+        metadata,
+    }))
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as i64)
+}
+
+/// Converts fields to Iceberg struct fields, assigning IDs after `last_id`. The fields of a
+/// struct get consecutive IDs, before the IDs of their nested fields.
+fn struct_fields<'a>(
+    fields: impl Iterator<Item = (&'a PlSmallStr, &'a DataType)>,
+    last_id: &mut u32,
+) -> PolarsResult<Vec<Value>> {
+    let fields = fields
+        .map(|(name, dtype)| {
+            *last_id += 1;
+            (*last_id, name, dtype)
+        })
+        .collect::<Vec<_>>();
+
+    fields
+        .into_iter()
+        .map(|(id, name, dtype)| {
+            Ok(json!({
+                "id": id,
+                "name": name.as_str(),
+                "required": false,
+                "type": iceberg_type(dtype, last_id)?,
+            }))
+        })
+        .collect()
+}
+
+fn iceberg_type(dtype: &DataType, last_id: &mut u32) -> PolarsResult<Value> {
+    Ok(match dtype {
+        DataType::Boolean => json!("boolean"),
+        DataType::Int32 => json!("int"),
+        DataType::Int64 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Date => json!("date"),
+        DataType::Datetime(TimeUnit::Microseconds, None) => json!("timestamp"),
+        DataType::Datetime(TimeUnit::Microseconds, Some(_)) => json!("timestamptz"),
+        DataType::String => json!("string"),
+        DataType::Binary => json!("binary"),
+        DataType::Decimal(Some(precision), Some(scale)) if *precision <= 38 => {
+            json!(format!("decimal({precision}, {scale})"))
+        },
+        DataType::List(inner) => {
+            *last_id += 1;
+            let element_id = *last_id;
+            json!({
+                "type": "list",
+                "element-id": element_id,
+                "element-required": false,
+                "element": iceberg_type(inner, last_id)?,
+            })
+        },
+        DataType::Struct(fields) => json!({
+            "type": "struct",
+            "fields": struct_fields(fields.iter().map(|f| (f.name(), f.dtype())), last_id)?,
+        }),
+        dtype => polars_bail!(
+            ComputeError:
+            "data type {} cannot be written to an Iceberg table, cast it to a supported type",
+            dtype
+        ),
+    })
+}
+
+/// Whether the Iceberg type contains types that cannot be written from their Polars
+/// representation.
+fn has_unwritable_type(ty: &Value) -> bool {
+    match ty {
+        Value::String(name) => name == "uuid" || name.starts_with("fixed["),
+        Value::Object(obj) => match obj.get("type").and_then(Value::as_str) {
+            Some("list") => obj.get("element").is_some_and(has_unwritable_type),
+            Some("struct") => obj
+                .get("fields")
+                .and_then(Value::as_array)
+                .is_some_and(|fields| fields.iter().any(|f| has_unwritable_type(&f["type"]))),
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::partition::WrittenColumnStats;
+
+    fn written_file(path: String, p: &str, a: (i64, i64)) -> WrittenFile {
+        let stats = |lower, upper| WrittenColumnStats {
+            null_count: 0,
+            nan_count: 0,
+            lower_bound: lower,
+            upper_bound: upper,
+        };
+        WrittenFile {
+            path,
+            num_rows: 2,
+            file_size: 100,
+            keys: vec![("p".into(), AnyValue::StringOwned(p.into()))],
+            columns: vec![
+                (
+                    "a".into(),
+                    stats(AnyValue::Int64(a.0), AnyValue::Int64(a.1)),
+                ),
+                (
+                    "p".into(),
+                    stats(
+                        AnyValue::StringOwned(p.into()),
+                        AnyValue::StringOwned(p.into()),
+                    ),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_commit_append_and_overwrite() -> PolarsResult<()> {
+        use crate::iceberg::IcebergTable;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("table");
+        let location = PlPath::new(dir.to_str().unwrap());
+        let data = |name: &str| dir.join("data").join(name).to_str().unwrap().to_string();
+
+        let schema = Schema::from_iter([
+            Field::new("a".into(), DataType::Int64),
+            Field::new(
+                "s".into(),
+                DataType::Struct(vec![Field::new(
+                    "b".into(),
+                    DataType::List(Box::new(DataType::String)),
+                )]),
+            ),
+            Field::new("p".into(), DataType::String),
+        ]);
+        let commit =
+            |mode| IcebergCommit::new(location.as_ref(), mode, &schema, &["p".into()], None);
+
+        let append = commit(IcebergWriteMode::Append)?;
+        assert_eq!(append.schema().as_ref(), &schema);
+        let overwrites = append.field_overwrites();
+        assert_eq!(
+            overwrites.iter().map(|o| o.field_id).collect::<Vec<_>>(),
+            [Some(1), Some(2), Some(3)]
+        );
+        let ChildFieldOverwrites::Struct(children) = &overwrites[1].children else {
+            unreachable!()
+        };
+        assert_eq!(children[0].field_id, Some(4));
+        let ChildFieldOverwrites::ListLike(element) = &children[0].children else {
+            unreachable!()
+        };
+        assert_eq!((element.name.as_ref(), element.field_id), (None, Some(5)));
+
+        append.commit(&[written_file(data("0.parquet"), "x", (0, 5))])?;
+        let v2 = append.commit(&[written_file(data("1.parquet"), "y", (3, 9))])?;
+        assert_eq!(
+            std::fs::read_to_string(dir.join("metadata/version-hint.text"))?,
+            "2"
+        );
+
+        let table = IcebergTable::load(v2.as_ref(), None)?;
+        assert_eq!(table.metadata().snapshots.len(), 2);
+        let scan = table.scan(None, None)?;
+        assert_eq!(scan.schema().as_ref(), &schema);
+        let mut paths = scan
+            .files()
+            .iter()
+            .map(|f| f.path.to_str().to_string())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, [data("0.parquet"), data("1.parquet")]);
+
+        let stats = scan.statistics_df()?;
+        let a_max = stats
+            .column("a_max")?
+            .i64()?
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert!(a_max.contains(&Some(9)));
+
+        // Appending columns which upcast to the table types writes those types.
+        let s_dtype = schema.get("s").unwrap().clone();
+        let with_a = |dtype| {
+            Schema::from_iter([
+                Field::new("a".into(), dtype),
+                Field::new("s".into(), s_dtype.clone()),
+                Field::new("p".into(), DataType::String),
+            ])
+        };
+        let narrow = IcebergCommit::new(
+            location.as_ref(),
+            IcebergWriteMode::Append,
+            &with_a(DataType::Int32),
+            &["p".into()],
+            None,
+        )?;
+        assert_eq!(narrow.schema().as_ref(), &schema);
+
+        // Appending columns with the same names but other types fails.
+        for dtype in [DataType::Float64, DataType::String] {
+            let out = IcebergCommit::new(
+                location.as_ref(),
+                IcebergWriteMode::Append,
+                &with_a(dtype),
+                &["p".into()],
+                None,
+            );
+            assert!(
+                matches!(out, Err(PolarsError::SchemaMismatch(_))),
+                "{:?}",
+                out.map(|c| c.schema().clone())
+            );
+        }
+
+        // Appending with a different schema fails.
+        let other_schema = Schema::from_iter([
+            Field::new("a".into(), DataType::String),
+            Field::new("p".into(), DataType::String),
+        ]);
+        assert!(
+            IcebergCommit::new(
+                location.as_ref(),
+                IcebergWriteMode::Append,
+                &other_schema,
+                &["p".into()],
+                None
+            )
+            .is_err()
+        );
+
+        let overwrite = IcebergCommit::new(
+            location.as_ref(),
+            IcebergWriteMode::Overwrite,
+            &other_schema,
+            &[],
+            None,
+        )?;
+        assert_eq!(
+            overwrite.field_overwrites()[0].field_id,
+            Some(6),
+            "new field IDs are assigned"
+        );
+        let v3 = overwrite.commit(&[WrittenFile {
+            path: data("2.parquet"),
+            num_rows: 1,
+            file_size: 10,
+            keys: vec![],
+            columns: vec![],
+        }])?;
+
+        let scan = IcebergTable::load(v3.as_ref(), None)?.scan(None, None)?;
+        assert_eq!(scan.schema().as_ref(), &other_schema);
+        assert_eq!(scan.files().len(), 1);
+        assert_eq!(scan.files()[0].path.to_str(), data("2.parquet"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+//! Reading of JSON documents that hold a top-level array of records, without parsing the entire
+//! document at once.
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use simd_json::BorrowedValue;
+
+use crate::json::infer::dtypes_to_supertype;
+use crate::ndjson::core::parse_json_values;
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    First,
+    Next,
+    Done,
+}
+
+/// Iterator over the byte ranges of the elements of a top-level JSON array.
+///
+/// The elements are only tokenized far enough to find where they end; they are not validated or
+/// parsed.
+pub struct JsonArrayElements<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    state: State,
+}
+
+impl<'a> JsonArrayElements<'a> {
+    pub fn try_new(bytes: &'a [u8]) -> PolarsResult<Self> {
+        let mut out = Self {
+            bytes,
+            pos: if bytes.starts_with(UTF8_BOM) {
+                UTF8_BOM.len()
+            } else {
+                0
+            },
+            state: State::First,
+        };
+
+        out.skip_whitespace();
+        polars_ensure!(
+            out.peek() == Some(b'['),
+            ComputeError: "expected a json array at the top-level of the document"
+        );
+        out.pos += 1;
+
+        Ok(out)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .peek()
+            .is_some_and(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn unexpected_end() -> PolarsError {
+        polars_err!(ComputeError: "unexpected end of json array")
+    }
+
+    /// Advances past the string starting at the current position.
+    fn skip_string(&mut self) -> PolarsResult<()> {
+        self.pos += 1;
+        loop {
+            let rest = self
+                .bytes
+                .get(self.pos..)
+                .ok_or_else(Self::unexpected_end)?;
+            let offset = memchr::memchr2(b'"', b'\\', rest).ok_or_else(Self::unexpected_end)?;
+            self.pos += offset;
+            if self.bytes[self.pos] == b'\\' {
+                self.pos += 2;
+            } else {
+                self.pos += 1;
+                return Ok(());
+            }
+        }
+    }
+
+    /// Advances past the object or array starting at the current position.
+    fn skip_nested(&mut self) -> PolarsResult<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek().ok_or_else(Self::unexpected_end)? {
+                b'"' => {
+                    self.skip_string()?;
+                    continue;
+                },
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += 1;
+                        return Ok(());
+                    }
+                },
+                _ => {},
+            }
+            self.pos += 1;
+        }
+    }
+
+    fn next_element(&mut self) -> PolarsResult<Option<Range<usize>>> {
+        self.skip_whitespace();
+
+        match (self.state, self.peek()) {
+            (State::Done, _) => return Ok(None),
+            (_, Some(b']')) => {
+                self.pos += 1;
+                self.skip_whitespace();
+                polars_ensure!(
+                    self.pos == self.bytes.len(),
+                    ComputeError: "unexpected trailing characters after json array"
+                );
+                self.state = State::Done;
+                return Ok(None);
+            },
+            (State::First, _) => {},
+            (State::Next, Some(b',')) => {
+                self.pos += 1;
+                self.skip_whitespace();
+            },
+            (State::Next, Some(b)) => {
+                polars_bail!(
+                    ComputeError: "expected ',' or ']' in json array, found '{}'", b as char
+                )
+            },
+            (State::Next, None) => return Err(Self::unexpected_end()),
+        }
+
+        let start = self.pos;
+        match self.peek().ok_or_else(Self::unexpected_end)? {
+            b'{' | b'[' => self.skip_nested()?,
+            b'"' => self.skip_string()?,
+            b',' | b']' => polars_bail!(ComputeError: "missing value in json array"),
+            _ => {
+                while self
+                    .peek()
+                    .is_some_and(|b| !matches!(b, b',' | b']' | b' ' | b'\t' | b'\r' | b'\n'))
+                {
+                    self.pos += 1;
+                }
+            },
+        }
+        self.state = State::Next;
+
+        Ok(Some(start..self.pos))
+    }
+}
+
+impl Iterator for JsonArrayElements<'_> {
+    type Item = PolarsResult<Range<usize>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let out = self.next_element().transpose();
+        if matches!(out, Some(Err(_))) {
+            self.state = State::Done;
+        }
+        out
+    }
+}
+
+/// Infers the schema of a JSON array of objects from its first `infer_schema_len` elements.
+pub fn infer_json_array_schema(
+    bytes: &[u8],
+    infer_schema_len: Option<NonZeroUsize>,
+) -> PolarsResult<Schema> {
+    let mut scratch = vec![];
+
+    let dtypes = JsonArrayElements::try_new(bytes)?
+        .take(infer_schema_len.map_or(usize::MAX, NonZeroUsize::get))
+        .map(|element| {
+            scratch.clear();
+            scratch.extend_from_slice(&bytes[element?]);
+            let value = simd_json::to_borrowed_value(&mut scratch).map_err(to_compute_err)?;
+            polars_ensure!(
+                matches!(value, BorrowedValue::Object(_)),
+                ComputeError: "can only deserialize json objects"
+            );
+            Ok(DataType::from_arrow_dtype(&polars_json::json::infer(
+                &value,
+            )?))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    if dtypes.is_empty() {
+        return Ok(Schema::default());
+    }
+
+    let DataType::Struct(fields) = dtypes_to_supertype(dtypes.into_iter())? else {
+        unreachable!()
+    };
+
+    Ok(Schema::from_iter(fields))
+}
+
+/// Counts the elements of a JSON array.
+pub fn count_json_array_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    JsonArrayElements::try_new(bytes)?.try_fold(0, |n, element| element.map(|_| n + 1))
+}
+
+/// Parses the elements at the given byte ranges of `bytes` into a DataFrame with the given schema.
+pub fn parse_json_array_elements(
+    bytes: &[u8],
+    elements: &[Range<usize>],
+    schema: &Schema,
+    ignore_errors: bool,
+) -> PolarsResult<DataFrame> {
+    parse_json_values(
+        elements.iter().map(|element| &bytes[element.clone()]),
+        elements.len(),
+        schema,
+        ignore_errors,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(json: &str) -> PolarsResult<Vec<&str>> {
+        JsonArrayElements::try_new(json.as_bytes())?
+            .map(|element| Ok(&json[element?]))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_array_elements() {
+        assert_eq!(elements(" [ ] ").unwrap(), Vec::<&str>::new());
+        assert_eq!(
+            elements("\u{feff}[1, -2.5e3,true ,null,\"a,]\\\"\"]\n").unwrap(),
+            ["1", "-2.5e3", "true", "null", "\"a,]\\\"\""]
+        );
+        assert_eq!(
+            elements(r#"[{"a": [1, {"b": "}"}]}, [[]], {}]"#).unwrap(),
+            [r#"{"a": [1, {"b": "}"}]}"#, "[[]]", "{}"]
+        );
+
+        assert!(elements("{}").is_err());
+        assert!(elements("[1, 2").is_err());
+        assert!(elements("[1 2]").is_err());
+        assert!(elements("[1,]").is_err());
+        assert!(elements("[1] 2").is_err());
+        assert!(elements(r#"[{"a": "b]"#).is_err());
+    }
+
+    #[test]
+    fn test_read_json_array() {
+        let json = br#"[{"a": 1, "b": "x"}, {"a": 2}, {"b": "y", "c": [1.5]}]"#;
+
+        let schema = infer_json_array_schema(json, NonZeroUsize::new(2)).unwrap();
+        assert_eq!(
+            schema,
+            Schema::from_iter([
+                Field::new("a".into(), DataType::Int64),
+                Field::new("b".into(), DataType::String),
+            ])
+        );
+        assert_eq!(count_json_array_rows(json).unwrap(), 3);
+
+        let elements = JsonArrayElements::try_new(json)
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+        let df = parse_json_array_elements(json, &elements[1..], &schema, false).unwrap();
+        let expected = df!(
+            "a" => [Some(2i64), None],
+            "b" => [None, Some("y")],
+        )
+        .unwrap();
+        assert!(df.equals_missing(&expected));
+    }
+}
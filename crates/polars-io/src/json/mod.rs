@@ -62,6 +62,7 @@
 //! +-----+--------+-------+--------+
 //! ```
 //!
+mod array;
 pub(crate) mod infer;
 
 use std::io::Write;
@@ -78,6 +79,9 @@ use polars_json::json::write::FallibleStreamingIterator;
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+pub use self::array::{
+    JsonArrayElements, count_json_array_rows, infer_json_array_schema, parse_json_array_elements,
+};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
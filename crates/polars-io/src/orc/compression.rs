@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+use std::io::Read;
+
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+/// Compression codec of the streams and the metadata of an ORC file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrcCompression {
+    Zlib,
+    Snappy,
+    Zstd,
+}
+
+impl OrcCompression {
+    pub(super) fn from_proto(kind: u64) -> PolarsResult<Option<Self>> {
+        Ok(match kind {
+            0 => None,
+            1 => Some(Self::Zlib),
+            2 => Some(Self::Snappy),
+            5 => Some(Self::Zstd),
+            3 => polars_bail!(ComputeError: "unsupported orc compression 'LZO'"),
+            4 => polars_bail!(ComputeError: "unsupported orc compression 'LZ4'"),
+            _ => polars_bail!(ComputeError: "unknown orc compression {kind}"),
+        })
+    }
+
+    fn decompress_chunk(self, chunk: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        match self {
+            // ORC uses raw deflate, without the zlib header.
+            Self::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk).read_to_end(out)?;
+            },
+            Self::Snappy => {
+                let len = snap::raw::decompress_len(chunk).map_err(to_compute_err)?;
+                let start = out.len();
+                out.resize(start + len, 0);
+                snap::raw::Decoder::new()
+                    .decompress(chunk, &mut out[start..])
+                    .map_err(to_compute_err)?;
+            },
+            Self::Zstd => {
+                zstd::stream::read::Decoder::new(chunk)?.read_to_end(out)?;
+            },
+        }
+        Ok(())
+    }
+}
+
+/// Decompresses a stream or a metadata section of an ORC file.
+///
+/// A compressed stream is a sequence of chunks that each start with a 3 byte header holding the
+/// length of the chunk and whether it is stored uncompressed, see
+/// <https://orc.apache.org/specification/ORCv1/#compression>.
+pub(super) fn decompress(
+    compression: Option<OrcCompression>,
+    data: &[u8],
+) -> PolarsResult<Cow<'_, [u8]>> {
+    let Some(compression) = compression else {
+        return Ok(Cow::Borrowed(data));
+    };
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut rest = data;
+    while !rest.is_empty() {
+        polars_ensure!(
+            rest.len() >= 3,
+            ComputeError: "corrupt orc file: truncated compression chunk header"
+        );
+        let header = u32::from_le_bytes([rest[0], rest[1], rest[2], 0]);
+        let is_original = header & 1 == 1;
+        let len = (header >> 1) as usize;
+        rest = &rest[3..];
+        polars_ensure!(
+            rest.len() >= len,
+            ComputeError: "corrupt orc file: truncated compression chunk"
+        );
+
+        let (chunk, remaining) = rest.split_at(len);
+        if is_original {
+            out.extend_from_slice(chunk);
+        } else {
+            compression.decompress_chunk(chunk, &mut out)?;
+        }
+        rest = remaining;
+    }
+
+    Ok(Cow::Owned(out))
+}
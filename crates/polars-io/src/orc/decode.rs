@@ -0,0 +1,468 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use arrow::array::{BinaryViewArray, ListArray, MutableBinaryViewArray};
+use arrow::bitmap::Bitmap;
+use arrow::offset::Offsets;
+use polars_core::prelude::*;
+use polars_utils::aliases::PlHashMap;
+
+use super::compression::decompress;
+use super::metadata::{OrcFileMetadata, decimal_precision_scale};
+use super::proto::{ColumnEncoding, StreamKind, StripeFooter, TypeKind};
+use super::rle::{decode_bool_rle, decode_byte_rle, decode_i128_varints, decode_int_rle};
+
+/// Seconds from the unix epoch to the ORC epoch, 2015-01-01.
+const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// The decompressed streams of the projected columns of a stripe.
+struct StripeStreams<'a> {
+    metadata: &'a OrcFileMetadata,
+    streams: PlHashMap<(usize, StreamKind), Cow<'a, [u8]>>,
+    encodings: &'a [ColumnEncoding],
+}
+
+/// The streams of a stripe that are needed to decode a projection, see
+/// [`OrcFileMetadata::stripe_streams`].
+pub struct OrcStripeStreams {
+    stripe: usize,
+    projection: Vec<usize>,
+    /// The column id, kind and byte range in the file of every needed stream.
+    streams: Vec<(usize, StreamKind, Range<usize>)>,
+    encodings: Vec<ColumnEncoding>,
+}
+
+impl OrcStripeStreams {
+    /// The non-empty byte ranges in the file that hold the needed streams, in file order.
+    pub fn byte_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.streams
+            .iter()
+            .map(|(_, _, range)| range.clone())
+            .filter(|range| !range.is_empty())
+    }
+}
+
+impl OrcFileMetadata {
+    /// Decompresses and decodes the top-level columns at `projection` of a single stripe of the
+    /// file in `bytes`.
+    pub fn decode_stripe(
+        &self,
+        bytes: &[u8],
+        stripe: usize,
+        projection: &[usize],
+    ) -> PolarsResult<DataFrame> {
+        let slice = |range: Range<usize>| {
+            bytes
+                .get(range)
+                .ok_or_else(|| polars_err!(ComputeError: "corrupt orc file: stripe out of bounds"))
+        };
+
+        let footer = slice(self.stripe_footer_range(stripe)?)?;
+        let streams = self.stripe_streams(stripe, footer, projection)?;
+        let data = streams
+            .byte_ranges()
+            .map(slice)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        self.decode_stripe_streams(&streams, &data)
+    }
+
+    /// Returns the byte range in the file of the footer of a stripe.
+    pub fn stripe_footer_range(&self, stripe: usize) -> PolarsResult<Range<usize>> {
+        let stripe = &self.stripes[stripe];
+        let start = stripe.offset + stripe.index_length + stripe.data_length;
+        self.byte_range(start, stripe.footer_length)
+    }
+
+    /// Decodes the `footer` of a stripe and returns the streams that are needed to decode the
+    /// top-level columns at `projection`.
+    pub fn stripe_streams(
+        &self,
+        stripe: usize,
+        footer: &[u8],
+        projection: &[usize],
+    ) -> PolarsResult<OrcStripeStreams> {
+        let footer = StripeFooter::decode(&decompress(self.compression, footer)?)?;
+
+        let mut needed = vec![false; self.types.len()];
+        for &index in projection {
+            self.mark_needed(self.column_id(index), &mut needed);
+        }
+
+        // The streams are stored back to back from the start of the stripe, in the order of the
+        // stripe footer.
+        let mut streams = vec![];
+        let mut offset = self.stripes[stripe].offset;
+        for stream in &footer.streams {
+            if stream.kind != StreamKind::Other && needed.get(stream.column) == Some(&true) {
+                let range = self.byte_range(offset, stream.length)?;
+                streams.push((stream.column, stream.kind, range));
+            }
+            offset = offset.saturating_add(stream.length);
+        }
+
+        Ok(OrcStripeStreams {
+            stripe,
+            projection: projection.to_vec(),
+            streams,
+            encodings: footer.encodings,
+        })
+    }
+
+    /// Decompresses and decodes a stripe from the bytes of the
+    /// [`byte_ranges`](OrcStripeStreams::byte_ranges) of its streams.
+    pub fn decode_stripe_streams<B: AsRef<[u8]>>(
+        &self,
+        streams: &OrcStripeStreams,
+        data: &[B],
+    ) -> PolarsResult<DataFrame> {
+        let num_rows = self.stripes[streams.stripe].num_rows as usize;
+
+        let mut data = data.iter();
+        let mut decompressed = PlHashMap::with_capacity(streams.streams.len());
+        for (column, kind, range) in &streams.streams {
+            let bytes = if range.is_empty() {
+                &[]
+            } else {
+                data.next()
+                    .map(AsRef::as_ref)
+                    .filter(|bytes| bytes.len() == range.len())
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "orc stripe streams don't match their ranges"),
+                    )?
+            };
+            decompressed.insert((*column, *kind), decompress(self.compression, bytes)?);
+        }
+
+        let stripe_streams = StripeStreams {
+            metadata: self,
+            streams: decompressed,
+            encodings: &streams.encodings,
+        };
+
+        let columns = streams
+            .projection
+            .iter()
+            .map(|&index| {
+                let name = self.schema.get_at_index(index).unwrap().0;
+                Ok(stripe_streams
+                    .decode_column(self.column_id(index), num_rows)?
+                    .with_name(name.clone())
+                    .into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        DataFrame::new_with_height(num_rows, columns)
+    }
+
+    fn byte_range(&self, offset: u64, len: u64) -> PolarsResult<Range<usize>> {
+        offset
+            .checked_add(len)
+            .filter(|end| *end <= self.file_size as u64)
+            .map(|end| offset as usize..end as usize)
+            .ok_or_else(|| polars_err!(ComputeError: "corrupt orc file: stripe out of bounds"))
+    }
+
+    fn mark_needed(&self, column_id: usize, needed: &mut [bool]) {
+        needed[column_id] = true;
+        for &child in &self.types[column_id].subtypes {
+            self.mark_needed(child, needed);
+        }
+    }
+}
+
+impl StripeStreams<'_> {
+    fn stream(&self, column_id: usize, kind: StreamKind) -> PolarsResult<&[u8]> {
+        match self.streams.get(&(column_id, kind)) {
+            Some(data) => Ok(data),
+            None => polars_bail!(
+                ComputeError: "corrupt orc file: missing {kind:?} stream of column {column_id}"
+            ),
+        }
+    }
+
+    fn int_stream(
+        &self,
+        column_id: usize,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> PolarsResult<Vec<i64>> {
+        if n == 0 {
+            return Ok(vec![]);
+        }
+        let v2 = self.encoding(column_id).kind.is_v2();
+        decode_int_rle(self.stream(column_id, kind)?, n, signed, v2)
+    }
+
+    fn encoding(&self, column_id: usize) -> ColumnEncoding {
+        self.encodings.get(column_id).copied().unwrap_or_default()
+    }
+
+    /// Decodes `n` values of a column, including its nulls.
+    fn decode_column(&self, column_id: usize, n: usize) -> PolarsResult<Series> {
+        let present = match self.streams.get(&(column_id, StreamKind::Present)) {
+            Some(data) => Some(decode_bool_rle(data, n)?),
+            None => None,
+        };
+
+        let num_values = present.as_ref().map_or(n, |present| present.set_bits());
+        let values = self.decode_values(column_id, num_values)?;
+
+        match present {
+            Some(present) if present.unset_bits() > 0 => {
+                // Spread the values over the rows that are present.
+                let mut next = 0;
+                let idx = present
+                    .iter()
+                    .map(|is_present| {
+                        is_present.then(|| {
+                            next += 1;
+                            (next - 1) as IdxSize
+                        })
+                    })
+                    .collect::<IdxCa>();
+                values.take(&idx)
+            },
+            _ => Ok(values),
+        }
+    }
+
+    /// Decodes the `n` non-null values of a column.
+    fn decode_values(&self, column_id: usize, n: usize) -> PolarsResult<Series> {
+        use StreamKind as S;
+
+        let ty = &self.metadata.types[column_id];
+        let name = PlSmallStr::EMPTY;
+
+        Ok(match ty.kind {
+            TypeKind::Boolean => {
+                let values = if n == 0 {
+                    Bitmap::new()
+                } else {
+                    decode_bool_rle(self.stream(column_id, S::Data)?, n)?
+                };
+                BooleanChunked::from_iter_values(name, values.iter()).into_series()
+            },
+            TypeKind::Byte => {
+                let values = if n == 0 {
+                    vec![]
+                } else {
+                    decode_byte_rle(self.stream(column_id, S::Data)?, n)?
+                };
+                Int8Chunked::from_vec(name, values.into_iter().map(|v| v as i8).collect())
+                    .into_series()
+            },
+            TypeKind::Short => {
+                let values = self.int_stream(column_id, S::Data, n, true)?;
+                Int16Chunked::from_vec(name, values.into_iter().map(|v| v as i16).collect())
+                    .into_series()
+            },
+            TypeKind::Int => {
+                let values = self.int_stream(column_id, S::Data, n, true)?;
+                Int32Chunked::from_vec(name, values.into_iter().map(|v| v as i32).collect())
+                    .into_series()
+            },
+            TypeKind::Long => {
+                Int64Chunked::from_vec(name, self.int_stream(column_id, S::Data, n, true)?)
+                    .into_series()
+            },
+            TypeKind::Float => {
+                let data = self.fixed_width_stream(column_id, n, 4)?;
+                let values = data
+                    .chunks_exact(4)
+                    .map(|v| f32::from_le_bytes(v.try_into().unwrap()))
+                    .collect();
+                Float32Chunked::from_vec(name, values).into_series()
+            },
+            TypeKind::Double => {
+                let data = self.fixed_width_stream(column_id, n, 8)?;
+                let values = data
+                    .chunks_exact(8)
+                    .map(|v| f64::from_le_bytes(v.try_into().unwrap()))
+                    .collect();
+                Float64Chunked::from_vec(name, values).into_series()
+            },
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let values = self.decode_binary(column_id, n)?;
+                if ty.kind == TypeKind::Binary {
+                    BinaryChunked::with_chunk(name, values).into_series()
+                } else {
+                    StringChunked::with_chunk(name, values.to_utf8view()?).into_series()
+                }
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.int_stream(column_id, S::Data, n, true)?;
+                let nanos = self.int_stream(column_id, S::Secondary, n, false)?;
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        // The lower 3 bits hold the number of trailing decimal zeros that were
+                        // removed, minus one.
+                        let zeros = nanos & 7;
+                        let mut nanos = nanos >> 3;
+                        if zeros != 0 {
+                            nanos *= 10i64.pow(zeros as u32 + 1);
+                        }
+                        let mut seconds = seconds + ORC_EPOCH_SECONDS;
+                        // Writers round negative timestamps towards zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds.wrapping_mul(1_000_000_000).wrapping_add(nanos)
+                    })
+                    .collect();
+                let tz = (ty.kind == TypeKind::TimestampInstant).then_some(TimeZone::UTC);
+                Int64Chunked::from_vec(name, values)
+                    .into_datetime(TimeUnit::Nanoseconds, tz)
+                    .into_series()
+            },
+            TypeKind::Date => {
+                let values = self.int_stream(column_id, S::Data, n, true)?;
+                Int32Chunked::from_vec(name, values.into_iter().map(|v| v as i32).collect())
+                    .into_date()
+                    .into_series()
+            },
+            TypeKind::Decimal => {
+                let (precision, scale) = decimal_precision_scale(ty);
+                let values = if n == 0 {
+                    vec![]
+                } else {
+                    decode_i128_varints(self.stream(column_id, S::Data)?, n)?
+                };
+                let scales = self.int_stream(column_id, S::Secondary, n, true)?;
+                // Every value has its own scale, which is usually the scale of the column.
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(value, value_scale)| {
+                        let diff = scale as i64 - value_scale;
+                        match diff {
+                            0 => value,
+                            1.. => value.saturating_mul(10i128.saturating_pow(diff as u32)),
+                            _ => value / 10i128.saturating_pow(diff.unsigned_abs() as u32),
+                        }
+                    })
+                    .collect();
+                Int128Chunked::from_vec(name, values)
+                    .into_decimal_unchecked(Some(precision), scale)
+                    .into_series()
+            },
+            TypeKind::Struct => {
+                let fields = ty
+                    .subtypes
+                    .iter()
+                    .zip(&ty.field_names)
+                    .map(|(&child, name)| Ok(self.decode_column(child, n)?.with_name(name.clone())))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructChunked::from_series(name, n, fields.iter())?.into_series()
+            },
+            TypeKind::List => {
+                let lengths = self.int_stream(column_id, S::Length, n, false)?;
+                let num_values = total_length(&lengths)?;
+                let values = self.decode_column(ty.subtypes[0], num_values)?;
+                build_list(name, &lengths, values)?
+            },
+            TypeKind::Map => {
+                let lengths = self.int_stream(column_id, S::Length, n, false)?;
+                let num_values = total_length(&lengths)?;
+                let keys = self
+                    .decode_column(ty.subtypes[0], num_values)?
+                    .with_name(PlSmallStr::from_static("key"));
+                let values = self
+                    .decode_column(ty.subtypes[1], num_values)?
+                    .with_name(PlSmallStr::from_static("value"));
+                let entries = StructChunked::from_series(
+                    PlSmallStr::EMPTY,
+                    num_values,
+                    [keys, values].iter(),
+                )?;
+                build_list(name, &lengths, entries.into_series())?
+            },
+            TypeKind::Union => polars_bail!(ComputeError: "orc union types are not supported"),
+        })
+    }
+
+    fn fixed_width_stream(&self, column_id: usize, n: usize, width: usize) -> PolarsResult<&[u8]> {
+        if n == 0 {
+            return Ok(&[]);
+        }
+        let data = self.stream(column_id, StreamKind::Data)?;
+        polars_ensure!(
+            data.len() >= n * width,
+            ComputeError: "corrupt orc file: the data stream of column {column_id} is too short"
+        );
+        Ok(&data[..n * width])
+    }
+
+    fn decode_binary(&self, column_id: usize, n: usize) -> PolarsResult<BinaryViewArray> {
+        let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(n);
+        if n == 0 {
+            return Ok(out.freeze());
+        }
+
+        let encoding = self.encoding(column_id);
+        if encoding.kind.is_dictionary() {
+            let lengths = self.int_stream(
+                column_id,
+                StreamKind::Length,
+                encoding.dictionary_size,
+                false,
+            )?;
+            let dictionary = split_values(
+                self.stream(column_id, StreamKind::DictionaryData)?,
+                &lengths,
+            )?;
+            for key in self.int_stream(column_id, StreamKind::Data, n, false)? {
+                let Some(value) = dictionary.get(key as usize) else {
+                    polars_bail!(ComputeError: "corrupt orc file: dictionary key out of bounds");
+                };
+                out.push_value(*value);
+            }
+        } else {
+            let lengths = self.int_stream(column_id, StreamKind::Length, n, false)?;
+            for value in split_values(self.stream(column_id, StreamKind::Data)?, &lengths)? {
+                out.push_value(value);
+            }
+        }
+
+        Ok(out.freeze())
+    }
+}
+
+fn split_values<'a>(mut data: &'a [u8], lengths: &[i64]) -> PolarsResult<Vec<&'a [u8]>> {
+    lengths
+        .iter()
+        .map(|&len| {
+            let len = usize::try_from(len).ok().filter(|len| *len <= data.len());
+            let Some(len) = len else {
+                polars_bail!(ComputeError: "corrupt orc file: invalid length of a value");
+            };
+            let (value, rest) = data.split_at(len);
+            data = rest;
+            Ok(value)
+        })
+        .collect()
+}
+
+fn total_length(lengths: &[i64]) -> PolarsResult<usize> {
+    lengths.iter().try_fold(0usize, |acc, &len| {
+        usize::try_from(len)
+            .ok()
+            .and_then(|len| acc.checked_add(len))
+            .ok_or_else(|| polars_err!(ComputeError: "corrupt orc file: invalid length of a list"))
+    })
+}
+
+fn build_list(name: PlSmallStr, lengths: &[i64], values: Series) -> PolarsResult<Series> {
+    let offsets = Offsets::<i64>::try_from_lengths(lengths.iter().map(|len| *len as usize))?;
+    let values = values.rechunk().to_arrow(0, CompatLevel::newest());
+    let array = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values.dtype().clone()),
+        offsets.into(),
+        values,
+        None,
+    );
+    Series::from_arrow(name, array.boxed())
+}
@@ -0,0 +1,311 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+
+use super::compression::{OrcCompression, decompress};
+use super::proto::{
+    ColumnStatistics, Footer, MinMax, OrcStripe, OrcType, PostScript, TypeKind,
+    decode_stripe_statistics,
+};
+
+const ORC_MAGIC: &[u8] = b"ORC";
+
+/// Metadata of an ORC file, read from the file tail.
+///
+/// The stripes can be decoded independently and in parallel, see
+/// [`OrcFileMetadata::decode_stripe`].
+#[derive(Clone, Debug)]
+pub struct OrcFileMetadata {
+    pub schema: SchemaRef,
+    pub compression: Option<OrcCompression>,
+    pub stripes: Vec<OrcStripe>,
+    pub(super) types: Vec<OrcType>,
+    /// The statistics of every column of every stripe, indexed by stripe and column id. This is
+    /// empty if the file does not contain stripe statistics.
+    stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    pub(super) file_size: usize,
+}
+
+/// The statistics of a column over a set of stripes, with a row per stripe.
+pub struct OrcColumnStatistics {
+    pub min: Column,
+    pub max: Column,
+    pub null_count: Column,
+}
+
+fn slice_tail(bytes: &[u8], start: Option<usize>, len: usize) -> PolarsResult<&[u8]> {
+    start
+        .and_then(|start| bytes.get(start..start.checked_add(len)?))
+        .ok_or_else(|| polars_err!(ComputeError: "corrupt orc file: invalid file tail"))
+}
+
+fn to_dtype(types: &[OrcType], column_id: usize) -> PolarsResult<DataType> {
+    let Some(ty) = types.get(column_id) else {
+        polars_bail!(ComputeError: "corrupt orc file: unknown column id {column_id}");
+    };
+    let subtype = |i: usize| -> PolarsResult<DataType> {
+        let Some(&id) = ty.subtypes.get(i) else {
+            polars_bail!(ComputeError: "corrupt orc file: missing subtype of column {column_id}");
+        };
+        to_dtype(types, id)
+    };
+
+    Ok(match ty.kind {
+        TypeKind::Boolean => DataType::Boolean,
+        TypeKind::Byte => DataType::Int8,
+        TypeKind::Short => DataType::Int16,
+        TypeKind::Int => DataType::Int32,
+        TypeKind::Long => DataType::Int64,
+        TypeKind::Float => DataType::Float32,
+        TypeKind::Double => DataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => DataType::String,
+        TypeKind::Binary => DataType::Binary,
+        // TIMESTAMP holds the wall clock time of the writer, TIMESTAMP_INSTANT an instant in UTC.
+        TypeKind::Timestamp => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        TypeKind::TimestampInstant => {
+            DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC))
+        },
+        TypeKind::Date => DataType::Date,
+        TypeKind::Decimal => {
+            let (precision, scale) = decimal_precision_scale(ty);
+            DataType::Decimal(Some(precision), Some(scale))
+        },
+        TypeKind::List => DataType::List(Box::new(subtype(0)?)),
+        TypeKind::Map => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), subtype(0)?),
+            Field::new(PlSmallStr::from_static("value"), subtype(1)?),
+        ]))),
+        TypeKind::Struct => DataType::Struct(
+            ty.field_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Ok(Field::new(name.clone(), subtype(i)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        TypeKind::Union => polars_bail!(ComputeError: "orc union types are not supported"),
+    })
+}
+
+/// Files written by old versions of Hive don't store the precision and the scale.
+pub(super) fn decimal_precision_scale(ty: &OrcType) -> (usize, usize) {
+    let precision = ty.precision.filter(|p| *p > 0).unwrap_or(38);
+    (precision, ty.scale.unwrap_or(0))
+}
+
+impl OrcFileMetadata {
+    /// The maximum length of the postscript together with its length byte at the end of the file.
+    pub const MAX_POSTSCRIPT_LENGTH: usize = 256;
+
+    /// Reads the tail of the ORC file in `bytes`, see
+    /// <https://orc.apache.org/specification/ORCv1/#file-tail>.
+    pub fn read(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() > ORC_MAGIC.len() && bytes.starts_with(ORC_MAGIC),
+            ComputeError: "not an orc file"
+        );
+
+        Self::read_tail(bytes, bytes.len())
+    }
+
+    /// Returns the length of the file tail, given at least the last
+    /// [`MAX_POSTSCRIPT_LENGTH`](Self::MAX_POSTSCRIPT_LENGTH) bytes of the file (or the entire
+    /// file if it is shorter).
+    pub fn tail_length(tail: &[u8]) -> PolarsResult<usize> {
+        let (postscript, postscript_start) = Self::read_postscript(tail)?;
+        Ok((tail.len() - postscript_start)
+            .saturating_add(postscript.footer_length as usize)
+            .saturating_add(postscript.metadata_length as usize))
+    }
+
+    /// Reads the file tail from the last bytes of a file of `file_size` bytes. `tail` must be at
+    /// least [`tail_length`](Self::tail_length) bytes long.
+    pub fn read_tail(tail: &[u8], file_size: usize) -> PolarsResult<Self> {
+        polars_ensure!(
+            tail.len() <= file_size,
+            ComputeError: "corrupt orc file: invalid file tail"
+        );
+
+        let (postscript, postscript_start) = Self::read_postscript(tail)?;
+        let compression = OrcCompression::from_proto(postscript.compression)?;
+
+        let footer_len = postscript.footer_length as usize;
+        let footer_start = postscript_start.checked_sub(footer_len);
+        let footer = Footer::decode(&decompress(
+            compression,
+            slice_tail(tail, footer_start, footer_len)?,
+        )?)?;
+
+        let metadata_len = postscript.metadata_length as usize;
+        let stripe_statistics = if metadata_len > 0 {
+            let metadata_start = footer_start.and_then(|start| start.checked_sub(metadata_len));
+            decode_stripe_statistics(&decompress(
+                compression,
+                slice_tail(tail, metadata_start, metadata_len)?,
+            )?)?
+        } else {
+            vec![]
+        };
+
+        let Some(root) = footer.types.first() else {
+            polars_bail!(ComputeError: "orc file has no schema");
+        };
+        polars_ensure!(
+            root.kind == TypeKind::Struct,
+            ComputeError: "the root type of an orc file must be a struct"
+        );
+        let DataType::Struct(fields) = to_dtype(&footer.types, 0)? else {
+            unreachable!()
+        };
+
+        polars_ensure!(
+            footer.stripes.iter().map(|s| s.num_rows).sum::<u64>() == footer.number_of_rows,
+            ComputeError: "corrupt orc file: the stripes don't add up to the number of rows"
+        );
+
+        Ok(Self {
+            schema: Arc::new(Schema::from_iter(fields)),
+            compression,
+            stripes: footer.stripes,
+            types: footer.types,
+            stripe_statistics,
+            file_size,
+        })
+    }
+
+    fn postscript_start(tail: &[u8]) -> Option<usize> {
+        let postscript_len = *tail.last()? as usize;
+        (tail.len() - 1).checked_sub(postscript_len)
+    }
+
+    /// Returns the postscript and its offset in `tail`.
+    fn read_postscript(tail: &[u8]) -> PolarsResult<(PostScript, usize)> {
+        let Some(postscript_start) = Self::postscript_start(tail) else {
+            polars_bail!(ComputeError: "corrupt orc file: invalid file tail");
+        };
+        let postscript = PostScript::decode(&tail[postscript_start..tail.len() - 1])?;
+        polars_ensure!(postscript.magic == ORC_MAGIC, ComputeError: "not an orc file");
+        Ok((postscript, postscript_start))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.stripes
+            .iter()
+            .map(|stripe| stripe.num_rows as usize)
+            .sum()
+    }
+
+    /// Returns the indices of the columns of the file that are in `projection`.
+    pub fn projection_indices(&self, projection: &Schema) -> Vec<usize> {
+        self.schema
+            .iter_names()
+            .enumerate()
+            .filter(|(_, name)| projection.contains(name))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns the id of the top-level column at `index` in the schema.
+    pub(super) fn column_id(&self, index: usize) -> usize {
+        self.types[0].subtypes[index]
+    }
+
+    /// Returns the statistics of the top-level column at `index` over the given stripes. Returns
+    /// `None` if the file does not contain stripe statistics.
+    ///
+    /// Statistics of nested, binary and timestamp columns only contain the null count.
+    pub fn column_statistics(
+        &self,
+        index: usize,
+        stripes: &[usize],
+    ) -> PolarsResult<Option<OrcColumnStatistics>> {
+        if self.stripe_statistics.len() != self.stripes.len() {
+            return Ok(None);
+        }
+
+        let column_id = self.column_id(index);
+        let Some(statistics) = stripes
+            .iter()
+            .map(|&i| self.stripe_statistics[i].get(column_id))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Ok(None);
+        };
+
+        let null_count = stripes
+            .iter()
+            .zip(&statistics)
+            .map(|(&i, stats)| {
+                stats
+                    .number_of_values
+                    .map(|v| self.stripes[i].num_rows.saturating_sub(v) as IdxSize)
+            })
+            .collect::<IdxCa>()
+            .into_column();
+
+        let dtype = self.schema.get_at_index(index).unwrap().1;
+        let (min, max): (Series, Series) = match dtype {
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                let (min, max): (Vec<_>, Vec<_>) = statistics
+                    .iter()
+                    .map(|stats| match stats.min_max {
+                        MinMax::Integer(min, max) => (min, max),
+                        _ => (None, None),
+                    })
+                    .unzip();
+                (
+                    Int64Chunked::from_iter(min).cast(dtype)?,
+                    Int64Chunked::from_iter(max).cast(dtype)?,
+                )
+            },
+            DataType::Float32 | DataType::Float64 => {
+                let (min, max): (Vec<_>, Vec<_>) = statistics
+                    .iter()
+                    .map(|stats| match stats.min_max {
+                        MinMax::Double(min, max) => (min, max),
+                        _ => (None, None),
+                    })
+                    .unzip();
+                (
+                    Float64Chunked::from_iter(min).cast(dtype)?,
+                    Float64Chunked::from_iter(max).cast(dtype)?,
+                )
+            },
+            DataType::String => {
+                let (min, max): (Vec<_>, Vec<_>) = statistics
+                    .iter()
+                    .map(|stats| match &stats.min_max {
+                        MinMax::String(min, max) => (min.as_deref(), max.as_deref()),
+                        _ => (None, None),
+                    })
+                    .unzip();
+                (
+                    StringChunked::from_iter(min).into_series(),
+                    StringChunked::from_iter(max).into_series(),
+                )
+            },
+            DataType::Date => {
+                let (min, max): (Vec<_>, Vec<_>) = statistics
+                    .iter()
+                    .map(|stats| match stats.min_max {
+                        MinMax::Date(min, max) => (min, max),
+                        _ => (None, None),
+                    })
+                    .unzip();
+                (
+                    Int32Chunked::from_iter(min).into_date().into_series(),
+                    Int32Chunked::from_iter(max).into_date().into_series(),
+                )
+            },
+            _ => (
+                Series::full_null(PlSmallStr::EMPTY, stripes.len(), dtype),
+                Series::full_null(PlSmallStr::EMPTY, stripes.len(), dtype),
+            ),
+        };
+
+        Ok(Some(OrcColumnStatistics {
+            min: min.into_column(),
+            max: max.into_column(),
+            null_count,
+        }))
+    }
+}
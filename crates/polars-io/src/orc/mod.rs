@@ -0,0 +1,221 @@
+//! Reading of [Apache ORC] files.
+//!
+//! [Apache ORC]: https://orc.apache.org/specification/ORCv1/
+mod compression;
+mod decode;
+mod metadata;
+mod proto;
+mod rle;
+
+pub use compression::OrcCompression;
+pub use decode::OrcStripeStreams;
+pub use metadata::{OrcColumnStatistics, OrcFileMetadata};
+pub use proto::OrcStripe;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Options to scan ORC files with.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct OrcScanOptions {}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn varint(out: &mut Vec<u8>, mut v: u64) {
+        while v >= 0x80 {
+            out.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+
+    fn varint_field(out: &mut Vec<u8>, field: u64, v: u64) {
+        varint(out, field << 3);
+        varint(out, v);
+    }
+
+    fn bytes_field(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+        varint(out, (field << 3) | 2);
+        varint(out, bytes.len() as u64);
+        out.extend_from_slice(bytes);
+    }
+
+    fn compress(compression: Option<OrcCompression>, data: &[u8]) -> Vec<u8> {
+        let compressed = match compression {
+            None => return data.to_vec(),
+            Some(OrcCompression::Zlib) => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data).unwrap();
+                encoder.finish().unwrap()
+            },
+            Some(OrcCompression::Snappy) => snap::raw::Encoder::new().compress_vec(data).unwrap(),
+            Some(OrcCompression::Zstd) => zstd::encode_all(data, 0).unwrap(),
+        };
+        let header = (compressed.len() as u32) << 1;
+        let mut out = header.to_le_bytes()[..3].to_vec();
+        out.extend(compressed);
+        out
+    }
+
+    /// Builds a file with a single stripe of a `LONG` column `a = [1, 2, 3]` and a `STRING`
+    /// column `b = ["x", null, "yz"]`.
+    fn build_file(compression: Option<OrcCompression>) -> Vec<u8> {
+        let mut file = b"ORC".to_vec();
+
+        // (kind, column, data), the integers are encoded with the run length encoding v2.
+        let streams: [(u64, u64, &[u8]); 4] = [
+            // DIRECT, 3 bit zigzag encoded [1, 2, 3]
+            (1, 1, &[0x44, 0x02, 0x53, 0x00]),
+            // PRESENT [true, false, true]
+            (0, 2, &[0xff, 0xa0]),
+            // LENGTH, DIRECT, 2 bit [1, 2]
+            (2, 2, &[0x42, 0x01, 0x60]),
+            (1, 2, b"xyz"),
+        ];
+
+        let mut stripe_footer = vec![];
+        let mut data_length = 0;
+        for (kind, column, data) in streams {
+            let data = compress(compression, data);
+            let mut stream = vec![];
+            varint_field(&mut stream, 1, kind);
+            varint_field(&mut stream, 2, column);
+            varint_field(&mut stream, 3, data.len() as u64);
+            bytes_field(&mut stripe_footer, 1, &stream);
+            data_length += data.len();
+            file.extend(data);
+        }
+        for kind in [0, 2, 2] {
+            let mut encoding = vec![];
+            varint_field(&mut encoding, 1, kind);
+            bytes_field(&mut stripe_footer, 2, &encoding);
+        }
+        let stripe_footer = compress(compression, &stripe_footer);
+        file.extend_from_slice(&stripe_footer);
+
+        let mut metadata = vec![];
+        let mut stripe_statistics = vec![];
+        for (number_of_values, field, min_max) in [
+            (3, None, vec![]),
+            // sint64 1 and 3
+            (3, Some(2), vec![0x08, 0x02, 0x10, 0x06]),
+            (2, Some(4), vec![0x0a, 0x01, b'x', 0x12, 0x02, b'y', b'z']),
+        ] {
+            let mut column = vec![];
+            varint_field(&mut column, 1, number_of_values);
+            if let Some(field) = field {
+                bytes_field(&mut column, field, &min_max);
+            }
+            bytes_field(&mut stripe_statistics, 1, &column);
+        }
+        bytes_field(&mut metadata, 1, &stripe_statistics);
+        let metadata = compress(compression, &metadata);
+        file.extend_from_slice(&metadata);
+
+        let mut footer = vec![];
+        let mut stripe = vec![];
+        varint_field(&mut stripe, 1, 3);
+        varint_field(&mut stripe, 2, 0);
+        varint_field(&mut stripe, 3, data_length as u64);
+        varint_field(&mut stripe, 4, stripe_footer.len() as u64);
+        varint_field(&mut stripe, 5, 3);
+        bytes_field(&mut footer, 3, &stripe);
+        let mut root = vec![];
+        varint_field(&mut root, 1, 12);
+        bytes_field(&mut root, 2, &[1, 2]);
+        bytes_field(&mut root, 3, b"a");
+        bytes_field(&mut root, 3, b"b");
+        for ty in [root, vec![0x08, 4], vec![0x08, 7]] {
+            bytes_field(&mut footer, 4, &ty);
+        }
+        varint_field(&mut footer, 6, 3);
+        let footer = compress(compression, &footer);
+        file.extend_from_slice(&footer);
+
+        let mut postscript = vec![];
+        varint_field(&mut postscript, 1, footer.len() as u64);
+        varint_field(
+            &mut postscript,
+            2,
+            match compression {
+                None => 0,
+                Some(OrcCompression::Zlib) => 1,
+                Some(OrcCompression::Snappy) => 2,
+                Some(OrcCompression::Zstd) => 5,
+            },
+        );
+        varint_field(&mut postscript, 3, 1 << 18);
+        varint_field(&mut postscript, 5, metadata.len() as u64);
+        bytes_field(&mut postscript, 8000, b"ORC");
+        file.extend_from_slice(&postscript);
+        file.push(postscript.len() as u8);
+
+        file
+    }
+
+    #[test]
+    fn test_read_orc_file() {
+        for compression in [
+            None,
+            Some(OrcCompression::Zlib),
+            Some(OrcCompression::Snappy),
+            Some(OrcCompression::Zstd),
+        ] {
+            let file = build_file(compression);
+            let metadata = OrcFileMetadata::read(&file).unwrap();
+
+            assert_eq!(metadata.compression, compression);
+            assert_eq!(metadata.num_rows(), 3);
+            assert_eq!(
+                metadata.schema.as_ref(),
+                &Schema::from_iter([
+                    Field::new("a".into(), DataType::Int64),
+                    Field::new("b".into(), DataType::String),
+                ])
+            );
+
+            let df = metadata.decode_stripe(&file, 0, &[0, 1]).unwrap();
+            let expected = df!(
+                "a" => [1i64, 2, 3],
+                "b" => [Some("x"), None, Some("yz")],
+            )
+            .unwrap();
+            assert!(df.equals_missing(&expected));
+
+            let df = metadata.decode_stripe(&file, 0, &[1]).unwrap();
+            assert_eq!(df.get_column_names(), ["b"]);
+
+            // Read only the file tail and the byte ranges of the streams of `b`.
+            let postscript_start = file
+                .len()
+                .saturating_sub(OrcFileMetadata::MAX_POSTSCRIPT_LENGTH);
+            let tail_length = OrcFileMetadata::tail_length(&file[postscript_start..]).unwrap();
+            let metadata =
+                OrcFileMetadata::read_tail(&file[file.len() - tail_length..], file.len()).unwrap();
+            let footer = &file[metadata.stripe_footer_range(0).unwrap()];
+            let streams = metadata.stripe_streams(0, footer, &[1]).unwrap();
+            let data = streams.byte_ranges().map(|r| &file[r]).collect::<Vec<_>>();
+            assert_eq!(data.len(), 3);
+            let df = metadata.decode_stripe_streams(&streams, &data).unwrap();
+            assert!(df.equals_missing(&expected.select(["b"]).unwrap()));
+
+            let statistics = metadata.column_statistics(0, &[0]).unwrap().unwrap();
+            assert_eq!(statistics.min.i64().unwrap().get(0), Some(1));
+            assert_eq!(statistics.max.i64().unwrap().get(0), Some(3));
+            assert_eq!(statistics.null_count.idx().unwrap().get(0), Some(0));
+
+            let statistics = metadata.column_statistics(1, &[0]).unwrap().unwrap();
+            assert_eq!(statistics.min.str().unwrap().get(0), Some("x"));
+            assert_eq!(statistics.max.str().unwrap().get(0), Some("yz"));
+            assert_eq!(statistics.null_count.idx().unwrap().get(0), Some(1));
+        }
+    }
+}
@@ -0,0 +1,524 @@
+//! Decoding of the protobuf messages in the tail and the stripe footers of an ORC file, see
+//! <https://orc.apache.org/specification/ORCv1/#file-tail>.
+//!
+//! Only the fields that are needed for reading are decoded, all other fields are skipped.
+
+use polars_core::prelude::*;
+
+/// A decoded protobuf field value.
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32,
+}
+
+impl<'a> WireValue<'a> {
+    fn varint(&self) -> PolarsResult<u64> {
+        match self {
+            Self::Varint(v) => Ok(*v),
+            _ => polars_bail!(ComputeError: "corrupt orc metadata: expected a varint field"),
+        }
+    }
+
+    fn sint(&self) -> PolarsResult<i64> {
+        let v = self.varint()?;
+        Ok((v >> 1) as i64 ^ -((v & 1) as i64))
+    }
+
+    fn double(&self) -> PolarsResult<f64> {
+        match self {
+            Self::Fixed64(v) => Ok(f64::from_bits(*v)),
+            _ => polars_bail!(ComputeError: "corrupt orc metadata: expected a double field"),
+        }
+    }
+
+    fn bytes(&self) -> PolarsResult<&'a [u8]> {
+        match self {
+            Self::Bytes(v) => Ok(v),
+            _ => polars_bail!(ComputeError: "corrupt orc metadata: expected a bytes field"),
+        }
+    }
+
+    fn string(&self) -> PolarsResult<PlSmallStr> {
+        let bytes = self.bytes()?;
+        let s = std::str::from_utf8(bytes)
+            .map_err(|_| polars_err!(ComputeError: "corrupt orc metadata: invalid utf-8 string"))?;
+        Ok(s.into())
+    }
+
+    /// Appends the values of a repeated varint field, which may or may not be packed.
+    fn extend_varints(&self, out: &mut Vec<u64>) -> PolarsResult<()> {
+        match *self {
+            Self::Varint(v) => out.push(v),
+            Self::Bytes(mut data) => {
+                while !data.is_empty() {
+                    out.push(read_varint(&mut data)?);
+                }
+            },
+            _ => polars_bail!(ComputeError: "corrupt orc metadata: expected a varint field"),
+        }
+        Ok(())
+    }
+}
+
+pub(super) fn read_varint(data: &mut &[u8]) -> PolarsResult<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let Some((&byte, rest)) = data.split_first() else {
+            polars_bail!(ComputeError: "corrupt orc file: unexpected end of varint");
+        };
+        *data = rest;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    polars_bail!(ComputeError: "corrupt orc file: varint is too long")
+}
+
+/// Iterates over the fields of an encoded protobuf message.
+struct MessageReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> MessageReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn next_field(&mut self) -> PolarsResult<Option<(u64, WireValue<'a>)>> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+
+        let key = read_varint(&mut self.data)?;
+        let value = match key & 7 {
+            0 => WireValue::Varint(read_varint(&mut self.data)?),
+            1 => WireValue::Fixed64(u64::from_le_bytes(self.take(8)?.try_into().unwrap())),
+            2 => {
+                let len = read_varint(&mut self.data)? as usize;
+                WireValue::Bytes(self.take(len)?)
+            },
+            5 => {
+                self.take(4)?;
+                WireValue::Fixed32
+            },
+            wire_type => {
+                polars_bail!(ComputeError: "corrupt orc metadata: unsupported protobuf wire type {wire_type}")
+            },
+        };
+
+        Ok(Some((key >> 3, value)))
+    }
+
+    fn take(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            self.data.len() >= len,
+            ComputeError: "corrupt orc metadata: unexpected end of message"
+        );
+        let (out, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(out)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: u64,
+    pub compression_block_size: Option<u64>,
+    pub metadata_length: u64,
+    pub magic: Vec<u8>,
+}
+
+impl PostScript {
+    pub fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.footer_length = value.varint()?,
+                2 => out.compression = value.varint()?,
+                3 => out.compression_block_size = Some(value.varint()?),
+                5 => out.metadata_length = value.varint()?,
+                8000 => out.magic = value.bytes()?.to_vec(),
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct Footer {
+    pub stripes: Vec<OrcStripe>,
+    pub types: Vec<OrcType>,
+    pub number_of_rows: u64,
+}
+
+impl Footer {
+    pub fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                3 => out.stripes.push(OrcStripe::decode(value.bytes()?)?),
+                4 => out.types.push(OrcType::decode(value.bytes()?)?),
+                6 => out.number_of_rows = value.varint()?,
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// The location of a stripe in an ORC file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrcStripe {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub num_rows: u64,
+}
+
+impl OrcStripe {
+    fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.offset = value.varint()?,
+                2 => out.index_length = value.varint()?,
+                3 => out.data_length = value.varint()?,
+                4 => out.footer_length = value.varint()?,
+                5 => out.num_rows = value.varint()?,
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn from_proto(kind: u64) -> PolarsResult<Self> {
+        use TypeKind::*;
+
+        Ok(match kind {
+            0 => Boolean,
+            1 => Byte,
+            2 => Short,
+            3 => Int,
+            4 => Long,
+            5 => Float,
+            6 => Double,
+            7 => String,
+            8 => Binary,
+            9 => Timestamp,
+            10 => List,
+            11 => Map,
+            12 => Struct,
+            13 => Union,
+            14 => Decimal,
+            15 => Date,
+            16 => Varchar,
+            17 => Char,
+            18 => TimestampInstant,
+            _ => polars_bail!(ComputeError: "unknown orc type kind {kind}"),
+        })
+    }
+}
+
+/// A node of the type tree of an ORC file. The types are stored in pre-order, the index of a
+/// type is the id of its column.
+#[derive(Clone, Debug)]
+pub(super) struct OrcType {
+    pub kind: TypeKind,
+    pub subtypes: Vec<usize>,
+    pub field_names: Vec<PlSmallStr>,
+    pub precision: Option<usize>,
+    pub scale: Option<usize>,
+}
+
+impl OrcType {
+    fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut kind = None;
+        let mut subtypes = vec![];
+        let mut field_names = vec![];
+        let mut precision = None;
+        let mut scale = None;
+
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => kind = Some(TypeKind::from_proto(value.varint()?)?),
+                2 => value.extend_varints(&mut subtypes)?,
+                3 => field_names.push(value.string()?),
+                5 => precision = Some(value.varint()? as usize),
+                6 => scale = Some(value.varint()? as usize),
+                _ => {},
+            }
+        }
+
+        Ok(Self {
+            // BOOLEAN is the default value of the field.
+            kind: kind.unwrap_or(TypeKind::Boolean),
+            subtypes: subtypes.into_iter().map(|v| v as usize).collect(),
+            field_names,
+            precision,
+            scale,
+        })
+    }
+}
+
+/// The minimum and maximum of the values of a column, by kind of statistics.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum MinMax {
+    Integer(Option<i64>, Option<i64>),
+    Double(Option<f64>, Option<f64>),
+    String(Option<PlSmallStr>, Option<PlSmallStr>),
+    Date(Option<i32>, Option<i32>),
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct ColumnStatistics {
+    /// The number of non-null values.
+    pub number_of_values: Option<u64>,
+    pub min_max: MinMax,
+}
+
+impl ColumnStatistics {
+    fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self {
+            number_of_values: None,
+            min_max: MinMax::Unknown,
+        };
+
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.number_of_values = Some(value.varint()?),
+                2 => {
+                    let (mut min, mut max) = (None, None);
+                    let mut reader = MessageReader::new(value.bytes()?);
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => min = Some(value.sint()?),
+                            2 => max = Some(value.sint()?),
+                            _ => {},
+                        }
+                    }
+                    out.min_max = MinMax::Integer(min, max);
+                },
+                3 => {
+                    let (mut min, mut max) = (None, None);
+                    let mut reader = MessageReader::new(value.bytes()?);
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => min = Some(value.double()?),
+                            2 => max = Some(value.double()?),
+                            _ => {},
+                        }
+                    }
+                    out.min_max = MinMax::Double(min, max);
+                },
+                // Note: Only the exact minimum and maximum are used, the lower and upper bounds
+                // are truncated and would need to be handled differently.
+                4 => {
+                    let (mut min, mut max) = (None, None);
+                    let mut reader = MessageReader::new(value.bytes()?);
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => min = Some(value.string()?),
+                            2 => max = Some(value.string()?),
+                            _ => {},
+                        }
+                    }
+                    out.min_max = MinMax::String(min, max);
+                },
+                7 => {
+                    let (mut min, mut max) = (None, None);
+                    let mut reader = MessageReader::new(value.bytes()?);
+                    while let Some((field, value)) = reader.next_field()? {
+                        match field {
+                            1 => min = Some(value.sint()? as i32),
+                            2 => max = Some(value.sint()? as i32),
+                            _ => {},
+                        }
+                    }
+                    out.min_max = MinMax::Date(min, max);
+                },
+                _ => {},
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Decodes the file metadata section, which holds the statistics of every column of every stripe.
+pub(super) fn decode_stripe_statistics(data: &[u8]) -> PolarsResult<Vec<Vec<ColumnStatistics>>> {
+    let mut out = vec![];
+    let mut reader = MessageReader::new(data);
+    while let Some((field, value)) = reader.next_field()? {
+        if field != 1 {
+            continue;
+        }
+
+        let mut columns = vec![];
+        let mut reader = MessageReader::new(value.bytes()?);
+        while let Some((field, value)) = reader.next_field()? {
+            if field == 1 {
+                columns.push(ColumnStatistics::decode(value.bytes()?)?);
+            }
+        }
+        out.push(columns);
+    }
+    Ok(out)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(super) enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Streams that are not needed for decoding, e.g. the row index and bloom filters.
+    Other,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: usize,
+    pub length: u64,
+}
+
+impl Stream {
+    fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut kind = 0;
+        let mut column = 0;
+        let mut length = 0;
+
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => kind = value.varint()?,
+                2 => column = value.varint()? as usize,
+                3 => length = value.varint()?,
+                _ => {},
+            }
+        }
+
+        let kind = match kind {
+            0 => StreamKind::Present,
+            1 => StreamKind::Data,
+            2 => StreamKind::Length,
+            3 => StreamKind::DictionaryData,
+            5 => StreamKind::Secondary,
+            _ => StreamKind::Other,
+        };
+
+        Ok(Self {
+            kind,
+            column,
+            length,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) enum EncodingKind {
+    #[default]
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+impl EncodingKind {
+    /// Whether the integer streams are encoded with run length encoding version 2.
+    pub fn is_v2(self) -> bool {
+        matches!(self, Self::DirectV2 | Self::DictionaryV2)
+    }
+
+    pub fn is_dictionary(self) -> bool {
+        matches!(self, Self::Dictionary | Self::DictionaryV2)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct ColumnEncoding {
+    pub kind: EncodingKind,
+    pub dictionary_size: usize,
+}
+
+impl ColumnEncoding {
+    fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => {
+                    out.kind = match value.varint()? {
+                        0 => EncodingKind::Direct,
+                        1 => EncodingKind::Dictionary,
+                        2 => EncodingKind::DirectV2,
+                        3 => EncodingKind::DictionaryV2,
+                        kind => polars_bail!(ComputeError: "unknown orc column encoding {kind}"),
+                    }
+                },
+                2 => out.dictionary_size = value.varint()? as usize,
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub encodings: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub fn decode(data: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        let mut reader = MessageReader::new(data);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => out.streams.push(Stream::decode(value.bytes()?)?),
+                2 => out.encodings.push(ColumnEncoding::decode(value.bytes()?)?),
+                _ => {},
+            }
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,411 @@
+//! Run length decoders of the ORC streams, see
+//! <https://orc.apache.org/specification/ORCv1/#run-length-encoding>.
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+
+use super::proto::read_varint;
+
+fn next_byte(data: &mut &[u8]) -> PolarsResult<u8> {
+    let Some((&byte, rest)) = data.split_first() else {
+        polars_bail!(ComputeError: "corrupt orc file: unexpected end of run length encoded stream");
+    };
+    *data = rest;
+    Ok(byte)
+}
+
+fn take_bytes<'a>(data: &mut &'a [u8], len: usize) -> PolarsResult<&'a [u8]> {
+    polars_ensure!(
+        data.len() >= len,
+        ComputeError: "corrupt orc file: unexpected end of run length encoded stream"
+    );
+    let (out, rest) = data.split_at(len);
+    *data = rest;
+    Ok(out)
+}
+
+fn zigzag_decode(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+/// Decodes `n` bytes of a byte run length encoded stream.
+pub(super) fn decode_byte_rle(mut data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let header = next_byte(&mut data)?;
+        if header < 128 {
+            let value = next_byte(&mut data)?;
+            out.extend(std::iter::repeat_n(value, header as usize + 3));
+        } else {
+            let count = 256 - header as usize;
+            out.extend_from_slice(take_bytes(&mut data, count)?);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decodes `n` values of a boolean stream, which is a byte run length encoded stream of bits in
+/// most significant bit first order.
+pub(super) fn decode_bool_rle(data: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let bytes = decode_byte_rle(data, n.div_ceil(8))?;
+    let mut out = MutableBitmap::with_capacity(n);
+    for i in 0..n {
+        out.push((bytes[i / 8] >> (7 - i % 8)) & 1 == 1);
+    }
+    Ok(out.freeze())
+}
+
+/// Decodes `n` values of an integer run length encoded stream, using version 1 or version 2 of the
+/// encoding.
+///
+/// Values of unsigned streams that don't fit in an `i64` wrap around.
+pub(super) fn decode_int_rle(
+    data: &[u8],
+    n: usize,
+    signed: bool,
+    v2: bool,
+) -> PolarsResult<Vec<i64>> {
+    let mut out = Vec::with_capacity(n);
+    let mut data = data;
+    while out.len() < n {
+        if v2 {
+            decode_int_rle_v2_run(&mut data, signed, &mut out)?;
+        } else {
+            decode_int_rle_v1_run(&mut data, signed, &mut out)?;
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn read_int(data: &mut &[u8], signed: bool) -> PolarsResult<i64> {
+    let v = read_varint(data)?;
+    Ok(if signed { zigzag_decode(v) } else { v as i64 })
+}
+
+fn decode_int_rle_v1_run(data: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let header = next_byte(data)?;
+    if header < 128 {
+        let run = header as usize + 3;
+        let delta = next_byte(data)? as i8 as i64;
+        let base = read_int(data, signed)?;
+        out.extend((0..run as i64).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+    } else {
+        for _ in 0..256 - header as usize {
+            out.push(read_int(data, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// Decodes the 5 bit encoded bit width of the version 2 encoding.
+fn decode_bit_width(encoded: u8) -> usize {
+    match encoded {
+        0..=23 => encoded as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+/// Unpacks `n` big endian bit packed values of `width` bits. The packed values are padded to a
+/// whole number of bytes.
+fn unpack(data: &mut &[u8], width: usize, n: usize, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let bytes = take_bytes(data, (width * n).div_ceil(8))?;
+
+    let mut bit_pos = 0;
+    for _ in 0..n {
+        let mut value = 0u64;
+        let mut remaining = width;
+        while remaining > 0 {
+            let byte = bytes[bit_pos / 8];
+            let available = 8 - bit_pos % 8;
+            let take = available.min(remaining);
+            let bits = (byte >> (available - take)) & ((1u16 << take) - 1) as u8;
+            value = (value << take) | u64::from(bits);
+            remaining -= take;
+            bit_pos += take;
+        }
+        out.push(value as i64);
+    }
+    Ok(())
+}
+
+fn decode_int_rle_v2_run(data: &mut &[u8], signed: bool, out: &mut Vec<i64>) -> PolarsResult<()> {
+    let first = next_byte(data)?;
+    match first >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let width = ((first >> 3) & 7) as usize + 1;
+            let count = (first & 7) as usize + 3;
+            let value = take_bytes(data, width)?
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+            let value = if signed {
+                zigzag_decode(value)
+            } else {
+                value as i64
+            };
+            out.extend(std::iter::repeat_n(value, count));
+        },
+        // DIRECT
+        1 => {
+            let width = decode_bit_width((first >> 1) & 0x1F);
+            let len = ((((first & 1) as usize) << 8) | next_byte(data)? as usize) + 1;
+            let start = out.len();
+            unpack(data, width, len, out)?;
+            if signed {
+                for v in &mut out[start..] {
+                    *v = zigzag_decode(*v as u64);
+                }
+            }
+        },
+        // PATCHED_BASE
+        2 => {
+            let width = decode_bit_width((first >> 1) & 0x1F);
+            let len = ((((first & 1) as usize) << 8) | next_byte(data)? as usize) + 1;
+            let third = next_byte(data)?;
+            let base_width = ((third >> 5) & 7) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1F);
+            let fourth = next_byte(data)?;
+            let patch_gap_width = ((fourth >> 5) & 7) as usize + 1;
+            let patch_list_len = (fourth & 0x1F) as usize;
+
+            // The base is stored in big endian, with the most significant bit as the sign.
+            let mut base = take_bytes(data, base_width)?
+                .iter()
+                .fold(0u64, |acc, b| (acc << 8) | u64::from(*b)) as i64;
+            let sign_mask = 1i64 << (base_width * 8 - 1);
+            if base & sign_mask != 0 {
+                base = -(base & !sign_mask);
+            }
+
+            let mut values = Vec::with_capacity(len);
+            unpack(data, width, len, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_list_len);
+            unpack(
+                data,
+                closest_fixed_bits(patch_width + patch_gap_width),
+                patch_list_len,
+                &mut patches,
+            )?;
+
+            let patch_mask = 1u64
+                .checked_shl(patch_width as u32)
+                .map_or(u64::MAX, |v| v - 1);
+            let mut patch_idx = 0;
+            // Returns the position of the next patch relative to the previous one, and its value.
+            // A gap of 255 with a patch of 0 means that the gap continues in the next entry.
+            let next_patch = |patch_idx: &mut usize| -> PolarsResult<(usize, u64)> {
+                let mut gap = 0;
+                loop {
+                    let Some(&entry) = patches.get(*patch_idx) else {
+                        polars_bail!(ComputeError: "corrupt orc file: invalid patch list");
+                    };
+                    let entry = entry as u64;
+                    let (entry_gap, patch) = (
+                        entry.checked_shr(patch_width as u32).unwrap_or(0) as usize,
+                        entry & patch_mask,
+                    );
+                    gap += entry_gap;
+                    if entry_gap == 255 && patch == 0 {
+                        *patch_idx += 1;
+                        continue;
+                    }
+                    return Ok((gap, patch));
+                }
+            };
+
+            let mut next = if patch_list_len > 0 {
+                Some(next_patch(&mut patch_idx)?)
+            } else {
+                None
+            };
+            for (i, value) in values.into_iter().enumerate() {
+                let mut value = value as u64;
+                if let Some((position, patch)) = next
+                    && position == i
+                {
+                    value |= patch.checked_shl(width as u32).unwrap_or(0);
+                    patch_idx += 1;
+                    next = if patch_idx < patch_list_len {
+                        let (gap, patch) = next_patch(&mut patch_idx)?;
+                        Some((i + gap, patch))
+                    } else {
+                        None
+                    };
+                }
+                out.push(base.wrapping_add(value as i64));
+            }
+        },
+        // DELTA
+        _ => {
+            let encoded_width = (first >> 1) & 0x1F;
+            let width = if encoded_width == 0 {
+                0
+            } else {
+                decode_bit_width(encoded_width)
+            };
+            let len = ((((first & 1) as usize) << 8) | next_byte(data)? as usize) + 1;
+            let base = read_int(data, signed)?;
+            let delta_base = read_int(data, true)?;
+
+            out.push(base);
+            if width == 0 {
+                // Fixed delta.
+                let mut prev = base;
+                for _ in 1..len {
+                    prev = prev.wrapping_add(delta_base);
+                    out.push(prev);
+                }
+            } else if len > 1 {
+                let mut prev = base.wrapping_add(delta_base);
+                out.push(prev);
+
+                let start = out.len();
+                unpack(data, width, len.saturating_sub(2), out)?;
+                for v in &mut out[start..] {
+                    prev = if delta_base < 0 {
+                        prev.wrapping_sub(*v)
+                    } else {
+                        prev.wrapping_add(*v)
+                    };
+                    *v = prev;
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Decodes `n` zigzag encoded base 128 varints of unbounded length, as used for the values of
+/// decimal columns.
+pub(super) fn decode_i128_varints(mut data: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut out = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut value = 0u128;
+        let mut shift = 0;
+        loop {
+            let byte = next_byte(&mut data)?;
+            polars_ensure!(
+                shift < 128,
+                ComputeError: "corrupt orc file: decimal value is too long"
+            );
+            value |= u128::from(byte & 0x7F) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        out.push((value >> 1) as i128 ^ -((value & 1) as i128));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The examples are taken from the ORC specification.
+
+    #[test]
+    fn test_byte_rle() {
+        assert_eq!(decode_byte_rle(&[0x61, 0x00], 100).unwrap(), vec![0; 100]);
+        assert_eq!(
+            decode_byte_rle(&[0xfe, 0x44, 0x45], 2).unwrap(),
+            vec![0x44, 0x45]
+        );
+
+        let bools = decode_bool_rle(&[0xff, 0x80], 8).unwrap();
+        assert_eq!(
+            bools.iter().collect::<Vec<_>>(),
+            [true, false, false, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn test_int_rle_v1() {
+        assert_eq!(
+            decode_int_rle(&[0x61, 0x00, 0x07], 100, false, false).unwrap(),
+            vec![7; 100]
+        );
+        assert_eq!(
+            decode_int_rle(&[0x61, 0xff, 0x64], 100, false, false).unwrap(),
+            (1..=100).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            decode_int_rle(&[0xfb, 0x02, 0x03, 0x04, 0x07, 0x0b], 5, false, false).unwrap(),
+            vec![2, 3, 4, 7, 11]
+        );
+    }
+
+    #[test]
+    fn test_int_rle_v2() {
+        // SHORT_REPEAT
+        assert_eq!(
+            decode_int_rle(&[0x0a, 0x27, 0x10], 5, false, true).unwrap(),
+            vec![10000; 5]
+        );
+        // DIRECT
+        assert_eq!(
+            decode_int_rle(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4,
+                false,
+                true
+            )
+            .unwrap(),
+            vec![23713, 43806, 57005, 48879]
+        );
+        // PATCHED_BASE
+        assert_eq!(
+            decode_int_rle(
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8,
+                ],
+                20,
+                false,
+                true
+            )
+            .unwrap(),
+            vec![
+                2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120,
+                2130, 2140, 2150, 2160, 2170, 2180, 2190
+            ]
+        );
+        // DELTA
+        assert_eq!(
+            decode_int_rle(
+                &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+                10,
+                false,
+                true
+            )
+            .unwrap(),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Options for reading and writing encrypted Apache Parquet files.
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use polars_error::{PolarsResult, polars_bail};
+use polars_parquet::parquet::encryption::{
+    ColumnEncryptionKey, EncryptionAlgorithm, FileDecryptionProperties, FileEncryptionProperties,
+};
+pub use polars_parquet::parquet::encryption::{KeyMap, KeyRetriever};
+use polars_parquet::parquet::metadata::SchemaDescriptor;
+use polars_parquet::write::ColumnWriteOptions;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Retrieves the keys of encrypted Parquet files from their key metadata.
+#[derive(Clone)]
+pub struct ParquetKeyRetriever(Arc<dyn KeyRetriever>);
+
+impl ParquetKeyRetriever {
+    pub fn new(key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        Self(key_retriever)
+    }
+
+    /// Retrieves the keys from memory, identified by their key metadata.
+    pub fn from_key_map(keys: KeyMap) -> Self {
+        Self(Arc::new(keys))
+    }
+
+    pub fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>> {
+        Ok(self.0.retrieve_key(key_metadata)?)
+    }
+}
+
+impl Debug for ParquetKeyRetriever {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parquet key retriever at 0x{:016x}",
+            self.0.as_ref() as *const _ as *const () as usize
+        )
+    }
+}
+
+impl Eq for ParquetKeyRetriever {}
+
+impl PartialEq for ParquetKeyRetriever {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for ParquetKeyRetriever {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ParquetKeyRetriever {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize ParquetKeyRetriever"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ParquetKeyRetriever {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!("cannot serialize {self:?}")))
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for ParquetKeyRetriever {
+    fn schema_name() -> String {
+        "ParquetKeyRetriever".to_owned()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "ParquetKeyRetriever"))
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        Vec::<u8>::json_schema(generator)
+    }
+}
+
+/// Options to read encrypted Parquet files with.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetDecryptionOptions {
+    pub key_retriever: ParquetKeyRetriever,
+    /// The AAD prefix of files that were written without storing it.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl ParquetDecryptionOptions {
+    pub fn new(key_retriever: ParquetKeyRetriever) -> Self {
+        Self {
+            key_retriever,
+            aad_prefix: None,
+        }
+    }
+
+    pub fn to_properties(&self) -> FileDecryptionProperties {
+        FileDecryptionProperties {
+            key_retriever: self.key_retriever.0.clone(),
+            aad_prefix: self.aad_prefix.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetEncryptionAlgorithm {
+    /// AES-GCM for all data and metadata.
+    #[default]
+    AesGcm,
+    /// AES-CTR for the page data and AES-GCM for the metadata, which is faster but does not
+    /// authenticate the page data.
+    AesGcmCtr,
+}
+
+/// Options to write encrypted Parquet files with.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetEncryptionOptions {
+    pub key_retriever: ParquetKeyRetriever,
+    pub algorithm: ParquetEncryptionAlgorithm,
+    /// The key metadata of the footer key, which is stored in the file.
+    pub footer_key_metadata: Vec<u8>,
+    /// The (root) columns encrypted with their own key, with the key metadata of their key. If
+    /// empty, all columns are encrypted with the footer key, otherwise the other columns are not
+    /// encrypted.
+    pub column_key_metadata: Vec<(PlSmallStr, Vec<u8>)>,
+    /// Write the footer in plaintext, so that readers without the keys can read the unencrypted
+    /// columns.
+    pub plaintext_footer: bool,
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Store the AAD prefix in the file. Otherwise, readers must supply it.
+    pub store_aad_prefix: bool,
+}
+
+impl ParquetEncryptionOptions {
+    pub fn new(key_retriever: ParquetKeyRetriever, footer_key_metadata: Vec<u8>) -> Self {
+        Self {
+            key_retriever,
+            algorithm: ParquetEncryptionAlgorithm::default(),
+            footer_key_metadata,
+            column_key_metadata: vec![],
+            plaintext_footer: false,
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    /// Retrieves the keys of the columns of `schema`.
+    ///
+    /// Bloom filters of encrypted columns are not supported, so this errors if `column_options`
+    /// request one for an encrypted column before any data is written.
+    pub fn to_properties(
+        &self,
+        schema: &SchemaDescriptor,
+        column_options: &[ColumnWriteOptions],
+    ) -> PolarsResult<FileEncryptionProperties> {
+        let mut leaf_options = vec![];
+        for options in column_options {
+            options.to_leaves(&mut leaf_options);
+        }
+        for (column, options) in schema.columns().iter().zip(leaf_options) {
+            let is_encrypted = self.column_key_metadata.is_empty()
+                || self
+                    .column_key_metadata
+                    .iter()
+                    .any(|(name, _)| column.path_in_schema[0] == *name);
+            if is_encrypted && options.bloom_filter.is_some() {
+                polars_bail!(
+                    InvalidOperation: "cannot write a bloom filter for column '{}', bloom filters of encrypted columns are not supported",
+                    column.path_in_schema.join(".")
+                );
+            }
+        }
+
+        let mut column_keys = vec![];
+        for (name, key_metadata) in self.column_key_metadata.iter() {
+            let key = self.key_retriever.retrieve_key(key_metadata)?;
+            let mut leaves = schema
+                .columns()
+                .iter()
+                .filter(|c| c.path_in_schema[0] == *name)
+                .peekable();
+            if leaves.peek().is_none() {
+                polars_bail!(ColumnNotFound: "cannot encrypt column '{name}', it is not in the schema");
+            }
+            column_keys.extend(leaves.map(|c| ColumnEncryptionKey {
+                path_in_schema: c.path_in_schema.iter().map(|s| s.to_string()).collect(),
+                key: key.clone(),
+                key_metadata: Some(key_metadata.clone()),
+            }));
+        }
+
+        Ok(FileEncryptionProperties {
+            algorithm: match self.algorithm {
+                ParquetEncryptionAlgorithm::AesGcm => EncryptionAlgorithm::AesGcmV1,
+                ParquetEncryptionAlgorithm::AesGcmCtr => EncryptionAlgorithm::AesGcmCtrV1,
+            },
+            footer_key: self.key_retriever.retrieve_key(&self.footer_key_metadata)?,
+            footer_key_metadata: Some(self.footer_key_metadata.clone()),
+            column_keys,
+            plaintext_footer: self.plaintext_footer,
+            aad_prefix: self.aad_prefix.clone(),
+            store_aad_prefix: self.store_aad_prefix,
+        })
+    }
+}
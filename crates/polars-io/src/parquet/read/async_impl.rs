@@ -3,11 +3,13 @@
 use arrow::datatypes::ArrowSchemaRef;
 use object_store::path::Path as ObjectPath;
 use polars_core::prelude::*;
+use polars_parquet::parquet::encryption::FileDecryptionProperties;
 use polars_parquet::write::FileMetadata;
 
 use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
+use crate::parquet::encryption::ParquetDecryptionOptions;
 use crate::parquet::metadata::FileMetadataRef;
 
 pub struct ParquetObjectStore {
@@ -16,6 +18,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl ParquetObjectStore {
@@ -33,9 +36,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            decryption: None,
         })
     }
 
+    /// Read encrypted files with the keys of `decryption`.
+    pub fn with_decryption(mut self, decryption: Option<ParquetDecryptionOptions>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -53,7 +63,8 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        let decryption = self.decryption.as_ref().map(|d| d.to_properties());
+        fetch_metadata(&self.store, &self.path, length, decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -97,6 +108,7 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetadata> {
     let footer_header_bytes = store
         .get_range(
@@ -114,9 +126,9 @@ pub async fn fetch_metadata(
     let footer_byte_length: usize = {
         let reader = &mut footer_header_bytes.as_ref();
         let footer_byte_size = read_i32le(reader).unwrap();
-        let magic = read_n(reader).unwrap();
+        let magic: [u8; 4] = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != polars_parquet::parquet::PARQUET_MAGIC {
+        if !polars_parquet::parquet::read::is_parquet_magic(&magic) {
             return Err(polars_parquet::parquet::error::ParquetError::OutOfSpec(
                 "incorrect magic in parquet footer".to_string(),
             )
@@ -142,11 +154,8 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
-        std::io::Cursor::new(footer_bytes.as_ref()),
-        // TODO: Describe why this makes sense. Taken from the previous
-        // implementation which said "a highly nested but sparse struct could
-        // result in many allocations".
-        footer_bytes.as_ref().len() * 2 + 1024,
+    Ok(polars_parquet::parquet::read::deserialize_footer(
+        footer_bytes.as_ref(),
+        decryption,
     )?)
 }
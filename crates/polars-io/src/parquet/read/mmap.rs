@@ -3,7 +3,7 @@ use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
-    BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
+    BasicDecompressor, ColumnChunkMetadata, Filter, PageMetaData, PageReader, column_iter_to_arrays,
 };
 use polars_utils::mmap::{MemReader, MemSlice};
 
@@ -53,14 +53,35 @@ pub fn to_deserializer(
     columns: Vec<(&ColumnChunkMetadata, MemSlice)>,
     field: Field,
     filter: Option<Filter>,
+) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
+    let columns = columns
+        .into_iter()
+        .map(|(column_meta, chunk)| (column_meta, PageMetaData::from(column_meta), chunk))
+        .collect();
+
+    to_deserializer_with_page_meta(columns, field, filter)
+}
+
+/// Like [`to_deserializer`], but the pages of each column chunk are read with the given
+/// [`PageMetaData`]. This allows deserializing column chunks of which only some of the pages were
+/// fetched, in which case the chunk is expected to contain those pages back to back.
+pub fn to_deserializer_with_page_meta(
+    columns: Vec<(&ColumnChunkMetadata, PageMetaData, MemSlice)>,
+    field: Field,
+    filter: Option<Filter>,
 ) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
     let (columns, types): (Vec<_>, Vec<_>) = columns
         .into_iter()
-        .map(|(column_meta, chunk)| {
+        .map(|(column_meta, page_meta, chunk)| {
             // Advise fetching the data for the column chunk
             chunk.prefetch();
 
-            let pages = PageReader::new(MemReader::new(chunk), column_meta, vec![], usize::MAX);
+            let pages = PageReader::new_with_page_meta(
+                MemReader::new(chunk),
+                page_meta,
+                vec![],
+                usize::MAX,
+            );
             (
                 BasicDecompressor::new(pages, vec![]),
                 &column_meta.descriptor().descriptor.primitive_type,
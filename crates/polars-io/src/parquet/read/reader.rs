@@ -27,6 +27,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
+    decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -61,6 +62,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Read encrypted files with the keys of `decryption`.
+    pub fn with_decryption(mut self, decryption: Option<ParquetDecryptionOptions>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Add a row index column.
     pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
         self.row_index = row_index;
@@ -169,7 +176,11 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            let decryption = self.decryption.as_ref().map(|d| d.to_properties());
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -191,6 +202,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
@@ -8,7 +8,8 @@ use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
@@ -81,7 +82,8 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -91,14 +93,20 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    /// Write a row group of compressed pages, together with the bitsets of the bloom filters of
+    /// its leaf columns (see [`array_to_bloom_filters`]).
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_bloom_filters(rg, bloom_filters)?;
         Ok(())
     }
 
@@ -143,6 +151,12 @@ impl<W: Write> BatchedWriter<W> {
     }
 }
 
+/// A row group together with the bitsets of the bloom filters of its leaf columns.
+type RowGroupWithBloomFilters = (
+    RowGroupIterColumns<'static, PolarsError>,
+    Vec<Option<Vec<u8>>>,
+);
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
@@ -150,11 +164,15 @@ fn prepare_rg_iter<'a>(
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<RowGroupWithBloomFilters>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
+            let bloom_filters = match batch_to_bloom_filters(&batch, column_options) {
+                Ok(bloom_filters) => bloom_filters,
+                Err(e) => return Some(Err(e)),
+            };
             let row_group = create_serializer(
                 batch,
                 parquet_schema.fields(),
@@ -163,11 +181,22 @@ fn prepare_rg_iter<'a>(
                 parallel,
             );
 
-            Some(row_group)
+            Some(row_group.map(|row_group| (row_group, bloom_filters)))
         },
     })
 }
 
+fn batch_to_bloom_filters(
+    batch: &RecordBatch,
+    column_options: &[ColumnWriteOptions],
+) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let mut bloom_filters = Vec::new();
+    for (array, column_options) in batch.columns().iter().zip(column_options) {
+        bloom_filters.extend(array_to_bloom_filters(array.as_ref(), column_options)?);
+    }
+    Ok(bloom_filters)
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
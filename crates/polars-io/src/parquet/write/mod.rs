@@ -8,8 +8,8 @@ mod writer;
 pub use batched_writer::BatchedWriter;
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{
-    BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetCompression,
-    ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
+    BrotliLevel, ChildFieldOverwrites, GzipLevel, MetadataKeyValue, ParquetBloomFilterOptions,
+    ParquetCompression, ParquetEncoding, ParquetFieldOverwrites, ParquetWriteOptions, ZstdLevel,
 };
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
 pub use writer::{ParquetWriter, get_column_write_options};
@@ -1,13 +1,15 @@
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 use polars_utils::pl_str::PlSmallStr;
+use polars_utils::total_ord::TotalOrdWrap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::KeyValueMetadata;
+use crate::parquet::encryption::ParquetEncryptionOptions;
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -26,6 +28,8 @@ pub struct ParquetWriteOptions {
 
     /// Per-field overwrites for writing properties.
     pub field_overwrites: Vec<ParquetFieldOverwrites>,
+    /// Encrypt the file with the Parquet modular encryption.
+    pub encryption: Option<ParquetEncryptionOptions>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -57,6 +61,64 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a split-block bloom filter per row group for this (leaf) column.
+    pub bloom_filter: Option<ParquetBloomFilterOptions>,
+    /// Encoding of the data pages of this (leaf) column.
+    pub encoding: Option<ParquetEncoding>,
+}
+
+/// The encoding of the data pages of a Parquet column.
+///
+/// Leaves of lists are always written as [`ParquetEncoding::Plain`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetEncoding {
+    Plain,
+    /// Dictionary encoding, falling back to plain if the dictionary gets too large.
+    Dictionary,
+    /// Integers only.
+    DeltaBinaryPacked,
+    /// Binary and strings only.
+    DeltaLengthByteArray,
+    /// Binary and strings only.
+    DeltaByteArray,
+    /// Integers and floats only.
+    ByteStreamSplit,
+    /// `ByteStreamSplit` for floats, `DeltaBinaryPacked` for integers and temporal columns whose
+    /// values are sorted in a row group, and the default encoding otherwise.
+    Auto,
+}
+
+/// Sizing of the bloom filters written for a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct ParquetBloomFilterOptions {
+    /// Expected number of distinct values in a row group. If `None`, the number of non-null
+    /// values in the row group is used.
+    pub ndv: Option<u64>,
+    /// Target false positive probability.
+    pub fpp: TotalOrdWrap<f64>,
+}
+
+impl Default for ParquetBloomFilterOptions {
+    fn default() -> Self {
+        let BloomFilterOptions { ndv, fpp } = BloomFilterOptions::default();
+        Self {
+            ndv,
+            fpp: TotalOrdWrap(fpp),
+        }
+    }
+}
+
+impl From<ParquetBloomFilterOptions> for BloomFilterOptions {
+    fn from(value: ParquetBloomFilterOptions) -> Self {
+        Self {
+            ndv: value.ndv,
+            fpp: value.fpp.0,
+        }
+    }
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -12,7 +12,11 @@ use polars_parquet::write::{
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
-use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
+use super::{
+    KeyValueMetadata, MetadataKeyValue, ParquetEncoding, ParquetFieldOverwrites,
+    ParquetWriteOptions,
+};
+use crate::parquet::encryption::ParquetEncryptionOptions;
 use crate::prelude::ChildFieldOverwrites;
 use crate::shared::schema_to_arrow_checked;
 
@@ -27,6 +31,8 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -49,6 +55,8 @@ pub struct ParquetWriter<W> {
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Encrypt the file with the Parquet modular encryption.
+    encryption: Option<ParquetEncryptionOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -70,6 +78,7 @@ where
             field_overwrites: Vec::new(),
             key_value_metadata: None,
             context_info: None,
+            encryption: None,
         }
     }
 
@@ -107,6 +116,13 @@ where
         self
     }
 
+    /// Set the overwrites of the field IDs, metadata, nullability and bloom filters of the written
+    /// columns.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set custom file-level key value metadata for the Parquet file
     pub fn with_key_value_metadata(mut self, key_value_metadata: Option<KeyValueMetadata>) -> Self {
         self.key_value_metadata = key_value_metadata;
@@ -119,17 +135,23 @@ where
         self
     }
 
+    /// Encrypt the file with the Parquet modular encryption.
+    pub fn with_encryption(mut self, encryption: Option<ParquetEncryptionOptions>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let column_options = get_column_write_options(&schema, &self.field_overwrites);
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(
-            self.writer,
-            schema,
-            options,
-            &column_options,
-        )?);
+        let mut writer = FileWriter::try_new(self.writer, schema, options, &column_options)?;
+        if let Some(encryption) = &self.encryption {
+            let properties = encryption.to_properties(&parquet_schema, &column_options)?;
+            writer = writer.with_encryption(properties)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
@@ -184,9 +206,9 @@ fn to_column_write_options_rec(
         required: None,
 
         // Dummy value.
-        children: ChildWriteOptions::Leaf(FieldWriteOptions {
-            encoding: Encoding::Plain,
-        }),
+        children: ChildWriteOptions::Leaf(FieldWriteOptions::default_with_encoding(
+            Encoding::Plain,
+        )),
     };
 
     if let Some(overwrites) = overwrites {
@@ -199,8 +221,19 @@ fn to_column_write_options_rec(
     match field.dtype().to_physical_type() {
         Null | Boolean | Primitive(_) | Binary | FixedSizeBinary | LargeBinary | Utf8
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
+            let encoding = overwrites.and_then(|o| o.encoding);
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
-                encoding: encoding_map(field.dtype()),
+                encoding: match encoding {
+                    None | Some(ParquetEncoding::Auto) => encoding_map(field.dtype()),
+                    Some(ParquetEncoding::Plain) => Encoding::Plain,
+                    Some(ParquetEncoding::Dictionary) => Encoding::RleDictionary,
+                    Some(ParquetEncoding::DeltaBinaryPacked) => Encoding::DeltaBinaryPacked,
+                    Some(ParquetEncoding::DeltaLengthByteArray) => Encoding::DeltaLengthByteArray,
+                    Some(ParquetEncoding::DeltaByteArray) => Encoding::DeltaByteArray,
+                    Some(ParquetEncoding::ByteStreamSplit) => Encoding::ByteStreamSplit,
+                },
+                auto_encoding: encoding == Some(ParquetEncoding::Auto),
+                bloom_filter: overwrites.and_then(|o| o.bloom_filter).map(Into::into),
             });
         },
         List | FixedSizeList | LargeList => {
@@ -6,6 +6,8 @@ use polars_core::series::IsSorted;
 use polars_utils::plpath::PlPathRef;
 use rayon::prelude::*;
 
+#[cfg(feature = "avro")]
+use crate::avro::AvroWriterOptions;
 use crate::cloud::CloudOptions;
 use crate::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "ipc")]
@@ -41,6 +43,20 @@ impl WriteDataFrameToFile for IpcWriterOptions {
     }
 }
 
+#[cfg(feature = "avro")]
+impl WriteDataFrameToFile for AvroWriterOptions {
+    fn write_df_to_file(
+        &self,
+        df: &mut DataFrame,
+        addr: PlPathRef<'_>,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<()> {
+        let f = try_get_writeable(addr, cloud_options)?;
+        self.to_writer(f).finish(df)?;
+        Ok(())
+    }
+}
+
 /// Write a partitioned parquet dataset. This functionality is unstable.
 pub fn write_partitioned_dataset(
     df: &mut DataFrame,
@@ -196,3 +212,92 @@ pub fn write_partitioned_dataset(
 
     Ok(())
 }
+
+/// A file written by a partitioned sink, read from the DataFrame that the sink passes to its
+/// finish callback.
+#[derive(Debug, Clone)]
+pub struct WrittenFile {
+    /// Full path of the file.
+    pub path: String,
+    pub num_rows: u64,
+    pub file_size: u64,
+    /// Values of the partition keys of the file, by key column name.
+    pub keys: Vec<(PlSmallStr, AnyValue<'static>)>,
+    /// Statistics of the columns written to the file, by column name.
+    pub columns: Vec<(PlSmallStr, WrittenColumnStats)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WrittenColumnStats {
+    pub null_count: u64,
+    pub nan_count: u64,
+    /// Minimum non-NaN value, null if not known.
+    pub lower_bound: AnyValue<'static>,
+    /// Maximum non-NaN value, null if not known.
+    pub upper_bound: AnyValue<'static>,
+}
+
+impl WrittenFile {
+    /// Reads the files from the DataFrame passed to the finish callback of a partitioned sink.
+    /// It has `path`, `num_rows`, `file_size` and `keys` columns, followed by a `{name}_stats`
+    /// struct column for every written column.
+    pub fn from_sink_df(df: &DataFrame) -> PolarsResult<Vec<Self>> {
+        let path = df.column("path")?.str()?;
+        let num_rows = df.column("num_rows")?.u64()?;
+        let file_size = df.column("file_size")?.u64()?;
+        let keys = df.column("keys")?.struct_()?.fields_as_series();
+
+        let stats = df
+            .get_columns()
+            .iter()
+            .skip(4)
+            .map(|c| {
+                let name = c.name().strip_suffix("_stats").ok_or_else(
+                    || polars_err!(ComputeError: "unexpected column '{}' in written files", c.name()),
+                )?;
+                let ca = c.struct_()?;
+                Ok((
+                    PlSmallStr::from_str(name),
+                    ca.field_by_name("null_count")?,
+                    ca.field_by_name("nan_count")?,
+                    ca.field_by_name("lower_bound")?,
+                    ca.field_by_name("upper_bound")?,
+                ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        (0..df.height())
+            .map(|i| {
+                let u64_at =
+                    |s: &Series| -> PolarsResult<u64> { Ok(s.u64()?.get(i).unwrap_or_default()) };
+
+                Ok(Self {
+                    path: path
+                        .get(i)
+                        .ok_or_else(|| polars_err!(ComputeError: "missing path of written file"))?
+                        .to_string(),
+                    num_rows: num_rows.get(i).unwrap_or_default(),
+                    file_size: file_size.get(i).unwrap_or_default(),
+                    keys: keys
+                        .iter()
+                        .map(|s| Ok((s.name().clone(), s.get(i)?.into_static())))
+                        .collect::<PolarsResult<_>>()?,
+                    columns: stats
+                        .iter()
+                        .map(|(name, null_count, nan_count, lower_bound, upper_bound)| {
+                            Ok((
+                                name.clone(),
+                                WrittenColumnStats {
+                                    null_count: u64_at(null_count)?,
+                                    nan_count: u64_at(nan_count)?,
+                                    lower_bound: lower_bound.get(i)?.into_static(),
+                                    upper_bound: upper_bound.get(i)?.into_static(),
+                                },
+                            ))
+                        })
+                        .collect::<PolarsResult<_>>()?,
+                })
+            })
+            .collect()
+    }
+}
@@ -196,6 +196,104 @@ impl DerefMut for Writeable {
     }
 }
 
+/// Reads the file at `addr`, returning `None` if it does not exist.
+pub fn read_file_if_exists(
+    addr: PlPathRef<'_>,
+    #[cfg_attr(not(feature = "cloud"), allow(unused))] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Option<Vec<u8>>> {
+    match addr {
+        PlPathRef::Local(path) => match std::fs::read(resolve_homedir(&path)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        },
+        PlPathRef::Cloud(p) => {
+            feature_gated!("cloud", {
+                use crate::cloud::object_path_from_str;
+
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let (location, store) =
+                        crate::cloud::build_object_store(&p.to_string(), cloud_options, false)
+                            .await?;
+                    let path = object_path_from_str(&location.prefix)?;
+
+                    match store.to_dyn_object_store().await.get(&path).await {
+                        Ok(result) => Ok(Some(result.bytes().await?.to_vec())),
+                        Err(object_store::Error::NotFound { .. }) => Ok(None),
+                        Err(err) => Err(err.into()),
+                    }
+                })
+            })
+        },
+    }
+}
+
+/// Writes `bytes` to `addr` only if no file exists there yet, returning `false` if it did. Of
+/// concurrent writers to the same path, at most one succeeds, and readers never observe a
+/// partially written file.
+///
+/// Local files are written to a temporary file that is then hard-linked into place, cloud files
+/// use a conditional put. The latter may need to be enabled for some object stores.
+pub fn write_new_file(
+    addr: PlPathRef<'_>,
+    bytes: &[u8],
+    #[cfg_attr(not(feature = "cloud"), allow(unused))] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<bool> {
+    match addr {
+        PlPathRef::Local(path) => {
+            static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+            let path = resolve_homedir(&path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            let tmp_path = path.with_file_name(format!(
+                ".{file_name}.{}-{}.tmp",
+                std::process::id(),
+                COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            ));
+            std::fs::write(&tmp_path, bytes)?;
+
+            let result = std::fs::hard_link(&tmp_path, &path);
+            let _ = std::fs::remove_file(&tmp_path);
+
+            match result {
+                Ok(()) => Ok(true),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+                Err(err) => Err(err.into()),
+            }
+        },
+        PlPathRef::Cloud(p) => {
+            feature_gated!("cloud", {
+                use object_store::{PutMode, PutPayload};
+
+                use crate::cloud::object_path_from_str;
+
+                crate::pl_async::get_runtime().block_in_place_on(async {
+                    let (location, store) =
+                        crate::cloud::build_object_store(&p.to_string(), cloud_options, false)
+                            .await?;
+                    let path = object_path_from_str(&location.prefix)?;
+                    let payload = PutPayload::from(bytes.to_vec());
+
+                    match store
+                        .to_dyn_object_store()
+                        .await
+                        .put_opts(&path, payload, PutMode::Create.into())
+                        .await
+                    {
+                        Ok(_) => Ok(true),
+                        Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+                        Err(err) => Err(err.into()),
+                    }
+                })
+            })
+        },
+    }
+}
+
 /// Note: Prefer using [`Writeable`] / [`Writeable::try_new`] where possible.
 ///
 /// Open a path for writing. Supports cloud paths.
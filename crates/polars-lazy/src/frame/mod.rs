@@ -12,15 +12,23 @@ pub mod pivot;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
@@ -40,6 +48,8 @@ use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterato
 
 use crate::frame::cached_arenas::CachedArena;
 use crate::prelude::*;
+#[cfg(feature = "iceberg")]
+pub use crate::scan::iceberg::*;
 
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
@@ -951,6 +961,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an Avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(
+        self,
+        target: SinkTarget,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into a parquet file in a partitioned manner. This is useful if the
     /// final result doesn't fit into memory. This methods will return an error if the query cannot
     /// be completely done in a streaming fashion.
@@ -1063,6 +1092,34 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into Avro files in a partitioned manner. This is useful if the
+    /// final result doesn't fit into memory. This methods will return an error if the query cannot
+    /// be completely done in a streaming fashion.
+    #[cfg(feature = "avro")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_avro_partitioned(
+        self,
+        base_path: Arc<PlPath>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+        per_partition_sort_by: Option<Vec<SortColumn>>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Avro(options),
+            cloud_options,
+            per_partition_sort_by,
+            finish_callback,
+        }))
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn try_new_streaming_if_requested(
         &mut self,
@@ -1260,6 +1317,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                grouping_sets: None,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1272,6 +1330,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                grouping_sets: None,
             }
         }
     }
@@ -1308,6 +1367,7 @@ impl LazyFrame {
             opt_state,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            grouping_sets: None,
             dynamic_options: None,
             rolling_options: Some(options),
         }
@@ -1353,6 +1413,7 @@ impl LazyFrame {
             opt_state,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            grouping_sets: None,
             dynamic_options: Some(options),
             rolling_options: None,
         }
@@ -1374,6 +1435,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                grouping_sets: None,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1386,10 +1448,41 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                grouping_sets: None,
             }
         }
     }
 
+    /// Group by multiple grouping sets of the keys `by` at once, producing a [`LazyGroupBy`].
+    ///
+    /// The aggregations are computed for every grouping set in a single pass over the input. Keys
+    /// that are not part of a grouping set are null in its groups.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn subtotals(df: DataFrame) -> LazyFrame {
+    ///       df.lazy()
+    ///        .group_by_grouping_sets(
+    ///            [col("region"), col("city")],
+    ///            GroupingSetsOptions::rollup(2).with_grouping_id(Some("level".into())),
+    ///        )
+    ///        .agg([col("sales").sum()])
+    /// }
+    /// ```
+    pub fn group_by_grouping_sets<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        options: GroupingSetsOptions,
+    ) -> LazyGroupBy {
+        let mut lgb = self.group_by(by);
+        lgb.grouping_sets = Some(options);
+        lgb
+    }
+
     /// Left anti join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -2070,6 +2163,51 @@ impl LazyFrame {
         };
         Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
     }
+
+    /// Recursively extend this query, the anchor, with the rows of a recursive query.
+    ///
+    /// `recursive` builds the recursive query from the work table, which holds the rows that
+    /// were added in the previous iteration. It is evaluated until it adds no new rows, or until
+    /// [`RecursiveUnionOptions::max_iterations`] is exceeded, which raises an error. With
+    /// [`RecursiveUnionOptions::distinct`] duplicate rows are removed, so that cyclic data
+    /// reaches a fixed point.
+    ///
+    /// The columns of the recursive query are matched to those of the anchor by position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn count_to_ten() -> PolarsResult<LazyFrame> {
+    ///     df!("n" => [1])?.lazy().recursive_union(
+    ///         RecursiveUnionOptions::new("counter".into()),
+    ///         |counter| Ok(counter.select([col("n") + lit(1)]).filter(col("n").lt_eq(lit(10)))),
+    ///     )
+    /// }
+    /// ```
+    pub fn recursive_union<F>(
+        mut self,
+        options: RecursiveUnionOptions,
+        recursive: F,
+    ) -> PolarsResult<LazyFrame>
+    where
+        F: FnOnce(LazyFrame) -> PolarsResult<LazyFrame>,
+    {
+        let work_table = DslPlan::WorkTableScan {
+            name: options.name.clone(),
+            schema: self.collect_schema()?,
+        };
+        let recursive = recursive(LazyFrame::from_logical_plan(work_table, self.opt_state))?;
+
+        let lp = DslPlan::RecursiveUnion {
+            anchor: Arc::new(self.logical_plan),
+            recursive: Arc::new(recursive.logical_plan),
+            options,
+        };
+        Ok(LazyFrame::from_logical_plan(lp, self.opt_state))
+    }
 }
 
 /// Utility struct for lazy group_by operation.
@@ -2079,6 +2217,7 @@ pub struct LazyGroupBy {
     opt_state: OptFlags,
     keys: Vec<Expr>,
     maintain_order: bool,
+    grouping_sets: Option<GroupingSetsOptions>,
     #[cfg(feature = "dynamic_group_by")]
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
@@ -2125,6 +2264,7 @@ impl LazyGroupBy {
                 aggs,
                 None,
                 self.maintain_order,
+                self.grouping_sets,
                 self.dynamic_options,
                 self.rolling_options,
             )
@@ -2132,7 +2272,13 @@ impl LazyGroupBy {
 
         #[cfg(not(feature = "dynamic_group_by"))]
         let lp = DslBuilder::from(self.logical_plan)
-            .group_by(self.keys, aggs, None, self.maintain_order)
+            .group_by(
+                self.keys,
+                aggs,
+                None,
+                self.maintain_order,
+                self.grouping_sets,
+            )
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -2170,11 +2316,15 @@ impl LazyGroupBy {
         let options = GroupbyOptions {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
+            grouping_sets: self.grouping_sets,
             slice: None,
         };
 
         #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
+        let options = GroupbyOptions {
+            grouping_sets: self.grouping_sets,
+            slice: None,
+        };
 
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
@@ -1,4 +1,6 @@
 pub(crate) use polars_expr::prelude::*;
+#[cfg(feature = "avro")]
+pub use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
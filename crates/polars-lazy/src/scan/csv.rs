@@ -2,6 +2,7 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
+    maybe_rewrite_dialect, maybe_transcode_bytes,
 };
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::compression::maybe_decompress_bytes;
@@ -143,6 +144,13 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_separator(separator))
     }
 
+    /// Set a separator of more than one character, e.g. `||`. Takes precedence over the single
+    /// byte separator.
+    #[must_use]
+    pub fn with_multi_char_separator(self, separator: Option<PlSmallStr>) -> Self {
+        self.map_parse_options(|opts| opts.with_multi_char_separator(separator.clone()))
+    }
+
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
     #[must_use]
     pub fn with_comment_prefix(self, comment_prefix: Option<PlSmallStr>) -> Self {
@@ -163,12 +171,25 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_quote_char(quote_char))
     }
 
+    /// Set the `char` that escapes the `char` after it, e.g. `b'\\'`. The default is [`None`].
+    #[must_use]
+    pub fn with_escape_char(self, escape_char: Option<u8>) -> Self {
+        self.map_parse_options(|opts| opts.with_escape_char(escape_char))
+    }
+
     /// Set the `char` used as end of line. The default is `b'\n'`.
     #[must_use]
     pub fn with_eol_char(self, eol_char: u8) -> Self {
         self.map_parse_options(|opts| opts.with_eol_char(eol_char))
     }
 
+    /// Set a line terminator of more than one character, e.g. `\r\n`. Takes precedence over the
+    /// eol char.
+    #[must_use]
+    pub fn with_line_terminator(self, line_terminator: Option<PlSmallStr>) -> Self {
+        self.map_parse_options(|opts| opts.with_line_terminator(line_terminator.clone()))
+    }
+
     /// Set values that will be interpreted as missing/ null.
     #[must_use]
     pub fn with_null_values(self, null_values: Option<NullValues>) -> Self {
@@ -253,6 +274,10 @@ impl LazyCsvReader {
 
             let mut owned = vec![];
             let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let transcoded = maybe_transcode_bytes(bytes, parse_options.encoding)?;
+            let mut rewritten = vec![];
+            let bytes = maybe_rewrite_dialect(&transcoded, &parse_options, &mut rewritten)?;
+            let parse_options = parse_options.to_standard_dialect();
 
             PolarsResult::Ok(
                 infer_file_schema(
@@ -0,0 +1,279 @@
+use polars_core::prelude::*;
+use polars_io::HiveOptions;
+use polars_io::cloud::CloudOptions;
+pub use polars_io::delta::{DeltaCommit, DeltaSnapshot, DeltaVersion, DeltaWriteMode};
+use polars_io::partition::WrittenFile;
+use polars_io::prelude::ParquetOptions;
+use polars_plan::dsl::deletion::{
+    DeletionFilesList, DeltaDeletionVectorDescriptor, DeltaDeletionVectorStorageType,
+};
+use polars_utils::plpath::PlPath;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsDelta {
+    pub version: DeltaVersion,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+}
+
+impl Default for ScanArgsDelta {
+    fn default() -> Self {
+        Self {
+            version: DeltaVersion::Latest,
+            cloud_options: None,
+            rechunk: false,
+            cache: true,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct SinkArgsDelta {
+    pub mode: DeltaWriteMode,
+    /// Columns to partition the table by, whose values are written as Hive-style directories.
+    pub partition_by: Vec<PlSmallStr>,
+    pub options: ParquetWriteOptions,
+    pub cloud_options: Option<CloudOptions>,
+    pub sink_options: SinkOptions,
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the Delta Lake table at `table_root`.
+    ///
+    /// The active data files are found by replaying the transaction log, and are then scanned as
+    /// Parquet files with the partition columns taken from the partition values in the log.
+    pub fn scan_delta(table_root: PlPath, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let snapshot = DeltaSnapshot::load(
+            table_root.as_ref(),
+            args.version,
+            args.cloud_options.as_ref(),
+        )?;
+        Self::scan_delta_snapshot(snapshot, args)
+    }
+
+    /// Create a LazyFrame from an already loaded snapshot of a Delta Lake table, e.g. one whose
+    /// files were pruned with [`DeltaSnapshot::prune`].
+    pub fn scan_delta_snapshot(snapshot: DeltaSnapshot, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let schema = snapshot.schema();
+        let physical_schema = snapshot.physical_schema();
+
+        if snapshot.files().is_empty() {
+            return Ok(DataFrame::empty_with_schema(schema).lazy());
+        }
+
+        let partition_values = snapshot.partition_values_df()?;
+        let file_schema: Schema = physical_schema
+            .iter()
+            .filter(|(name, _)| !partition_values.schema().contains(name))
+            .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+            .collect();
+        let table_root = snapshot.table_root().as_ref().to_str();
+
+        let deletion_vectors = snapshot
+            .files()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, file)| {
+                let dv = file.deletion_vector.as_ref()?;
+                Some(
+                    DeltaDeletionVectorStorageType::from_log_value(&dv.storage_type)
+                        .ok_or_else(|| {
+                            polars_err!(
+                                ComputeError:
+                                "invalid Delta deletion vector storage type: {}",
+                                dv.storage_type
+                            )
+                        })
+                        .map(|storage_type| {
+                            (
+                                idx,
+                                DeltaDeletionVectorDescriptor {
+                                    storage_type,
+                                    path_or_inline_dv: dv.path_or_inline_dv.as_str().into(),
+                                    offset: dv.offset.map(|v| v as usize),
+                                    size_in_bytes: dv.size_in_bytes as usize,
+                                    cardinality: dv.cardinality as usize,
+                                },
+                            )
+                        }),
+                )
+            })
+            .collect::<PolarsResult<PlIndexMap<_, _>>>()?;
+
+        // The partition values are taken from the log rather than the file paths, as those
+        // don't have to be Hive-style, e.g. with column mapping. Files with the same partition
+        // values are scanned together, with the values added as literals.
+        let mut groups: PlIndexMap<Vec<Option<&str>>, Vec<usize>> = PlIndexMap::new();
+        for (idx, file) in snapshot.files().iter().enumerate() {
+            let key = partition_values
+                .get_column_names()
+                .into_iter()
+                .map(|name| {
+                    file.partition_values
+                        .get(name.as_str())
+                        .and_then(|v| v.as_deref())
+                        .filter(|v| !v.is_empty())
+                })
+                .collect();
+            groups.entry(key).or_default().push(idx);
+        }
+
+        let parquet_options = ParquetOptions {
+            schema: Some(Arc::new(file_schema)),
+            ..Default::default()
+        };
+
+        let lfs = groups
+            .into_values()
+            .map(|file_idxs| {
+                let vectors = file_idxs
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, file_idx)| Some((i, deletion_vectors.get(file_idx)?.clone())))
+                    .collect::<PlIndexMap<_, _>>();
+
+                let unified_scan_args = UnifiedScanArgs {
+                    schema: None,
+                    cloud_options: args.cloud_options.clone(),
+                    hive_options: HiveOptions::new_disabled(),
+                    rechunk: args.rechunk,
+                    cache: args.cache,
+                    glob: false,
+                    projection: None,
+                    column_mapping: None,
+                    default_values: None,
+                    row_index: None,
+                    pre_slice: None,
+                    cast_columns_policy: CastColumnsPolicy {
+                        integer_upcast: true,
+                        float_upcast: true,
+                        float_downcast: true,
+                        datetime_nanoseconds_downcast: true,
+                        datetime_microseconds_downcast: false,
+                        datetime_convert_timezone: true,
+                        missing_struct_fields: MissingColumnsPolicy::Insert,
+                        extra_struct_fields: ExtraColumnsPolicy::Ignore,
+                    },
+                    missing_columns_policy: MissingColumnsPolicy::Insert,
+                    extra_columns_policy: ExtraColumnsPolicy::Ignore,
+                    include_file_paths: None,
+                    deletion_files: DeletionFilesList::filter_empty(Some(
+                        DeletionFilesList::DeltaDeletionVector {
+                            table_root: table_root.into(),
+                            vectors: Arc::new(vectors),
+                        },
+                    )),
+                };
+
+                let sources = ScanSources::Paths(
+                    file_idxs
+                        .iter()
+                        .map(|&idx| snapshot.files()[idx].path.clone())
+                        .collect(),
+                );
+                let lf: LazyFrame =
+                    DslBuilder::scan_parquet(sources, parquet_options.clone(), unified_scan_args)?
+                        .build()
+                        .into();
+
+                let partition_exprs = partition_values
+                    .get_columns()
+                    .iter()
+                    .map(|c| {
+                        let value = c.get(file_idxs[0])?.into_static();
+                        Ok(lit(Scalar::new(c.dtype().clone(), value)).alias(c.name().clone()))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(lf.with_columns(partition_exprs))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let lf = concat(lfs, UnionArgs::default())?;
+
+        // The scan appends the partition columns, restore the column order of the table and
+        // rename the physical columns to their logical names.
+        let exprs = schema
+            .iter()
+            .zip(physical_schema.iter())
+            .map(|((name, dtype), (physical_name, physical_dtype))| {
+                polars_ensure!(
+                    dtype == physical_dtype,
+                    nyi = "column mapping of nested fields in Delta column '{}'",
+                    name
+                );
+                Ok(col(physical_name.clone()).alias(name.clone()))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(lf.select(exprs))
+    }
+
+    /// Stream the result of the query into the Delta Lake table at `table_root`, which is created
+    /// if it does not exist yet.
+    ///
+    /// The data is cast to the table schema and written as Parquet files, which are committed to
+    /// the transaction log as a single new version once all of them are written.
+    pub fn sink_delta(mut self, table_root: PlPath, args: SinkArgsDelta) -> PolarsResult<Self> {
+        let schema = self.collect_schema()?;
+        let commit = Arc::new(DeltaCommit::new(
+            table_root.as_ref(),
+            args.mode,
+            &schema,
+            args.partition_by.clone(),
+            args.cloud_options.as_ref(),
+        )?);
+
+        let table_schema = commit.schema().clone();
+        let casts: PlHashMap<&str, DataType> = table_schema
+            .iter()
+            .filter(|(name, dtype)| schema.get(name) != Some(dtype))
+            .map(|(name, dtype)| (name.as_str(), dtype.clone()))
+            .collect();
+        let lf = if casts.is_empty() {
+            self
+        } else {
+            self.cast(casts, true)
+        };
+
+        let variant = if args.partition_by.is_empty() {
+            PartitionVariant::MaxSize(IdxSize::MAX)
+        } else {
+            PartitionVariant::ByKey {
+                key_exprs: args.partition_by.iter().cloned().map(col).collect(),
+                include_key: false,
+            }
+        };
+
+        let file_path_cb = {
+            let commit = commit.clone();
+            PartitionTargetCallback::Rust(SpecialEq::new(Arc::new(move |ctx| {
+                let name = commit.data_file_name(ctx.file_idx);
+                let path = match ctx.file_path.rsplit_once('/') {
+                    Some((dir, _)) => format!("{dir}/{name}"),
+                    None => name,
+                };
+                Ok(PartitionTargetCallbackResult::Str(path))
+            })))
+        };
+        let finish_callback = SinkFinishCallback::Rust(SpecialEq::new(Arc::new(move |df| {
+            commit.commit(&WrittenFile::from_sink_df(&df)?)?;
+            Ok(())
+        })));
+
+        lf.sink_parquet_partitioned(
+            Arc::new(table_root),
+            Some(file_path_cb),
+            variant,
+            args.options,
+            args.cloud_options,
+            SinkOptions {
+                mkdir: true,
+                ..args.sink_options
+            },
+            None,
+            Some(finish_callback),
+        )
+    }
+}
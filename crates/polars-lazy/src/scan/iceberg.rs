@@ -0,0 +1,206 @@
+use polars_core::prelude::*;
+use polars_io::HiveOptions;
+use polars_io::cloud::CloudOptions;
+pub use polars_io::iceberg::{IcebergCommit, IcebergScan, IcebergTable, IcebergWriteMode};
+use polars_io::partition::WrittenFile;
+use polars_io::prelude::ParquetOptions;
+use polars_plan::dsl::default_values::{
+    DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
+};
+use polars_plan::dsl::deletion::{DeletionFilesList, IcebergEqualityDeleteFile};
+use polars_utils::plpath::PlPath;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsIceberg {
+    /// Snapshot to read, the current snapshot of the table if `None`.
+    pub snapshot_id: Option<i64>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+}
+
+impl Default for ScanArgsIceberg {
+    fn default() -> Self {
+        Self {
+            snapshot_id: None,
+            cloud_options: None,
+            rechunk: false,
+            cache: true,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct SinkArgsIceberg {
+    pub mode: IcebergWriteMode,
+    /// Columns to partition the table by with identity transforms.
+    pub partition_by: Vec<PlSmallStr>,
+    pub options: ParquetWriteOptions,
+    pub cloud_options: Option<CloudOptions>,
+    pub sink_options: SinkOptions,
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from the Iceberg table with the metadata file at `metadata_location`.
+    ///
+    /// The data files are found by reading the manifests of the snapshot, and are then scanned
+    /// as Parquet files, with their columns matched to the table schema by field ID.
+    pub fn scan_iceberg(metadata_location: PlPath, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let table = IcebergTable::load(metadata_location.as_ref(), args.cloud_options.as_ref())?;
+        let scan = table.scan(args.snapshot_id, None)?;
+        Self::scan_iceberg_files(scan, args)
+    }
+
+    /// Create a LazyFrame from an already planned scan of an Iceberg table, e.g. one planned
+    /// with a predicate through [`IcebergTable::scan`].
+    pub fn scan_iceberg_files(scan: IcebergScan, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        if scan.files().is_empty() {
+            return Ok(DataFrame::empty_with_schema(scan.schema()).lazy());
+        }
+
+        let mut position_deletes = PlIndexMap::new();
+        let mut equality_deletes = PlIndexMap::new();
+
+        for (idx, file) in scan.files().iter().enumerate() {
+            if !file.position_deletes.is_empty() {
+                position_deletes.insert(idx, file.position_deletes.iter().cloned().collect());
+            }
+            if !file.equality_deletes.is_empty() {
+                equality_deletes.insert(
+                    idx,
+                    file.equality_deletes
+                        .iter()
+                        .map(|d| IcebergEqualityDeleteFile {
+                            path: d.path.clone(),
+                            equality_ids: d.equality_ids.as_slice().into(),
+                        })
+                        .collect(),
+                );
+            }
+        }
+
+        let default_values =
+            IcebergIdentityTransformedPartitionFields(scan.identity_partition_values());
+
+        let parquet_options = ParquetOptions {
+            schema: Some(scan.schema().clone()),
+            ..Default::default()
+        };
+
+        let unified_scan_args = UnifiedScanArgs {
+            schema: None,
+            cloud_options: args.cloud_options,
+            hive_options: HiveOptions::new_disabled(),
+            rechunk: args.rechunk,
+            cache: args.cache,
+            glob: false,
+            projection: None,
+            column_mapping: Some(ColumnMapping::Iceberg(scan.iceberg_schema().clone())),
+            default_values: (!default_values.is_empty())
+                .then(|| DefaultFieldValues::Iceberg(Arc::new(default_values))),
+            row_index: None,
+            pre_slice: None,
+            cast_columns_policy: CastColumnsPolicy {
+                integer_upcast: true,
+                float_upcast: true,
+                float_downcast: true,
+                datetime_nanoseconds_downcast: true,
+                datetime_microseconds_downcast: false,
+                datetime_convert_timezone: true,
+                missing_struct_fields: MissingColumnsPolicy::Insert,
+                extra_struct_fields: ExtraColumnsPolicy::Ignore,
+            },
+            missing_columns_policy: MissingColumnsPolicy::Insert,
+            extra_columns_policy: ExtraColumnsPolicy::Ignore,
+            include_file_paths: None,
+            deletion_files: DeletionFilesList::filter_empty(Some(
+                DeletionFilesList::IcebergEqualityDelete {
+                    position_deletes: Arc::new(position_deletes),
+                    equality_deletes: Arc::new(equality_deletes),
+                },
+            )),
+        };
+
+        let sources = ScanSources::Paths(scan.files().iter().map(|f| f.path.clone()).collect());
+        let lf: LazyFrame = DslBuilder::scan_parquet(sources, parquet_options, unified_scan_args)?
+            .build()
+            .into();
+
+        Ok(lf)
+    }
+
+    /// Stream the result of the query into the Iceberg table at `location`, which is created if
+    /// it does not exist yet.
+    ///
+    /// The data is cast to the table schema and written as Parquet files under
+    /// `{location}/data`, which are committed as a single new snapshot once all of them are
+    /// written. The table metadata is tracked without a catalog, see [`IcebergCommit`].
+    pub fn sink_iceberg(mut self, location: PlPath, args: SinkArgsIceberg) -> PolarsResult<Self> {
+        let schema = self.collect_schema()?;
+        let commit = Arc::new(IcebergCommit::new(
+            location.as_ref(),
+            args.mode,
+            &schema,
+            &args.partition_by,
+            args.cloud_options.as_ref(),
+        )?);
+
+        let table_schema = commit.schema().clone();
+        let casts: PlHashMap<&str, DataType> = table_schema
+            .iter()
+            .filter(|(name, dtype)| schema.get(name) != Some(dtype))
+            .map(|(name, dtype)| (name.as_str(), dtype.clone()))
+            .collect();
+        let lf = if casts.is_empty() {
+            self
+        } else {
+            self.cast(casts, true)
+        };
+
+        let variant = if args.partition_by.is_empty() {
+            PartitionVariant::MaxSize(IdxSize::MAX)
+        } else {
+            PartitionVariant::ByKey {
+                key_exprs: args.partition_by.iter().cloned().map(col).collect(),
+                include_key: true,
+            }
+        };
+
+        let file_path_cb = {
+            let commit = commit.clone();
+            PartitionTargetCallback::Rust(SpecialEq::new(Arc::new(move |ctx| {
+                let name = commit.data_file_name(ctx.file_idx);
+                let path = match ctx.file_path.rsplit_once('/') {
+                    Some((dir, _)) => format!("{dir}/{name}"),
+                    None => name,
+                };
+                Ok(PartitionTargetCallbackResult::Str(path))
+            })))
+        };
+        let options = ParquetWriteOptions {
+            field_overwrites: commit.field_overwrites(),
+            ..args.options
+        };
+        let base_path = commit.data_location();
+        let finish_callback = SinkFinishCallback::Rust(SpecialEq::new(Arc::new(move |df| {
+            commit.commit(&WrittenFile::from_sink_df(&df)?)?;
+            Ok(())
+        })));
+
+        lf.sink_parquet_partitioned(
+            Arc::new(base_path),
+            Some(file_path_cb),
+            variant,
+            options,
+            args.cloud_options,
+            SinkOptions {
+                mkdir: true,
+                ..args.sink_options
+            },
+            None,
+            Some(finish_callback),
+        )
+    }
+}
@@ -1,11 +1,21 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "avro")]
+pub(super) mod avro;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
+#[cfg(feature = "delta")]
+pub(super) mod delta;
 pub(super) mod file_list_reader;
+#[cfg(feature = "iceberg")]
+pub(super) mod iceberg;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
 #[cfg(feature = "json")]
+pub(super) mod json;
+#[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
 
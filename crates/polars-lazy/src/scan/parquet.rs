@@ -1,7 +1,7 @@
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
-use polars_io::prelude::ParquetOptions;
+use polars_io::prelude::{ParquetDecryptionOptions, ParquetOptions};
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
@@ -24,6 +24,8 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Keys to read encrypted files with.
+    pub decryption: Option<ParquetDecryptionOptions>,
 }
 
 impl Default for ScanArgsParquet {
@@ -42,6 +44,7 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            decryption: None,
         }
     }
 }
@@ -69,6 +72,7 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            decryption: self.args.decryption,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -1,4 +1,4 @@
-use polars_io::RowIndex;
+use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 use polars_utils::plpath::PlPath;
@@ -504,6 +504,59 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn test_scan_json() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+
+    let mut files = vec![];
+    for (name, rows) in [("0.json", 0..1500i64), ("1.json", 1500..2000)] {
+        let mut df = df!(
+            "a" => rows.clone().collect::<Vec<_>>(),
+            "b" => rows.map(|i| format!("v{i}")).collect::<Vec<_>>(),
+        )?;
+        JsonWriter::new(std::fs::File::create(root.join(name))?)
+            .with_json_format(JsonFormat::Json)
+            .finish(&mut df)?;
+        files.push(df);
+    }
+    let expected = files[0].vstack(&files[1])?;
+
+    let scan = || LazyJsonReader::new(PlPath::new(root.join("*.json").to_str().unwrap()));
+
+    assert!(scan().finish()?.collect()?.equals(&expected));
+
+    let out = scan()
+        .finish()?
+        .select([col("b")])
+        .slice(1400, 200)
+        .collect()?;
+    assert_eq!(out, expected.select(["b"])?.slice(1400, 200));
+
+    let out = scan()
+        .with_row_index(Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }))
+        .finish()?
+        .tail(2)
+        .collect()?;
+    assert_eq!(
+        out,
+        df!(
+            "index" => [2008 as IdxSize, 2009],
+            "a" => [1998i64, 1999],
+            "b" => ["v1998", "v1999"],
+        )?
+    );
+
+    let out = scan().with_n_rows(Some(300)).finish()?.collect()?;
+    assert_eq!(out, expected.slice(0, 300));
+
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -617,6 +670,780 @@ fn test_slice_filter() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_bloom_filter() -> PolarsResult<()> {
+    use polars_io::parquet::write::{
+        ChildFieldOverwrites, ParquetBloomFilterOptions, ParquetFieldOverwrites,
+    };
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("data.parquet");
+
+    // Every row group holds 10 values of `a` and `b`.
+    let mut df = df!(
+        "a" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100i64).map(|i| format!("v{i}")).collect::<Vec<_>>(),
+    )?;
+    let overwrite = |name: &str| ParquetFieldOverwrites {
+        name: Some(name.into()),
+        children: ChildFieldOverwrites::None,
+        required: None,
+        field_id: None,
+        metadata: None,
+        bloom_filter: Some(ParquetBloomFilterOptions::default()),
+        encoding: None,
+    };
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_row_group_size(Some(10))
+        .with_field_overwrites(vec![overwrite("a"), overwrite("b")])
+        .finish(&mut df)?;
+
+    let metadata = ParquetReader::new(std::fs::File::open(&path)?)
+        .get_metadata()?
+        .clone();
+    assert_eq!(metadata.row_groups.len(), 10);
+    for rg in metadata.row_groups.iter() {
+        for column in rg.parquet_columns() {
+            assert!(column.metadata().bloom_filter_length.is_some());
+        }
+    }
+
+    let scan = || LazyFrame::scan_parquet(PlPath::new(path.to_str().unwrap()), Default::default());
+
+    let out = scan()?.filter(col("a").eq(lit(42i64))).collect()?;
+    assert_eq!(out.column("b")?.str()?.get(0), Some("v42"));
+    assert_eq!(out.height(), 1);
+
+    #[cfg(feature = "is_in")]
+    {
+        let out = scan()?
+            .filter(col("b").is_in(lit(Series::new("".into(), ["v7", "v77", "x"])), false))
+            .collect()?;
+        assert_eq!(out.column("a")?.i64()?.to_vec(), [Some(7), Some(77)]);
+    }
+
+    let out = scan()?.filter(col("b").eq(lit("x"))).collect()?;
+    assert_eq!(out.height(), 0);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_field_encodings() -> PolarsResult<()> {
+    use polars_io::parquet::write::{
+        ChildFieldOverwrites, ParquetEncoding, ParquetFieldOverwrites,
+    };
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("data.parquet");
+
+    let sorted = (0..1000i64).collect::<Vec<_>>();
+    let unsorted = (0..1000i64).map(|i| (i * 7919) % 1000).collect::<Vec<_>>();
+    let floats = (0..1000).map(|i| i as f64 / 7.0).collect::<Vec<_>>();
+    let strings = (0..1000).map(|i| format!("prefix-{i}")).collect::<Vec<_>>();
+    let mut df = df!(
+        "sorted_auto" => &sorted,
+        "sorted_delta" => &sorted,
+        "unsorted_auto" => &unsorted,
+        "unsorted_default" => &unsorted,
+        "float_auto" => &floats,
+        "float_bss" => &floats,
+        "float_default" => &floats,
+        "str_delta" => &strings,
+        "str_delta_length" => &strings,
+    )?;
+
+    let overwrite = |name: &str, encoding: ParquetEncoding| ParquetFieldOverwrites {
+        name: Some(name.into()),
+        children: ChildFieldOverwrites::None,
+        required: None,
+        field_id: None,
+        metadata: None,
+        bloom_filter: None,
+        encoding: Some(encoding),
+    };
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_field_overwrites(vec![
+            overwrite("sorted_auto", ParquetEncoding::Auto),
+            overwrite("sorted_delta", ParquetEncoding::DeltaBinaryPacked),
+            overwrite("unsorted_auto", ParquetEncoding::Auto),
+            overwrite("float_auto", ParquetEncoding::Auto),
+            overwrite("float_bss", ParquetEncoding::ByteStreamSplit),
+            overwrite("str_delta", ParquetEncoding::DeltaByteArray),
+            overwrite("str_delta_length", ParquetEncoding::DeltaLengthByteArray),
+        ])
+        .finish(&mut df)?;
+
+    let metadata = ParquetReader::new(std::fs::File::open(&path)?)
+        .get_metadata()?
+        .clone();
+    let columns = metadata.row_groups[0].parquet_columns();
+    let encodings = |i: usize| columns[i].column_encoding().clone();
+    assert_eq!(encodings(0), encodings(1));
+    assert_eq!(encodings(2), encodings(3));
+    assert_ne!(encodings(0), encodings(2));
+    assert_eq!(encodings(4), encodings(5));
+    assert_ne!(encodings(4), encodings(6));
+    assert_ne!(encodings(7), encodings(8));
+
+    let out = ParquetReader::new(std::fs::File::open(&path)?).finish()?;
+    assert!(out.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_page_index_skipping() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("data.parquet");
+
+    // A single row group with many small pages.
+    let mut df = df!(
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+        "b" => (0..10_000i64).map(|i| format!("v{i}")).collect::<Vec<_>>(),
+    )?;
+    ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_data_page_size(Some(1024))
+        .finish(&mut df)?;
+
+    let metadata = ParquetReader::new(std::fs::File::open(&path)?)
+        .get_metadata()?
+        .clone();
+    assert_eq!(metadata.row_groups.len(), 1);
+    for column in metadata.row_groups[0].parquet_columns() {
+        assert!(column.column_index_range().is_some());
+        assert!(column.offset_index_range().is_some());
+    }
+
+    let scan = || LazyFrame::scan_parquet(PlPath::new(path.to_str().unwrap()), Default::default());
+
+    let out = scan()?.filter(col("a").eq(lit(4242i64))).collect()?;
+    assert_eq!(out.column("b")?.str()?.get(0), Some("v4242"));
+    assert_eq!(out.height(), 1);
+
+    let out = scan()?
+        .with_row_index("index", Some(10))
+        .filter(
+            col("a")
+                .gt_eq(lit(9_990i64))
+                .and(col("b").neq(lit("v9995"))),
+        )
+        .collect()?;
+    assert_eq!(
+        out.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (10_000..10_010)
+            .filter(|&i| i != 10_005)
+            .collect::<Vec<IdxSize>>()
+    );
+    assert_eq!(
+        out.column("a")?
+            .i64()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (9_990..10_000).filter(|&i| i != 9_995).collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_encryption() -> PolarsResult<()> {
+    use polars_io::parquet::write::{
+        ChildFieldOverwrites, ParquetBloomFilterOptions, ParquetFieldOverwrites,
+    };
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("data.parquet");
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000i64).map(|i| format!("v{i}")).collect::<Vec<_>>(),
+    )?;
+    let keys = ParquetKeyRetriever::from_key_map(KeyMap::from_iter([
+        ("footer", [1u8; 16]),
+        ("column", [2u8; 16]),
+    ]));
+    let decryption = ParquetDecryptionOptions::new(keys.clone());
+
+    for (algorithm, plaintext_footer) in [
+        (ParquetEncryptionAlgorithm::AesGcm, false),
+        (ParquetEncryptionAlgorithm::AesGcm, true),
+        (ParquetEncryptionAlgorithm::AesGcmCtr, false),
+    ] {
+        let encryption = ParquetEncryptionOptions {
+            algorithm,
+            column_key_metadata: vec![("b".into(), b"column".to_vec())],
+            plaintext_footer,
+            ..ParquetEncryptionOptions::new(keys.clone(), b"footer".to_vec())
+        };
+        ParquetWriter::new(std::fs::File::create(&path)?)
+            .with_data_page_size(Some(1024))
+            .with_encryption(Some(encryption))
+            .finish(&mut df)?;
+
+        let out = ParquetReader::new(std::fs::File::open(&path)?)
+            .with_decryption(Some(decryption.clone()))
+            .finish()?;
+        assert!(out.equals(&df));
+
+        let args = ScanArgsParquet {
+            decryption: Some(decryption.clone()),
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_parquet(PlPath::new(path.to_str().unwrap()), args)?
+            .filter(col("a").eq(lit(42i64)))
+            .collect()?;
+        assert_eq!(out, df!("a" => [42i64], "b" => ["v42"])?);
+
+        let scan =
+            || LazyFrame::scan_parquet(PlPath::new(path.to_str().unwrap()), Default::default());
+        assert!(scan()?.select([col("b")]).collect().is_err());
+        if plaintext_footer {
+            // The column that is not encrypted can be read without the keys.
+            assert_eq!(scan()?.select([col("a")]).collect()?, df.select(["a"])?);
+        } else {
+            assert!(scan()?.select([col("a")]).collect().is_err());
+        }
+    }
+
+    // Bloom filters of encrypted columns are rejected before any data is written.
+    let bloom_filter = ParquetFieldOverwrites {
+        name: Some("b".into()),
+        children: ChildFieldOverwrites::None,
+        required: None,
+        field_id: None,
+        metadata: None,
+        bloom_filter: Some(ParquetBloomFilterOptions::default()),
+        encoding: None,
+    };
+    let encryption = ParquetEncryptionOptions {
+        column_key_metadata: vec![("b".into(), b"column".to_vec())],
+        ..ParquetEncryptionOptions::new(keys, b"footer".to_vec())
+    };
+    let result = ParquetWriter::new(std::fs::File::create(&path)?)
+        .with_field_overwrites(vec![bloom_filter])
+        .with_encryption(Some(encryption))
+        .finish(&mut df);
+    assert!(result.is_err());
+    assert_eq!(std::fs::metadata(&path)?.len(), 0);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "avro", feature = "new_streaming"))]
+fn test_scan_avro() -> PolarsResult<()> {
+    use polars_io::SerWriter;
+    use polars_io::avro::AvroWriter;
+
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+
+    // Every chunk is written as a separate block.
+    let chunk = |i: i64| {
+        df!(
+            "a" => (i * 250..(i + 1) * 250).collect::<Vec<_>>(),
+            "b" => (i * 250..(i + 1) * 250).map(|i| format!("v{i}")).collect::<Vec<_>>(),
+        )
+    };
+    let mut files = vec![];
+    for (name, chunks) in [("0.avro", 0..4), ("1.avro", 4..8)] {
+        let mut df = chunk(chunks.start)?;
+        for i in chunks.skip(1) {
+            df.vstack_mut(&chunk(i)?)?;
+        }
+        assert_eq!(df.first_col_n_chunks(), 4);
+        AvroWriter::new(std::fs::File::create(root.join(name))?).finish(&mut df)?;
+        files.push(df);
+    }
+    let expected = files[0].vstack(&files[1])?;
+
+    let scan = |args: ScanArgsAvro| {
+        LazyFrame::scan_avro(PlPath::new(root.join("*.avro").to_str().unwrap()), args)
+    };
+
+    assert!(scan(Default::default())?.collect()?.equals(&expected));
+
+    let out = scan(Default::default())?
+        .select([col("b")])
+        .slice(900, 200)
+        .collect()?;
+    assert_eq!(out, expected.select(["b"])?.slice(900, 200));
+
+    let args = ScanArgsAvro {
+        row_index: Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let out = scan(args)?.tail(3).collect()?;
+    assert_eq!(
+        out,
+        df!(
+            "index" => [2007 as IdxSize, 2008, 2009],
+            "a" => [1997i64, 1998, 1999],
+            "b" => ["v1997", "v1998", "v1999"],
+        )?
+    );
+
+    let out = scan(ScanArgsAvro {
+        n_rows: Some(300),
+        ..Default::default()
+    })?
+    .collect()?;
+    assert_eq!(out, expected.slice(0, 300));
+
+    let out = scan(Default::default())?.select([len()]).collect()?;
+    assert_eq!(out, df!("len" => [2000 as IdxSize])?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "avro", feature = "new_streaming"))]
+fn test_sink_avro() -> PolarsResult<()> {
+    use polars_io::avro::{AvroCodec, AvroFileMetadata};
+
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    let path = |name: &str| PlPath::new(root.join(name).to_str().unwrap());
+
+    let df = df!(
+        "a" => (0..2000i64).collect::<Vec<_>>(),
+        "b" => (0..2000i64).map(|i| (i % 7 != 0).then(|| format!("v{i}"))).collect::<Vec<_>>(),
+    )?;
+
+    for compression in [
+        None,
+        Some(AvroCodec::Deflate),
+        Some(AvroCodec::Snappy),
+        Some(AvroCodec::Zstd),
+    ] {
+        let options = AvroWriterOptions {
+            compression,
+            block_size: 300,
+            ..Default::default()
+        };
+
+        df.clone()
+            .lazy()
+            .sink_avro(
+                SinkTarget::Path(path("out.avro")),
+                options.clone(),
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+
+        let metadata = AvroFileMetadata::read(&std::fs::read(root.join("out.avro"))?)?;
+        assert_eq!(metadata.compression, compression);
+        assert_eq!(
+            metadata
+                .blocks
+                .iter()
+                .map(|block| block.num_rows)
+                .collect::<Vec<_>>(),
+            [300, 300, 300, 300, 300, 300, 200]
+        );
+        let out = LazyFrame::scan_avro(path("out.avro"), Default::default())?.collect()?;
+        assert!(out.equals_missing(&df));
+
+        df.clone()
+            .lazy()
+            .sink_avro_partitioned(
+                Arc::new(path("parts")),
+                None,
+                PartitionVariant::MaxSize(700),
+                options,
+                None,
+                SinkOptions {
+                    mkdir: true,
+                    ..Default::default()
+                },
+                None,
+                None,
+            )?
+            .collect()?;
+
+        assert_eq!(std::fs::read_dir(root.join("parts"))?.count(), 3);
+        let out = LazyFrame::scan_avro(path("parts/*.avro"), Default::default())?.collect()?;
+        assert!(out.equals_missing(&df));
+    }
+
+    // Every file gets a random sync marker, unless one is given.
+    let write = |sync_marker| -> PolarsResult<Vec<u8>> {
+        let options = AvroWriterOptions {
+            sync_marker,
+            ..Default::default()
+        };
+        df.clone()
+            .lazy()
+            .sink_avro(
+                SinkTarget::Path(path("marker.avro")),
+                options,
+                None,
+                Default::default(),
+            )?
+            .collect()?;
+        Ok(std::fs::read(root.join("marker.avro"))?)
+    };
+    assert_ne!(write(None)?, write(None)?);
+    assert_eq!(write(Some([7; 16]))?, write(Some([7; 16]))?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "delta")]
+fn test_scan_delta() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("_delta_log"))?;
+
+    // The data files store `a` as Int32, the table schema declares it as long.
+    for (p, a) in [("x", [1i32, 2]), ("y", [3, 4])] {
+        std::fs::create_dir_all(root.join(format!("p={p}")))?;
+        let f = std::fs::File::create(root.join(format!("p={p}/0.parquet")))?;
+        ParquetWriter::new(f).finish(&mut df!("a" => a)?)?;
+    }
+
+    let schema_string = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}},{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}"#;
+    let add = |p: &str| {
+        format!(
+            r#"{{"add":{{"path":"p={p}/0.parquet","partitionValues":{{"p":"{p}"}},"size":1,"modificationTime":0,"dataChange":true}}}}"#
+        )
+    };
+    let commits = [
+        [
+            r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#.to_string(),
+            format!(
+                r#"{{"metaData":{{"id":"1","format":{{"provider":"parquet","options":{{}}}},"schemaString":"{schema_string}","partitionColumns":["p"],"configuration":{{}}}}}}"#
+            ),
+            add("x"),
+            add("y"),
+        ]
+        .join("\n"),
+        r#"{"remove":{"path":"p=x/0.parquet","deletionTimestamp":0,"dataChange":true}}"#
+            .to_string(),
+    ];
+    for (version, commit) in commits.iter().enumerate() {
+        std::fs::write(root.join(format!("_delta_log/{version:020}.json")), commit)?;
+    }
+
+    let table_root = PlPath::Local(root.into());
+
+    let out = LazyFrame::scan_delta(table_root.clone(), Default::default())?.collect()?;
+    assert_eq!(out, df!("p" => ["y", "y"], "a" => [3i64, 4])?);
+
+    let args = ScanArgsDelta {
+        version: DeltaVersion::Version(0),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_delta(table_root, args)?
+        .filter(col("p").eq(lit("x")))
+        .select([col("a")])
+        .collect()?;
+    assert_eq!(out, df!("a" => [1i64, 2])?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "delta")]
+fn test_scan_delta_column_mapping() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("_delta_log"))?;
+
+    // With column mapping the data files are at random prefixes and store the physical column
+    // names, the partition values are only in the log.
+    let files = [
+        ("Ab/part-0.parquet", r#"{"col-d":"2024-01-31"}"#, [1i64, 2]),
+        ("Cd/part-1.parquet", r#"{"col-d":null}"#, [3, 4]),
+        ("Ef/part-2.parquet", r#"{"col-d":"2024-02-01"}"#, [5, 6]),
+    ];
+    for (path, _, a) in &files {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        ParquetWriter::new(std::fs::File::create(path)?).finish(&mut df!("col-a" => a)?)?;
+    }
+
+    let schema_string = r#"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{\"delta.columnMapping.id\":1,\"delta.columnMapping.physicalName\":\"col-a\"}},{\"name\":\"d\",\"type\":\"date\",\"nullable\":true,\"metadata\":{\"delta.columnMapping.id\":2,\"delta.columnMapping.physicalName\":\"col-d\"}}]}"#;
+    let mut commit = vec![
+        r#"{"protocol":{"minReaderVersion":2,"minWriterVersion":5}}"#.to_string(),
+        format!(
+            r#"{{"metaData":{{"id":"1","format":{{"provider":"parquet","options":{{}}}},"schemaString":"{schema_string}","partitionColumns":["d"],"configuration":{{"delta.columnMapping.mode":"name","delta.columnMapping.maxColumnId":"2"}}}}}}"#
+        ),
+    ];
+    for (path, partition_values, _) in &files {
+        commit.push(format!(
+            r#"{{"add":{{"path":"{path}","partitionValues":{partition_values},"size":1,"modificationTime":0,"dataChange":true}}}}"#
+        ));
+    }
+    std::fs::write(
+        root.join("_delta_log/00000000000000000000.json"),
+        commit.join("\n"),
+    )?;
+
+    let table_root = PlPath::Local(root.into());
+    let out = LazyFrame::scan_delta(table_root.clone(), Default::default())?
+        .sort(["a"], Default::default())
+        .collect()?;
+    let expected = df!(
+        "a" => [1i64, 2, 3, 4, 5, 6],
+        "d" => [Some(19753i32), Some(19753), None, None, Some(19754), Some(19754)],
+    )?
+    .lazy()
+    .with_column(col("d").cast(DataType::Date))
+    .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    let out = LazyFrame::scan_delta(table_root, Default::default())?
+        .filter(col("d").is_null())
+        .select([col("a")])
+        .collect()?;
+    assert_eq!(out, df!("a" => [3i64, 4])?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "iceberg")]
+fn test_scan_iceberg() -> PolarsResult<()> {
+    use polars_io::SerWriter;
+    use polars_io::avro::AvroWriter;
+    use polars_io::parquet::write::{
+        ChildFieldOverwrites, MetadataKeyValue, ParquetFieldOverwrites,
+    };
+
+    let dir = tempfile::tempdir()?;
+    let root = dir.path();
+    let path = |name: &str| root.join(name).to_str().unwrap().to_string();
+
+    // `x.parquet` does not store the partition column `p`, `y.parquet` stores `a` under the name
+    // it had when the file was written.
+    // The field IDs are also stored in the Arrow schema, as that is what the reader uses.
+    for (name, mut df, field_ids) in [
+        ("x.parquet", df!("a" => [1i64, 2])?, vec![("a", 1)]),
+        (
+            "y.parquet",
+            df!("a_old" => [3i32, 4], "p" => ["y", "y"])?,
+            vec![("a_old", 1), ("p", 2)],
+        ),
+    ] {
+        let field_overwrites = field_ids
+            .into_iter()
+            .map(|(name, field_id)| ParquetFieldOverwrites {
+                name: Some(name.into()),
+                children: ChildFieldOverwrites::None,
+                required: None,
+                field_id: Some(field_id),
+                metadata: Some(vec![MetadataKeyValue {
+                    key: "PARQUET:field_id".into(),
+                    value: Some(field_id.to_string().into()),
+                }]),
+                bloom_filter: None,
+                encoding: None,
+            })
+            .collect();
+        ParquetWriter::new(std::fs::File::create(root.join(name))?)
+            .with_field_overwrites(field_overwrites)
+            .finish(&mut df)?;
+    }
+    // Deletes the first row of `y.parquet`.
+    ParquetWriter::new(std::fs::File::create(root.join("y-deletes.parquet"))?)
+        .finish(&mut df!("file_path" => [path("y.parquet")], "pos" => [0i64])?)?;
+
+    let write_avro = |name: &str, mut df: DataFrame| -> PolarsResult<()> {
+        AvroWriter::new(std::fs::File::create(root.join(name))?).finish(&mut df)
+    };
+    let manifest = |content: i32, file: &str, p: &str| -> PolarsResult<DataFrame> {
+        let data_file = StructChunked::from_series(
+            "data_file".into(),
+            1,
+            [
+                Series::new("content".into(), [content]),
+                Series::new("file_path".into(), [path(file)]),
+                Series::new("file_format".into(), ["PARQUET"]),
+                StructChunked::from_series(
+                    "partition".into(),
+                    1,
+                    [Series::new("p".into(), [p])].iter(),
+                )?
+                .into_series(),
+                Series::new("record_count".into(), [2i64]),
+            ]
+            .iter(),
+        )?;
+        DataFrame::new(vec![
+            Column::new("status".into(), [1i32]),
+            data_file.into_series().into_column(),
+        ])
+    };
+    write_avro("m-x.avro", manifest(0, "x.parquet", "x")?)?;
+    write_avro("m-y.avro", manifest(0, "y.parquet", "y")?)?;
+    write_avro("m-deletes.avro", manifest(1, "y-deletes.parquet", "y")?)?;
+
+    let manifest_list = |manifests: &[(&str, i32, i64)]| {
+        df!(
+            "manifest_path" => manifests.iter().map(|m| path(m.0)).collect::<Vec<_>>(),
+            "partition_spec_id" => vec![0i32; manifests.len()],
+            "content" => manifests.iter().map(|m| m.1).collect::<Vec<_>>(),
+            "sequence_number" => manifests.iter().map(|m| m.2).collect::<Vec<_>>(),
+        )
+    };
+    write_avro("snap-1.avro", manifest_list(&[("m-x.avro", 0, 1)])?)?;
+    write_avro(
+        "snap-2.avro",
+        manifest_list(&[
+            ("m-x.avro", 0, 1),
+            ("m-y.avro", 0, 2),
+            ("m-deletes.avro", 1, 2),
+        ])?,
+    )?;
+
+    let metadata = format!(
+        r#"{{
+            "format-version": 2,
+            "location": "{root}",
+            "current-schema-id": 0,
+            "schemas": [{{"type": "struct", "schema-id": 0, "fields": [
+                {{"id": 1, "name": "a", "required": false, "type": "long"}},
+                {{"id": 2, "name": "p", "required": false, "type": "string"}}
+            ]}}],
+            "partition-specs": [{{"spec-id": 0, "fields": [
+                {{"source-id": 2, "field-id": 1000, "name": "p", "transform": "identity"}}
+            ]}}],
+            "current-snapshot-id": 2,
+            "snapshots": [
+                {{"snapshot-id": 1, "timestamp-ms": 1, "sequence-number": 1,
+                    "manifest-list": "{snap_1}", "schema-id": 0}},
+                {{"snapshot-id": 2, "timestamp-ms": 2, "sequence-number": 2,
+                    "manifest-list": "{snap_2}", "schema-id": 0}}
+            ]
+        }}"#,
+        root = root.display(),
+        snap_1 = path("snap-1.avro"),
+        snap_2 = path("snap-2.avro"),
+    );
+    std::fs::write(root.join("v2.metadata.json"), metadata)?;
+    let metadata_location = PlPath::new(&path("v2.metadata.json"));
+
+    let out = LazyFrame::scan_iceberg(metadata_location.clone(), Default::default())?.collect()?;
+    assert_eq!(out, df!("a" => [1i64, 2, 4], "p" => ["x", "x", "y"])?);
+
+    let args = ScanArgsIceberg {
+        snapshot_id: Some(1),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_iceberg(metadata_location, args)?.collect()?;
+    assert_eq!(out, df!("a" => [1i64, 2], "p" => ["x", "x"])?);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "delta", feature = "new_streaming"))]
+fn test_sink_delta() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path().join("table");
+    let table_root = PlPath::Local(root.as_path().into());
+
+    let sink = |df: DataFrame, mode: DeltaWriteMode| -> PolarsResult<()> {
+        let args = SinkArgsDelta {
+            mode,
+            partition_by: vec!["p".into()],
+            ..Default::default()
+        };
+        df.lazy()
+            .sink_delta(table_root.clone(), args)?
+            .collect_with_engine(Engine::Streaming)?;
+        Ok(())
+    };
+    let scan = || -> PolarsResult<DataFrame> {
+        LazyFrame::scan_delta(table_root.clone(), Default::default())?
+            .sort(["a"], Default::default())
+            .collect()
+    };
+
+    sink(
+        df!("p" => ["x", "y"], "a" => [1i64, 2])?,
+        DeltaWriteMode::Append,
+    )?;
+    // `a` is cast to the table schema.
+    sink(df!("p" => ["x"], "a" => [3i32])?, DeltaWriteMode::Append)?;
+    assert_eq!(scan()?, df!("p" => ["x", "y", "x"], "a" => [1i64, 2, 3])?);
+
+    sink(df!("p" => ["z"], "a" => [4i64])?, DeltaWriteMode::Overwrite)?;
+    assert_eq!(scan()?, df!("p" => ["z"], "a" => [4i64])?);
+    assert!(root.join("_delta_log/00000000000000000002.json").exists());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "iceberg", feature = "new_streaming"))]
+fn test_sink_iceberg() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let root = dir.path().join("table");
+    let location = PlPath::Local(root.as_path().into());
+
+    let sink = |df: DataFrame, mode: IcebergWriteMode| -> PolarsResult<()> {
+        let args = SinkArgsIceberg {
+            mode,
+            partition_by: vec!["p".into()],
+            ..Default::default()
+        };
+        df.lazy()
+            .sink_iceberg(location.clone(), args)?
+            .collect_with_engine(Engine::Streaming)?;
+        Ok(())
+    };
+    let scan = |version: usize| -> PolarsResult<DataFrame> {
+        let metadata_location = root.join(format!("metadata/v{version}.metadata.json"));
+        LazyFrame::scan_iceberg(
+            PlPath::Local(metadata_location.as_path().into()),
+            Default::default(),
+        )?
+        .sort(["a"], Default::default())
+        .collect()
+    };
+
+    sink(
+        df!("a" => [1i64, 2], "p" => ["x", "y"])?,
+        IcebergWriteMode::Append,
+    )?;
+    // `a` is cast to the table schema.
+    sink(df!("a" => [3i32], "p" => ["x"])?, IcebergWriteMode::Append)?;
+    assert_eq!(scan(2)?, df!("a" => [1i64, 2, 3], "p" => ["x", "y", "x"])?);
+    // Types that don't upcast to the table schema are not coerced.
+    let out = sink(
+        df!("a" => [1.5f64], "p" => ["x"])?,
+        IcebergWriteMode::Append,
+    );
+    assert!(
+        matches!(out, Err(PolarsError::SchemaMismatch(_))),
+        "{out:?}"
+    );
+
+    sink(
+        df!("a" => [4i64], "p" => ["z"])?,
+        IcebergWriteMode::Overwrite,
+    )?;
+    assert_eq!(scan(3)?, df!("a" => [4i64], "p" => ["z"])?);
+    // Earlier snapshots stay readable from their own metadata.
+    assert_eq!(scan(1)?, df!("a" => [1i64, 2], "p" => ["x", "y"])?);
+
+    Ok(())
+}
+
 #[test]
 fn skip_rows_and_slice() -> PolarsResult<()> {
     let out = LazyCsvReader::new(PlPath::new(FOODS_CSV))
@@ -629,6 +1456,41 @@ fn skip_rows_and_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "new_streaming")]
+fn scan_csv_dialect() -> PolarsResult<()> {
+    use polars_plan::dsl::ScanSources;
+    use polars_utils::mmap::MemSlice;
+
+    let csv = b"a||b\r\n1||x\\|\\|y\r\n# comment\r\n2||\"say \\\"hi\\\"\r\n\"\r\n3||z";
+    let lf = LazyCsvReader::new_with_sources(ScanSources::Buffers(
+        [MemSlice::from_vec(csv.to_vec())].into(),
+    ))
+    .with_multi_char_separator(Some("||".into()))
+    .with_escape_char(Some(b'\\'))
+    .with_line_terminator(Some("\r\n".into()))
+    .with_comment_prefix(Some("#".into()))
+    .finish()?;
+
+    let df = lf.clone().collect_with_engine(Engine::Streaming)?;
+    let expected = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x||y", "say \"hi\"\r\n", "z"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    let df = lf
+        .clone()
+        .slice(1, 1)
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(df.equals(&expected.slice(1, 1)));
+
+    let df = lf.select([len()]).collect()?;
+    assert_eq!(df, df!("len" => [3 as IdxSize])?);
+
+    Ok(())
+}
+
 #[test]
 fn test_row_index_on_files() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -781,6 +1643,140 @@ fn scan_anonymous_fn_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "new_streaming")]
+fn scan_anonymous_fn_streaming() -> PolarsResult<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct MyScan {
+        batched: bool,
+        offset: AtomicUsize,
+    }
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn allows_predicate_pushdown(&self) -> bool {
+            true
+        }
+
+        fn allows_batched_scan(&self) -> bool {
+            self.batched
+        }
+
+        fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            let mut lf = fruits_cars().lazy();
+            if let Some(predicate) = scan_opts.predicate {
+                lf = lf.filter(predicate);
+            }
+            if let Some(with_columns) = scan_opts.with_columns {
+                lf = lf.select(
+                    with_columns
+                        .iter()
+                        .map(|c| col(c.clone()))
+                        .collect::<Vec<_>>(),
+                );
+            }
+            lf.collect()
+        }
+
+        fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
+            let df = self.scan(scan_opts)?;
+            let offset = self.offset.fetch_add(2, Ordering::Relaxed);
+            Ok((offset < df.height()).then(|| df.slice(offset as i64, 2)))
+        }
+    }
+
+    for batched in [false, true] {
+        let function = Arc::new(MyScan {
+            batched,
+            offset: AtomicUsize::new(0),
+        });
+        let args = ScanArgsAnonymous {
+            schema: Some(fruits_cars().schema().clone()),
+            ..ScanArgsAnonymous::default()
+        };
+
+        let df = LazyFrame::anonymous_scan(function, args)?
+            .filter(col("B").gt(lit(1)))
+            .select([col("A"), col("fruits")])
+            .collect_with_engine(Engine::Streaming)?;
+
+        let expected = df![
+            "A" => [1, 2, 3, 4],
+            "fruits" => ["banana", "banana", "apple", "apple"],
+        ]?;
+        assert!(df.equals(&expected));
+    }
+
+    let function = Arc::new(MyScan {
+        batched: true,
+        offset: AtomicUsize::new(0),
+    });
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+    let df = LazyFrame::anonymous_scan(function, args)?
+        .limit(3)
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(df.equals(&fruits_cars().head(Some(3))));
+
+    Ok(())
+}
+
+#[test]
+fn scan_anonymous_fn_rejects_file_options() {
+    struct MyScan {}
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            Ok(fruits_cars())
+        }
+    }
+
+    let scan = |unified_scan_args| {
+        DslBuilder::anonymous_scan(
+            Arc::new(MyScan {}),
+            AnonymousScanOptions {
+                skip_rows: None,
+                fmt_str: "",
+            },
+            UnifiedScanArgs {
+                schema: Some(fruits_cars().schema().clone()),
+                ..unified_scan_args
+            },
+        )
+    };
+
+    assert!(
+        scan(UnifiedScanArgs {
+            hive_options: HiveOptions::new_disabled(),
+            include_file_paths: Some("path".into()),
+            ..Default::default()
+        })
+        .is_err()
+    );
+    assert!(scan(UnifiedScanArgs::default()).is_err());
+    assert!(
+        scan(UnifiedScanArgs {
+            hive_options: HiveOptions::new_disabled(),
+            ..Default::default()
+        })
+        .is_ok()
+    );
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
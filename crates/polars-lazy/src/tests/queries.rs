@@ -786,6 +786,92 @@ fn test_lazy_group_by() {
     assert_eq!(out.column("a").unwrap().f64().unwrap().get(0), Some(1.0));
 }
 
+#[test]
+fn test_lazy_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df! {
+        "region" => ["a", "a", "b"],
+        "city" => ["x", "y", "x"],
+        "sales" => [1, 2, 3]
+    }?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_grouping_sets(
+            [col("region"), col("city")],
+            GroupingSetsOptions::rollup(2).with_grouping_id(Some("gid".into())),
+        )
+        .agg([col("sales").sum()])
+        .sort(["gid", "region", "city"], Default::default())
+        .collect()?;
+
+    let expected = df! {
+        "region" => [Some("a"), Some("a"), Some("b"), Some("a"), Some("b"), None],
+        "city" => [Some("x"), Some("y"), Some("x"), None, None, None],
+        "gid" => [0u32, 0, 0, 1, 1, 3],
+        "sales" => [1, 2, 3, 3, 3, 6]
+    }?;
+    assert!(out.equals_missing(&expected), "{out:?}");
+
+    // The output of an applied function has no place for the grouping set keys.
+    let out = df
+        .lazy()
+        .group_by_grouping_sets([col("region")], GroupingSetsOptions::cube(1))
+        .apply(PlanCallback::new(Ok), df.schema().clone())
+        .collect();
+    assert!(
+        matches!(out, Err(PolarsError::InvalidOperation(_))),
+        "{out:?}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_recursive_union() -> PolarsResult<()> {
+    let out = df!("n" => [1])?
+        .lazy()
+        .recursive_union(RecursiveUnionOptions::new("counter".into()), |counter| {
+            Ok(counter
+                .select([col("n") + lit(1)])
+                .filter(col("n").lt_eq(lit(5))))
+        })?
+        .collect()?;
+    assert!(out.equals(&df!("n" => [1, 2, 3, 4, 5])?), "{out:?}");
+
+    // A cycle only reaches a fixed point if duplicate rows are removed.
+    let edges = df! {
+        "src" => ["a", "b", "b"],
+        "dst" => ["b", "a", "c"]
+    }?
+    .lazy();
+    let reachable = |distinct: bool| {
+        df!("node" => ["a"])?.lazy().recursive_union(
+            RecursiveUnionOptions::new("reachable".into())
+                .with_distinct(distinct)
+                .with_max_iterations(10),
+            |reachable| {
+                Ok(reachable
+                    .join(
+                        edges.clone(),
+                        [col("node")],
+                        [col("src")],
+                        JoinArgs::new(JoinType::Inner),
+                    )
+                    .select([col("dst")]))
+            },
+        )
+    };
+
+    let out = reachable(true)?
+        .sort(["node"], Default::default())
+        .collect()?;
+    assert!(out.equals(&df!("node" => ["a", "b", "c"])?), "{out:?}");
+    assert!(reachable(false)?.collect().is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_lazy_tail() {
     let df = df! {
@@ -0,0 +1,141 @@
+use super::*;
+
+/// Group-by over multiple grouping sets of the keys.
+///
+/// The groups of all grouping sets are collected into a single set of groups, so that the
+/// aggregations are evaluated in one pass over the input.
+pub(crate) struct GroupByGroupingSetsExec {
+    pub(crate) input: Box<dyn Executor>,
+    pub(crate) keys: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) aggs: Vec<Arc<dyn PhysicalExpr>>,
+    pub(crate) options: GroupingSetsOptions,
+    pub(crate) maintain_order: bool,
+    pub(crate) input_schema: SchemaRef,
+    pub(crate) slice: Option<(i64, usize)>,
+}
+
+impl GroupByGroupingSetsExec {
+    fn execute_impl(
+        &mut self,
+        state: &ExecutionState,
+        mut df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        df.as_single_chunk_par();
+
+        let keys = self
+            .keys
+            .iter()
+            .map(|e| e.evaluate(&df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut first = vec![];
+        let mut all = vec![];
+        let mut key_columns = keys
+            .iter()
+            .map(|k| Column::new_empty(k.name().clone(), k.dtype()))
+            .collect::<Vec<_>>();
+        let mut grouping_ids = vec![];
+
+        for set in &self.options.sets {
+            let (set_keys, set_groups) = if set.is_empty() {
+                // The empty grouping set has a single group with all rows.
+                let groups = GroupsType::Slice {
+                    groups: vec![[0, df.height() as IdxSize]],
+                    rolling: false,
+                };
+                (vec![], groups.into_sliceable())
+            } else {
+                let by = set.iter().map(|&i| keys[i].clone()).collect();
+                let gb = df.group_by_with_series(by, true, self.maintain_order)?;
+                (gb.keys(), gb.take_groups())
+            };
+
+            for group in set_groups.iter() {
+                match group {
+                    GroupsIndicator::Idx((first_idx, idx)) => {
+                        first.push(first_idx);
+                        all.push(idx.clone());
+                    },
+                    GroupsIndicator::Slice([offset, len]) => {
+                        first.push(offset);
+                        all.push((offset..offset + len).collect());
+                    },
+                }
+            }
+
+            // Keys that are not in the grouping set are null in its groups.
+            let n_groups = set_groups.len();
+            for (i, key_column) in key_columns.iter_mut().enumerate() {
+                let column = match set.iter().position(|&j| j == i) {
+                    Some(pos) => set_keys[pos].clone(),
+                    None => {
+                        Column::full_null(key_column.name().clone(), n_groups, key_column.dtype())
+                    },
+                };
+                key_column.append_owned(column)?;
+            }
+
+            if self.options.grouping_id.is_some() {
+                let id = GroupingSetsOptions::grouping_id_of(set, keys.len());
+                grouping_ids.extend(std::iter::repeat_n(id, n_groups));
+            }
+        }
+
+        let groups = GroupsType::Idx(GroupsIdx::new(first, all, false)).into_sliceable();
+
+        if let Some(name) = &self.options.grouping_id {
+            key_columns.push(UInt32Chunked::from_vec(name.clone(), grouping_ids).into_column());
+        }
+
+        let mut groups = &groups;
+        #[allow(unused_assignments)]
+        // it is unused because we only use it to keep the lifetime of sliced_group valid
+        let mut sliced_groups = None;
+
+        if let Some((offset, len)) = self.slice {
+            sliced_groups = Some(groups.slice(offset, len));
+            groups = sliced_groups.as_ref().unwrap();
+
+            for k in &mut key_columns {
+                *k = k.slice(offset, len);
+            }
+        }
+
+        let agg_columns = evaluate_aggs(&df, &self.aggs, groups, state)?;
+
+        let mut columns = key_columns;
+        columns.extend(agg_columns);
+        DataFrame::new(columns)
+    }
+}
+
+impl Executor for GroupByGroupingSetsExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run GroupByGroupingSetsExec")
+            }
+        }
+        let df = self.input.execute(state)?;
+        let profile_name = if state.has_node_timer() {
+            let by = self
+                .keys
+                .iter()
+                .map(|s| Ok(s.to_field(&self.input_schema)?.name))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let name = comma_delimited("group_by_grouping_sets".to_string(), &by);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        if state.has_node_timer() {
+            let new_state = state.clone();
+            new_state.record(|| self.execute_impl(state, df), profile_name)
+        } else {
+            self.execute_impl(state, df)
+        }
+    }
+}
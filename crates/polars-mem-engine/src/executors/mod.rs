@@ -4,6 +4,7 @@ mod ext_context;
 mod filter;
 mod group_by;
 mod group_by_dynamic;
+mod group_by_grouping_sets;
 mod group_by_partitioned;
 pub(super) mod group_by_rolling;
 mod hconcat;
@@ -13,6 +14,7 @@ mod merge_sorted;
 mod projection;
 mod projection_simple;
 mod projection_utils;
+mod recursive_union;
 mod scan;
 mod sink;
 mod slice;
@@ -36,6 +38,7 @@ pub(super) use self::filter::*;
 pub(super) use self::group_by::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_dynamic::*;
+pub(super) use self::group_by_grouping_sets::*;
 pub(super) use self::group_by_partitioned::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(super) use self::group_by_rolling::GroupByRollingExec;
@@ -45,6 +48,7 @@ pub(super) use self::join::*;
 pub(super) use self::merge_sorted::*;
 pub(super) use self::projection::*;
 pub(super) use self::projection_simple::*;
+pub(super) use self::recursive_union::*;
 pub(super) use self::scan::*;
 pub(super) use self::sink::*;
 pub(super) use self::slice::*;
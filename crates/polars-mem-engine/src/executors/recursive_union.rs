@@ -0,0 +1,164 @@
+use polars_core::utils::concat_df;
+use polars_plan::plans::prune::prune_plan;
+use polars_utils::unique_id::UniqueId;
+
+use super::*;
+use crate::{StreamingExecutorBuilder, create_physical_plan};
+
+/// Evaluates the recursive input of a recursive union until it adds no new rows.
+///
+/// The recursive input is planned again for every iteration, with its work table scans replaced
+/// by the rows the previous iteration added.
+pub(crate) struct RecursiveUnionExec {
+    anchor: Box<dyn Executor>,
+    recursive: IRPlan,
+    work_table_scans: Vec<Node>,
+    schema: SchemaRef,
+    options: RecursiveUnionOptions,
+    builder: Option<StreamingExecutorBuilder>,
+}
+
+impl RecursiveUnionExec {
+    pub(crate) fn new(
+        anchor: Box<dyn Executor>,
+        recursive: Node,
+        id: UniqueId,
+        options: RecursiveUnionOptions,
+        lp_arena: &Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+        builder: Option<StreamingExecutorBuilder>,
+    ) -> Self {
+        let schema = lp_arena.get(recursive).schema(lp_arena).into_owned();
+
+        // Prune the recursive input into separate arenas
+        let mut recursive = prune_plan(IRPlanRef {
+            lp_top: recursive,
+            lp_arena,
+            expr_arena,
+        });
+
+        // Every iteration fills its own caches, so they must not collide with the caches of the
+        // rest of the plan.
+        let mut cache_ids = PlHashMap::new();
+        let mut work_table_scans = vec![];
+        let nodes = recursive
+            .lp_arena
+            .iter(recursive.lp_top)
+            .map(|(node, _)| node)
+            .collect::<Vec<_>>();
+        for node in nodes {
+            match recursive.lp_arena.get_mut(node) {
+                IR::Cache { id, .. } => *id = *cache_ids.entry(*id).or_insert_with(UniqueId::new),
+                IR::WorkTableScan { id: scan_id, .. } if *scan_id == id => {
+                    work_table_scans.push(node)
+                },
+                _ => {},
+            }
+        }
+
+        Self {
+            anchor,
+            recursive,
+            work_table_scans,
+            schema,
+            options,
+            builder,
+        }
+    }
+
+    fn execute_iteration(
+        &self,
+        state: &mut ExecutionState,
+        work_table: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        let mut lp_arena = self.recursive.lp_arena.clone();
+        let mut expr_arena = self.recursive.expr_arena.clone();
+
+        let df = Arc::new(work_table);
+        for &node in &self.work_table_scans {
+            lp_arena.replace(
+                node,
+                IR::DataFrameScan {
+                    df: df.clone(),
+                    schema: self.schema.clone(),
+                    output_schema: None,
+                },
+            );
+        }
+
+        let mut exec = create_physical_plan(
+            self.recursive.lp_top,
+            &mut lp_arena,
+            &mut expr_arena,
+            self.builder,
+        )?;
+        exec.execute(state)
+    }
+
+    fn execute_impl(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let distinct = self.options.distinct;
+        let mut seen = PlHashSet::new();
+        let mut new_rows = |df: DataFrame| {
+            if distinct {
+                remove_seen_rows(df, &mut seen)
+            } else {
+                Ok(df)
+            }
+        };
+
+        let mut work_table = new_rows(self.anchor.execute(state)?)?;
+        let mut dfs = vec![work_table.clone()];
+        let mut iterations = 0;
+
+        while work_table.height() > 0 {
+            polars_ensure!(
+                iterations < self.options.max_iterations,
+                ComputeError: "recursive union '{}' did not finish within {} iterations",
+                self.options.name, self.options.max_iterations
+            );
+            iterations += 1;
+
+            work_table = new_rows(self.execute_iteration(state, work_table)?)?;
+            dfs.push(work_table.clone());
+        }
+
+        if state.verbose() {
+            eprintln!(
+                "RECURSIVE UNION '{}': finished after {iterations} iterations",
+                self.options.name
+            )
+        }
+        concat_df(&dfs)
+    }
+}
+
+/// Removes the rows of `df` that were seen before, and marks the remaining rows as seen.
+fn remove_seen_rows(df: DataFrame, seen: &mut PlHashSet<Vec<u8>>) -> PolarsResult<DataFrame> {
+    let rows = row_encode::encode_rows_unordered(df.get_columns())?;
+    let mask: BooleanChunked = rows
+        .into_iter()
+        .map(|row| seen.insert(row.unwrap().to_vec()))
+        .collect_ca(PlSmallStr::EMPTY);
+    df.filter(&mask)
+}
+
+impl Executor for RecursiveUnionExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        state.should_stop()?;
+        #[cfg(debug_assertions)]
+        {
+            if state.verbose() {
+                eprintln!("run RecursiveUnionExec")
+            }
+        }
+        let profile_name = if state.has_node_timer() {
+            Cow::Owned(format!("recursive_union({})", self.options.name))
+        } else {
+            Cow::Borrowed("")
+        };
+
+        state
+            .clone()
+            .record(|| self.execute_impl(state), profile_name)
+    }
+}
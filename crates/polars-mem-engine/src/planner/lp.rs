@@ -287,6 +287,7 @@ fn create_physical_plan_impl(
                                         .with_statistics(options.statistics)
                                         .with_row_group_size(options.row_group_size)
                                         .with_data_page_size(options.data_page_size)
+                                        .with_field_overwrites(options.field_overwrites.clone())
                                         .with_key_value_metadata(options.key_value_metadata.clone())
                                         .finish(&mut df)?;
                                 },
@@ -306,6 +307,7 @@ fn create_physical_plan_impl(
                                     CsvWriter::new(BufWriter::new(writer))
                                         .include_bom(options.include_bom)
                                         .include_header(options.include_header)
+                                        .with_encoding(options.encoding)
                                         .with_separator(options.serialize_options.separator)
                                         .with_line_terminator(
                                             options.serialize_options.line_terminator.clone(),
@@ -341,6 +343,11 @@ fn create_physical_plan_impl(
                                         .with_json_format(JsonFormat::JsonLines)
                                         .finish(&mut df)?;
                                 },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
+                                },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
                             }
@@ -646,6 +653,23 @@ fn create_physical_plan_impl(
                 }));
             }
 
+            if let Some(options) = options.grouping_sets {
+                polars_ensure!(
+                    apply.is_none(),
+                    InvalidOperation: "cannot apply a function over the groups of a group-by with grouping sets"
+                );
+                let input = recurse!(input, state)?;
+                return Ok(Box::new(executors::GroupByGroupingSetsExec {
+                    input,
+                    keys: phys_keys,
+                    aggs: phys_aggs,
+                    options,
+                    maintain_order,
+                    input_schema,
+                    slice: _slice,
+                }));
+            }
+
             // We first check if we can partition the group_by on the latest moment.
             let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
@@ -847,6 +871,28 @@ fn create_physical_plan_impl(
             };
             Ok(Box::new(exec))
         },
+        RecursiveUnion {
+            anchor,
+            recursive,
+            id,
+            options,
+        } => {
+            let anchor = recurse!(anchor, state)?;
+            let exec = executors::RecursiveUnionExec::new(
+                anchor,
+                recursive,
+                id,
+                options,
+                lp_arena,
+                expr_arena,
+                build_streaming_executor,
+            );
+            Ok(Box::new(exec))
+        },
+        WorkTableScan { name, .. } => polars_bail!(
+            InvalidOperation:
+            "work table '{}' can only be scanned by the recursive input of its recursive union", name
+        ),
         Invalid => unreachable!(),
     }
 }
@@ -23,13 +23,17 @@ pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_met
 // re-exports of crate::parquet's relevant APIs
 pub use crate::parquet::{
     FallibleStreamingIterator,
+    encryption::FileDecryptionProperties,
     error::ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
-        BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
-        get_column_iterator, read_metadata as _read_metadata,
+        BasicDecompressor, ColumnIndex, MutStreamingIterator, OffsetIndex, PageLocation,
+        PageMetaData, PageReader, ReadColumnIterator, State, decompress, deserialize_column_index,
+        deserialize_footer as _deserialize_footer, deserialize_offset_index, get_column_iterator,
+        is_parquet_magic, read_metadata as _read_metadata,
+        read_metadata_with_decryption as _read_metadata_with_decryption,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
@@ -58,6 +62,23 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads the metadata of parquet files that may be encrypted synchronously.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_read_metadata_with_decryption(reader, decryption)?)
+}
+
+/// Parses the footer of a parquet file that may be encrypted, i.e. the metadata followed by its
+/// length and the magic.
+pub fn deserialize_footer(
+    footer: &[u8],
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_deserialize_footer(footer, decryption)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
@@ -12,8 +12,11 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
-use crate::parquet::statistics::Statistics as ParquetStatistics;
+use crate::parquet::read::ColumnIndex;
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
+use crate::parquet::statistics::{
+    ParquetStatistics as ThriftStatistics, Statistics as ParquetStatistics,
+};
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
     convert_year_month, int96_to_i64_ns,
@@ -299,6 +302,72 @@ pub fn deserialize_all(
     field_idx: usize,
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+
+    deserialize_statistics_arrays(
+        field,
+        primitive_type,
+        row_groups
+            .iter()
+            .map(|rg| rg.parquet_columns()[field_idx].statistics().transpose()),
+    )
+}
+
+/// Deserializes the statistics of the pages of a column chunk from its [`ColumnIndex`], with one
+/// value per page.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_page_statistics(
+    field: &Field,
+    column: &ColumnChunkMetadata,
+    column_index: &ColumnIndex,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let num_pages = column_index.null_pages.len();
+    if column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|v| v.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "Column index has a different number of values for its fields",
+        ));
+    }
+
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+
+    deserialize_statistics_arrays(
+        field,
+        primitive_type,
+        (0..num_pages).map(|i| {
+            let is_null_page = column_index.null_pages[i];
+            let statistics = ThriftStatistics {
+                null_count: column_index.null_counts.as_ref().map(|v| v[i]),
+                distinct_count: None,
+                max_value: (!is_null_page).then(|| column_index.max_values[i].clone()),
+                min_value: (!is_null_page).then(|| column_index.min_values[i].clone()),
+                max: None,
+                min: None,
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+            ParquetStatistics::deserialize(&statistics, primitive_type.clone()).map(Some)
+        }),
+    )
+}
+
+fn deserialize_statistics_arrays(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    statistics: impl ExactSizeIterator<Item = ParquetResult<Option<ParquetStatistics>>>,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let len = statistics.len();
+
     use ArrowDataType as D;
     match field.dtype() {
         // @TODO: These are all a bit more complex, skip for now.
@@ -308,26 +377,19 @@ pub fn deserialize_all(
         D::Struct(..) => Ok(None),
 
         _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
+            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
+            let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
 
             let logical_type = &primitive_type.logical_type;
             let physical_type = &primitive_type.physical_type;
 
             macro_rules! rmap {
                 ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
+                    let mut min_arr = <$arr>::with_capacity(len$(, $arg)?);
+                    let mut max_arr = <$arr>::with_capacity(len$(, $arg)?);
 
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics().transpose()?;
+                    for s in statistics {
+                        let s = s?;
 
                         let (v_min, v_max, v_null_count, v_distinct_count) = match s {
                             None => (None, None, None, None),
@@ -401,8 +463,8 @@ pub fn deserialize_all(
             use {ArrowDataType as D, ParquetPhysicalType as PPT};
             let (min_value, max_value) = match (field.dtype(), physical_type) {
                 (D::Null, _) => (
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                    NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, len).to_boxed(),
+                    NullArray::new(ArrowDataType::Null, len).to_boxed(),
                 ),
 
                 (D::Boolean, _) => rmap!(
@@ -5,7 +5,7 @@ use polars_error::PolarsResult;
 
 use super::super::{WriteOptions, utils};
 use crate::arrow::read::schema::is_nullable;
-use crate::parquet::encoding::{Encoding, delta_bitpacked};
+use crate::parquet::encoding::{Encoding, delta_bitpacked, delta_byte_array};
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::{BinaryStatistics, ParquetStatistics};
 use crate::write::utils::invalid_encoding;
@@ -23,6 +23,21 @@ pub(crate) fn encode_non_null_values<'a, I: Iterator<Item = &'a [u8]>>(
     })
 }
 
+/// Encodes the values of `iter` with DELTA_BYTE_ARRAY, skipping the nulls if `options` is
+/// optional. Nulls of a required column are encoded as empty values.
+pub(crate) fn encode_delta_byte_array<'a, I: Iterator<Item = Option<&'a [u8]>>>(
+    iter: I,
+    options: EncodeNullability,
+    buffer: &mut Vec<u8>,
+) {
+    let values = if options.is_optional() {
+        iter.flatten().collect::<Vec<_>>()
+    } else {
+        iter.map(|v| v.unwrap_or_default()).collect::<Vec<_>>()
+    };
+    delta_byte_array::encode(values.iter().copied(), buffer);
+}
+
 pub(crate) fn encode_plain<O: Offset>(
     array: &BinaryArray<O>,
     options: EncodeNullability,
@@ -76,6 +91,9 @@ pub fn array_to_page<O: Offset>(
             encode_options,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => {
+            encode_delta_byte_array(array.iter(), encode_options, &mut buffer)
+        },
         _ => return Err(invalid_encoding(encoding, array.dtype())),
     }
 
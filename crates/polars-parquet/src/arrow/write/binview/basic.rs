@@ -6,7 +6,7 @@ use crate::parquet::encoding::delta_bitpacked;
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::{BinaryStatistics, ParquetStatistics};
 use crate::read::schema::is_nullable;
-use crate::write::binary::encode_non_null_values;
+use crate::write::binary::{encode_delta_byte_array, encode_non_null_values};
 use crate::write::utils::invalid_encoding;
 use crate::write::{EncodeNullability, Encoding, Page, StatisticsOptions, WriteOptions, utils};
 
@@ -82,6 +82,9 @@ pub fn array_to_page(
     match encoding {
         Encoding::Plain => encode_plain(array, encode_options, &mut buffer),
         Encoding::DeltaLengthByteArray => encode_delta(array, encode_options, &mut buffer),
+        Encoding::DeltaByteArray => {
+            encode_delta_byte_array(array.iter(), encode_options, &mut buffer)
+        },
         _ => return Err(invalid_encoding(encoding, array.dtype())),
     }
 
@@ -0,0 +1,132 @@
+use arrow::array::*;
+use arrow::datatypes::{PhysicalType, PrimitiveType};
+use arrow::types::NativeType;
+use polars_error::{PolarsResult, polars_bail};
+
+use super::{BloomFilterOptions, ColumnWriteOptions, to_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+
+/// Returns the bitsets of the bloom filters of the Parquet columns of `array`, one per leaf, with
+/// `None` for the leaves that have no bloom filter.
+///
+/// Values are hashed in their physical Parquet representation, so that they can be checked with
+/// the hashes of the values of a predicate.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    column_options: &ColumnWriteOptions,
+) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let mut field_options = Vec::new();
+    column_options.to_leaves(&mut field_options);
+
+    if field_options.iter().all(|o| o.bloom_filter.is_none()) {
+        return Ok(vec![None; field_options.len()]);
+    }
+
+    let mut leaves = Vec::new();
+    to_leaves(array, &mut leaves);
+
+    leaves
+        .iter()
+        .zip(field_options)
+        .map(|(leaf, field_options)| {
+            field_options
+                .bloom_filter
+                .as_ref()
+                .map(|options| leaf_to_bloom_filter(leaf.as_ref(), options))
+                .transpose()
+        })
+        .collect()
+}
+
+fn leaf_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> PolarsResult<Vec<u8>> {
+    let ndv = options
+        .ndv
+        .unwrap_or((array.len() - array.null_count()) as u64);
+    let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+    hash_array_values(array, |hash| insert(&mut bitset, hash))?;
+    Ok(bitset)
+}
+
+/// Calls `f` with the bloom filter hash of every non-null value of `array`, and returns the
+/// physical Parquet type that the values are hashed as.
+///
+/// This is the hashing used when writing bloom filters, so it can also be used to check values
+/// against the bloom filters of columns written with an array of the same type.
+pub fn hash_array_values(
+    array: &dyn Array,
+    mut f: impl FnMut(u64),
+) -> PolarsResult<ParquetPhysicalType> {
+    fn hash_primitive<A: NativeType, N: crate::parquet::types::NativeType>(
+        array: &dyn Array,
+        f: &mut impl FnMut(u64),
+        to_physical: impl Fn(A) -> N,
+    ) -> ParquetPhysicalType {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<A>>().unwrap();
+        for v in array.iter().flatten() {
+            f(hash_native(to_physical(*v)));
+        }
+        N::TYPE
+    }
+
+    fn hash_bytes<'a, I: Iterator<Item = Option<&'a [u8]>>>(
+        values: I,
+        f: &mut impl FnMut(u64),
+    ) -> ParquetPhysicalType {
+        for v in values.flatten() {
+            f(hash_byte(v));
+        }
+        ParquetPhysicalType::ByteArray
+    }
+
+    macro_rules! downcast {
+        ($ty:ty) => {
+            array.as_any().downcast_ref::<$ty>().unwrap()
+        };
+    }
+
+    use {PhysicalType as P, PrimitiveType as T};
+    let f = &mut f;
+    Ok(match array.dtype().to_physical_type() {
+        P::Primitive(T::Int8) => hash_primitive(array, f, |v: i8| v as i32),
+        P::Primitive(T::Int16) => hash_primitive(array, f, |v: i16| v as i32),
+        P::Primitive(T::Int32) => hash_primitive(array, f, |v: i32| v),
+        P::Primitive(T::Int64) => hash_primitive(array, f, |v: i64| v),
+        P::Primitive(T::UInt8) => hash_primitive(array, f, |v: u8| v as i32),
+        P::Primitive(T::UInt16) => hash_primitive(array, f, |v: u16| v as i32),
+        P::Primitive(T::UInt32) => hash_primitive(array, f, |v: u32| v as i32),
+        P::Primitive(T::UInt64) => hash_primitive(array, f, |v: u64| v as i64),
+        P::Primitive(T::Float32) => hash_primitive(array, f, |v: f32| v),
+        P::Primitive(T::Float64) => hash_primitive(array, f, |v: f64| v),
+        P::Binary => hash_bytes(downcast!(BinaryArray<i32>).iter(), f),
+        P::LargeBinary => hash_bytes(downcast!(BinaryArray<i64>).iter(), f),
+        P::Utf8 => hash_bytes(
+            downcast!(Utf8Array<i32>)
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            f,
+        ),
+        P::LargeUtf8 => hash_bytes(
+            downcast!(Utf8Array<i64>)
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            f,
+        ),
+        P::BinaryView => hash_bytes(downcast!(BinaryViewArray).iter(), f),
+        P::Utf8View => hash_bytes(
+            downcast!(Utf8ViewArray)
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            f,
+        ),
+        P::FixedSizeBinary => {
+            let array = downcast!(FixedSizeBinaryArray);
+            hash_bytes(array.iter(), f);
+            ParquetPhysicalType::FixedLenByteArray(array.size())
+        },
+        _ => polars_bail!(
+            InvalidOperation: "Parquet bloom filters are not supported for values of type {:?}",
+            array.dtype()
+        ),
+    })
+}
@@ -5,6 +5,7 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{ColumnWriteOptions, ThriftFileMetadata, WriteOptions, to_parquet_schema};
+use crate::parquet::encryption::FileEncryptionProperties;
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
@@ -78,11 +79,30 @@ impl<W: Write> FileWriter<W> {
         ))
     }
 
+    /// Encrypts the file, see [`crate::parquet::write::FileWriter::with_encryption`].
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> PolarsResult<Self> {
+        self.writer = self.writer.with_encryption(properties)?;
+        Ok(self)
+    }
+
     /// Writes a row group to the file.
     pub fn write(&mut self, row_group: RowGroupIterColumns<'_, PolarsError>) -> PolarsResult<()> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file, together with the bloom filters of its columns, see
+    /// [`array_to_bloom_filters`](super::array_to_bloom_filters).
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(row_group, bloom_filters)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
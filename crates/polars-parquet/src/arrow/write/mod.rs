@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -124,6 +126,30 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    /// Choose the encoding from the values of every column chunk instead of using `encoding`:
+    /// `BYTE_STREAM_SPLIT` for floats and `DELTA_BINARY_PACKED` for sorted integers. Other
+    /// columns use `encoding`.
+    pub auto_encoding: bool,
+    /// Write a split-block bloom filter of the values of every column chunk.
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterOptions {
+    /// Expected number of distinct values per column chunk. Defaults to the number of non-null
+    /// values, which gives the highest size.
+    pub ndv: Option<u64>,
+    /// False positive probability.
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            ndv: None,
+            fpp: 0.05,
+        }
+    }
 }
 
 impl ColumnWriteOptions {
@@ -139,7 +165,11 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            auto_encoding: false,
+            bloom_filter: None,
+        }
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -159,6 +189,8 @@ pub struct StructFieldWriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{array_to_bloom_filters, hash_array_values};
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
 use polars_error::{PolarsResult, polars_bail};
@@ -355,6 +387,11 @@ pub fn array_to_pages(
     field_options: &FieldWriteOptions,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
     let mut encoding = field_options.encoding;
+    if field_options.auto_encoding && matches!(nested.first(), Some(Nested::Primitive(_))) {
+        if let Some(auto) = auto_encoding(primitive_array) {
+            encoding = auto;
+        }
+    }
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_logical_type() {
         return match_integer_type!(key_type, |$T| {
             dictionary::array_to_pages::<$T>(
@@ -427,6 +464,38 @@ pub fn array_to_pages(
     Ok(DynIter::new(pages))
 }
 
+/// Returns the encoding picked for `array` when writing with [`FieldWriteOptions::auto_encoding`].
+fn auto_encoding(array: &dyn Array) -> Option<Encoding> {
+    fn is_sorted_ascending<T: NativeType + PartialOrd>(array: &dyn Array) -> bool {
+        let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+        let mut values = array.non_null_values_iter();
+        let Some(mut prev) = values.next() else {
+            return false;
+        };
+        values.all(|v| {
+            let sorted = prev <= v;
+            prev = v;
+            sorted
+        })
+    }
+
+    use PrimitiveType as T;
+    let is_sorted = match array.dtype().to_physical_type() {
+        PhysicalType::Primitive(T::Float32 | T::Float64) => return Some(Encoding::ByteStreamSplit),
+        PhysicalType::Primitive(T::Int8) => is_sorted_ascending::<i8>(array),
+        PhysicalType::Primitive(T::Int16) => is_sorted_ascending::<i16>(array),
+        PhysicalType::Primitive(T::Int32) => is_sorted_ascending::<i32>(array),
+        PhysicalType::Primitive(T::Int64) => is_sorted_ascending::<i64>(array),
+        PhysicalType::Primitive(T::UInt8) => is_sorted_ascending::<u8>(array),
+        PhysicalType::Primitive(T::UInt16) => is_sorted_ascending::<u16>(array),
+        PhysicalType::Primitive(T::UInt32) => is_sorted_ascending::<u32>(array),
+        PhysicalType::Primitive(T::UInt64) => is_sorted_ascending::<u64>(array),
+        _ => return None,
+    };
+
+    is_sorted.then_some(Encoding::DeltaBinaryPacked)
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
 pub fn array_to_page(
     array: &dyn Array,
@@ -531,16 +600,22 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
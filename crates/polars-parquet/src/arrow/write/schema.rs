@@ -56,7 +56,7 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
 }
 
 fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
-    if !options.metadata.is_empty() {
+    if !options.metadata.is_empty() || options.field_id.is_some() {
         let field = field.to_mut();
         let mut metadata = field.metadata.as_deref().cloned().unwrap_or_default();
 
@@ -66,6 +66,14 @@ fn insert_field_metadata(field: &mut Cow<Field>, options: &ColumnWriteOptions) {
                 kv.value.as_deref().unwrap_or_default().into(),
             );
         }
+        // Keep the field ID in the Arrow schema as well, as readers prefer the Arrow schema over
+        // the Parquet schema when it is present.
+        if let Some(field_id) = options.field_id {
+            metadata.insert(
+                PlSmallStr::from_static("PARQUET:field_id"),
+                PlSmallStr::from(field_id.to_string()),
+            );
+        }
         field.metadata = Some(Arc::new(metadata));
     }
 
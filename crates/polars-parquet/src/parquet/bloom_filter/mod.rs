@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{read, read_header};
 pub use split_block::{insert, is_in_set};
+pub use write::{optimal_num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,22 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn write_and_read_header() {
+        assert_eq!(optimal_num_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_bytes(1_000_000, 0.01), 2 * 1024 * 1024);
+        assert_eq!(optimal_num_bytes(u64::MAX, 0.01), 128 * 1024 * 1024);
+
+        let mut bitset = vec![0; optimal_num_bytes(10, 0.01)];
+        insert(&mut bitset, hash_native(42i64));
+
+        let mut bytes = vec![];
+        let len = write(&mut bytes, &bitset).unwrap();
+        assert_eq!(len as usize, bytes.len());
+
+        let (header_len, num_bytes) = read_header(&bytes).unwrap().unwrap();
+        assert_eq!(header_len + num_bytes, bytes.len());
+        assert_eq!(&bytes[header_len..], bitset.as_slice());
+    }
 }
@@ -32,11 +32,7 @@ pub fn read<R: Read + Seek>(
     let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
     let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
 
-    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}) {
-        bitset.clear();
-        return Ok(());
-    }
-    if header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {}) {
+    if !is_supported(&header) {
         bitset.clear();
         return Ok(());
     }
@@ -49,3 +45,23 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Reads the header of the bloom filter at the start of `bytes`, which were read from the bloom
+/// filter offset of a column chunk. Returns the length of the header and the length of the bitset
+/// that follows it, or `None` if the algorithm is not supported.
+pub fn read_header(mut bytes: &[u8]) -> ParquetResult<Option<(usize, usize)>> {
+    let len = bytes.len();
+    let mut prot = TCompactInputProtocol::new(&mut bytes, usize::MAX);
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+
+    if !is_supported(&header) {
+        return Ok(None);
+    }
+
+    Ok(Some((len - bytes.len(), header.num_bytes.try_into()?)))
+}
+
+fn is_supported(header: &BloomFilterHeader) -> bool {
+    header.algorithm == BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        && header.compression == BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+}
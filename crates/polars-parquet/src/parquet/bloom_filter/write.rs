@@ -0,0 +1,41 @@
+use std::io::Write;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// Size of a block of the split-block bloom filter, in bytes.
+const BLOCK_SIZE: usize = 32;
+/// Maximum size of a bitset, in bytes, as used by other writers.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a bitset that holds `ndv` distinct values with a false positive
+/// probability of `fpp`, as described in
+/// <https://github.com/apache/parquet-format/blob/master/BloomFilter.md#sizing-an-sbbf>.
+pub fn optimal_num_bytes(ndv: u64, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    ((num_bits / 8.0) as usize)
+        .clamp(BLOCK_SIZE, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
+/// Writes `bitset` as an uncompressed split-block bloom filter, returning the number of bytes
+/// written.
+pub fn write<W: Write>(mut writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header = BloomFilterHeader {
+        num_bytes: bitset.len().try_into()?,
+        algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        hash: BloomFilterHash::XXHASH(XxHash {}),
+        compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    };
+
+    let mut protocol = TCompactOutputProtocol::new(&mut writer);
+    let header_len = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+
+    Ok(header_len + bitset.len() as u64)
+}
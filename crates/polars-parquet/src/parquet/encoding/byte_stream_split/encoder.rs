@@ -0,0 +1,19 @@
+/// Encodes values according to BYTE_STREAM_SPLIT.
+///
+/// `values` are the plain (little-endian) encoded values of `element_size` bytes each. The `k`-th
+/// byte of every value is written to the `k`-th stream, and the streams are appended to `buffer`
+/// one after the other.
+pub fn encode(values: &[u8], element_size: usize, buffer: &mut Vec<u8>) {
+    debug_assert_eq!(values.len() % element_size, 0);
+    let num_elements = values.len() / element_size;
+
+    let start = buffer.len();
+    buffer.resize(start + values.len(), 0);
+    let out = &mut buffer[start..];
+
+    for (i, value) in values.chunks_exact(element_size).enumerate() {
+        for (n, byte) in value.iter().enumerate() {
+            out[num_elements * n + i] = *byte;
+        }
+    }
+}
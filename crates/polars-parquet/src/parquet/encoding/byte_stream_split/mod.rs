@@ -1,6 +1,8 @@
 mod decoder;
+mod encoder;
 
 pub use decoder::Decoder;
+pub use encoder::encode;
 
 #[cfg(test)]
 mod tests {
@@ -12,7 +14,7 @@ mod tests {
     fn round_trip_f32() -> Result<(), ParquetError> {
         let data = vec![1.0e-2_f32, 2.5_f32, 3.0e2_f32];
         let mut buffer = vec![];
-        encode(&data, &mut buffer);
+        encode_values(&data, &mut buffer);
 
         let mut decoder = Decoder::try_new(&buffer, size_of::<f32>())?;
         let values = decoder
@@ -28,7 +30,7 @@ mod tests {
     fn round_trip_f64() -> Result<(), ParquetError> {
         let data = vec![1.0e-2_f64, 2.5_f64, 3.0e2_f64];
         let mut buffer = vec![];
-        encode(&data, &mut buffer);
+        encode_values(&data, &mut buffer);
 
         let mut decoder = Decoder::try_new(&buffer, size_of::<f64>())?;
         let values = decoder
@@ -60,18 +62,11 @@ mod tests {
         Ok(())
     }
 
-    fn encode<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
-        let element_size = size_of::<T>();
-        let num_elements = data.len();
-        let total_length = size_of_val(data);
-        buffer.resize(total_length, 0);
-
-        for (i, v) in data.iter().enumerate() {
-            let value_bytes = v.to_le_bytes();
-            let value_bytes_ref = value_bytes.as_ref();
-            for n in 0..element_size {
-                buffer[(num_elements * n) + i] = value_bytes_ref[n];
-            }
-        }
+    fn encode_values<T: NativeType>(data: &[T], buffer: &mut Vec<u8>) {
+        let plain = data
+            .iter()
+            .flat_map(|v| v.to_le_bytes().as_ref().to_vec())
+            .collect::<Vec<_>>();
+        encode(&plain, size_of::<T>(), buffer);
     }
 }
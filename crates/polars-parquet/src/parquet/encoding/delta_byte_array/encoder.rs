@@ -18,7 +18,7 @@ pub fn encode<'a, I: ExactSizeIterator<Item = &'a [u8]> + Clone>(
                 .enumerate()
                 // find first difference
                 .find_map(|(length, (lhs, rhs))| (lhs != rhs).then_some(length))
-                .unwrap_or(previous.len());
+                .unwrap_or(previous.len().min(item.len()));
             previous = item;
 
             sum_lengths += item.len() - prefix_length;
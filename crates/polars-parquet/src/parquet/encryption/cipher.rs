@@ -0,0 +1,290 @@
+//! AES-GCM and AES-CTR ciphers of Parquet modules.
+//!
+//! A GCM module is `length | nonce | ciphertext | tag` and a CTR module is
+//! `length | nonce | ciphertext`, where `length` is the 4-byte little-endian length of the rest of
+//! the module.
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+pub(crate) const LENGTH_LEN: usize = 4;
+
+#[cfg_attr(not(feature = "encryption"), allow(dead_code))]
+fn check_key(key: &[u8]) -> ParquetResult<()> {
+    if matches!(key.len(), 16 | 24 | 32) {
+        Ok(())
+    } else {
+        Err(ParquetError::InvalidParameter(format!(
+            "AES keys must be 16, 24 or 32 bytes long, got {} bytes",
+            key.len()
+        )))
+    }
+}
+
+fn prepend_length(mut module: Vec<u8>) -> ParquetResult<Vec<u8>> {
+    let length: u32 = (module.len() - LENGTH_LEN)
+        .try_into()
+        .map_err(|_| ParquetError::oos("An encrypted module can be at most u32::MAX bytes"))?;
+    module[..LENGTH_LEN].copy_from_slice(&length.to_le_bytes());
+    Ok(module)
+}
+
+/// Splits the length from `module` and checks it against the length of the rest of the module.
+fn strip_length(module: &[u8]) -> ParquetResult<&[u8]> {
+    let Some((length, rest)) = module.split_first_chunk::<LENGTH_LEN>() else {
+        return Err(ParquetError::oos("Encrypted module is too short"));
+    };
+    if u32::from_le_bytes(*length) as usize != rest.len() {
+        return Err(ParquetError::oos(
+            "The length of an encrypted module does not match its size",
+        ));
+    }
+    Ok(rest)
+}
+
+/// Returns the length of the module that starts at `bytes`, including the length itself.
+pub(crate) fn module_len(bytes: &[u8]) -> ParquetResult<usize> {
+    let Some(length) = bytes.first_chunk::<LENGTH_LEN>() else {
+        return Err(ParquetError::oos("Encrypted module is too short"));
+    };
+    Ok(LENGTH_LEN + u32::from_le_bytes(*length) as usize)
+}
+
+#[cfg(feature = "encryption")]
+mod aes {
+    use aes_gcm::aead::consts::U12;
+    use aes_gcm::aead::rand_core::RngCore;
+    use aes_gcm::aead::{AeadInPlace, KeyInit, OsRng};
+    use aes_gcm::aes::{Aes128, Aes192, Aes256};
+    use aes_gcm::{AesGcm, Nonce, Tag};
+    use ctr::cipher::{KeyIvInit, StreamCipher};
+    use subtle::ConstantTimeEq;
+
+    use super::*;
+
+    macro_rules! with_aes {
+        ($key:expr, |$aes:ident| $body:expr) => {
+            match $key.len() {
+                16 => {
+                    type $aes = Aes128;
+                    $body
+                },
+                24 => {
+                    type $aes = Aes192;
+                    $body
+                },
+                _ => {
+                    type $aes = Aes256;
+                    $body
+                },
+            }
+        };
+    }
+
+    pub(crate) fn random_bytes(buffer: &mut [u8]) {
+        OsRng.fill_bytes(buffer)
+    }
+
+    fn crypto_err(_: impl std::fmt::Debug) -> ParquetError {
+        ParquetError::oos("Failed to decrypt a module, the key or the AAD is wrong")
+    }
+
+    /// Encrypts `plaintext` in place with `nonce`, returning the tag.
+    pub(crate) fn gcm_encrypt_detached(
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        plaintext: &mut [u8],
+    ) -> ParquetResult<[u8; TAG_LEN]> {
+        check_key(key)?;
+        with_aes!(key, |A| {
+            let cipher = AesGcm::<A, U12>::new_from_slice(key).unwrap();
+            let tag = cipher
+                .encrypt_in_place_detached(Nonce::<U12>::from_slice(nonce), aad, plaintext)
+                .map_err(|_| ParquetError::oos("Failed to encrypt a module"))?;
+            Ok(tag.into())
+        })
+    }
+
+    /// Checks in constant time that `tag` is the tag of encrypting `plaintext` with `nonce`.
+    pub(crate) fn gcm_verify_detached(
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        plaintext: &[u8],
+        tag: &[u8],
+    ) -> ParquetResult<bool> {
+        let mut ciphertext = plaintext.to_vec();
+        let expected = gcm_encrypt_detached(key, nonce, aad, &mut ciphertext)?;
+        Ok(expected.ct_eq(tag).into())
+    }
+
+    pub(crate) fn gcm_decrypt_detached(
+        key: &[u8],
+        nonce: &[u8],
+        aad: &[u8],
+        ciphertext: &mut [u8],
+        tag: &[u8],
+    ) -> ParquetResult<()> {
+        check_key(key)?;
+        with_aes!(key, |A| {
+            let cipher = AesGcm::<A, U12>::new_from_slice(key).unwrap();
+            cipher
+                .decrypt_in_place_detached(
+                    Nonce::<U12>::from_slice(nonce),
+                    aad,
+                    ciphertext,
+                    Tag::from_slice(tag),
+                )
+                .map_err(crypto_err)
+        })
+    }
+
+    pub(crate) fn ctr_apply(key: &[u8], nonce: &[u8], data: &mut [u8]) -> ParquetResult<()> {
+        check_key(key)?;
+        // The counter starts at 1, in the last 4 (big-endian) bytes of the IV.
+        let mut iv = [0u8; 16];
+        iv[..NONCE_LEN].copy_from_slice(nonce);
+        iv[15] = 1;
+        with_aes!(key, |A| {
+            let mut cipher = ctr::Ctr32BE::<A>::new_from_slices(key, &iv).unwrap();
+            cipher.apply_keystream(data);
+            Ok(())
+        })
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+mod aes {
+    use super::*;
+    use crate::parquet::error::Feature;
+
+    fn inactive() -> ParquetError {
+        ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "encrypt or decrypt Parquet modules".to_string(),
+        )
+    }
+
+    pub(crate) fn random_bytes(_buffer: &mut [u8]) {}
+
+    pub(crate) fn gcm_encrypt_detached(
+        _key: &[u8],
+        _nonce: &[u8; NONCE_LEN],
+        _aad: &[u8],
+        _plaintext: &mut [u8],
+    ) -> ParquetResult<[u8; TAG_LEN]> {
+        Err(inactive())
+    }
+
+    pub(crate) fn gcm_verify_detached(
+        _key: &[u8],
+        _nonce: &[u8; NONCE_LEN],
+        _aad: &[u8],
+        _plaintext: &[u8],
+        _tag: &[u8],
+    ) -> ParquetResult<bool> {
+        Err(inactive())
+    }
+
+    pub(crate) fn gcm_decrypt_detached(
+        _key: &[u8],
+        _nonce: &[u8],
+        _aad: &[u8],
+        _ciphertext: &mut [u8],
+        _tag: &[u8],
+    ) -> ParquetResult<()> {
+        Err(inactive())
+    }
+
+    pub(crate) fn ctr_apply(_key: &[u8], _nonce: &[u8], _data: &mut [u8]) -> ParquetResult<()> {
+        Err(inactive())
+    }
+}
+
+use aes::gcm_decrypt_detached;
+pub(crate) use aes::{gcm_encrypt_detached, gcm_verify_detached, random_bytes};
+
+/// Encrypts `plaintext` into a GCM module.
+pub(crate) fn gcm_encrypt(key: &[u8], aad: &[u8], plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+    let mut nonce = [0u8; NONCE_LEN];
+    random_bytes(&mut nonce);
+
+    let mut module = Vec::with_capacity(LENGTH_LEN + NONCE_LEN + plaintext.len() + TAG_LEN);
+    module.extend_from_slice(&[0; LENGTH_LEN]);
+    module.extend_from_slice(&nonce);
+    module.extend_from_slice(plaintext);
+    let tag = gcm_encrypt_detached(key, &nonce, aad, &mut module[LENGTH_LEN + NONCE_LEN..])?;
+    module.extend_from_slice(&tag);
+    prepend_length(module)
+}
+
+/// Decrypts a GCM module, including its length.
+pub(crate) fn gcm_decrypt(key: &[u8], aad: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+    let module = strip_length(module)?;
+    if module.len() < NONCE_LEN + TAG_LEN {
+        return Err(ParquetError::oos("Encrypted module is too short"));
+    }
+    let (nonce, rest) = module.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+
+    let mut plaintext = ciphertext.to_vec();
+    gcm_decrypt_detached(key, nonce, aad, &mut plaintext, tag)?;
+    Ok(plaintext)
+}
+
+/// Encrypts `plaintext` into a CTR module.
+pub(crate) fn ctr_encrypt(key: &[u8], plaintext: &[u8]) -> ParquetResult<Vec<u8>> {
+    let mut nonce = [0u8; NONCE_LEN];
+    random_bytes(&mut nonce);
+
+    let mut module = Vec::with_capacity(LENGTH_LEN + NONCE_LEN + plaintext.len());
+    module.extend_from_slice(&[0; LENGTH_LEN]);
+    module.extend_from_slice(&nonce);
+    module.extend_from_slice(plaintext);
+    aes::ctr_apply(key, &nonce, &mut module[LENGTH_LEN + NONCE_LEN..])?;
+    prepend_length(module)
+}
+
+/// Decrypts a CTR module, including its length.
+pub(crate) fn ctr_decrypt(key: &[u8], module: &[u8]) -> ParquetResult<Vec<u8>> {
+    let module = strip_length(module)?;
+    if module.len() < NONCE_LEN {
+        return Err(ParquetError::oos("Encrypted module is too short"));
+    }
+    let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+
+    let mut plaintext = ciphertext.to_vec();
+    aes::ctr_apply(key, nonce, &mut plaintext)?;
+    Ok(plaintext)
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcm_round_trip() -> ParquetResult<()> {
+        for key in [[1u8; 16].as_slice(), &[2u8; 24], &[3u8; 32]] {
+            let module = gcm_encrypt(key, b"aad", b"hello parquet")?;
+            assert_eq!(module_len(&module)?, module.len());
+            assert_eq!(gcm_decrypt(key, b"aad", &module)?, b"hello parquet");
+            assert!(gcm_decrypt(key, b"other aad", &module).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn ctr_round_trip() -> ParquetResult<()> {
+        let key = [7u8; 16];
+        let module = ctr_encrypt(&key, b"hello parquet")?;
+        assert_eq!(module.len(), LENGTH_LEN + NONCE_LEN + 13);
+        assert_eq!(ctr_decrypt(&key, &module)?, b"hello parquet");
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_key_length() {
+        assert!(gcm_encrypt(&[0u8; 15], b"", b"").is_err());
+    }
+}
@@ -0,0 +1,699 @@
+//! [Parquet modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are encrypted with `AES_GCM_V1`, where all modules are encrypted with AES-GCM, or with
+//! `AES_GCM_CTR_V1`, where the page data is encrypted with AES-CTR instead. The footer is either
+//! encrypted, or written in plaintext and signed. Columns are encrypted with the footer key or
+//! with their own key.
+mod cipher;
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    AesGcmCtrV1, AesGcmV1, ColumnChunk, ColumnCryptoMetaData, ColumnMetaData,
+    EncryptionAlgorithm as ThriftEncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData,
+};
+use polars_utils::aliases::PlHashMap;
+
+pub(crate) use self::cipher::module_len;
+use self::cipher::{
+    NONCE_LEN, TAG_LEN, ctr_decrypt, ctr_encrypt, gcm_decrypt, gcm_encrypt, gcm_encrypt_detached,
+    gcm_verify_detached, random_bytes,
+};
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::SchemaDescriptor;
+
+/// The number of bytes of the random part of the AAD of a file.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// The encryption algorithm of a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EncryptionAlgorithm {
+    /// All modules are encrypted with AES-GCM.
+    #[default]
+    AesGcmV1,
+    /// The page data is encrypted with AES-CTR, the other modules with AES-GCM.
+    AesGcmCtrV1,
+}
+
+/// Retrieves the keys of encrypted files from their key metadata, e.g. from a key management
+/// service.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the AES key (of 16, 24 or 32 bytes) identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// A [`KeyRetriever`] over keys held in memory, with the key metadata as key identifiers.
+#[derive(Debug, Clone, Default)]
+pub struct KeyMap {
+    keys: PlHashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl KeyMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `key` identified by `key_metadata`.
+    pub fn insert(&mut self, key_metadata: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) {
+        self.keys.insert(key_metadata.into(), key.into());
+    }
+}
+
+impl<M: Into<Vec<u8>>, K: Into<Vec<u8>>> FromIterator<(M, K)> for KeyMap {
+    fn from_iter<T: IntoIterator<Item = (M, K)>>(iter: T) -> Self {
+        let mut keys = Self::new();
+        for (key_metadata, key) in iter {
+            keys.insert(key_metadata, key);
+        }
+        keys
+    }
+}
+
+impl KeyRetriever for KeyMap {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        self.keys.get(key_metadata).cloned().ok_or_else(|| {
+            ParquetError::InvalidParameter(format!(
+                "no key for key metadata '{}'",
+                String::from_utf8_lossy(key_metadata)
+            ))
+        })
+    }
+}
+
+/// The properties to read encrypted files with.
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    pub key_retriever: Arc<dyn KeyRetriever>,
+    /// The AAD prefix of files that were written without storing it.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+/// The key of an encrypted column.
+#[derive(Debug, Clone)]
+pub struct ColumnEncryptionKey {
+    /// The path of the (leaf) column.
+    pub path_in_schema: Vec<String>,
+    pub key: Vec<u8>,
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+/// The properties to write encrypted files with.
+#[derive(Debug, Clone, Default)]
+pub struct FileEncryptionProperties {
+    pub algorithm: EncryptionAlgorithm,
+    pub footer_key: Vec<u8>,
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// The columns encrypted with their own key. If empty, all columns are encrypted with the
+    /// footer key, otherwise the other columns are not encrypted.
+    pub column_keys: Vec<ColumnEncryptionKey>,
+    /// Write the footer in plaintext, signed with the footer key, so that readers without the
+    /// keys can read the unencrypted columns.
+    pub plaintext_footer: bool,
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Store the AAD prefix in the file. Otherwise, readers must supply it.
+    pub store_aad_prefix: bool,
+}
+
+/// The type of an encrypted module, which is part of its AAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+}
+
+fn ordinal_to_aad(ordinal: usize, what: &str) -> ParquetResult<[u8; 2]> {
+    let ordinal = i16::try_from(ordinal).map_err(|_| {
+        ParquetError::not_supported(format!(
+            "encrypted files can have at most {} {what}s",
+            i16::MAX as usize + 1
+        ))
+    })?;
+    Ok(ordinal.to_le_bytes())
+}
+
+fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = file_aad.to_vec();
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// The keys and the AAD of the modules of a column chunk.
+#[derive(Clone)]
+struct ModuleCipher {
+    algorithm: EncryptionAlgorithm,
+    key: Vec<u8>,
+    /// The AAD of the modules, without the page ordinal.
+    aad: Vec<u8>,
+}
+
+impl ModuleCipher {
+    fn new(
+        algorithm: EncryptionAlgorithm,
+        key: Vec<u8>,
+        file_aad: &[u8],
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> ParquetResult<Self> {
+        let mut aad = file_aad.to_vec();
+        // The module type goes here.
+        aad.push(0);
+        aad.extend_from_slice(&ordinal_to_aad(row_group_ordinal, "row group")?);
+        aad.extend_from_slice(&ordinal_to_aad(column_ordinal, "column")?);
+        Ok(Self {
+            algorithm,
+            key,
+            aad,
+        })
+    }
+
+    fn aad(&self, module_type: ModuleType, page_ordinal: Option<usize>) -> ParquetResult<Vec<u8>> {
+        let mut aad = self.aad.clone();
+        aad[self.aad.len() - 5] = module_type as u8;
+        if let Some(page_ordinal) = page_ordinal {
+            aad.extend_from_slice(&ordinal_to_aad(page_ordinal, "page")?);
+        }
+        Ok(aad)
+    }
+
+    fn is_ctr(&self, module_type: ModuleType) -> bool {
+        self.algorithm == EncryptionAlgorithm::AesGcmCtrV1
+            && matches!(
+                module_type,
+                ModuleType::DataPage | ModuleType::DictionaryPage
+            )
+    }
+
+    fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        if self.is_ctr(module_type) {
+            ctr_encrypt(&self.key, plaintext)
+        } else {
+            gcm_encrypt(&self.key, &self.aad(module_type, page_ordinal)?, plaintext)
+        }
+    }
+
+    fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        if self.is_ctr(module_type) {
+            ctr_decrypt(&self.key, module)
+        } else {
+            gcm_decrypt(&self.key, &self.aad(module_type, page_ordinal)?, module)
+        }
+    }
+}
+
+/// Encrypts the modules of a column chunk.
+#[derive(Clone)]
+pub(crate) struct ColumnEncryptor(ModuleCipher);
+
+impl ColumnEncryptor {
+    pub(crate) fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        self.0.encrypt(module_type, page_ordinal, plaintext)
+    }
+}
+
+/// Decrypts the modules of a column chunk.
+#[derive(Clone)]
+pub struct ColumnDecryptor {
+    /// `None` if the file is read without the key of the column.
+    cipher: Option<ModuleCipher>,
+    path_in_schema: Vec<String>,
+    has_dictionary_page: bool,
+}
+
+impl Debug for ColumnDecryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnDecryptor")
+            .field("path_in_schema", &self.path_in_schema)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ColumnDecryptor {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for ColumnDecryptor {}
+
+impl ColumnDecryptor {
+    /// Whether the first page of the column chunk is a dictionary page.
+    pub(crate) fn has_dictionary_page(&self) -> bool {
+        self.has_dictionary_page
+    }
+
+    pub(crate) fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let Some(cipher) = &self.cipher else {
+            return Err(ParquetError::InvalidParameter(format!(
+                "column '{}' is encrypted, decryption keys are required to read it",
+                self.path_in_schema.join(".")
+            )));
+        };
+        cipher.decrypt(module_type, page_ordinal, module)
+    }
+}
+
+/// The key of an encrypted leaf column of a file that is being written.
+#[derive(Clone)]
+struct ColumnKey {
+    key: Vec<u8>,
+    /// `None` for columns encrypted with the footer key.
+    key_metadata: Option<Vec<u8>>,
+}
+
+/// Encrypts the modules of a file that is being written.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    aad_file_unique: Vec<u8>,
+    file_aad: Vec<u8>,
+    /// The key of every leaf column, or `None` for the columns that are not encrypted.
+    column_keys: Vec<Option<ColumnKey>>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(
+        properties: FileEncryptionProperties,
+        schema: &SchemaDescriptor,
+    ) -> ParquetResult<Self> {
+        let mut column_keys = vec![None; schema.columns().len()];
+        if properties.column_keys.is_empty() {
+            column_keys.fill(Some(ColumnKey {
+                key: properties.footer_key.clone(),
+                key_metadata: None,
+            }));
+        }
+        for column_key in properties.column_keys.iter() {
+            let Some(i) = schema.columns().iter().position(|c| {
+                c.path_in_schema
+                    .iter()
+                    .map(|s| s.as_str())
+                    .eq(column_key.path_in_schema.iter().map(|s| s.as_str()))
+            }) else {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "cannot encrypt column '{}', it is not a (leaf) column of the schema",
+                    column_key.path_in_schema.join(".")
+                )));
+            };
+            column_keys[i] = Some(ColumnKey {
+                key: column_key.key.clone(),
+                key_metadata: Some(column_key.key_metadata.clone().unwrap_or_default()),
+            });
+        }
+
+        // Check the keys before any data is written.
+        for key in std::iter::once(&properties.footer_key)
+            .chain(column_keys.iter().flatten().map(|c| &c.key))
+        {
+            if !matches!(key.len(), 16 | 24 | 32) {
+                return Err(ParquetError::InvalidParameter(format!(
+                    "AES keys must be 16, 24 or 32 bytes long, got {} bytes",
+                    key.len()
+                )));
+            }
+        }
+
+        let mut aad_file_unique = vec![0; AAD_FILE_UNIQUE_LEN];
+        random_bytes(&mut aad_file_unique);
+        let mut file_aad = properties.aad_prefix.clone().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+            file_aad,
+            column_keys,
+        })
+    }
+
+    pub(crate) fn is_footer_encrypted(&self) -> bool {
+        !self.properties.plaintext_footer
+    }
+
+    pub(crate) fn column_encryptor(
+        &self,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> ParquetResult<Option<ColumnEncryptor>> {
+        let Some(column_key) = &self.column_keys[column_ordinal] else {
+            return Ok(None);
+        };
+        ModuleCipher::new(
+            self.properties.algorithm,
+            column_key.key.clone(),
+            &self.file_aad,
+            row_group_ordinal,
+            column_ordinal,
+        )
+        .map(|cipher| Some(ColumnEncryptor(cipher)))
+    }
+
+    fn thrift_algorithm(&self) -> ThriftEncryptionAlgorithm {
+        let (aad_prefix, supply_aad_prefix) = match &self.properties.aad_prefix {
+            Some(prefix) if self.properties.store_aad_prefix => (Some(prefix.clone()), None),
+            Some(_) => (None, Some(true)),
+            None => (None, None),
+        };
+        let aad_file_unique = Some(self.aad_file_unique.clone());
+
+        match self.properties.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => ThriftEncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+            EncryptionAlgorithm::AesGcmCtrV1 => {
+                ThriftEncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+                    aad_prefix,
+                    aad_file_unique,
+                    supply_aad_prefix,
+                })
+            },
+        }
+    }
+
+    /// Sets the crypto metadata of the column chunks of a row group, and encrypts their column
+    /// metadata where required.
+    pub(crate) fn encrypt_column_chunks(
+        &self,
+        row_group_ordinal: usize,
+        column_chunks: &mut [ColumnChunk],
+    ) -> ParquetResult<()> {
+        for (column_ordinal, column_chunk) in column_chunks.iter_mut().enumerate() {
+            let Some(ColumnKey { key_metadata, .. }) = &self.column_keys[column_ordinal] else {
+                continue;
+            };
+            let metadata = column_chunk.meta_data.as_mut().unwrap();
+
+            column_chunk.crypto_metadata = Some(match key_metadata {
+                None => ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {}),
+                Some(key_metadata) => {
+                    ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                        path_in_schema: metadata.path_in_schema.clone(),
+                        key_metadata: (!key_metadata.is_empty()).then(|| key_metadata.clone()),
+                    })
+                },
+            });
+
+            // The column metadata is already protected by an encrypted footer if the column is
+            // encrypted with the footer key.
+            if key_metadata.is_none() && self.is_footer_encrypted() {
+                continue;
+            }
+
+            let encryptor = self
+                .column_encryptor(row_group_ordinal, column_ordinal)?
+                .unwrap();
+            let plaintext = serialize(|protocol| metadata.write_to_out_protocol(protocol))?;
+            column_chunk.encrypted_column_metadata =
+                Some(encryptor.encrypt(ModuleType::ColumnMetaData, None, &plaintext)?);
+
+            if self.is_footer_encrypted() {
+                column_chunk.meta_data = None;
+            } else {
+                // Readers without the key only see the plaintext metadata.
+                metadata.statistics = None;
+                metadata.size_statistics = None;
+                metadata.encoding_stats = None;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the footer of the file, without its length and magic.
+    pub(crate) fn encrypt_footer(
+        &self,
+        metadata: &polars_parquet_format::FileMetaData,
+    ) -> ParquetResult<Vec<u8>> {
+        let footer_aad = footer_aad(&self.file_aad);
+        let key = &self.properties.footer_key;
+
+        if self.is_footer_encrypted() {
+            let crypto_metadata = FileCryptoMetaData {
+                encryption_algorithm: self.thrift_algorithm(),
+                key_metadata: self.properties.footer_key_metadata.clone(),
+            };
+            let mut footer = serialize(|protocol| crypto_metadata.write_to_out_protocol(protocol))?;
+            let plaintext = serialize(|protocol| metadata.write_to_out_protocol(protocol))?;
+            footer.extend(gcm_encrypt(key, &footer_aad, &plaintext)?);
+            Ok(footer)
+        } else {
+            let mut metadata = metadata.clone();
+            metadata.encryption_algorithm = Some(self.thrift_algorithm());
+            metadata.footer_signing_key_metadata = self.properties.footer_key_metadata.clone();
+            let mut footer = serialize(|protocol| metadata.write_to_out_protocol(protocol))?;
+
+            // The signature is the nonce and the tag of the encrypted footer.
+            let mut nonce = [0u8; NONCE_LEN];
+            random_bytes(&mut nonce);
+            let mut ciphertext = footer.clone();
+            let tag = gcm_encrypt_detached(key, &nonce, &footer_aad, &mut ciphertext)?;
+            footer.extend_from_slice(&nonce);
+            footer.extend_from_slice(&tag);
+            Ok(footer)
+        }
+    }
+}
+
+fn serialize(
+    f: impl FnOnce(
+        &mut TCompactOutputProtocol<&mut Vec<u8>>,
+    ) -> polars_parquet_format::thrift::Result<usize>,
+) -> ParquetResult<Vec<u8>> {
+    let mut buffer = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+    f(&mut protocol)?;
+    Ok(buffer)
+}
+
+/// Decrypts the footer and the column metadata of a file that is being read.
+pub(crate) struct FileDecryptor {
+    algorithm: EncryptionAlgorithm,
+    file_aad: Vec<u8>,
+    /// `None` if the file is read without decryption properties.
+    properties: Option<FileDecryptionProperties>,
+    footer_key: Option<Vec<u8>>,
+}
+
+impl FileDecryptor {
+    pub(crate) fn try_new(
+        algorithm: &ThriftEncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+        properties: Option<&FileDecryptionProperties>,
+    ) -> ParquetResult<Self> {
+        let (algorithm, aad_prefix, aad_file_unique, supply_aad_prefix) = match algorithm {
+            ThriftEncryptionAlgorithm::AESGCMV1(v) => (
+                EncryptionAlgorithm::AesGcmV1,
+                &v.aad_prefix,
+                &v.aad_file_unique,
+                v.supply_aad_prefix,
+            ),
+            ThriftEncryptionAlgorithm::AESGCMCTRV1(v) => (
+                EncryptionAlgorithm::AesGcmCtrV1,
+                &v.aad_prefix,
+                &v.aad_file_unique,
+                v.supply_aad_prefix,
+            ),
+        };
+
+        let Some(properties) = properties else {
+            return Ok(Self {
+                algorithm,
+                file_aad: vec![],
+                properties: None,
+                footer_key: None,
+            });
+        };
+
+        let aad_prefix = match (aad_prefix, &properties.aad_prefix) {
+            (Some(stored), Some(supplied)) if stored != supplied => {
+                return Err(ParquetError::InvalidParameter(
+                    "the supplied AAD prefix does not match the AAD prefix stored in the file"
+                        .to_string(),
+                ));
+            },
+            (Some(prefix), _) | (None, Some(prefix)) => prefix.as_slice(),
+            (None, None) if supply_aad_prefix == Some(true) => {
+                return Err(ParquetError::InvalidParameter(
+                    "the file was encrypted with an AAD prefix that is not stored in the file, it must be supplied to read it"
+                        .to_string(),
+                ));
+            },
+            (None, None) => &[],
+        };
+        let mut file_aad = aad_prefix.to_vec();
+        file_aad.extend_from_slice(aad_file_unique.as_deref().unwrap_or_default());
+
+        let footer_key = properties
+            .key_retriever
+            .retrieve_key(footer_key_metadata.unwrap_or_default())?;
+
+        Ok(Self {
+            algorithm,
+            file_aad,
+            properties: Some(properties.clone()),
+            footer_key: Some(footer_key),
+        })
+    }
+
+    pub(crate) fn decrypt_footer(&self, module: &[u8]) -> ParquetResult<Vec<u8>> {
+        let key = self.footer_key.as_ref().ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "the Parquet file has an encrypted footer, decryption keys are required to read it"
+                    .to_string(),
+            )
+        })?;
+        gcm_decrypt(key, &footer_aad(&self.file_aad), module)
+    }
+
+    /// Checks the signature of a plaintext footer, if the footer key is known.
+    pub(crate) fn verify_plaintext_footer(
+        &self,
+        footer: &[u8],
+        signature: &[u8],
+    ) -> ParquetResult<()> {
+        let Some(key) = &self.footer_key else {
+            return Ok(());
+        };
+        if signature.len() < NONCE_LEN + TAG_LEN {
+            return Err(ParquetError::oos(
+                "The plaintext footer of an encrypted file must be signed",
+            ));
+        }
+        let (nonce, tag) = signature.split_at(NONCE_LEN);
+        let nonce: &[u8; NONCE_LEN] = nonce.try_into().unwrap();
+        // The signature is checked by encrypting the footer again with the same nonce.
+        let footer_aad = footer_aad(&self.file_aad);
+        if !gcm_verify_detached(key, nonce, &footer_aad, footer, &tag[..TAG_LEN])? {
+            return Err(ParquetError::oos(
+                "The signature of the plaintext footer does not match, the file is corrupted or the footer key is wrong",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the decryptor of an encrypted column chunk, and decrypts its column metadata if
+    /// it is encrypted.
+    pub(crate) fn decrypt_column_chunk(
+        &self,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+        column_chunk: &mut ColumnChunk,
+    ) -> ParquetResult<Option<Arc<ColumnDecryptor>>> {
+        let Some(crypto_metadata) = &column_chunk.crypto_metadata else {
+            return Ok(None);
+        };
+
+        let path_in_schema = match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(v) => v.path_in_schema.clone(),
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => column_chunk
+                .meta_data
+                .as_ref()
+                .map(|m| m.path_in_schema.clone())
+                .unwrap_or_default(),
+        };
+
+        let key = match (crypto_metadata, &self.properties) {
+            (_, None) => None,
+            (ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_), Some(_)) => self.footer_key.clone(),
+            (ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(v), Some(properties)) => Some(
+                properties
+                    .key_retriever
+                    .retrieve_key(v.key_metadata.as_deref().unwrap_or_default())?,
+            ),
+        };
+
+        let cipher = key
+            .map(|key| {
+                ModuleCipher::new(
+                    self.algorithm,
+                    key,
+                    &self.file_aad,
+                    row_group_ordinal,
+                    column_ordinal,
+                )
+            })
+            .transpose()?;
+
+        if let (Some(cipher), Some(module)) = (&cipher, &column_chunk.encrypted_column_metadata) {
+            let plaintext = cipher.decrypt(ModuleType::ColumnMetaData, None, module)?;
+            let mut protocol = polars_parquet_format::thrift::protocol::TCompactInputProtocol::new(
+                plaintext.as_slice(),
+                plaintext.len() * 2 + 1024,
+            );
+            column_chunk.meta_data = Some(ColumnMetaData::read_from_in_protocol(&mut protocol)?);
+        }
+
+        let has_dictionary_page = column_chunk
+            .meta_data
+            .as_ref()
+            .is_some_and(|m| m.dictionary_page_offset.is_some());
+
+        Ok(Some(Arc::new(ColumnDecryptor {
+            cipher,
+            path_in_schema,
+            has_dictionary_page,
+        })))
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn module_aad() -> ParquetResult<()> {
+        let cipher = ModuleCipher::new(EncryptionAlgorithm::AesGcmV1, vec![0; 16], b"file", 1, 2)?;
+        assert_eq!(
+            cipher.aad(ModuleType::DataPageHeader, Some(3))?,
+            b"file\x04\x01\x00\x02\x00\x03\x00"
+        );
+        assert_eq!(
+            cipher.aad(ModuleType::ColumnIndex, None)?,
+            b"file\x06\x01\x00\x02\x00"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ctr_only_for_pages() -> ParquetResult<()> {
+        let cipher = ModuleCipher::new(EncryptionAlgorithm::AesGcmCtrV1, vec![0; 16], b"", 0, 0)?;
+        let page = cipher.encrypt(ModuleType::DataPage, Some(0), b"page")?;
+        assert_eq!(page.len(), 4 + NONCE_LEN + 4);
+        let header = cipher.encrypt(ModuleType::DataPageHeader, Some(0), b"header")?;
+        assert_eq!(header.len(), 4 + NONCE_LEN + 6 + TAG_LEN);
+        assert_eq!(
+            cipher.decrypt(ModuleType::DataPage, Some(0), &page)?,
+            b"page"
+        );
+        Ok(())
+    }
+}
@@ -15,6 +15,8 @@ pub enum Feature {
     Lz4,
     /// Zstd compression and decompression
     Zstd,
+    /// AES encryption and decryption of modules
+    Encryption,
 }
 
 /// Errors generated by this crate
@@ -1,7 +1,10 @@
+use std::sync::Arc;
+
 use polars_parquet_format::{ColumnChunk, ColumnMetaData, Encoding};
 
 use super::column_descriptor::ColumnDescriptor;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -36,6 +39,10 @@ pub struct ColumnChunkMetadata {
     )]
     column_chunk: ColumnChunk,
     column_descr: ColumnDescriptor,
+    /// Decrypts the pages of an encrypted column chunk. This is not serialized, the metadata of
+    /// encrypted files must be read again to read them.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 #[cfg(feature = "serde")]
@@ -76,6 +83,7 @@ impl ColumnChunkMetadata {
         Self {
             column_chunk,
             column_descr,
+            decryptor: None,
         }
     }
 
@@ -102,6 +110,16 @@ impl ColumnChunkMetadata {
         self.column_chunk.meta_data.as_ref().unwrap()
     }
 
+    /// Whether the column chunk is encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto_metadata.is_some()
+    }
+
+    /// The decryptor of the pages of this column chunk, if it is encrypted.
+    pub fn decryptor(&self) -> Option<&Arc<ColumnDecryptor>> {
+        self.decryptor.as_ref()
+    }
+
     /// The [`ColumnDescriptor`] for this column. This descriptor contains the physical and logical type
     /// of the pages.
     pub fn descriptor(&self) -> &ColumnDescriptor {
@@ -172,10 +190,27 @@ impl ColumnChunkMetadata {
         column_metadata_byte_range(self.metadata())
     }
 
+    /// Returns the byte range of the column index of this column chunk, if any.
+    pub fn column_index_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.column_index_offset,
+            self.column_chunk.column_index_length,
+        )
+    }
+
+    /// Returns the byte range of the offset index of this column chunk, if any.
+    pub fn offset_index_range(&self) -> Option<core::ops::Range<u64>> {
+        index_byte_range(
+            self.column_chunk.offset_index_offset,
+            self.column_chunk.offset_index_length,
+        )
+    }
+
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         column_descr: ColumnDescriptor,
         column_chunk: ColumnChunk,
+        decryptor: Option<Arc<ColumnDecryptor>>,
     ) -> ParquetResult<Self> {
         // validate metadata
         if let Some(meta) = &column_chunk.meta_data {
@@ -194,6 +229,7 @@ impl ColumnChunkMetadata {
         Ok(Self {
             column_chunk,
             column_descr,
+            decryptor,
         })
     }
 
@@ -214,3 +250,9 @@ pub(super) fn column_metadata_byte_range(
     let len = column_metadata.total_compressed_size as u64;
     offset..offset.checked_add(len).unwrap()
 }
+
+fn index_byte_range(offset: Option<i64>, length: Option<i32>) -> Option<core::ops::Range<u64>> {
+    let offset = u64::try_from(offset?).ok()?;
+    let length = u64::try_from(length?).ok()?;
+    Some(offset..offset.checked_add(length)?)
+}
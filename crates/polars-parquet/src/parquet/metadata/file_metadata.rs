@@ -3,6 +3,7 @@ use polars_parquet_format::ColumnOrder as TColumnOrder;
 use super::RowGroupMetadata;
 use super::column_order::ColumnOrder;
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::ParquetError;
 use crate::parquet::metadata::get_sort_order;
 pub use crate::parquet::thrift_format::KeyValue;
@@ -66,6 +67,14 @@ impl FileMetadata {
     /// Deserializes [`crate::parquet::thrift_format::FileMetadata`] into this struct
     pub fn try_from_thrift(
         metadata: polars_parquet_format::FileMetaData,
+    ) -> Result<Self, ParquetError> {
+        Self::try_from_thrift_with_decryptor(metadata, None)
+    }
+
+    /// Deserializes the metadata of an encrypted file, decrypting the metadata of its columns.
+    pub(crate) fn try_from_thrift_with_decryptor(
+        metadata: polars_parquet_format::FileMetaData,
+        decryptor: Option<&FileDecryptor>,
     ) -> Result<Self, ParquetError> {
         let schema_descr = SchemaDescriptor::try_from_thrift(&metadata.schema)?;
 
@@ -74,8 +83,11 @@ impl FileMetadata {
         let row_groups = metadata
             .row_groups
             .into_iter()
-            .map(|rg| {
-                let md = RowGroupMetadata::try_from_thrift(&schema_descr, rg)?;
+            .enumerate()
+            .map(|(i, rg)| {
+                // SPEC: the AAD of a module contains the ordinal of its row group.
+                let ordinal = rg.ordinal.map_or(i, |ordinal| ordinal as usize);
+                let md = RowGroupMetadata::try_from_thrift(&schema_descr, rg, ordinal, decryptor)?;
                 max_row_group_height = max_row_group_height.max(md.num_rows());
                 Ok(md)
             })
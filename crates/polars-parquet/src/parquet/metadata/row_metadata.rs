@@ -9,6 +9,7 @@ use polars_utils::unitvec;
 
 use super::column_chunk_metadata::{ColumnChunkMetadata, column_metadata_byte_range};
 use super::schema_descriptor::SchemaDescriptor;
+use crate::parquet::encryption::FileDecryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 type ColumnLookup = PlHashMap<PlSmallStr, UnitVec<usize>>;
@@ -105,7 +106,9 @@ impl RowGroupMetadata {
     /// Method to convert from Thrift.
     pub(crate) fn try_from_thrift(
         schema_descr: &SchemaDescriptor,
-        rg: RowGroup,
+        mut rg: RowGroup,
+        row_group_ordinal: usize,
+        decryptor: Option<&FileDecryptor>,
     ) -> ParquetResult<RowGroupMetadata> {
         if schema_descr.columns().len() != rg.columns.len() {
             return Err(ParquetError::oos(format!(
@@ -114,6 +117,20 @@ impl RowGroupMetadata {
                 schema_descr.columns().len()
             )));
         }
+
+        // The column metadata of encrypted columns may itself be encrypted.
+        let decryptors = match decryptor {
+            Some(decryptor) => rg
+                .columns
+                .iter_mut()
+                .enumerate()
+                .map(|(i, column_chunk)| {
+                    decryptor.decrypt_column_chunk(row_group_ordinal, i, column_chunk)
+                })
+                .collect::<ParquetResult<Vec<_>>>()?,
+            None => vec![None; rg.columns.len()],
+        };
+
         let total_byte_size = rg.total_byte_size.try_into()?;
         let num_rows = rg.num_rows.try_into()?;
 
@@ -133,10 +150,14 @@ impl RowGroupMetadata {
             .columns
             .into_iter()
             .zip(schema_descr.columns())
+            .zip(decryptors)
             .enumerate()
-            .map(|(i, (column_chunk, descriptor))| {
-                let column =
-                    ColumnChunkMetadata::try_from_thrift(descriptor.clone(), column_chunk)?;
+            .map(|(i, ((column_chunk, descriptor), decryptor))| {
+                let column = ColumnChunkMetadata::try_from_thrift(
+                    descriptor.clone(),
+                    column_chunk,
+                    decryptor,
+                )?;
 
                 column_lookup.add_column(i, &column);
 
@@ -0,0 +1,20 @@
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::ParquetResult;
+
+/// Deserializes the [`ColumnIndex`] of a column chunk from the bytes at its
+/// [`column_index_range`](crate::parquet::metadata::ColumnChunkMetadata::column_index_range).
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = bytes.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes the [`OffsetIndex`] of a column chunk from the bytes at its
+/// [`offset_index_range`](crate::parquet::metadata::ColumnChunkMetadata::offset_index_range).
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = bytes.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
@@ -1,11 +1,14 @@
 use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
-use polars_parquet_format::FileMetaData as TFileMetadata;
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{FileCryptoMetaData, FileMetaData as TFileMetadata};
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use super::super::{
+    DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC,
+};
+use crate::parquet::encryption::{FileDecryptionProperties, FileDecryptor};
 use crate::parquet::error::{ParquetError, ParquetResult};
 
 pub(super) fn metadata_len(buffer: &[u8], len: usize) -> u32 {
@@ -33,10 +36,28 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> ParquetResult<FileMetada
     read_metadata_with_size(reader, file_size)
 }
 
+/// Reads a [`FileMetadata`] of a file that may be encrypted from the reader, located at the end
+/// of the file.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    read_footer(reader, file_size, decryption)
+}
+
 /// Reads a [`FileMetadata`] from the reader, located at the end of the file, with known file size.
 pub fn read_metadata_with_size<R: Read + Seek>(
     reader: &mut R,
     file_size: u64,
+) -> ParquetResult<FileMetadata> {
+    read_footer(reader, file_size, None)
+}
+
+fn read_footer<R: Read + Seek>(
+    reader: &mut R,
+    file_size: u64,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> ParquetResult<FileMetadata> {
     if file_size < HEADER_SIZE + FOOTER_SIZE {
         return Err(ParquetError::oos(
@@ -55,8 +76,8 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         .read_to_end(&mut buffer)?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
+    if !is_parquet_magic(&buffer[default_end_len - 4..]) {
+        return Err(ParquetError::oos("The file must end with PAR1 or PARE"));
     }
 
     let metadata_len: u32 = metadata_len(&buffer, default_end_len);
@@ -84,10 +105,66 @@ pub fn read_metadata_with_size<R: Read + Seek>(
         &buffer
     };
 
+    deserialize_footer(reader, decryption)
+}
+
+/// Whether `magic` is the magic of a plaintext or an encrypted Parquet file.
+pub fn is_parquet_magic(magic: &[u8]) -> bool {
+    magic == PARQUET_MAGIC || magic == PARQUET_ENCRYPTED_MAGIC
+}
+
+/// Parses the footer of a file that may be encrypted, i.e. the metadata followed by its length
+/// and the magic.
+pub fn deserialize_footer(
+    footer: &[u8],
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let Some(metadata_end) = footer.len().checked_sub(FOOTER_SIZE as usize) else {
+        return Err(ParquetError::oos("The footer is too short"));
+    };
+    let (metadata, footer) = footer.split_at(metadata_end);
+    let magic = &footer[4..];
     // a highly nested but sparse struct could result in many allocations
-    let max_size = reader.len() * 2 + 1024;
+    let max_size = metadata.len() * 2 + 1024;
+
+    if magic == PARQUET_ENCRYPTED_MAGIC {
+        let mut reader = metadata;
+        let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+        let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+
+        let decryptor = FileDecryptor::try_new(
+            &crypto_metadata.encryption_algorithm,
+            crypto_metadata.key_metadata.as_deref(),
+            decryption,
+        )?;
+        let metadata = decryptor.decrypt_footer(reader)?;
+
+        let mut prot = TCompactInputProtocol::new(metadata.as_slice(), max_size);
+        let metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+        return FileMetadata::try_from_thrift_with_decryptor(metadata, Some(&decryptor));
+    }
 
-    deserialize_metadata(reader, max_size)
+    let mut reader = metadata;
+    let mut prot = TCompactInputProtocol::new(&mut reader, max_size);
+    let thrift_metadata = TFileMetadata::read_from_in_protocol(&mut prot)?;
+
+    match &thrift_metadata.encryption_algorithm {
+        // A plaintext footer of an encrypted file, followed by its signature.
+        Some(algorithm) => {
+            let decryptor = FileDecryptor::try_new(
+                algorithm,
+                thrift_metadata.footer_signing_key_metadata.as_deref(),
+                decryption,
+            )?;
+            let signature = reader;
+            decryptor.verify_plaintext_footer(
+                &metadata[..metadata.len() - signature.len()],
+                signature,
+            )?;
+            FileMetadata::try_from_thrift_with_decryptor(thrift_metadata, Some(&decryptor))
+        },
+        None => FileMetadata::try_from_thrift(thrift_metadata),
+    }
 }
 
 /// Parse loaded metadata bytes
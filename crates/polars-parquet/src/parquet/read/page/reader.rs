@@ -1,5 +1,5 @@
 use std::io::Seek;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_utils::mmap::{MemReader, MemSlice};
@@ -7,6 +7,7 @@ use polars_utils::mmap::{MemReader, MemSlice};
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnDecryptor, ModuleType, module_len};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -26,6 +27,8 @@ pub struct PageMetaData {
     pub compression: Compression,
     /// The descriptor of this parquet column
     pub descriptor: Descriptor,
+    /// The decryptor of the pages, if the column chunk is encrypted
+    pub decryptor: Option<Arc<ColumnDecryptor>>,
 }
 
 impl PageMetaData {
@@ -41,6 +44,7 @@ impl PageMetaData {
             num_values,
             compression,
             descriptor,
+            decryptor: None,
         }
     }
 }
@@ -52,6 +56,7 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
             num_values: column.num_values(),
             compression: column.compression(),
             descriptor: column.descriptor().descriptor.clone(),
+            decryptor: column.decryptor().cloned(),
         }
     }
 }
@@ -81,6 +86,12 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Decrypts the pages of encrypted column chunks.
+    decryptor: Option<Arc<ColumnDecryptor>>,
+
+    // The number of pages read so far, whose ordinal is part of the AAD of encrypted pages.
+    num_pages_read: usize,
 }
 
 impl PageReader {
@@ -114,6 +125,8 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            decryptor: reader_meta.decryptor,
+            num_pages_read: 0,
         }
     }
 
@@ -134,10 +147,20 @@ impl PageReader {
             return Ok(None);
         }
 
+        // The header of an encrypted page can only be decrypted if it is known whether it is a
+        // dictionary page.
+        if self
+            .decryptor
+            .as_ref()
+            .is_some_and(|d| self.num_pages_read > 0 || !d.has_dictionary_page())
+        {
+            return Ok(None);
+        }
+
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
-        let page_header = read_page_header(&mut self.reader, self.max_page_size)?;
+        let page_header = self.read_page_header()?;
         let page_type = page_header.type_.try_into()?;
 
         if !matches!(page_type, PageType::DictionaryPage) {
@@ -146,6 +169,59 @@ impl PageReader {
             return Ok(None);
         }
 
+        let buffer = self.read_page(&page_header)?;
+
+        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
+            if let CompressedPage::Dict(d) = p {
+                Some(d)
+            } else {
+                unreachable!()
+            }
+        })
+    }
+}
+
+impl PageReader {
+    /// Whether the next page is the dictionary page of an encrypted column chunk.
+    fn is_encrypted_dict_page(&self, decryptor: &ColumnDecryptor) -> bool {
+        self.num_pages_read == 0 && decryptor.has_dictionary_page()
+    }
+
+    /// The ordinal of the next data page of an encrypted column chunk.
+    fn data_page_ordinal(&self, decryptor: &ColumnDecryptor) -> usize {
+        self.num_pages_read - decryptor.has_dictionary_page() as usize
+    }
+
+    fn read_page_header(&mut self) -> ParquetResult<ParquetPageHeader> {
+        let Some(decryptor) = &self.decryptor else {
+            return read_page_header(&mut self.reader, self.max_page_size);
+        };
+
+        let (module_type, page_ordinal) = if self.is_encrypted_dict_page(decryptor) {
+            (ModuleType::DictionaryPageHeader, None)
+        } else {
+            (
+                ModuleType::DataPageHeader,
+                Some(self.data_page_ordinal(decryptor)),
+            )
+        };
+
+        let position = self.reader.position();
+        let read_size = module_len(&self.reader.read_slice(4))?;
+        if read_size > self.max_page_size {
+            return Err(ParquetError::WouldOverAllocate);
+        }
+        self.reader
+            .seek(std::io::SeekFrom::Start(position as u64))?;
+        let module = self.reader.read_slice(read_size);
+
+        let header = decryptor.decrypt(module_type, page_ordinal, &module)?;
+        let mut prot = TCompactInputProtocol::new(header.as_slice(), self.max_page_size);
+        Ok(ParquetPageHeader::read_from_in_protocol(&mut prot)?)
+    }
+
+    /// Reads (and decrypts) the page of `page_header`.
+    fn read_page(&mut self, page_header: &ParquetPageHeader) -> ParquetResult<MemSlice> {
         let read_size: usize = page_header.compressed_page_size.try_into()?;
 
         if read_size > self.max_page_size {
@@ -160,13 +236,22 @@ impl PageReader {
             ));
         }
 
-        finish_page(page_header, buffer, self.compression, &self.descriptor).map(|p| {
-            if let CompressedPage::Dict(d) = p {
-                Some(d)
-            } else {
-                unreachable!()
-            }
-        })
+        let Some(decryptor) = &self.decryptor else {
+            self.num_pages_read += 1;
+            return Ok(buffer);
+        };
+
+        let (module_type, page_ordinal) = if self.is_encrypted_dict_page(decryptor) {
+            (ModuleType::DictionaryPage, None)
+        } else {
+            (
+                ModuleType::DataPage,
+                Some(self.data_page_ordinal(decryptor)),
+            )
+        };
+        let page = decryptor.decrypt(module_type, page_ordinal, &buffer)?;
+        self.num_pages_read += 1;
+        Ok(MemSlice::from_vec(page))
     }
 }
 
@@ -210,23 +295,11 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
-    let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
+    let page_header = reader.read_page_header()?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
 
-    let read_size: usize = page_header.compressed_page_size.try_into()?;
-
-    if read_size > reader.max_page_size {
-        return Err(ParquetError::WouldOverAllocate);
-    }
-
-    let buffer = reader.reader.read_slice(read_size);
-
-    if buffer.len() != read_size {
-        return Err(ParquetError::oos(
-            "The page header reported the wrong page size",
-        ));
-    }
+    let buffer = reader.read_page(&page_header)?;
 
     finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some)
 }
@@ -30,6 +30,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     max_header_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + 'a> {
     let page_metadata: PageMetaData = column_metadata.into();
+    check_not_encrypted(&page_metadata)?;
     Ok(_get_page_stream(
         reader,
         page_metadata.num_values,
@@ -47,6 +48,7 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     scratch: Vec<u8>,
     max_page_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + '_> {
+    check_not_encrypted(&page_metadata)?;
     let column_start = page_metadata.column_start;
     reader.seek(SeekFrom::Start(column_start)).await?;
     Ok(_get_page_stream(
@@ -59,6 +61,15 @@ pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + Async
     ))
 }
 
+fn check_not_encrypted(page_metadata: &PageMetaData) -> ParquetResult<()> {
+    if page_metadata.decryptor.is_some() {
+        return Err(ParquetError::not_supported(
+            "reading encrypted column chunks as a stream",
+        ));
+    }
+    Ok(())
+}
+
 fn _get_page_stream<R: AsyncRead + Unpin + Send>(
     reader: &mut R,
     total_num_values: i64,
@@ -16,6 +16,7 @@ use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -25,6 +26,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<&ColumnEncryptor>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,14 +38,34 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut num_data_pages = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let spec = write_page(writer, offset, compressed_page, encryptor, num_data_pages)?;
+        num_data_pages += matches!(compressed_page, CompressedPage::Data(_)) as usize;
         offset += spec.bytes_written;
         specs.push(spec);
     }
     let mut bytes_written = offset - initial;
 
-    let column_chunk = build_column_chunk(&specs, descriptor)?;
+    let mut column_chunk = build_column_chunk(&specs, descriptor)?;
+
+    if encryptor.is_some() {
+        // Readers of encrypted columns need to know whether the first page is a dictionary page
+        // to decrypt it.
+        let metadata = column_chunk.meta_data.as_mut().unwrap();
+        match specs.as_slice() {
+            [dict, data, ..]
+                if dict.header.type_ == polars_parquet_format::PageType::DICTIONARY_PAGE =>
+            {
+                metadata.dictionary_page_offset = Some(dict.offset as i64);
+                metadata.data_page_offset = data.offset as i64;
+            },
+            _ => {},
+        }
+
+        // The column metadata of encrypted columns is only written to the footer.
+        return Ok((column_chunk, specs, bytes_written));
+    }
 
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
@@ -7,17 +7,53 @@ use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    ColumnEncryptor, FileEncryptionProperties, FileEncryptor, ModuleType,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_ENCRYPTED_MAGIC, PARQUET_MAGIC};
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
     Ok(PARQUET_MAGIC.len() as u64)
 }
 
+/// Writes the footer of an encrypted file, returned by [`FileEncryptor::encrypt_footer`].
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    footer: &[u8],
+    magic: &[u8; 4],
+) -> ParquetResult<u64> {
+    let footer_len: u32 = footer
+        .len()
+        .try_into()
+        .map_err(|_| ParquetError::oos("The footer can be at most u32::MAX bytes"))?;
+    writer.write_all(footer)?;
+    writer.write_all(&footer_len.to_le_bytes())?;
+    writer.write_all(magic)?;
+    writer.flush()?;
+    Ok(footer.len() as u64 + FOOTER_SIZE)
+}
+
+/// Writes an index with `write`, encrypting it if its column is encrypted.
+fn write_index<W: Write>(
+    writer: &mut W,
+    encryptor: Option<ColumnEncryptor>,
+    module_type: ModuleType,
+    write: impl FnOnce(&mut Vec<u8>) -> ParquetResult<u64>,
+) -> ParquetResult<u64> {
+    let mut buffer = vec![];
+    write(&mut buffer)?;
+    if let Some(encryptor) = encryptor {
+        buffer = encryptor.encrypt(module_type, None, &buffer)?;
+    }
+    writer.write_all(&buffer)?;
+    Ok(buffer.len() as u64)
+}
+
 pub(super) fn end_file<W: Write>(
     mut writer: &mut W,
     metadata: &ThriftFileMetadata,
@@ -39,6 +75,17 @@ pub(super) fn end_file<W: Write>(
     Ok(metadata_len as u64 + FOOTER_SIZE)
 }
 
+fn column_encryptor(
+    encryptor: Option<&FileEncryptor>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+) -> ParquetResult<Option<ColumnEncryptor>> {
+    encryptor
+        .map(|encryptor| encryptor.column_encryptor(row_group_ordinal, column_ordinal))
+        .transpose()
+        .map(Option::flatten)
+}
+
 fn create_column_orders(schema_desc: &SchemaDescriptor) -> Vec<polars_parquet_format::ColumnOrder> {
     // We only include ColumnOrder for leaf nodes.
     // Currently only supported ColumnOrder is TypeDefinedOrder so we set this
@@ -66,6 +113,12 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// Bitsets of the bloom filters of the column chunks of every row group, which are written
+    /// when the file ends.
+    #[cfg(feature = "bloom_filter")]
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
+    /// Encrypts the file, if set with [`FileWriter::with_encryption`].
+    encryptor: Option<FileEncryptor>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
@@ -125,11 +178,29 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            #[cfg(feature = "bloom_filter")]
+            bloom_filters: vec![],
+            encryptor: None,
             state: State::Initialised,
             metadata: None,
         }
     }
 
+    /// Encrypts the file with the [Parquet modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+    ///
+    /// # Errors
+    /// Returns an error if data has been written to the file, or if the properties are invalid
+    /// for the schema of the file.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        if self.offset != 0 {
+            return Err(ParquetError::InvalidParameter(
+                "Encryption must be set before writing to the file".to_string(),
+            ));
+        }
+        self.encryptor = Some(FileEncryptor::try_new(properties, &self.schema)?);
+        Ok(self)
+    }
+
     /// Writes the header of the file.
     ///
     /// This is automatically called by [`Self::write`] if not called following [`Self::new`].
@@ -138,7 +209,13 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = match &self.encryptor {
+                Some(encryptor) if encryptor.is_footer_encrypted() => {
+                    self.writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+                    PARQUET_ENCRYPTED_MAGIC.len() as u64
+                },
+                _ => start_file(&mut self.writer)?,
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -166,10 +243,39 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        #[cfg(feature = "bloom_filter")]
+        self.bloom_filters.push(vec![]);
+        Ok(())
+    }
+
+    /// Writes a row group to the file, together with the bitsets of the bloom filters of its
+    /// columns. `bloom_filters` has an entry for every column, or is empty if no column has a
+    /// bloom filter.
+    ///
+    /// This call is IO-bounded
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        if !bloom_filters.is_empty() && bloom_filters.len() != self.schema.columns().len() {
+            return Err(ParquetError::InvalidParameter(
+                "The number of bloom filters must equal the number of columns".to_string(),
+            ));
+        }
+
+        self.write(row_group)?;
+        *self.bloom_filters.last_mut().unwrap() = bloom_filters;
         Ok(())
     }
 
@@ -188,22 +294,70 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        // write bloom filters
+        #[cfg(feature = "bloom_filter")]
+        self.row_groups
+            .iter_mut()
+            .zip(self.bloom_filters.iter())
+            .enumerate()
+            .try_for_each(|(row_group_ordinal, (group, bloom_filters))| {
+                group
+                    .columns
+                    .iter_mut()
+                    .zip(bloom_filters.iter())
+                    .enumerate()
+                    .filter_map(|(i, (column, bitset))| Some((i, column, bitset.as_ref()?)))
+                    .try_for_each(|(column_ordinal, column, bitset)| {
+                        if column_encryptor(
+                            self.encryptor.as_ref(),
+                            row_group_ordinal,
+                            column_ordinal,
+                        )?
+                        .is_some()
+                        {
+                            return Err(ParquetError::not_supported(
+                                "bloom filters of encrypted columns",
+                            ));
+                        }
+                        let offset = self.offset;
+                        let length = crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+                        self.offset += length;
+                        let metadata = column.meta_data.as_mut().unwrap();
+                        metadata.bloom_filter_offset = Some(offset as i64);
+                        metadata.bloom_filter_length = Some(length.try_into()?);
+                        ParquetResult::Ok(())
+                    })
+            })?;
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
                 .iter_mut()
                 .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
+                .enumerate()
+                .try_for_each(|(row_group_ordinal, (group, pages))| {
+                    group
+                        .columns
+                        .iter_mut()
+                        .zip(pages.iter())
+                        .enumerate()
+                        .try_for_each(|(column_ordinal, (column, pages))| {
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
+                            self.offset += write_index(
+                                &mut self.writer,
+                                column_encryptor(
+                                    self.encryptor.as_ref(),
+                                    row_group_ordinal,
+                                    column_ordinal,
+                                )?,
+                                ModuleType::ColumnIndex,
+                                |buffer| write_column_index(buffer, pages),
+                            )?;
                             let length = self.offset - offset;
                             column.column_index_length = Some(length as i32);
                             ParquetResult::Ok(())
-                        },
-                    )?;
+                        })?;
                     ParquetResult::Ok(())
                 })?;
         };
@@ -212,15 +366,26 @@ impl<W: Write> FileWriter<W> {
         self.row_groups
             .iter_mut()
             .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
+            .enumerate()
+            .try_for_each(|(row_group_ordinal, (group, pages))| {
                 group
                     .columns
                     .iter_mut()
                     .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
+                    .enumerate()
+                    .try_for_each(|(column_ordinal, (column, pages))| {
                         let offset = self.offset;
                         column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
+                        self.offset += write_index(
+                            &mut self.writer,
+                            column_encryptor(
+                                self.encryptor.as_ref(),
+                                row_group_ordinal,
+                                column_ordinal,
+                            )?,
+                            ModuleType::OffsetIndex,
+                            |buffer| write_offset_index(buffer, pages),
+                        )?;
                         column.offset_index_length = Some((self.offset - offset) as i32);
                         ParquetResult::Ok(())
                     })?;
@@ -239,7 +404,22 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => {
+                let mut footer_metadata = metadata.clone();
+                for (ordinal, row_group) in footer_metadata.row_groups.iter_mut().enumerate() {
+                    encryptor.encrypt_column_chunks(ordinal, &mut row_group.columns)?;
+                }
+                let footer = encryptor.encrypt_footer(&footer_metadata)?;
+                let magic = if encryptor.is_footer_encrypted() {
+                    &PARQUET_ENCRYPTED_MAGIC
+                } else {
+                    &PARQUET_MAGIC
+                };
+                end_encrypted_file(&mut self.writer, &footer, magic)?
+            },
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -8,6 +8,7 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnEncryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -48,33 +49,60 @@ pub struct PageWriteSpec {
     pub statistics: Option<Statistics>,
 }
 
+/// Writes a page, encrypting it with `encryptor` if given. `page_ordinal` is the ordinal of the
+/// page amongst the data pages of the column chunk, which is part of the AAD of encrypted pages.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    encryptor: Option<&ColumnEncryptor>,
+    page_ordinal: usize,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer: &[u8] = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
+    let (header_size, bytes_written) = if let Some(encryptor) = encryptor {
+        let (page_type, header_type, page_ordinal) = match &compressed_page {
+            CompressedPage::Data(_) => (
+                ModuleType::DataPage,
+                ModuleType::DataPageHeader,
+                Some(page_ordinal),
+            ),
+            CompressedPage::Dict(_) => (
+                ModuleType::DictionaryPage,
+                ModuleType::DictionaryPageHeader,
+                None,
+            ),
+        };
+
+        // SPEC: the compressed page size of an encrypted page is the size of its module.
+        let page = encryptor.encrypt(page_type, page_ordinal, buffer)?;
+        (_, header.compressed_page_size) = maybe_bytes(0, page.len())?;
+
+        let mut plaintext_header = vec![];
+        write_page_header(&mut plaintext_header, &header)?;
+        let encrypted_header = encryptor.encrypt(header_type, page_ordinal, &plaintext_header)?;
+
+        writer.write_all(&encrypted_header)?;
+        writer.write_all(&page)?;
+        let header_size = encrypted_header.len() as u64;
+        (header_size, header_size + page.len() as u64)
+    } else {
+        let header_size = write_page_header(writer, &header)?;
+        writer.write_all(buffer)?;
+        (header_size, header_size + buffer.len() as u64)
     };
 
     let statistics = match &compressed_page {
@@ -102,8 +102,14 @@ impl DslPlan {
                 scratch.push(input);
                 scratch.extend(contexts);
             },
+            RecursiveUnion {
+                anchor, recursive, ..
+            } => {
+                scratch.push(anchor);
+                scratch.push(recursive);
+            },
             IR { dsl, .. } => scratch.push(dsl),
-            Scan { .. } | DataFrameScan { .. } => (),
+            Scan { .. } | DataFrameScan { .. } | WorkTableScan { .. } => (),
             #[cfg(feature = "python")]
             PythonScan { .. } => (),
             #[cfg(feature = "merge_sorted")]
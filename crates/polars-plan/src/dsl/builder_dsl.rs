@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 
@@ -32,6 +36,15 @@ impl DslBuilder {
                 "anonymous scan requires schema to be specified in unified_scan_args"
             )
         })?;
+        // An anonymous scan has no files to take paths or hive partitions from.
+        polars_ensure!(
+            unified_scan_args.include_file_paths.is_none(),
+            InvalidOperation: "anonymous scan does not support 'include_file_paths'"
+        );
+        polars_ensure!(
+            unified_scan_args.hive_options.enabled != Some(true),
+            InvalidOperation: "anonymous scan does not support hive partitioning"
+        );
 
         Ok(DslPlan::Scan {
             sources: ScanSources::default(),
@@ -82,6 +95,36 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
+    #[cfg(feature = "orc")]
+    pub fn scan_orc(
+        sources: ScanSources,
+        options: OrcScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Orc { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
@@ -215,12 +258,14 @@ impl DslBuilder {
         .into()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn group_by<E: AsRef<[Expr]>>(
         self,
         keys: Vec<Expr>,
         aggs: E,
         apply: Option<(PlanCallback<DataFrame, DataFrame>, SchemaRef)>,
         maintain_order: bool,
+        grouping_sets: Option<GroupingSetsOptions>,
         #[cfg(feature = "dynamic_group_by")] dynamic_options: Option<DynamicGroupOptions>,
         #[cfg(feature = "dynamic_group_by")] rolling_options: Option<RollingGroupOptions>,
     ) -> Self {
@@ -230,6 +275,7 @@ impl DslBuilder {
             dynamic: dynamic_options,
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
+            grouping_sets,
             slice: None,
         };
 
@@ -1,9 +1,7 @@
 use std::sync::Arc;
 
-use polars_core::prelude::PlIndexMap;
-
-// Note, there are a lot of single variant enums here, but the intention is that we'll support
-// Delta deletion vectors as well at some point in the future.
+use polars_core::prelude::{PlHashSet, PlIndexMap};
+use polars_utils::pl_str::PlSmallStr;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -20,6 +18,82 @@ pub enum DeletionFilesList {
     //
     /// Iceberg positional deletes
     IcebergPositionDelete(Arc<PlIndexMap<usize, Arc<[String]>>>),
+
+    /// Iceberg equality deletes. Writers that produce equality deletes (e.g. Flink upserts) also
+    /// produce positional deletes, so these are carried alongside.
+    IcebergEqualityDelete {
+        position_deletes: Arc<PlIndexMap<usize, Arc<[String]>>>,
+        equality_deletes: Arc<PlIndexMap<usize, Arc<[IcebergEqualityDeleteFile]>>>,
+    },
+
+    /// Delta deletion vectors, a data file has at most one.
+    DeltaDeletionVector {
+        /// Used to resolve the paths of deletion vectors stored relative to the table.
+        table_root: PlSmallStr,
+        vectors: Arc<PlIndexMap<usize, DeltaDeletionVectorDescriptor>>,
+    },
+}
+
+/// An Iceberg equality delete file that applies to a data file.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct IcebergEqualityDeleteFile {
+    pub path: String,
+    /// Field IDs of the columns used to match deleted rows.
+    pub equality_ids: Arc<[u32]>,
+}
+
+/// Location of a Delta deletion vector, as given by the `storageType` of its descriptor.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeltaDeletionVectorStorageType {
+    /// `u`: Stored in a file relative to the table root, identified by a UUID.
+    RelativePath,
+    /// `i`: Stored inline in the log.
+    Inline,
+    /// `p`: Stored in a file at an absolute path.
+    AbsolutePath,
+}
+
+impl DeltaDeletionVectorStorageType {
+    pub fn from_log_value(value: &str) -> Option<Self> {
+        use DeltaDeletionVectorStorageType::*;
+
+        Some(match value {
+            "u" => RelativePath,
+            "i" => Inline,
+            "p" => AbsolutePath,
+            _ => return None,
+        })
+    }
+
+    pub fn to_log_value(self) -> &'static str {
+        use DeltaDeletionVectorStorageType::*;
+
+        match self {
+            RelativePath => "u",
+            Inline => "i",
+            AbsolutePath => "p",
+        }
+    }
+}
+
+/// A Delta deletion vector descriptor, with the fields of the `deletionVector` of an add action.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DeltaDeletionVectorDescriptor {
+    pub storage_type: DeltaDeletionVectorStorageType,
+    /// Encoded path or inline data, depending on the storage type.
+    pub path_or_inline_dv: PlSmallStr,
+    /// Position of the deletion vector in its file. Not set for inline deletion vectors.
+    pub offset: Option<usize>,
+    /// Size of the serialized deletion vector.
+    pub size_in_bytes: usize,
+    /// Number of deleted rows.
+    pub cardinality: usize,
 }
 
 impl DeletionFilesList {
@@ -31,6 +105,27 @@ impl DeletionFilesList {
             Some(IcebergPositionDelete(paths)) => {
                 (!paths.is_empty()).then_some(IcebergPositionDelete(paths))
             },
+            Some(IcebergEqualityDelete {
+                position_deletes,
+                equality_deletes,
+            }) => {
+                if equality_deletes.is_empty() {
+                    (!position_deletes.is_empty())
+                        .then_some(IcebergPositionDelete(position_deletes))
+                } else {
+                    Some(IcebergEqualityDelete {
+                        position_deletes,
+                        equality_deletes,
+                    })
+                }
+            },
+            Some(DeltaDeletionVector {
+                table_root,
+                vectors,
+            }) => (!vectors.is_empty()).then_some(DeltaDeletionVector {
+                table_root,
+                vectors,
+            }),
             None => None,
         }
     }
@@ -40,6 +135,15 @@ impl DeletionFilesList {
 
         match self {
             IcebergPositionDelete(paths) => paths.len(),
+            IcebergEqualityDelete {
+                position_deletes,
+                equality_deletes,
+            } => position_deletes
+                .keys()
+                .chain(equality_deletes.keys())
+                .collect::<PlHashSet<_>>()
+                .len(),
+            DeltaDeletionVector { vectors, .. } => vectors.len(),
         }
     }
 }
@@ -58,6 +162,16 @@ impl std::hash::Hash for DeletionFilesList {
 
                 addr.hash(state)
             },
+            IcebergEqualityDelete {
+                position_deletes,
+                equality_deletes,
+            } => {
+                (Arc::as_ptr(position_deletes) as *const () as usize).hash(state);
+                (Arc::as_ptr(equality_deletes) as *const () as usize).hash(state)
+            },
+            DeltaDeletionVector { vectors, .. } => {
+                (Arc::as_ptr(vectors) as *const () as usize).hash(state)
+            },
         }
     }
 }
@@ -71,6 +185,15 @@ impl std::fmt::Display for DeletionFilesList {
                 let s = if paths.len() == 1 { "" } else { "s" };
                 write!(f, "iceberg-position-delete: {} source{s}", paths.len())?;
             },
+            IcebergEqualityDelete { .. } => {
+                let n = self.num_files_with_deletions();
+                let s = if n == 1 { "" } else { "s" };
+                write!(f, "iceberg-equality-delete: {n} source{s}")?;
+            },
+            DeltaDeletionVector { vectors, .. } => {
+                let s = if vectors.len() == 1 { "" } else { "s" };
+                write!(f, "delta-deletion-vector: {} source{s}", vectors.len())?;
+            },
         }
 
         Ok(())
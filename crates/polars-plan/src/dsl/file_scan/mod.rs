@@ -4,11 +4,15 @@ use std::sync::Mutex;
 use deletion::DeletionFilesList;
 use polars_core::schema::iceberg::IcebergSchemaRef;
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
+#[cfg(feature = "avro")]
+use polars_io::avro::{AvroFileMetadata, AvroScanOptions};
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::{OrcFileMetadata, OrcScanOptions};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
@@ -47,12 +51,22 @@ pub enum FileScanDsl {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    /// A JSON document holding a top-level array of records.
+    #[cfg(feature = "json")]
+    Json { options: NDJsonReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet { options: ParquetOptions },
 
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
+    #[cfg(feature = "orc")]
+    Orc { options: OrcScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -77,6 +91,10 @@ pub enum FileScanIR {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    /// A JSON document holding a top-level array of records.
+    #[cfg(feature = "json")]
+    Json { options: NDJsonReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
@@ -91,6 +109,20 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro {
+        options: AvroScanOptions,
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+        metadata: Option<Arc<AvroFileMetadata>>,
+    },
+
+    #[cfg(feature = "orc")]
+    Orc {
+        options: OrcScanOptions,
+        #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
+        metadata: Option<Arc<OrcFileMetadata>>,
+    },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -116,6 +148,8 @@ impl FileScanIR {
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => ScanFlags::empty(),
+            #[cfg(feature = "json")]
+            Self::Json { .. } => ScanFlags::empty(),
             #[allow(unreachable_patterns)]
             _ => ScanFlags::empty(),
         }
@@ -144,6 +178,8 @@ impl FileScanIR {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "json")]
+            Self::Json { .. } => false,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -314,6 +350,11 @@ mod _file_scan_eq_hash {
             options: &'a crate::prelude::NDJsonReadOptions,
         },
 
+        #[cfg(feature = "json")]
+        Json {
+            options: &'a crate::prelude::NDJsonReadOptions,
+        },
+
         #[cfg(feature = "parquet")]
         Parquet {
             options: &'a polars_io::prelude::ParquetOptions,
@@ -326,6 +367,18 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro {
+            options: &'a polars_io::avro::AvroScanOptions,
+            metadata: Option<usize>,
+        },
+
+        #[cfg(feature = "orc")]
+        Orc {
+            options: &'a polars_io::orc::OrcScanOptions,
+            metadata: Option<usize>,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -351,6 +404,9 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { options } => FileScanEqHashWrap::NDJson { options },
 
+                #[cfg(feature = "json")]
+                FileScanIR::Json { options } => FileScanEqHashWrap::Json { options },
+
                 #[cfg(feature = "parquet")]
                 FileScanIR::Parquet { options, metadata } => FileScanEqHashWrap::Parquet {
                     options,
@@ -363,6 +419,18 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { options, metadata } => FileScanEqHashWrap::Avro {
+                    options,
+                    metadata: metadata.as_ref().map(arc_as_ptr),
+                },
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { options, metadata } => FileScanEqHashWrap::Orc {
+                    options,
+                    metadata: metadata.as_ref().map(arc_as_ptr),
+                },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
@@ -8,6 +8,8 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -289,10 +291,70 @@ pub struct GroupbyOptions {
     pub dynamic: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     pub rolling: Option<RollingGroupOptions>,
+    /// Group by multiple sets of the keys at once.
+    pub grouping_sets: Option<GroupingSetsOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
 
+/// The grouping sets of a group-by.
+///
+/// Every grouping set is aggregated as if the data were grouped by only the keys in that set. The
+/// keys that are not in a set are null in the groups of that set, and the groups of all sets are
+/// returned in the order of the sets.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct GroupingSetsOptions {
+    /// The indices of the group-by keys in each grouping set.
+    pub sets: Vec<Vec<usize>>,
+    /// Name of an optional `UInt32` column that identifies the grouping set of a group.
+    ///
+    /// Bit `i` (counting from the most significant bit) is set if the `i`-th key is not in the
+    /// grouping set, so the set with all keys has id 0.
+    pub grouping_id: Option<PlSmallStr>,
+}
+
+impl GroupingSetsOptions {
+    pub fn new(sets: Vec<Vec<usize>>) -> Self {
+        Self {
+            sets,
+            grouping_id: None,
+        }
+    }
+
+    /// The grouping sets of `ROLLUP` over `n_keys` keys: all keys, all keys but the last, and so
+    /// on down to the empty set.
+    pub fn rollup(n_keys: usize) -> Self {
+        Self::new((0..=n_keys).rev().map(|n| (0..n).collect()).collect())
+    }
+
+    /// The grouping sets of `CUBE` over `n_keys` keys: all subsets of the keys, in the order of
+    /// their grouping id.
+    pub fn cube(n_keys: usize) -> Self {
+        let sets = (0..1usize << n_keys)
+            .map(|id| {
+                (0..n_keys)
+                    .filter(|i| id & (1 << (n_keys - 1 - i)) == 0)
+                    .collect()
+            })
+            .collect();
+        Self::new(sets)
+    }
+
+    pub fn with_grouping_id(mut self, grouping_id: Option<PlSmallStr>) -> Self {
+        self.grouping_id = grouping_id;
+        self
+    }
+
+    /// The grouping id of the grouping set `set` of `n_keys` keys.
+    pub fn grouping_id_of(set: &[usize], n_keys: usize) -> u32 {
+        (0..n_keys)
+            .filter(|i| !set.contains(i))
+            .fold(0, |id, i| id | (1 << (n_keys - 1 - i)))
+    }
+}
+
 impl GroupbyOptions {
     pub(crate) fn is_rolling(&self) -> bool {
         #[cfg(feature = "dynamic_group_by")]
@@ -361,6 +423,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileType {
@@ -374,6 +438,8 @@ impl FileType {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::Json(_) => "jsonl",
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -423,6 +489,42 @@ impl From<UnionArgs> for UnionOptions {
     }
 }
 
+/// Options of a recursive union, which evaluates its recursive input until it produces no new
+/// rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct RecursiveUnionOptions {
+    /// The name of the work table the recursive input scans.
+    pub name: PlSmallStr,
+    /// Remove duplicate rows, and stop once an iteration adds no rows that were not seen before.
+    pub distinct: bool,
+    /// Raise an error if no fixed point is reached after this many iterations.
+    pub max_iterations: usize,
+}
+
+impl RecursiveUnionOptions {
+    pub const DEFAULT_MAX_ITERATIONS: usize = 1000;
+
+    pub fn new(name: PlSmallStr) -> Self {
+        Self {
+            name,
+            distinct: false,
+            max_iterations: Self::DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    pub fn with_distinct(mut self, distinct: bool) -> Self {
+        self.distinct = distinct;
+        self
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
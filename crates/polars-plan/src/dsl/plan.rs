@@ -148,6 +148,18 @@ pub enum DslPlan {
         input_right: Arc<DslPlan>,
         key: PlSmallStr,
     },
+    /// Union of `anchor` with the rows of `recursive`, which is evaluated again on the rows the
+    /// previous iteration added until it adds no new rows.
+    RecursiveUnion {
+        anchor: Arc<DslPlan>,
+        recursive: Arc<DslPlan>,
+        options: RecursiveUnionOptions,
+    },
+    /// The rows the enclosing `RecursiveUnion` with this name added in the previous iteration.
+    WorkTableScan {
+        name: PlSmallStr,
+        schema: SchemaRef,
+    },
     IR {
         // Keep the original Dsl around as we need that for serialization.
         dsl: Arc<DslPlan>,
@@ -187,6 +199,8 @@ impl Clone for DslPlan {
             Self::SinkMultiple { inputs } => Self::SinkMultiple { inputs: inputs.clone() },
             #[cfg(feature = "merge_sorted")]
             Self::MergeSorted { input_left, input_right, key } => Self::MergeSorted { input_left: input_left.clone(), input_right: input_right.clone(), key: key.clone() },
+            Self::RecursiveUnion { anchor, recursive, options } => Self::RecursiveUnion { anchor: anchor.clone(), recursive: recursive.clone(), options: options.clone() },
+            Self::WorkTableScan { name, schema } => Self::WorkTableScan { name: name.clone(), schema: schema.clone() },
             Self::IR {node, dsl, version} => Self::IR {node: *node, dsl: dsl.clone(), version: *version},
         }
     }
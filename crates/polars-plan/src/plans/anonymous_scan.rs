@@ -18,8 +18,9 @@ pub trait AnonymousScan: Send + Sync {
     /// Creates a DataFrame from the supplied function & scan options.
     fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame>;
 
-    /// Produce the next batch Polars can consume. Implement this method to get proper
-    /// streaming support.
+    /// Produce the next batch Polars can consume, or `None` once all data has been produced.
+    /// Implement this method together with [`AnonymousScan::allows_batched_scan`] to get
+    /// proper streaming support.
     fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
         self.scan(scan_opts).map(Some)
     }
@@ -47,6 +48,13 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if the streaming engine should call [`AnonymousScan::next_batch`] until it
+    /// returns `None`, instead of calling [`AnonymousScan::scan`] once.
+    ///
+    /// Defaults to `false`
+    fn allows_batched_scan(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn AnonymousScan {
@@ -278,6 +278,14 @@ impl<'a> IRBuilder<'a> {
         let current_schema = self.schema();
         let mut schema = expr_irs_to_schema(&keys, &current_schema, self.expr_arena);
 
+        if let Some(name) = options
+            .grouping_sets
+            .as_ref()
+            .and_then(|gs| gs.grouping_id.as_ref())
+        {
+            schema.with_column(name.clone(), DataType::UInt32);
+        }
+
         #[cfg(feature = "dynamic_group_by")]
         {
             if let Some(options) = options.rolling.as_ref() {
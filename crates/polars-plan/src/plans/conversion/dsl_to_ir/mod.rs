@@ -54,6 +54,7 @@ pub fn to_alp(
         pushdown_maintain_errors: optimizer::pushdown_maintain_errors(),
         verbose: verbose(),
         cache_id_for_arc_ptr: Default::default(),
+        work_tables: Default::default(),
     };
 
     match to_alp_impl(lp, &mut ctxt) {
@@ -449,6 +450,11 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             let input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(group_by)))?;
 
+            polars_ensure!(
+                apply.is_none() || options.grouping_sets.is_none(),
+                InvalidOperation: "cannot apply a function over the groups of a group-by with grouping sets"
+            );
+
             // Rolling + group-by sorts the whole table, so remove unneeded columns
             if ctxt.opt_flags.eager() && options.is_rolling() && !keys.is_empty() {
                 ctxt.opt_flags.insert(OptFlags::PROJECTION_PUSHDOWN)
@@ -1026,9 +1032,51 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 key,
             }
         },
+        DslPlan::RecursiveUnion {
+            anchor,
+            recursive,
+            options,
+        } => {
+            let anchor = to_alp_impl(owned(anchor), ctxt)
+                .map_err(|e| e.context(failed_here!(recursive_union)))?;
+            let schema = ctxt.lp_arena.get(anchor).schema(ctxt.lp_arena).into_owned();
+
+            let id = UniqueId::new();
+            ctxt.work_tables
+                .push((options.name.clone(), id, schema.clone()));
+            let recursive = to_alp_impl(owned(recursive), ctxt);
+            ctxt.work_tables.pop();
+            let recursive = recursive.map_err(|e| e.context(failed_here!(recursive_union)))?;
+            let recursive = match_recursive_to_anchor(recursive, &schema, ctxt)?;
+
+            IR::RecursiveUnion {
+                anchor,
+                recursive,
+                id,
+                options,
+            }
+        },
+        DslPlan::WorkTableScan { name, schema } => {
+            let id = match ctxt.work_tables.iter().rev().find(|(n, _, _)| *n == name) {
+                Some((_, id, anchor_schema)) => {
+                    polars_ensure!(
+                        schema == *anchor_schema,
+                        SchemaMismatch: "the schema of work table '{}' does not match the schema of the anchor of its recursive union", name
+                    );
+                    *id
+                },
+                // A scan outside of its recursive union can be resolved, e.g. to get its schema,
+                // but not executed.
+                None => UniqueId::new(),
+            };
+            IR::WorkTableScan { id, name, schema }
+        },
         DslPlan::IR { node, dsl, version } => {
+            // Work table scans that were converted outside of their recursive union are not
+            // resolved, so plans inside a recursive union are always converted again.
             return if node.is_some()
                 && version == ctxt.lp_arena.version()
+                && ctxt.work_tables.is_empty()
                 && ctxt.conversion_optimizer.used_arenas.insert(version)
             {
                 Ok(node.unwrap())
@@ -1040,6 +1088,54 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
     Ok(ctxt.lp_arena.add(v))
 }
 
+/// The rows of the recursive input of a recursive union are appended to the anchor by position, so
+/// its columns are renamed and cast to the schema of the anchor.
+fn match_recursive_to_anchor(
+    recursive: Node,
+    schema: &SchemaRef,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<Node> {
+    let recursive_schema = ctxt
+        .lp_arena
+        .get(recursive)
+        .schema(ctxt.lp_arena)
+        .into_owned();
+    if recursive_schema == *schema {
+        return Ok(recursive);
+    }
+    polars_ensure!(
+        recursive_schema.len() == schema.len(),
+        InvalidOperation: "the recursive input of a recursive union has {} columns, expected {} columns of the anchor",
+        recursive_schema.len(), schema.len()
+    );
+
+    let exprs = recursive_schema
+        .iter()
+        .zip(schema.iter())
+        .map(|((name, dtype), (anchor_name, anchor_dtype))| {
+            let expr = col(name.clone());
+            let expr = if dtype == anchor_dtype {
+                expr
+            } else {
+                expr.strict_cast(anchor_dtype.clone())
+            };
+            expr.alias(anchor_name.clone())
+        })
+        .collect();
+    let exprs = to_expr_irs(
+        exprs,
+        &mut ExprToIRContext::new_with_opt_eager(
+            ctxt.expr_arena,
+            &recursive_schema,
+            ctxt.opt_flags,
+        ),
+    )?;
+
+    Ok(IRBuilder::new(recursive, ctxt.expr_arena, ctxt.lp_arena)
+        .project(exprs, Default::default())
+        .node())
+}
+
 fn resolve_with_columns(
     exprs: Vec<Expr>,
     input: Node,
@@ -1079,33 +1175,72 @@ fn resolve_group_by(
     input: Node,
     keys: Vec<Expr>,
     aggs: Vec<Expr>,
-    _options: &GroupbyOptions,
+    options: &GroupbyOptions,
     lp_arena: &Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
     opt_flags: &mut OptFlags,
 ) -> PolarsResult<(Vec<ExprIR>, Vec<ExprIR>, SchemaRef)> {
     let input_schema = lp_arena.get(input).schema(lp_arena);
     let input_schema = input_schema.as_ref();
+    let n_keys = keys.len();
     let mut keys = rewrite_projections(keys, &PlHashSet::default(), input_schema, opt_flags)?;
 
     // Initialize schema from keys
     let mut output_schema = expressions_to_schema(&keys, input_schema)?;
     let mut key_names: PlHashSet<PlSmallStr> = output_schema.iter_names().cloned().collect();
 
+    // Add grouping id column
+    let grouping_id = options
+        .grouping_sets
+        .as_ref()
+        .and_then(|gs| gs.grouping_id.as_ref());
+    if let Some(grouping_sets) = options.grouping_sets.as_ref() {
+        polars_ensure!(
+            !options.is_rolling() && !options.is_dynamic(),
+            InvalidOperation: "grouping sets are not supported in rolling or dynamic group-bys"
+        );
+        // The grouping sets refer to the keys by index.
+        polars_ensure!(
+            keys.len() == n_keys,
+            InvalidOperation: "the keys of a group-by with grouping sets must not expand to multiple columns"
+        );
+        for set in &grouping_sets.sets {
+            let mut in_set = vec![false; n_keys];
+            for &i in set {
+                polars_ensure!(
+                    i < n_keys,
+                    OutOfBounds: "grouping set refers to key {} of a group-by with {} keys", i, n_keys
+                );
+                polars_ensure!(
+                    !std::mem::replace(&mut in_set[i], true),
+                    InvalidOperation: "grouping set contains key {} more than once", i
+                );
+            }
+        }
+        if let Some(name) = grouping_id {
+            polars_ensure!(
+                n_keys <= 32,
+                InvalidOperation: "a grouping id supports at most 32 keys, got {}", n_keys
+            );
+            polars_ensure!(!output_schema.contains(name), duplicate = name);
+            output_schema.with_column(name.clone(), DataType::UInt32);
+        }
+    }
+
     #[allow(unused_mut)]
     let mut pop_keys = false;
     // Add dynamic groupby index column(s)
     // Also add index columns to keys for expression expansion.
     #[cfg(feature = "dynamic_group_by")]
     {
-        if let Some(options) = _options.rolling.as_ref() {
+        if let Some(options) = options.rolling.as_ref() {
             let name = options.index_column.clone();
             let dtype = input_schema.try_get(name.as_str())?;
             keys.push(col(name.clone()));
             key_names.insert(name.clone());
             pop_keys = true;
             output_schema.with_column(name.clone(), dtype.clone());
-        } else if let Some(options) = _options.dynamic.as_ref() {
+        } else if let Some(options) = options.dynamic.as_ref() {
             let name = options.index_column.clone();
             keys.push(col(name.clone()));
             key_names.insert(name.clone());
@@ -1161,8 +1296,8 @@ fn resolve_group_by(
     // Make sure aggregation columns do not contain keys or index columns
     if output_schema.len() < (keys_index_len + aggs.len()) {
         let mut names = PlHashSet::with_capacity(output_schema.len());
-        for agg in aggs.iter().chain(keys.iter()) {
-            let name = agg.output_name();
+        let names_iter = aggs.iter().chain(keys.iter()).map(|e| e.output_name());
+        for name in names_iter.chain(grouping_id) {
             polars_ensure!(names.insert(name.clone()), duplicate = name)
         }
     }
@@ -49,10 +49,20 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Ipc { .. } => {
                 sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
+            #[cfg(feature = "json")]
+            FileScanDsl::Json { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { .. } => {
                 // There are a lot of places that short-circuit if the paths is empty,
@@ -188,7 +198,12 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc"
+))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -219,6 +234,7 @@ pub(super) fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&polars_io::prelude::ParquetDecryptionOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
 
@@ -228,7 +244,9 @@ pub(super) fn parquet_file_info(
             feature_gated!("cloud", {
                 let uri = first_path.to_str();
                 get_runtime().block_in_place_on(async {
-                    let mut reader = ParquetObjectStore::from_uri(uri, cloud_options, None).await?;
+                    let mut reader = ParquetObjectStore::from_uri(uri, cloud_options, None)
+                        .await?
+                        .with_decryption(decryption.cloned());
 
                     PolarsResult::Ok((
                         reader.schema().await?,
@@ -242,7 +260,8 @@ pub(super) fn parquet_file_info(
                 .first()
                 .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 source"))?;
             let memslice = first_source.to_memslice()?;
-            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+            let mut reader = ParquetReader::new(std::io::Cursor::new(memslice))
+                .with_decryption(decryption.cloned());
             (
                 reader.schema()?,
                 Some(reader.num_rows()?),
@@ -314,6 +333,95 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<(FileInfo, polars_io::avro::AvroFileMetadata)> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    // Locating the blocks requires the full file, so the first file is downloaded into the file
+    // cache, from where the reader picks it up.
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.first_path().unwrap().to_str())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = polars_io::avro::AvroFileMetadata::read(&memslice)?;
+    let num_rows = metadata.num_rows();
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(
+            Schema::from_arrow_schema(metadata.schema.as_ref()),
+            row_index,
+        )?,
+        Some(Either::Left(Arc::clone(&metadata.schema))),
+        (None, num_rows.saturating_mul(sources.len())),
+    );
+
+    Ok((file_info, metadata))
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn orc_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<(FileInfo, polars_io::orc::OrcFileMetadata)> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    // The file tail is read from the end of the file, so the first file is downloaded into the
+    // file cache, from where the reader picks it up.
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.first_path().unwrap().to_str())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = polars_io::orc::OrcFileMetadata::read(&memslice)?;
+    let num_rows = metadata.num_rows();
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(metadata.schema.as_ref().clone(), row_index)?,
+        Some(Either::Right(Arc::clone(&metadata.schema))),
+        (None, num_rows.saturating_mul(sources.len())),
+    );
+
+    Ok((file_info, metadata))
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -361,7 +469,11 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let transcoded = polars_io::csv::read::maybe_transcode_bytes(
+            maybe_decompress_bytes(&memslice, owned)?,
+            csv_options.parse_options.encoding,
+        )?;
+        let mut reader = std::io::Cursor::new(&*transcoded);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -444,6 +556,43 @@ pub fn ndjson_file_info(
     row_index: Option<&RowIndex>,
     ndjson_options: &NDJsonReadOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    json_like_file_info(
+        sources,
+        row_index,
+        ndjson_options,
+        cloud_options,
+        |bytes, infer_schema_length| {
+            polars_io::ndjson::infer_schema(&mut std::io::Cursor::new(bytes), infer_schema_length)
+        },
+    )
+}
+
+#[cfg(feature = "json")]
+pub fn json_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    json_options: &NDJsonReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    json_like_file_info(
+        sources,
+        row_index,
+        json_options,
+        cloud_options,
+        polars_io::json::infer_json_array_schema,
+    )
+}
+
+/// Resolves the file info of NDJSON and JSON array scans, which only differ in how the schema is
+/// inferred from the first source.
+#[cfg(feature = "json")]
+fn json_like_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    ndjson_options: &NDJsonReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    infer_schema: impl FnOnce(&[u8], Option<std::num::NonZeroUsize>) -> PolarsResult<Schema>,
 ) -> PolarsResult<FileInfo> {
     use polars_core::config;
     use polars_core::error::feature_gated;
@@ -479,10 +628,9 @@ pub fn ndjson_file_info(
         schema
     } else {
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
 
-        Arc::new(polars_io::ndjson::infer_schema(
-            &mut reader,
+        Arc::new(infer_schema(
+            maybe_decompress_bytes(&memslice, owned)?,
             ndjson_options.infer_schema_length,
         )?)
     };
@@ -555,6 +703,7 @@ impl SourcesToFileInfo {
                         sources,
                         unified_scan_args.row_index.as_ref(),
                         cloud_options,
+                        options.decryption.as_ref(),
                     )
                     .map_err(|e| e.context(failed_here!(parquet scan)))?;
 
@@ -577,6 +726,38 @@ impl SourcesToFileInfo {
                     },
                 )
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options } => {
+                let (file_info, md) = scans::avro_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(avro scan)))?;
+                (
+                    file_info,
+                    FileScanIR::Avro {
+                        options,
+                        metadata: Some(Arc::new(md)),
+                    },
+                )
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options } => {
+                let (file_info, md) = scans::orc_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(orc scan)))?;
+                (
+                    file_info,
+                    FileScanIR::Orc {
+                        options,
+                        metadata: Some(Arc::new(md)),
+                    },
+                )
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 // TODO: This is a hack. We conditionally set `allow_missing_columns` to
@@ -608,6 +789,17 @@ impl SourcesToFileInfo {
                 .map_err(|e| e.context(failed_here!(ndjson scan)))?,
                 FileScanIR::NDJson { options },
             ),
+            #[cfg(feature = "json")]
+            FileScanDsl::Json { options } => (
+                scans::json_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    &options,
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(json scan)))?,
+                FileScanIR::Json { options },
+            ),
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { dataset_object } => {
                 if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -688,6 +880,26 @@ impl SourcesToFileInfo {
                 let v = self.inner.get(&key);
                 (key, v)
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options: _ } => {
+                let key = CachedSourceKey::ParquetIpc {
+                    first_path: paths[0].clone(),
+                    schema_overwrite: None,
+                };
+
+                let v = self.inner.get(&key);
+                (key, v)
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc { options: _ } => {
+                let key = CachedSourceKey::ParquetIpc {
+                    first_path: paths[0].clone(),
+                    schema_overwrite: None,
+                };
+
+                let v = self.inner.get(&key);
+                (key, v)
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { options } => {
                 let key = CachedSourceKey::CsvJson {
@@ -699,7 +911,7 @@ impl SourcesToFileInfo {
                 (key, v)
             },
             #[cfg(feature = "json")]
-            FileScanDsl::NDJson { options } => {
+            FileScanDsl::NDJson { options } | FileScanDsl::Json { options } => {
                 let key = CachedSourceKey::CsvJson {
                     paths: paths.clone(),
                     schema: options.schema.clone(),
@@ -11,6 +11,9 @@ pub(super) struct DslConversionContext<'a> {
     pub(super) pushdown_maintain_errors: bool,
     pub(super) verbose: bool,
     pub(super) cache_id_for_arc_ptr: PlHashMap<usize, UniqueId>,
+    /// Name, id and schema of the work tables of the recursive unions being converted, innermost
+    /// last.
+    pub(super) work_tables: Vec<(PlSmallStr, UniqueId, SchemaRef)>,
 }
 
 pub(super) fn expand_expressions(
@@ -4,14 +4,23 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro",
+    feature = "orc"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "json",
+    feature = "avro",
+    feature = "orc"
+))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
+use polars_io::parquet::encryption::ParquetDecryptionOptions;
+#[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
@@ -30,7 +39,9 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc"
     )))]
     {
         unreachable!()
@@ -40,14 +51,18 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
             #[cfg(feature = "csv")]
             FileScanIR::Csv { options } => count_all_rows_csv(sources, options),
             #[cfg(feature = "parquet")]
-            FileScanIR::Parquet { .. } => count_rows_parquet(sources, cloud_options),
+            FileScanIR::Parquet { options, .. } => {
+                count_rows_parquet(sources, cloud_options, options.decryption.as_ref())
+            },
             #[cfg(feature = "ipc")]
             FileScanIR::Ipc { options, metadata } => count_rows_ipc(
                 sources,
@@ -57,6 +72,12 @@ pub fn count_rows(
             ),
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "json")]
+            FileScanIR::Json { .. } => count_rows_json(sources, cloud_options),
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro { .. } => count_rows_avro(sources, cloud_options),
+            #[cfg(feature = "orc")]
+            FileScanIR::Orc { .. } => count_rows_orc(sources, cloud_options),
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -84,10 +105,7 @@ fn count_all_rows_csv(
         .map(|source| match source {
             ScanSourceRef::Path(addr) => polars_io::csv::read::count_rows(
                 addr,
-                parse_options.separator,
-                parse_options.quote_char,
-                parse_options.comment_prefix.as_ref(),
-                parse_options.eol_char,
+                &parse_options,
                 options.has_header,
                 options.skip_lines,
                 options.skip_rows,
@@ -95,9 +113,20 @@ fn count_all_rows_csv(
             ),
             _ => {
                 let memslice = source.to_memslice()?;
+                let transcoded = polars_io::csv::read::maybe_transcode_bytes(
+                    &memslice[..],
+                    parse_options.encoding,
+                )?;
+                let rewritten = &mut vec![];
+                let bytes = polars_io::csv::read::maybe_rewrite_dialect(
+                    &transcoded,
+                    &parse_options,
+                    rewritten,
+                )?;
+                let parse_options = parse_options.to_standard_dialect();
 
                 polars_io::csv::read::count_rows_from_slice_par(
-                    &memslice[..],
+                    bytes,
                     parse_options.separator,
                     parse_options.quote_char,
                     parse_options.comment_prefix.as_ref(),
@@ -116,6 +145,7 @@ fn count_all_rows_csv(
 pub(super) fn count_rows_parquet(
     sources: &ScanSources,
     #[allow(unused)] cloud_options: Option<&CloudOptions>,
+    decryption: Option<&ParquetDecryptionOptions>,
 ) -> PolarsResult<usize> {
     if sources.is_empty() {
         return Ok(0);
@@ -126,13 +156,16 @@ pub(super) fn count_rows_parquet(
             get_runtime().block_on(count_rows_cloud_parquet(
                 sources.as_paths().unwrap(),
                 cloud_options,
+                decryption,
             ))
         })
     } else {
         sources
             .iter()
             .map(|source| {
-                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?)).num_rows()
+                ParquetReader::new(std::io::Cursor::new(source.to_memslice()?))
+                    .with_decryption(decryption.cloned())
+                    .num_rows()
             })
             .sum::<PolarsResult<usize>>()
     }
@@ -142,13 +175,15 @@ pub(super) fn count_rows_parquet(
 async fn count_rows_cloud_parquet(
     addrs: &[PlPath],
     cloud_options: Option<&CloudOptions>,
+    decryption: Option<&ParquetDecryptionOptions>,
 ) -> PolarsResult<usize> {
     use polars_io::prelude::ParquetObjectStore;
 
     let collection = addrs.iter().map(|path| {
         with_concurrency_budget(1, || async {
-            let mut reader =
-                ParquetObjectStore::from_uri(path.to_str(), cloud_options, None).await?;
+            let mut reader = ParquetObjectStore::from_uri(path.to_str(), cloud_options, None)
+                .await?
+                .with_decryption(decryption.cloned());
             reader.num_rows().await
         })
     });
@@ -210,6 +245,29 @@ async fn count_rows_cloud_ipc(
 pub(super) fn count_rows_ndjson(
     sources: &ScanSources,
     cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    count_rows_json_like(sources, cloud_options, |bytes| {
+        polars_io::ndjson::core::JsonLineReader::new(std::io::Cursor::new(bytes)).count()
+    })
+}
+
+#[cfg(feature = "json")]
+pub(super) fn count_rows_json(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    count_rows_json_like(
+        sources,
+        cloud_options,
+        polars_io::json::count_json_array_rows,
+    )
+}
+
+#[cfg(feature = "json")]
+fn count_rows_json_like(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+    count: impl Fn(&[u8]) -> PolarsResult<usize>,
 ) -> PolarsResult<usize> {
     use polars_core::config;
     use polars_io::utils::compression::maybe_decompress_bytes;
@@ -247,10 +305,95 @@ pub(super) fn count_rows_ndjson(
                 source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
 
             let owned = &mut vec![];
-            let reader = polars_io::ndjson::core::JsonLineReader::new(std::io::Cursor::new(
-                maybe_decompress_bytes(&memslice[..], owned)?,
-            ));
-            reader.count()
+            count(maybe_decompress_bytes(&memslice[..], owned)?)
+        })
+        .sum()
+}
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::avro::AvroFileMetadata;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            Ok(AvroFileMetadata::read(&memslice)?.num_rows())
+        })
+        .sum()
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn count_rows_orc(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+    use polars_io::orc::OrcFileMetadata;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            Ok(OrcFileMetadata::read(&memslice)?.num_rows())
         })
         .sum()
 }
@@ -324,6 +324,25 @@ impl<'a> IRDotDisplay<'a> {
 
                 write_label(f, id, |f| write!(f, "MERGE_SORTED ON '{key}'",))?;
             },
+            RecursiveUnion {
+                anchor,
+                recursive,
+                id: _,
+                options,
+            } => {
+                recurse!(*anchor);
+                recurse!(*recursive);
+
+                let name = if options.distinct {
+                    "RECURSIVE UNION"
+                } else {
+                    "RECURSIVE UNION ALL"
+                };
+                write_label(f, id, |f| write!(f, "{name} '{}'", options.name))?;
+            },
+            WorkTableScan { name, .. } => {
+                write_label(f, id, |f| write!(f, "WORK TABLE '{name}'"))?;
+            },
             Invalid => write_label(f, id, |f| f.write_str("INVALID"))?,
         }
 
@@ -252,6 +252,16 @@ impl<'a> IRDisplay<'a> {
                 self.with_root(*input_right)._format(f, sub_indent)?;
                 write!(f, "\n{:indent$}END MERGE_SORTED", "")
             },
+            RecursiveUnion {
+                anchor, recursive, ..
+            } => {
+                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                write!(f, "\n{:indent$}ANCHOR PLAN:", "")?;
+                self.with_root(*anchor)._format(f, sub_indent)?;
+                write!(f, "\n{:indent$}RECURSIVE PLAN:", "")?;
+                self.with_root(*recursive)._format(f, sub_indent)?;
+                write!(f, "\n{:indent$}END RECURSIVE UNION", "")
+            },
             ir_node => {
                 write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
                 for input in ir_node.inputs() {
@@ -893,6 +903,33 @@ pub fn write_ir_non_recursive(
             input_right: _,
             key,
         } => write!(f, "{:indent$}MERGE SORTED ON '{key}'", ""),
+        IR::RecursiveUnion {
+            anchor: _,
+            recursive: _,
+            id: _,
+            options,
+        } => {
+            let name = if options.distinct {
+                "RECURSIVE UNION"
+            } else {
+                "RECURSIVE UNION ALL"
+            };
+            write!(
+                f,
+                "{:indent$}{name} '{}'[max_iterations: {}]",
+                "", options.name, options.max_iterations
+            )
+        },
+        IR::WorkTableScan {
+            id: _,
+            name,
+            schema,
+        } => write!(
+            f,
+            "{:indent$}WORK TABLE '{name}' {}",
+            "",
+            format_list_truncated!(schema.iter_names(), 4, '"'),
+        ),
         IR::Invalid => write!(f, "{:indent$}INVALID", ""),
     }
 }
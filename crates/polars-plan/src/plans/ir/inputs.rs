@@ -64,6 +64,8 @@ impl IR {
             SinkMultiple { .. } => Exprs::Empty,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => Exprs::Empty,
+            RecursiveUnion { .. } => Exprs::Empty,
+            WorkTableScan { .. } => Exprs::Empty,
 
             #[cfg(feature = "python")]
             PythonScan { options } => match &options.predicate {
@@ -136,6 +138,8 @@ impl IR {
             SinkMultiple { .. } => ExprsMut::Empty,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => ExprsMut::Empty,
+            RecursiveUnion { .. } => ExprsMut::Empty,
+            WorkTableScan { .. } => ExprsMut::Empty,
 
             #[cfg(feature = "python")]
             PythonScan { options } => match &mut options.predicate {
@@ -236,6 +240,10 @@ impl IR {
                 input_right,
                 ..
             } => Inputs::double(*input_left, *input_right),
+            RecursiveUnion {
+                anchor, recursive, ..
+            } => Inputs::double(*anchor, *recursive),
+            WorkTableScan { .. } => Inputs::Empty,
             Invalid => unreachable!(),
         }
     }
@@ -275,6 +283,10 @@ impl IR {
                 input_right,
                 ..
             } => InputsMut::double(input_left, input_right),
+            RecursiveUnion {
+                anchor, recursive, ..
+            } => InputsMut::double(anchor, recursive),
+            WorkTableScan { .. } => InputsMut::Empty,
             Invalid => unreachable!(),
         }
     }
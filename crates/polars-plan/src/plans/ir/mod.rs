@@ -156,6 +156,23 @@ pub enum IR {
         input_right: Node,
         key: PlSmallStr,
     },
+    /// Union of `anchor` with the rows of `recursive`, which is evaluated again on the rows the
+    /// previous iteration added until it adds no new rows.
+    ///
+    /// - Invariant: `recursive` has the same schema as `anchor`
+    RecursiveUnion {
+        anchor: Node,
+        recursive: Node,
+        /// The id of the work table scanned by `recursive`.
+        id: UniqueId,
+        options: RecursiveUnionOptions,
+    },
+    /// The rows the `RecursiveUnion` with this id added in the previous iteration.
+    WorkTableScan {
+        id: UniqueId,
+        name: PlSmallStr,
+        schema: SchemaRef,
+    },
     #[default]
     Invalid,
 }
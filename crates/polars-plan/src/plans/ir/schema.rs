@@ -44,6 +44,8 @@ impl IR {
             SimpleProjection { .. } => "simple_projection",
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => "merge_sorted",
+            RecursiveUnion { .. } => "recursive_union",
+            WorkTableScan { .. } => "work_table_scan",
             Invalid => "invalid",
         }
     }
@@ -53,7 +55,7 @@ impl IR {
         let schema = match self {
             #[cfg(feature = "python")]
             PythonScan { options } => &options.schema,
-            DataFrameScan { schema, .. } => schema,
+            DataFrameScan { schema, .. } | WorkTableScan { schema, .. } => schema,
             Scan { file_info, .. } => &file_info.schema,
             node => {
                 let input = node.get_input()?;
@@ -110,6 +112,8 @@ impl IR {
             ExtContext { schema, .. } => schema,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { input_left, .. } => return arena.get(*input_left).schema(arena),
+            RecursiveUnion { anchor, .. } => return arena.get(*anchor).schema(arena),
+            WorkTableScan { schema, .. } => schema,
             Invalid => unreachable!(),
         };
         Cow::Borrowed(schema)
@@ -170,6 +174,8 @@ impl IR {
             },
             #[cfg(feature = "merge_sorted")]
             MergeSorted { input_left, .. } => IR::schema_with_cache(*input_left, arena, cache),
+            RecursiveUnion { anchor, .. } => IR::schema_with_cache(*anchor, arena, cache),
+            WorkTableScan { schema, .. } => schema.clone(),
             Invalid => unreachable!(),
         };
         cache.insert(node, schema.clone());
@@ -378,6 +378,39 @@ impl<'a> TreeFmtNode<'a> {
                             .chain([self.lp_node(Some("RIGHT PLAN:".to_string()), *input_right)])
                             .collect(),
                     ),
+                    RecursiveUnion {
+                        anchor,
+                        recursive,
+                        id: _,
+                        options,
+                    } => ND(
+                        wh(
+                            h,
+                            &format!(
+                                "{} '{}'",
+                                if options.distinct {
+                                    "RECURSIVE UNION"
+                                } else {
+                                    "RECURSIVE UNION ALL"
+                                },
+                                options.name
+                            ),
+                        ),
+                        vec![
+                            self.lp_node(Some("ANCHOR PLAN:".to_string()), *anchor),
+                            self.lp_node(Some("RECURSIVE PLAN:".to_string()), *recursive),
+                        ],
+                    ),
+                    WorkTableScan { name, schema, .. } => ND(
+                        wh(
+                            h,
+                            &format!(
+                                "WORK TABLE '{name}' {}",
+                                format_list_truncated!(schema.iter_names(), 4, '"')
+                            ),
+                        ),
+                        vec![],
+                    ),
                     Invalid => ND(wh(h, "INVALID"), vec![]),
                 }
             },
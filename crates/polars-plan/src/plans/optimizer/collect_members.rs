@@ -33,6 +33,7 @@ pub(super) struct MemberCollector {
     pub(crate) has_distinct: bool,
     pub(crate) has_sort: bool,
     pub(crate) has_group_by: bool,
+    pub(crate) has_recursive_union: bool,
     #[cfg(feature = "cse")]
     scans: UniqueScans,
 }
@@ -48,6 +49,7 @@ impl MemberCollector {
             has_distinct: false,
             has_sort: false,
             has_group_by: false,
+            has_recursive_union: false,
             #[cfg(feature = "cse")]
             scans: UniqueScans::default(),
         }
@@ -71,6 +73,7 @@ impl MemberCollector {
                     self.has_sort = true;
                 },
                 Cache { .. } => self.has_cache = true,
+                RecursiveUnion { .. } => self.has_recursive_union = true,
                 ExtContext { .. } => self.has_ext_context = true,
                 #[cfg(feature = "cse")]
                 Scan { .. } => {
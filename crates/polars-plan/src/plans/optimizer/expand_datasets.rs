@@ -167,6 +167,18 @@ impl OptimizationRule for ExpandDatasets {
                                     metadata: None,
                                 },
 
+                                #[cfg(feature = "avro")]
+                                FileScanDsl::Avro { options } => FileScanIR::Avro {
+                                    options,
+                                    metadata: None,
+                                },
+
+                                #[cfg(feature = "orc")]
+                                FileScanDsl::Orc { options } => FileScanIR::Orc {
+                                    options,
+                                    metadata: None,
+                                },
+
                                 #[cfg(feature = "parquet")]
                                 FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                     options,
@@ -176,6 +188,9 @@ impl OptimizationRule for ExpandDatasets {
                                 #[cfg(feature = "json")]
                                 FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                                #[cfg(feature = "json")]
+                                FileScanDsl::Json { options } => FileScanIR::Json { options },
+
                                 #[cfg(feature = "python")]
                                 FileScanDsl::PythonDataset { dataset_object } => {
                                     FileScanIR::PythonDataset {
@@ -144,6 +144,9 @@ pub fn optimize(
         if (members.has_sink_multiple || members.has_joins_or_unions)
             && members.has_duplicate_scans()
             && !members.has_cache
+            // The recursive input of a recursive union is planned again for every iteration,
+            // so it can't share caches with the rest of the plan.
+            && !members.has_recursive_union
         {
             if verbose {
                 eprintln!("found multiple sources; run comm_subplan_elim")
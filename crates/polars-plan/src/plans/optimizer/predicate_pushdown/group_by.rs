@@ -23,7 +23,8 @@ pub(super) fn process_group_by(
     let no_push = false;
 
     // Don't pushdown predicates on these cases.
-    if apply.is_some() || no_push || options.slice.is_some() {
+    // With grouping sets, the keys are null in the groups of sets that don't contain them.
+    if apply.is_some() || no_push || options.slice.is_some() || options.grouping_sets.is_some() {
         let lp = GroupBy {
             input,
             keys,
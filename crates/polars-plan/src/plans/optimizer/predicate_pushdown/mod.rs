@@ -371,6 +371,10 @@ impl PredicatePushDown<'_> {
                     FileScanIR::Parquet { .. } => {},
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => {},
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { .. } => {},
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => {},
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
@@ -396,6 +400,8 @@ impl PredicatePushDown<'_> {
                     FileScanIR::Anonymous { function, .. } => function.allows_predicate_pushdown(),
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { .. } => true,
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { .. } => true,
                     #[allow(unreachable_patterns)]
                     _ => true,
                 };
@@ -628,6 +634,11 @@ impl PredicatePushDown<'_> {
             lp @ HConcat { .. } => {
                 self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
             },
+            // predicates influence the work table of the next iteration
+            lp @ RecursiveUnion { .. } => {
+                self.no_pushdown_restart_opt(lp, acc_predicates, lp_arena, expr_arena)
+            },
+            lp @ WorkTableScan { .. } => self.no_pushdown(lp, acc_predicates, lp_arena, expr_arena),
             // Caches will run predicate push-down in the `cache_states` run.
             Cache { .. } => {
                 if self.block_at_cache {
@@ -451,8 +451,14 @@ impl ProjectionPushDown {
                     FileScanIR::Anonymous { function, .. } => function.allows_projection_pushdown(),
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { .. } => true,
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { .. } => true,
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
@@ -772,6 +778,14 @@ impl ProjectionPushDown {
                     key,
                 })
             },
+            // All columns of the inputs are needed for the work table of the next iteration.
+            lp @ RecursiveUnion { .. } => {
+                self.no_pushdown_restart_opt(lp, ctx, lp_arena, expr_arena)
+            },
+            lp @ WorkTableScan { .. } => {
+                let builder = IRBuilder::from_lp(lp, expr_arena, lp_arena);
+                Ok(self.finish_node_simple_projection(&ctx.acc_projections, builder))
+            },
             Invalid => unreachable!(),
         }
     }
@@ -227,12 +227,21 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { .. } => true,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { .. } => true,
 
+                #[cfg(feature = "json")]
+                FileScanIR::Json { .. } => true,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset { .. } => true,
 
@@ -506,6 +515,10 @@ impl SlicePushDown {
                 // Slice can always be pushed down for sinks
                 self.pushdown_and_continue(lp, state, lp_arena, expr_arena)
             }
+            (lp @ RecursiveUnion { .. }, state) => {
+                // The number of rows of an iteration depends on the rows of the previous one.
+                self.no_pushdown_restart_opt(lp, state, lp_arena, expr_arena)
+            }
             (catch_all, state) => {
                 self.no_pushdown_finish_opt(catch_all, state, lp_arena)
             }
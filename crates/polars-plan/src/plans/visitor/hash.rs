@@ -193,6 +193,22 @@ impl Hash for HashableEqLP<'_> {
             } => {
                 key.hash(state);
             },
+            IR::RecursiveUnion {
+                anchor: _,
+                recursive: _,
+                id,
+                options,
+            } => {
+                id.hash(state);
+                options.hash(state);
+            },
+            IR::WorkTableScan {
+                id,
+                name: _,
+                schema: _,
+            } => {
+                id.hash(state);
+            },
             IR::Invalid => unreachable!(),
         }
     }
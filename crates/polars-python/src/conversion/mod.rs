@@ -13,7 +13,7 @@ pub use categorical::PyCategories;
 use polars::chunked_array::object::PolarsObjectSafe;
 use polars::frame::row::Row;
 #[cfg(feature = "avro")]
-use polars::io::avro::AvroCompression;
+use polars::io::avro::AvroCodec;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
 use polars::prelude::ColumnMapping;
@@ -832,15 +832,16 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
 }
 
 #[cfg(feature = "avro")]
-impl<'py> FromPyObject<'py> for Wrap<Option<AvroCompression>> {
+impl<'py> FromPyObject<'py> for Wrap<Option<AvroCodec>> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "uncompressed" => None,
-            "snappy" => Some(AvroCompression::Snappy),
-            "deflate" => Some(AvroCompression::Deflate),
+            "snappy" => Some(AvroCodec::Snappy),
+            "deflate" => Some(AvroCodec::Deflate),
+            "zstd" => Some(AvroCodec::Zstd),
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "avro `compression` must be one of {{'uncompressed', 'snappy', 'deflate'}}, got {v}",
+                    "avro `compression` must be one of {{'uncompressed', 'snappy', 'deflate', 'zstd'}}, got {v}",
                 )));
             },
         };
@@ -929,9 +930,13 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16-le', 'utf16-be'}}, got {v}",
                 )));
             },
         };
@@ -1072,6 +1077,28 @@ impl<'py> FromPyObject<'py> for Wrap<ParallelStrategy> {
     }
 }
 
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetEncoding> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::parquet::write::ParquetEncoding;
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "plain" => ParquetEncoding::Plain,
+            "dictionary" => ParquetEncoding::Dictionary,
+            "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+            "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+            "delta_byte_array" => ParquetEncoding::DeltaByteArray,
+            "byte_stream_split" => ParquetEncoding::ByteStreamSplit,
+            "auto" => ParquetEncoding::Auto,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "parquet `encoding` must be one of {{'plain', 'dictionary', 'delta_binary_packed', 'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split', 'auto'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<IndexOrder> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1681,33 +1708,96 @@ impl<'py> FromPyObject<'py> for Wrap<DeletionFilesList> {
 
         Ok(Wrap(match &*deletion_file_type {
             "iceberg-position-delete" => {
-                let dict: Bound<'_, PyDict> = ob.extract()?;
+                DeletionFilesList::IcebergPositionDelete(extract_iceberg_position_deletes(&ob)?)
+            },
+
+            "iceberg-equality-delete" => {
+                use polars::prelude::deletion::IcebergEqualityDeleteFile;
+
+                let (position_deletes, dict): (Bound<'_, PyAny>, Bound<'_, PyDict>) =
+                    ob.extract()?;
 
                 let mut out = PlIndexMap::new();
 
-                for (k, v) in dict
-                    .try_iter()?
-                    .zip(dict.call_method0("values")?.try_iter()?)
-                {
-                    let k: usize = k?.extract()?;
-                    let v: Bound<'_, PyAny> = v?.extract()?;
+                for (k, v) in dict.iter() {
+                    let k: usize = k.extract()?;
 
                     let files = v
                         .try_iter()?
                         .map(|x| {
-                            x.and_then(|x| {
-                                let x: String = x.extract()?;
-                                Ok(x)
+                            let (path, equality_ids): (String, Vec<u32>) = x?.extract()?;
+
+                            Ok(IcebergEqualityDeleteFile {
+                                path,
+                                equality_ids: equality_ids.into(),
                             })
                         })
-                        .collect::<PyResult<Arc<[String]>>>()?;
+                        .collect::<PyResult<Arc<[IcebergEqualityDeleteFile]>>>()?;
 
                     if !files.is_empty() {
                         out.insert(k, files);
                     }
                 }
 
-                DeletionFilesList::IcebergPositionDelete(Arc::new(out))
+                DeletionFilesList::IcebergEqualityDelete {
+                    position_deletes: extract_iceberg_position_deletes(&position_deletes)?,
+                    equality_deletes: Arc::new(out),
+                }
+            },
+
+            "delta-deletion-vector" => {
+                use polars::prelude::deletion::{
+                    DeltaDeletionVectorDescriptor, DeltaDeletionVectorStorageType,
+                };
+
+                let (table_root, dict): (String, Bound<'_, PyDict>) = ob.extract()?;
+
+                let mut out = PlIndexMap::new();
+
+                for (k, v) in dict.iter() {
+                    let k: usize = k.extract()?;
+                    let v: Bound<'_, PyDict> = v.extract()?;
+
+                    let get = |key: &str| {
+                        v.get_item(key)?.ok_or_else(|| {
+                            PyValueError::new_err(format!(
+                                "deletion vector descriptor is missing '{key}'"
+                            ))
+                        })
+                    };
+
+                    let storage_type: PyBackedStr = get("storageType")?.extract()?;
+                    let storage_type = DeltaDeletionVectorStorageType::from_log_value(
+                        &storage_type,
+                    )
+                    .ok_or_else(|| {
+                        PyValueError::new_err(format!(
+                            "unknown deletion vector storage type: {}",
+                            &*storage_type
+                        ))
+                    })?;
+                    let path_or_inline_dv: PyBackedStr = get("pathOrInlineDv")?.extract()?;
+
+                    out.insert(
+                        k,
+                        DeltaDeletionVectorDescriptor {
+                            storage_type,
+                            path_or_inline_dv: (&*path_or_inline_dv).into(),
+                            offset: v
+                                .get_item("offset")?
+                                .map(|x| x.extract::<Option<usize>>())
+                                .transpose()?
+                                .flatten(),
+                            size_in_bytes: get("sizeInBytes")?.extract()?,
+                            cardinality: get("cardinality")?.extract()?,
+                        },
+                    );
+                }
+
+                DeletionFilesList::DeltaDeletionVector {
+                    table_root: table_root.into(),
+                    vectors: Arc::new(out),
+                }
             },
 
             v => {
@@ -1719,6 +1809,39 @@ impl<'py> FromPyObject<'py> for Wrap<DeletionFilesList> {
     }
 }
 
+/// Extracts a `dict[int, list[str]]` of Iceberg position delete files.
+fn extract_iceberg_position_deletes(
+    ob: &Bound<'_, PyAny>,
+) -> PyResult<Arc<PlIndexMap<usize, Arc<[String]>>>> {
+    let dict: Bound<'_, PyDict> = ob.extract()?;
+
+    let mut out = PlIndexMap::new();
+
+    for (k, v) in dict
+        .try_iter()?
+        .zip(dict.call_method0("values")?.try_iter()?)
+    {
+        let k: usize = k?.extract()?;
+        let v: Bound<'_, PyAny> = v?.extract()?;
+
+        let files = v
+            .try_iter()?
+            .map(|x| {
+                x.and_then(|x| {
+                    let x: String = x.extract()?;
+                    Ok(x)
+                })
+            })
+            .collect::<PyResult<Arc<[String]>>>()?;
+
+        if !files.is_empty() {
+            out.insert(k, files);
+        }
+    }
+
+    Ok(Arc::new(out))
+}
+
 impl<'py> FromPyObject<'py> for Wrap<DefaultFieldValues> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let (default_values_type, ob): (PyBackedStr, Bound<'_, PyAny>) = ob.extract()?;
@@ -303,7 +303,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (
-        sources, schema, scan_options, parallel, low_memory, use_statistics
+        sources, schema, scan_options, parallel, low_memory, use_statistics,
+        decryption_key_retriever
     ))]
     fn new_from_parquet(
         sources: Wrap<ScanSources>,
@@ -312,9 +313,31 @@ impl PyLazyFrame {
         parallel: Wrap<ParallelStrategy>,
         low_memory: bool,
         use_statistics: bool,
+        decryption_key_retriever: Option<PyObject>,
     ) -> PyResult<Self> {
+        use polars_io::parquet::encryption::{
+            KeyRetriever, ParquetDecryptionOptions, ParquetKeyRetriever,
+        };
+        use polars_parquet::parquet::error::{ParquetError, ParquetResult};
+        use pyo3::types::PyBytes;
+
         use crate::utils::to_py_err;
 
+        /// Retrieves the keys by calling a Python function with the key metadata.
+        struct PyKeyRetriever(PyObject);
+
+        impl KeyRetriever for PyKeyRetriever {
+            fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+                Python::with_gil(|py| {
+                    let key = self.0.call1(py, (PyBytes::new(py, key_metadata),))?;
+                    PyResult::Ok(key.bind(py).downcast::<PyBytes>()?.as_bytes().to_vec())
+                })
+                .map_err(|err| {
+                    ParquetError::InvalidParameter(format!("failed to retrieve key: {err}"))
+                })
+            }
+        }
+
         let parallel = parallel.0;
 
         let options = ParquetOptions {
@@ -322,6 +345,9 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            decryption: decryption_key_retriever.map(|f| {
+                ParquetDecryptionOptions::new(ParquetKeyRetriever::new(Arc::new(PyKeyRetriever(f))))
+            }),
         };
 
         let sources = sources.0;
@@ -413,6 +439,81 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "orc")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, n_rows, cache, rechunk, row_index, cloud_options,credential_provider,
+        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
+        include_file_paths
+    ))]
+    fn new_from_orc(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        hive_partitioning: Option<bool>,
+        hive_schema: Option<Wrap<Schema>>,
+        try_parse_hive_dates: bool,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            hive_start_idx: 0,
+            schema: hive_schema.map(|x| Arc::new(x.0)),
+            try_parse_dates: try_parse_hive_dates,
+        };
+
+        let mut args = ScanArgsOrc {
+            n_rows,
+            cache,
+            rechunk,
+            row_index,
+            cloud_options: None,
+            hive_options,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_orc_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     #[pyo3(signature = (
         dataset_object
@@ -732,6 +833,7 @@ impl PyLazyFrame {
             data_page_size,
             key_value_metadata: metadata.0,
             field_overwrites: field_overwrites.into_iter().map(|f| f.0).collect(),
+            encryption: None,
         };
 
         let cloud_options = match target.base_path() {
@@ -884,6 +986,7 @@ impl PyLazyFrame {
         let options = CsvWriterOptions {
             include_bom,
             include_header,
+            encoding: CsvEncoding::Utf8,
             batch_size,
             serialize_options,
         };
@@ -1554,7 +1657,10 @@ impl PyLazyFrame {
 #[cfg(feature = "parquet")]
 impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOverwrites> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
-        use polars_io::parquet::write::ParquetFieldOverwrites;
+        use polars_io::parquet::write::{
+            ParquetBloomFilterOptions, ParquetEncoding, ParquetFieldOverwrites,
+        };
+        use polars_utils::total_ord::TotalOrdWrap;
 
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
@@ -1596,12 +1702,32 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| {
+                let v = v.extract::<pyo3::Bound<'_, PyDict>>()?;
+                let mut options = ParquetBloomFilterOptions::default();
+                if let Some(ndv) = PyDictMethods::get_item(&v, "ndv")? {
+                    options.ndv = Some(ndv.extract::<u64>()?);
+                }
+                if let Some(fpp) = PyDictMethods::get_item(&v, "fpp")? {
+                    options.fpp = TotalOrdWrap(fpp.extract::<f64>()?);
+                }
+                PyResult::Ok(options)
+            })
+            .transpose()?;
+
+        let encoding = PyDictMethods::get_item(&parsed, "encoding")?
+            .map(|v| PyResult::Ok(v.extract::<Wrap<ParquetEncoding>>()?.0))
+            .transpose()?;
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            bloom_filter,
+            encoding,
         }))
     }
 }
@@ -41,12 +41,18 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro { .. } => Err(PyNotImplementedError::new_err("avro scan")),
+        #[cfg(feature = "orc")]
+        FileScanIR::Orc { .. } => Err(PyNotImplementedError::new_err("orc scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
                 .map_err(|err| PyValueError::new_err(format!("{err:?}")))?;
             Ok(("ndjson", options).into_py_any(py)?)
         },
+        #[cfg(feature = "json")]
+        FileScanIR::Json { .. } => Err(PyNotImplementedError::new_err("json scan")),
         FileScanIR::PythonDataset { .. } => {
             Err(PyNotImplementedError::new_err("python dataset scan"))
         },
@@ -133,6 +139,8 @@ impl PyFileOptions {
     /// One of:
     /// * None
     /// * ("iceberg-position-delete", dict[int, list[str]])
+    /// * ("iceberg-equality-delete", (dict[int, list[str]], dict[int, list[(str, list[int])]]))
+    /// * ("delta-deletion-vector", (str, dict[int, dict[str, Any]]))
     #[getter]
     fn deletion_files(&self, py: Python<'_>) -> PyResult<PyObject> {
         Ok(match &self.inner.deletion_files {
@@ -150,6 +158,54 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+
+            Some(DeletionFilesList::IcebergEqualityDelete {
+                position_deletes,
+                equality_deletes,
+            }) => {
+                let positions = PyDict::new(py);
+
+                for (k, v) in position_deletes.iter() {
+                    positions.set_item(*k, v.as_ref())?;
+                }
+
+                let out = PyDict::new(py);
+
+                for (k, v) in equality_deletes.iter() {
+                    let files = v
+                        .iter()
+                        .map(|x| (x.path.as_str(), x.equality_ids.as_ref()))
+                        .collect::<Vec<_>>();
+                    out.set_item(*k, files)?;
+                }
+
+                ("iceberg-equality-delete", (positions, out))
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
+
+            Some(DeletionFilesList::DeltaDeletionVector {
+                table_root,
+                vectors,
+            }) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in vectors.iter() {
+                    let descriptor = PyDict::new(py);
+                    descriptor.set_item("storageType", v.storage_type.to_log_value())?;
+                    descriptor.set_item("pathOrInlineDv", v.path_or_inline_dv.as_str())?;
+                    descriptor.set_item("offset", v.offset)?;
+                    descriptor.set_item("sizeInBytes", v.size_in_bytes)?;
+                    descriptor.set_item("cardinality", v.cardinality)?;
+                    out.set_item(*k, descriptor)?;
+                }
+
+                ("delta-deletion-vector", (table_root.as_str(), out))
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
         })
     }
 
@@ -494,19 +550,26 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             apply,
             maintain_order,
             options,
-        } => GroupBy {
-            input: input.0,
-            keys: keys.iter().map(|e| e.into()).collect(),
-            aggs: aggs.iter().map(|e| e.into()).collect(),
-            apply: apply.as_ref().map_or(Ok(()), |_| {
-                Err(PyNotImplementedError::new_err(format!(
-                    "apply inside GroupBy {plan:?}"
-                )))
-            })?,
-            maintain_order: *maintain_order,
-            options: PyGroupbyOptions::new(options.as_ref().clone()).into_py_any(py)?,
-        }
-        .into_py_any(py),
+        } => {
+            if options.grouping_sets.is_some() {
+                return Err(PyNotImplementedError::new_err(
+                    "grouping sets inside GroupBy",
+                ));
+            }
+            GroupBy {
+                input: input.0,
+                keys: keys.iter().map(|e| e.into()).collect(),
+                aggs: aggs.iter().map(|e| e.into()).collect(),
+                apply: apply.as_ref().map_or(Ok(()), |_| {
+                    Err(PyNotImplementedError::new_err(format!(
+                        "apply inside GroupBy {plan:?}"
+                    )))
+                })?,
+                maintain_order: *maintain_order,
+                options: PyGroupbyOptions::new(options.as_ref().clone()).into_py_any(py)?,
+            }
+            .into_py_any(py)
+        },
         IR::Join {
             input_left,
             input_right,
@@ -710,6 +773,8 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             key: key.to_string(),
         }
         .into_py_any(py),
+        IR::RecursiveUnion { .. } => Err(PyNotImplementedError::new_err("recursive union")),
+        IR::WorkTableScan { .. } => Err(PyNotImplementedError::new_err("work table scan")),
         IR::Invalid => Err(PyNotImplementedError::new_err("Invalid")),
     }
 }
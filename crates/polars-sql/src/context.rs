@@ -7,23 +7,28 @@ use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
-use polars_utils::format_pl_smallstr;
+use polars_utils::{format_pl_smallstr, unique_column_name};
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    BinaryOperator, CreateTable, Cte, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr,
+    FromTable, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr, GroupByWithModifier,
+    Ident, JoinConstraint, JoinOperator, NamedWindowDefinition, NamedWindowExpr, ObjectName,
+    ObjectType, Offset, OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, Subscript, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions, WindowSpec, WindowType,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::functions::window_row_index;
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
 use crate::table_functions::PolarsTableFunctions;
 
+/// Name of the hidden grouping id column of a GROUP BY with grouping sets.
+pub(crate) const GROUPING_ID_NAME: PlSmallStr = PlSmallStr::from_static("__PL_GROUPING_ID");
+
 #[derive(Clone)]
 pub struct TableInfo {
     pub(crate) frame: LazyFrame,
@@ -52,6 +57,160 @@ impl SelectModifiers {
     }
 }
 
+/// The grouping sets of a GROUP BY clause with GROUPING SETS, ROLLUP or CUBE.
+struct SQLGroupingSets {
+    /// The distinct GROUP BY keys.
+    keys: Vec<SQLExpr>,
+    /// The indices of the keys in each grouping set.
+    sets: Vec<Vec<usize>>,
+}
+
+impl SQLGroupingSets {
+    /// Resolve the grouping sets of `GROUP BY <exprs> [WITH ROLLUP | WITH CUBE]`. Returns `None`
+    /// for a plain GROUP BY.
+    ///
+    /// Multiple elements in the GROUP BY clause give the cross product of their grouping sets,
+    /// e.g. `GROUP BY a, ROLLUP(b, c)` has the grouping sets `(a, b, c), (a, b), (a)`.
+    fn try_new(exprs: &[SQLExpr], modifiers: &[GroupByWithModifier]) -> PolarsResult<Option<Self>> {
+        let has_grouping_sets = exprs.iter().any(|e| {
+            matches!(
+                e,
+                SQLExpr::GroupingSets(_) | SQLExpr::Rollup(_) | SQLExpr::Cube(_)
+            )
+        });
+        let with_modifier;
+        let exprs = match modifiers {
+            [] if !has_grouping_sets => return Ok(None),
+            [] => exprs,
+            [modifier @ (GroupByWithModifier::Rollup | GroupByWithModifier::Cube)] => {
+                polars_ensure!(
+                    !has_grouping_sets,
+                    SQLSyntax: "GROUP BY {} cannot be combined with GROUPING SETS, ROLLUP or CUBE", modifier
+                );
+                // `GROUP BY a, b WITH ROLLUP` is `GROUP BY ROLLUP(a, b)`
+                let elements = exprs.iter().map(|e| vec![e.clone()]).collect();
+                with_modifier = if matches!(modifier, GroupByWithModifier::Rollup) {
+                    SQLExpr::Rollup(elements)
+                } else {
+                    SQLExpr::Cube(elements)
+                };
+                std::slice::from_ref(&with_modifier)
+            },
+            [modifier] => polars_bail!(SQLInterface: "GROUP BY does not support {}", modifier),
+            _ => polars_bail!(SQLSyntax: "GROUP BY supports at most one modifier"),
+        };
+
+        let mut keys = vec![];
+        let mut sets = vec![vec![]];
+        for expr in exprs {
+            let expr_sets: Vec<Vec<usize>> = match expr {
+                SQLExpr::GroupingSets(expr_sets) => expr_sets
+                    .iter()
+                    .map(|set| key_indices(&mut keys, set))
+                    .collect(),
+                SQLExpr::Rollup(elements) | SQLExpr::Cube(elements) => {
+                    let elements = elements
+                        .iter()
+                        .map(|element| key_indices(&mut keys, element))
+                        .collect::<Vec<_>>();
+                    let element_sets = if matches!(expr, SQLExpr::Rollup(_)) {
+                        GroupingSetsOptions::rollup(elements.len()).sets
+                    } else {
+                        GroupingSetsOptions::cube(elements.len()).sets
+                    };
+                    element_sets
+                        .iter()
+                        .map(|set| set.iter().flat_map(|&i| elements[i].clone()).collect())
+                        .collect()
+                },
+                _ => vec![key_indices(&mut keys, std::slice::from_ref(expr))],
+            };
+            sets = sets
+                .iter()
+                .flat_map(|set| {
+                    expr_sets.iter().map(move |expr_set| {
+                        let mut set = set.clone();
+                        for &i in expr_set {
+                            if !set.contains(&i) {
+                                set.push(i);
+                            }
+                        }
+                        set
+                    })
+                })
+                .collect();
+        }
+        Ok(Some(Self { keys, sets }))
+    }
+}
+
+/// Get the indices of `exprs` in the GROUP BY `keys`, adding the expressions that are not keys yet.
+fn key_indices(keys: &mut Vec<SQLExpr>, exprs: &[SQLExpr]) -> Vec<usize> {
+    let mut indices = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        let idx = keys.iter().position(|key| key == expr).unwrap_or_else(|| {
+            keys.push(expr.clone());
+            keys.len() - 1
+        });
+        if !indices.contains(&idx) {
+            indices.push(idx);
+        }
+    }
+    indices
+}
+
+/// The result of a subquery that is joined to the rows an expression is evaluated on.
+#[derive(Clone)]
+struct SubqueryJoin {
+    frame: LazyFrame,
+    /// The correlation keys of the outer rows, empty for an uncorrelated subquery.
+    left_on: Vec<Expr>,
+    right_on: Vec<PlSmallStr>,
+    validation: JoinValidation,
+    /// The columns added by the join, which are only referred to by the parsed expression.
+    columns: Vec<PlSmallStr>,
+}
+
+impl SubqueryJoin {
+    fn apply(self, lf: LazyFrame) -> LazyFrame {
+        if self.left_on.is_empty() {
+            lf.cross_join(self.frame, None)
+        } else {
+            lf.join_builder()
+                .with(self.frame)
+                .left_on(self.left_on)
+                .right_on(self.right_on.into_iter().map(col).collect::<Vec<_>>())
+                .how(JoinType::Left)
+                .validate(self.validation)
+                .coalesce(JoinCoalesce::KeepColumns)
+                .maintain_order(polars_ops::frame::MaintainOrderJoin::Left)
+                .finish()
+        }
+    }
+}
+
+/// A subquery whose WHERE clause refers to the outer query.
+struct CorrelatedSubquery {
+    query: Query,
+    /// The SELECT of the subquery, without the predicates that refer to the outer query.
+    select: Select,
+    /// Expressions of the subquery that must equal the expressions of the outer query.
+    inner_keys: Vec<SQLExpr>,
+    outer_keys: Vec<Expr>,
+    /// Whether the SELECT aggregates its rows (in which case it is grouped by the keys).
+    is_aggregate: bool,
+}
+
+impl CorrelatedSubquery {
+    /// Whether the subquery aggregates all its rows into a single row, which it has for every
+    /// outer row (including those without matching rows).
+    fn has_single_row(&self) -> bool {
+        self.is_aggregate
+            && self.select.having.is_none()
+            && matches!(&self.select.group_by, GroupByExpr::Expressions(exprs, _) if exprs.is_empty())
+    }
+}
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -59,10 +218,15 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    recursive_cte_max_iterations: usize,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    cte_refs: RefCell<PlHashSet<String>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    named_windows: RefCell<PlHashMap<String, WindowSpec>>,
+    grouping_keys: RefCell<Option<Vec<SQLExpr>>>,
+    subquery_joins: RefCell<Option<Vec<SubqueryJoin>>>,
 }
 
 impl Default for SQLContext {
@@ -70,9 +234,14 @@ impl Default for SQLContext {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
             table_map: Default::default(),
+            recursive_cte_max_iterations: RecursiveUnionOptions::DEFAULT_MAX_ITERATIONS,
             cte_map: Default::default(),
+            cte_refs: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            named_windows: Default::default(),
+            grouping_keys: Default::default(),
+            subquery_joins: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -164,8 +333,12 @@ impl SQLContext {
 
         // Every execution should clear the statement-level maps.
         self.cte_map.borrow_mut().clear();
+        self.cte_refs.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
+        self.named_windows.borrow_mut().clear();
+        self.grouping_keys.borrow_mut().take();
+        self.subquery_joins.borrow_mut().take();
 
         Ok(res)
     }
@@ -177,6 +350,12 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations of a recursive CTE, after which it raises an error.
+    pub fn with_recursive_cte_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.recursive_cte_max_iterations = max_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -225,13 +404,76 @@ impl SQLContext {
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
         let table = self.table_map.get(name).cloned();
         table
-            .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                let lf = self.cte_map.borrow().get(name).cloned();
+                if lf.is_some() {
+                    self.cte_refs.borrow_mut().insert(name.to_owned());
+                }
+                lf
             })
+            .or_else(|| {
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                })
+            })
+    }
+
+    /// Get a window defined in the WINDOW clause of the current SELECT.
+    pub(crate) fn get_named_window(&self, name: &Ident) -> PolarsResult<WindowSpec> {
+        self.named_windows
+            .borrow()
+            .get(&name.value)
+            .cloned()
+            .ok_or_else(|| polars_err!(SQLInterface: "window '{}' is not defined", name))
+    }
+
+    /// Resolve a window specification that builds on a named window, e.g. `OVER (w ORDER BY a)`.
+    pub(crate) fn resolve_window_spec(&self, spec: &WindowSpec) -> PolarsResult<WindowSpec> {
+        let Some(name) = &spec.window_name else {
+            return Ok(spec.clone());
+        };
+        let base = self.get_named_window(name)?;
+        polars_ensure!(
+            spec.partition_by.is_empty(),
+            SQLSyntax: "cannot override PARTITION BY of window '{}'", name
+        );
+        polars_ensure!(
+            spec.order_by.is_empty() || base.order_by.is_empty(),
+            SQLSyntax: "cannot override ORDER BY of window '{}'", name
+        );
+        polars_ensure!(
+            base.window_frame.is_none(),
+            SQLSyntax: "cannot copy window '{}' because it has a frame clause", name
+        );
+        Ok(WindowSpec {
+            window_name: None,
+            partition_by: base.partition_by,
+            order_by: if spec.order_by.is_empty() {
+                base.order_by
+            } else {
+                spec.order_by.clone()
+            },
+            window_frame: spec.window_frame.clone(),
+        })
+    }
+
+    /// Get the bit of a GROUP BY key in the grouping id of the current SELECT, for `GROUPING()`.
+    pub(crate) fn get_grouping_bit(&self, expr: &SQLExpr) -> PolarsResult<u32> {
+        let grouping_keys = self.grouping_keys.borrow();
+        let Some(keys) = grouping_keys.as_ref() else {
+            polars_bail!(SQLSyntax: "GROUPING requires a GROUP BY with GROUPING SETS, ROLLUP or CUBE")
+        };
+        polars_ensure!(
+            keys.len() <= 32,
+            SQLInterface: "GROUPING supports at most 32 GROUP BY keys, found {}", keys.len()
+        );
+        let idx = keys.iter().position(|key| key == expr).ok_or_else(
+            || polars_err!(SQLSyntax: "GROUPING argument '{}' is not a GROUP BY key", expr),
+        )?;
+        Ok(1 << (keys.len() - 1 - idx))
     }
 
     fn expr_or_ordinal(
@@ -594,24 +836,100 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match &*cte.query.body {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive => {
+                        self.execute_recursive_cte(cte, left, right, set_quantifier)?
+                    },
+                    _ => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Execute a `UNION` CTE of a `WITH RECURSIVE` clause.
+    ///
+    /// The CTE is recursive if the right side of the `UNION` refers to the CTE itself; it is then
+    /// evaluated on the rows that the previous iteration added, until no new rows are added.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        anchor: &SetExpr,
+        recursive: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let query = &cte.query;
+        let cte_name = &cte.alias.name.value;
+        self.register_ctes(query)?;
+
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", quantifier, cte_name
+            ),
+        };
+        let options = RecursiveUnionOptions::new(cte_name.as_str().into())
+            .with_distinct(distinct)
+            .with_max_iterations(self.recursive_cte_max_iterations);
+
+        let anchor = self.process_query(anchor, query)?;
+        let anchor = self.rename_columns_from_table_alias(anchor, &cte.alias)?;
+
+        self.cte_refs.borrow_mut().remove(cte_name);
+        let lf = anchor.recursive_union(options, |work_table| {
+            self.register_cte(cte_name, work_table);
+            self.process_query(recursive, query)
+        })?;
+        if !self.cte_refs.borrow_mut().remove(cte_name) {
+            // The CTE does not refer to itself, so it is a plain UNION.
+            let lf = self.execute_query_no_ctes(query)?;
+            return self.rename_columns_from_table_alias(lf, &cte.alias);
+        }
+        self.process_limit_offset(lf, &query.limit, &query.offset)
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
             for join in &tbl_expr.joins {
+                if let TableFactor::Derived {
+                    lateral: true,
+                    subquery,
+                    alias,
+                } = &join.relation
+                {
+                    let join_type = match &join.join_operator {
+                        JoinOperator::CrossJoin => JoinType::Inner,
+                        JoinOperator::Inner(constraint) if is_lateral_constraint(constraint) => {
+                            JoinType::Inner
+                        },
+                        JoinOperator::LeftOuter(constraint)
+                            if is_lateral_constraint(constraint) =>
+                        {
+                            JoinType::Left
+                        },
+                        join_type => polars_bail!(
+                            SQLInterface:
+                            "LATERAL join must be a CROSS JOIN or an INNER/LEFT JOIN with 'ON TRUE' (found {:?})",
+                            join_type
+                        ),
+                    };
+                    lf = self.process_lateral_join(lf, subquery, alias, join_type)?;
+                    continue;
+                }
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -680,48 +998,228 @@ impl SQLContext {
                 };
 
                 // track join-aliased columns so we can resolve them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
-
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.register_joined_aliases(&r_name, &left_schema, &right_schema, &mut lf)?;
             }
         };
         Ok(lf)
     }
 
+    fn register_joined_aliases(
+        &mut self,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+        lf: &mut LazyFrame,
+    ) -> PolarsResult<()> {
+        let joined_schema = self.get_frame_schema(lf)?;
+
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+        Ok(())
+    }
+
+    /// Join a LATERAL derived table, whose subquery can refer to the columns of `lf`.
+    ///
+    /// The predicates of the subquery that refer to `lf` become the keys of the join, so that the
+    /// subquery is evaluated for the correlation keys of all rows at once.
+    fn process_lateral_join(
+        &mut self,
+        mut lf: LazyFrame,
+        subquery: &Query,
+        alias: &Option<TableAlias>,
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        let Some(alias) = alias else {
+            polars_bail!(SQLSyntax: "derived tables must have aliases");
+        };
+        let r_name = alias.name.value.clone();
+        let suffix = format_pl_smallstr!(":{}", r_name);
+        let left_schema = self.get_frame_schema(&mut lf)?;
+
+        let (mut rf, mut joined) = match self.decorrelate_subquery(subquery, &left_schema)? {
+            Some(subquery) => {
+                // An aggregation over all rows has a row for every outer row, also for those
+                // without subquery rows
+                let projection = subquery.select.projection.clone();
+                let (join_type, no_rows) = if subquery.has_single_row() {
+                    let no_rows =
+                        self.execute_subquery_on_no_rows(&subquery, projection.clone())?;
+                    (JoinType::Left, Some(no_rows))
+                } else {
+                    (join_type, None)
+                };
+                let left_on = subquery.outer_keys.clone();
+                let (rf, keys) = self.execute_correlated_subquery(subquery, projection, false)?;
+                let mut rf = cast_join_keys(rf, &left_on, &keys, &left_schema)?;
+                let schema = self.get_frame_schema(&mut rf)?;
+                let mut columns: Vec<_> = schema
+                    .iter_names()
+                    .filter(|name| !keys.contains(name))
+                    .cloned()
+                    .collect();
+                if !alias.columns.is_empty() {
+                    polars_ensure!(
+                        alias.columns.len() == columns.len(),
+                        SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the table/query ({})",
+                        alias.columns.len(), alias.name.value, columns.len()
+                    );
+                    let new_columns: Vec<_> = alias
+                        .columns
+                        .iter()
+                        .map(|c| PlSmallStr::from_str(&c.name.value))
+                        .collect();
+                    rf = rf.rename(&columns, &new_columns, true);
+                    columns = new_columns;
+                }
+                let mut joined = lf
+                    .join_builder()
+                    .with(rf.clone())
+                    .left_on(left_on)
+                    .right_on(keys.iter().cloned().map(col).collect::<Vec<_>>())
+                    .how(join_type)
+                    .suffix(suffix.clone())
+                    .coalesce(JoinCoalesce::KeepColumns)
+                    .maintain_order(polars_ops::frame::MaintainOrderJoin::Left)
+                    .finish();
+                if let Some(mut no_rows) = no_rows {
+                    // Outer rows without subquery rows take the values over no rows
+                    let no_rows_schema = self.get_frame_schema(&mut no_rows)?;
+                    let no_rows_columns: Vec<PlSmallStr> =
+                        columns.iter().map(|_| unique_column_name()).collect();
+                    let no_rows = no_rows.select(
+                        no_rows_schema
+                            .iter_names()
+                            .zip(&no_rows_columns)
+                            .map(|(name, no_rows_name)| {
+                                col(name.clone()).alias(no_rows_name.clone())
+                            })
+                            .collect::<Vec<_>>(),
+                    );
+                    let matched = col(keys[0].clone()).is_not_null();
+                    joined = joined
+                        .cross_join(no_rows, None)
+                        .with_columns(
+                            columns
+                                .iter()
+                                .zip(&no_rows_columns)
+                                .map(|(name, no_rows_name)| {
+                                    let name = if left_schema.contains(name) {
+                                        format_pl_smallstr!("{}{}", name, suffix)
+                                    } else {
+                                        name.clone()
+                                    };
+                                    when(matched.clone())
+                                        .then(col(name.clone()))
+                                        .otherwise(col(no_rows_name.clone()))
+                                        .alias(name)
+                                })
+                                .collect::<Vec<_>>(),
+                        )
+                        .drop(by_name(no_rows_columns, true));
+                }
+                let joined = joined.drop(by_name(keys.clone(), true));
+                (rf.drop(by_name(keys, true)), joined)
+            },
+            None => {
+                polars_ensure!(
+                    matches!(join_type, JoinType::Inner),
+                    SQLInterface: "LEFT JOIN LATERAL requires a subquery that refers to the preceding tables"
+                );
+                let rf = self.execute_query_no_ctes(subquery)?;
+                let rf = self.rename_columns_from_table_alias(rf, alias)?;
+                (rf.clone(), lf.cross_join(rf, Some(suffix)))
+            },
+        };
+
+        self.table_map.insert(r_name.clone(), rf.clone());
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        self.register_joined_aliases(&r_name, &left_schema, &right_schema, &mut joined)?;
+        Ok(joined)
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
-        let mut lf = if select_stmt.from.is_empty() {
+        let lf = if select_stmt.from.is_empty() {
             DataFrame::empty().lazy()
         } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+            let from = &select_stmt.from;
+            let mut lf = self.execute_from_statement(&from[0])?;
+            for tbl_expr in &from[1..] {
+                match &tbl_expr.relation {
+                    // 'FROM tbl, LATERAL (...) AS alias' is a cross join with the LATERAL table
+                    TableFactor::Derived {
+                        lateral: true,
+                        subquery,
+                        alias,
+                    } if tbl_expr.joins.is_empty() => {
+                        lf = self.process_lateral_join(lf, subquery, alias, JoinType::Inner)?;
+                    },
+                    // Note: implicit joins need more work to support properly,
+                    // explicit joins are preferred for now (ref: #16662)
+                    _ => {
+                        polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+                    },
+                }
             }
-            self.execute_from_statement(from.first().unwrap())?
+            lf
         };
 
+        // Window functions resolve named windows from the WINDOW clause of their own SELECT.
+        let outer_named_windows = self.named_windows.take();
+        for NamedWindowDefinition(name, window) in &select_stmt.named_window {
+            let window_spec = match window {
+                NamedWindowExpr::NamedWindow(base) => self.get_named_window(base)?,
+                NamedWindowExpr::WindowSpec(window_spec) => {
+                    self.resolve_window_spec(window_spec)?
+                },
+            };
+            self.named_windows
+                .borrow_mut()
+                .insert(name.value.clone(), window_spec);
+        }
+        let outer_grouping_keys = self.grouping_keys.take();
+        let outer_subquery_joins = self.subquery_joins.take();
+        let lf = self.process_select(lf, select_stmt, query);
+        self.named_windows.replace(outer_named_windows);
+        self.grouping_keys.replace(outer_grouping_keys);
+        self.subquery_joins.replace(outer_subquery_joins);
+        lf
+    }
+
+    /// Process the clauses of a 'SELECT' that apply to the rows of its FROM clause.
+    fn process_select(
+        &mut self,
+        mut lf: LazyFrame,
+        select_stmt: &Select,
+        query: &Query,
+    ) -> PolarsResult<LazyFrame> {
         // Filter expression (WHERE clause)
         let schema = self.get_frame_schema(&mut lf)?;
         lf = self.process_where(lf, &select_stmt.selection, false)?;
 
+        // Resolve grouping sets before the projections, which can refer to them with GROUPING()
+        let grouping_sets = match &select_stmt.group_by {
+            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
+                SQLGroupingSets::try_new(group_by_exprs, modifiers)?
+            },
+            GroupByExpr::All(_) => None,
+        };
+        self.grouping_keys
+            .replace(grouping_sets.as_ref().map(|gs| gs.keys.clone()));
+
         // 'SELECT *' modifiers
         let mut select_modifiers = SelectModifiers {
             ilike: None,
@@ -730,16 +1228,26 @@ impl SQLContext {
             replace: vec![],
         };
 
-        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+        let (joined, projections, subquery_columns) = self.with_subquery_joins(lf, |ctx| {
+            ctx.column_projections(select_stmt, &schema, &mut select_modifiers)
+        })?;
+        lf = joined;
+        // The projections refer to the columns of the joined subquery results
+        let schema = if subquery_columns.is_empty() {
+            schema
+        } else {
+            self.get_frame_schema(&mut lf)?
+        };
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
         match &select_stmt.group_by {
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
-            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
+            GroupByExpr::Expressions(group_by_exprs, _) => {
+                let group_by_exprs = match &grouping_sets {
+                    Some(grouping_sets) => &grouping_sets.keys,
+                    None => group_by_exprs,
+                };
                 // translate the group expressions, allowing ordinal values
                 group_by_keys = group_by_exprs
                     .iter()
@@ -785,7 +1293,7 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // The 'having' clause is only valid inside 'group by'
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
@@ -888,15 +1396,25 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            let grouping_sets = grouping_sets.map(|gs| {
+                GroupingSetsOptions::new(gs.sets).with_grouping_id(Some(GROUPING_ID_NAME))
+            });
+            let has_grouping_sets = grouping_sets.is_some();
+            lf = self.process_group_by(lf, &group_by_keys, &projections, grouping_sets)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
+            };
+
+            // The grouping id is kept up to here for GROUPING() in the ORDER BY and HAVING clauses.
+            if has_grouping_sets {
+                lf = lf.drop(by_name([GROUPING_ID_NAME], true));
             }
+            lf
         };
 
         // Apply optional DISTINCT clause.
@@ -1009,16 +1527,20 @@ impl SQLContext {
             }
 
             // ...otherwise parse and apply the filter as normal
-            let mut filter_expression = parse_sql_expr(expr, self, Some(schema).as_deref())?;
+            let (joined, mut filter_expression, subquery_columns) =
+                self.with_subquery_joins(lf, |ctx| parse_sql_expr(expr, ctx, Some(&schema)))?;
             if filter_expression.clone().meta().has_multiple_outputs() {
                 filter_expression = all_horizontal([filter_expression])?;
             }
-            lf = self.process_subqueries(lf, vec![&mut filter_expression]);
+            lf = self.process_subqueries(joined, vec![&mut filter_expression]);
             lf = if invert_filter {
                 lf.remove(filter_expression)
             } else {
                 lf.filter(filter_expression)
             };
+            if !subquery_columns.is_empty() {
+                lf = lf.drop(by_name(subquery_columns, true));
+            }
         }
         Ok(lf)
     }
@@ -1070,6 +1592,499 @@ impl SQLContext {
         }
     }
 
+    /// Parse expressions that can contain EXISTS, scalar and correlated subqueries, whose results
+    /// are joined to `lf`.
+    ///
+    /// Returns `lf` with the joined subquery results, and the names of the joined columns (which
+    /// are only referred to by the parsed expressions).
+    fn with_subquery_joins<T>(
+        &mut self,
+        mut lf: LazyFrame,
+        parse: impl FnOnce(&mut Self) -> PolarsResult<T>,
+    ) -> PolarsResult<(LazyFrame, T, Vec<PlSmallStr>)> {
+        let outer_joins = self.subquery_joins.replace(Some(vec![]));
+        let parsed = parse(self);
+        let joins = self.subquery_joins.replace(outer_joins).unwrap_or_default();
+        let parsed = parsed?;
+
+        let mut columns = vec![];
+        if !joins.is_empty() {
+            // A SELECT without a FROM clause evaluates its expressions for a single row.
+            let mut no_from = self.get_frame_schema(&mut lf)?.is_empty();
+            for join in joins {
+                columns.extend(join.columns.iter().cloned());
+                lf = if no_from && join.left_on.is_empty() {
+                    no_from = false;
+                    join.frame
+                } else {
+                    join.apply(lf)
+                };
+            }
+        }
+        Ok((lf, parsed, columns))
+    }
+
+    /// Ensure that subqueries can be joined to the rows of the expression being parsed, whose
+    /// schema is `outer_schema`.
+    fn subquery_outer_schema<'s>(
+        &self,
+        outer_schema: Option<&'s Schema>,
+    ) -> PolarsResult<&'s Schema> {
+        match outer_schema {
+            Some(schema) if self.subquery_joins.borrow().is_some() => Ok(schema),
+            _ => polars_bail!(
+                SQLInterface: "EXISTS, scalar and correlated subqueries are only supported in the SELECT and WHERE clauses"
+            ),
+        }
+    }
+
+    fn add_subquery_join(
+        &mut self,
+        mut join: SubqueryJoin,
+        outer_schema: &Schema,
+    ) -> PolarsResult<()> {
+        join.frame = cast_join_keys(join.frame, &join.left_on, &join.right_on, outer_schema)?;
+        self.subquery_joins
+            .get_mut()
+            .as_mut()
+            .expect("subquery joins are in scope")
+            .push(join);
+        Ok(())
+    }
+
+    /// Join the result of an `EXISTS` subquery, returning whether it has rows for the outer row.
+    pub(crate) fn join_exists_subquery(
+        &mut self,
+        subquery: &Query,
+        outer_schema: Option<&Schema>,
+    ) -> PolarsResult<Expr> {
+        let outer_schema = self.subquery_outer_schema(outer_schema)?;
+        match self.decorrelate_subquery(subquery, outer_schema)? {
+            Some(subquery) => {
+                if subquery.has_single_row() {
+                    return Ok(lit(true));
+                }
+                let left_on = subquery.outer_keys.clone();
+                let (frame, keys) = self.execute_correlated_subquery(subquery, vec![], true)?;
+                let exists = col(keys[0].clone()).is_not_null();
+                self.add_subquery_join(
+                    SubqueryJoin {
+                        frame,
+                        left_on,
+                        right_on: keys.clone(),
+                        validation: JoinValidation::ManyToMany,
+                        columns: keys,
+                    },
+                    outer_schema,
+                )?;
+                Ok(exists)
+            },
+            None => {
+                let name = unique_column_name();
+                let frame = self
+                    .execute_query_no_ctes(subquery)?
+                    .limit(1)
+                    .select([len().gt(lit(0)).alias(name.clone())]);
+                self.add_subquery_join(
+                    SubqueryJoin {
+                        frame,
+                        left_on: vec![],
+                        right_on: vec![],
+                        validation: JoinValidation::ManyToMany,
+                        columns: vec![name.clone()],
+                    },
+                    outer_schema,
+                )?;
+                Ok(col(name))
+            },
+        }
+    }
+
+    /// Join the result of a correlated `IN` subquery, returning whether it has the value of
+    /// `expr` for the outer row. Returns `None` for an uncorrelated subquery.
+    ///
+    /// Like `expr = ANY (subquery)`, the result is NULL rather than FALSE if the value is NULL or
+    /// the subquery has a NULL for the outer row, unless the subquery has no rows for it.
+    pub(crate) fn join_in_subquery(
+        &mut self,
+        expr: Expr,
+        subquery: &Query,
+        outer_schema: Option<&Schema>,
+    ) -> PolarsResult<Option<Expr>> {
+        let Some(outer_schema) = outer_schema else {
+            return Ok(None);
+        };
+        let Some(subquery) = self.decorrelate_subquery(subquery, outer_schema)? else {
+            return Ok(None);
+        };
+        let outer_schema = self.subquery_outer_schema(Some(outer_schema))?;
+
+        let name = unique_column_name();
+        let value = SelectItem::ExprWithAlias {
+            expr: subquery_value(&subquery.select)?,
+            alias: Ident::new(name.as_str()),
+        };
+        let outer_keys = subquery.outer_keys.clone();
+        let (frame, keys) = self.execute_correlated_subquery(subquery, vec![value], true)?;
+
+        // Whether the subquery has a NULL for the outer row, which is NULL itself if it has no
+        // rows for it.
+        let has_null = unique_column_name();
+        let null_keys: Vec<PlSmallStr> = keys.iter().map(|_| unique_column_name()).collect();
+        let null_frame = frame
+            .clone()
+            .group_by(keys.iter().cloned().map(col).collect::<Vec<_>>())
+            .agg([col(name.clone())
+                .is_null()
+                .any(false)
+                .alias(has_null.clone())])
+            .select(
+                keys.iter()
+                    .zip(&null_keys)
+                    .map(|(key, null_key)| col(key.clone()).alias(null_key.clone()))
+                    .chain([col(has_null.clone())])
+                    .collect::<Vec<_>>(),
+            );
+        let mut null_columns = null_keys.clone();
+        null_columns.push(has_null.clone());
+        self.add_subquery_join(
+            SubqueryJoin {
+                frame: null_frame,
+                left_on: outer_keys.clone(),
+                right_on: null_keys,
+                validation: JoinValidation::ManyToOne,
+                columns: null_columns,
+            },
+            outer_schema,
+        )?;
+
+        let mut left_on = vec![expr.clone()];
+        left_on.extend(outer_keys);
+        let mut right_on = vec![name.clone()];
+        right_on.extend(keys);
+        self.add_subquery_join(
+            SubqueryJoin {
+                frame,
+                left_on,
+                right_on: right_on.clone(),
+                validation: JoinValidation::ManyToMany,
+                columns: right_on,
+            },
+            outer_schema,
+        )?;
+
+        let has_null = col(has_null);
+        Ok(Some(
+            when(has_null.clone().is_null())
+                .then(lit(false))
+                .when(col(name).is_not_null())
+                .then(lit(true))
+                .when(expr.is_null().or(has_null))
+                .then(lit(NULL).cast(DataType::Boolean))
+                .otherwise(lit(false)),
+        ))
+    }
+
+    /// Join the result of a scalar subquery, returning its value for the outer row.
+    pub(crate) fn join_scalar_subquery(
+        &mut self,
+        subquery: &Query,
+        outer_schema: Option<&Schema>,
+    ) -> PolarsResult<Expr> {
+        let outer_schema = self.subquery_outer_schema(outer_schema)?;
+        let name = unique_column_name();
+        match self.decorrelate_subquery(subquery, outer_schema)? {
+            Some(subquery) => {
+                let value = subquery_value(&subquery.select)?;
+                // An aggregation over all rows also has a value for outer rows without subquery
+                // rows, e.g. 0 for COUNT, which is the value of the projection over no rows.
+                let empty_value = if subquery.has_single_row() {
+                    let empty_name = unique_column_name();
+                    let projection = vec![SelectItem::ExprWithAlias {
+                        expr: value.clone(),
+                        alias: Ident::new(empty_name.as_str()),
+                    }];
+                    let frame = self.execute_subquery_on_no_rows(&subquery, projection)?;
+                    self.add_subquery_join(
+                        SubqueryJoin {
+                            frame,
+                            left_on: vec![],
+                            right_on: vec![],
+                            validation: JoinValidation::ManyToMany,
+                            columns: vec![empty_name.clone()],
+                        },
+                        outer_schema,
+                    )?;
+                    Some(col(empty_name))
+                } else {
+                    None
+                };
+
+                // The subquery must have at most one row per outer row, also if it is grouped
+                let left_on = subquery.outer_keys.clone();
+                let value = SelectItem::ExprWithAlias {
+                    expr: value,
+                    alias: Ident::new(name.as_str()),
+                };
+                let (frame, keys) =
+                    self.execute_correlated_subquery(subquery, vec![value], false)?;
+                let matched = col(keys[0].clone()).is_not_null();
+                let mut columns = vec![name.clone()];
+                columns.extend(keys.iter().cloned());
+                self.add_subquery_join(
+                    SubqueryJoin {
+                        frame,
+                        left_on,
+                        right_on: keys,
+                        validation: JoinValidation::ManyToOne,
+                        columns,
+                    },
+                    outer_schema,
+                )?;
+                Ok(match empty_value {
+                    Some(empty_value) => when(matched).then(col(name)).otherwise(empty_value),
+                    None => col(name),
+                })
+            },
+            None => {
+                let mut frame = self.execute_query_no_ctes(subquery)?;
+                let schema = self.get_frame_schema(&mut frame)?;
+                polars_ensure!(
+                    schema.len() == 1,
+                    SQLSyntax: "SQL subquery returns more than one column"
+                );
+                let value = col(schema.get_at_index(0).unwrap().0.clone())
+                    .implode()
+                    .map(
+                        |c| {
+                            let n_rows = c.list()?.get_as_series(0).map_or(0, |s| s.len());
+                            polars_ensure!(
+                                n_rows <= 1,
+                                SQLSyntax: "SQL subquery returns more than one row"
+                            );
+                            Ok(c)
+                        },
+                        |_, field| Ok(field.clone()),
+                    )
+                    .explode()
+                    .first();
+                let frame = frame.select([value.alias(name.clone())]);
+                self.add_subquery_join(
+                    SubqueryJoin {
+                        frame,
+                        left_on: vec![],
+                        right_on: vec![],
+                        validation: JoinValidation::ManyToMany,
+                        columns: vec![name.clone()],
+                    },
+                    outer_schema,
+                )?;
+                Ok(col(name))
+            },
+        }
+    }
+
+    /// Take the predicates that refer to the outer query out of the WHERE clause of a subquery.
+    ///
+    /// The predicates must be equalities between an expression of the subquery and one of the
+    /// outer query, whose rows have `outer_schema`; these become the keys that the result of the
+    /// subquery is joined on. Returns `None` if the subquery does not refer to the outer query.
+    fn decorrelate_subquery(
+        &mut self,
+        subquery: &Query,
+        outer_schema: &Schema,
+    ) -> PolarsResult<Option<CorrelatedSubquery>> {
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        let SetExpr::Select(select) = subquery.body.as_ref() else {
+            return Ok(None);
+        };
+        let [from] = select.from.as_slice() else {
+            return Ok(None);
+        };
+
+        // Identifiers refer to the subquery before they refer to the outer query
+        let mut inner_lf = self.execute_from_statement(from)?;
+        let inner_schema = self.get_frame_schema(&mut inner_lf)?;
+        let inner_relations: PlHashSet<String> = std::iter::once(&from.relation)
+            .chain(from.joins.iter().map(|join| &join.relation))
+            .filter_map(relation_name)
+            .collect();
+        let is_outer = |idents: &[Ident]| match idents {
+            [] => false,
+            [name] => !inner_schema.contains(&name.value) && outer_schema.contains(&name.value),
+            [tbl_name, ..] => {
+                !inner_relations.contains(&tbl_name.value)
+                    && !inner_schema.contains(&tbl_name.value)
+                    && (outer_schema.contains(&tbl_name.value)
+                        || self.get_table_from_current_scope(&tbl_name.value).is_some())
+            },
+        };
+
+        for item in &select.projection {
+            if let SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } = item {
+                polars_ensure!(
+                    !references(expr, &is_outer)?.0,
+                    SQLInterface: "subqueries can only refer to the outer query in their WHERE clause (found {})", expr
+                );
+            }
+        }
+
+        let mut predicates = vec![];
+        if let Some(selection) = &select.selection {
+            split_conjunction(selection, &mut predicates);
+        }
+        let mut residual = vec![];
+        let mut keys = vec![];
+        for predicate in predicates {
+            if !references(predicate, &is_outer)?.0 {
+                residual.push(predicate.clone());
+                continue;
+            }
+            let key = match predicate {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } => match (references(left, &is_outer)?, references(right, &is_outer)?) {
+                    ((false, _), (true, false)) => Some((left, right)),
+                    ((true, false), (false, _)) => Some((right, left)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            let Some((inner_key, outer_key)) = key else {
+                polars_bail!(
+                    SQLInterface: "predicates of subqueries that refer to the outer query must be equalities between an expression of the subquery and an expression of the outer query (found {})",
+                    predicate
+                );
+            };
+            keys.push(((**inner_key).clone(), (**outer_key).clone()));
+        }
+        if keys.is_empty() {
+            return Ok(None);
+        }
+
+        let outer_keys = keys
+            .iter()
+            .map(|(_, outer_key)| parse_sql_expr(outer_key, self, Some(outer_schema)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let inner_keys = keys.into_iter().map(|(inner_key, _)| inner_key).collect();
+
+        let is_aggregate = match &select.group_by {
+            GroupByExpr::All(_) => true,
+            GroupByExpr::Expressions(exprs, _) if !exprs.is_empty() => true,
+            GroupByExpr::Expressions(..) => {
+                // Parse the projections in a scope of their own, their subqueries are not joined
+                let outer_joins = self.subquery_joins.replace(Some(vec![]));
+                let projections = select
+                    .projection
+                    .iter()
+                    .filter_map(|item| match item {
+                        SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                            Some(parse_sql_expr(expr, self, Some(&inner_schema)))
+                        },
+                        _ => None,
+                    })
+                    .collect::<PolarsResult<Vec<_>>>();
+                self.subquery_joins.replace(outer_joins);
+                projections?.iter().any(|expr| {
+                    has_expr(expr, |e| matches!(e, Expr::Agg(_) | Expr::Len))
+                        && !has_expr(expr, |e| matches!(e, Expr::Window { .. }))
+                })
+            },
+        };
+
+        let mut select = (**select).clone();
+        select.selection = residual
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: BinaryOperator::And,
+                right: Box::new(right),
+            });
+        Ok(Some(CorrelatedSubquery {
+            query: subquery.clone(),
+            select,
+            inner_keys,
+            outer_keys,
+            is_aggregate,
+        }))
+    }
+
+    /// Execute a correlated subquery with the given projection, adding the columns of its
+    /// correlation keys (whose names are returned).
+    ///
+    /// The LIMIT and OFFSET of the subquery apply to the rows of each key.
+    /// Execute the projection of a subquery that aggregates all its rows over no rows, which gives
+    /// its values for outer rows without subquery rows (e.g. 0 for COUNT).
+    fn execute_subquery_on_no_rows(
+        &mut self,
+        subquery: &CorrelatedSubquery,
+        projection: Vec<SelectItem>,
+    ) -> PolarsResult<LazyFrame> {
+        let mut select = subquery.select.clone();
+        select.selection = Some(SQLExpr::Value(SQLValue::Boolean(false)));
+        select.projection = projection;
+        let mut query = subquery.query.clone();
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+        query.limit = None;
+        query.offset = None;
+        self.execute_query_no_ctes(&query)
+    }
+
+    fn execute_correlated_subquery(
+        &mut self,
+        subquery: CorrelatedSubquery,
+        projection: Vec<SelectItem>,
+        distinct: bool,
+    ) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+        let CorrelatedSubquery {
+            mut query,
+            mut select,
+            inner_keys,
+            is_aggregate,
+            ..
+        } = subquery;
+
+        let keys: Vec<PlSmallStr> = inner_keys.iter().map(|_| unique_column_name()).collect();
+        select.projection = projection;
+        select
+            .projection
+            .extend(
+                inner_keys
+                    .iter()
+                    .zip(&keys)
+                    .map(|(expr, key)| SelectItem::ExprWithAlias {
+                        expr: expr.clone(),
+                        alias: Ident::new(key.as_str()),
+                    }),
+            );
+        if is_aggregate && let GroupByExpr::Expressions(exprs, _) = &mut select.group_by {
+            exprs.extend(inner_keys);
+        }
+        if distinct {
+            select.distinct = Some(Distinct::Distinct);
+        }
+        let (limit, offset) = (query.limit.take(), query.offset.take());
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+
+        let mut lf = self.execute_query_no_ctes(&query)?;
+        if limit.is_some() || offset.is_some() {
+            let (offset, limit) = parse_limit_offset(&limit, &offset)?;
+            let row_index =
+                window_row_index().over(keys.iter().cloned().map(col).collect::<Vec<_>>());
+            let offset = offset.unwrap_or(0).max(0) as IdxSize;
+            let mut predicate = row_index.clone().gt_eq(lit(offset));
+            if let Some(limit) = limit {
+                predicate = predicate.and(row_index.lt(lit(offset.saturating_add(limit))));
+            }
+            lf = lf.filter(predicate);
+        }
+        Ok((lf, keys))
+    }
+
     fn execute_create_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::CreateTable(CreateTable {
             if_not_exists,
@@ -1129,7 +2144,10 @@ impl SQLContext {
                 subquery,
                 alias,
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
+                polars_ensure!(
+                    !(*lateral),
+                    SQLInterface: "LATERAL derived tables must follow the tables they refer to in the FROM clause"
+                );
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1297,9 +2315,16 @@ impl SQLContext {
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
         projections: &[Expr],
+        grouping_sets: Option<GroupingSetsOptions>,
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
-        let group_by_keys_schema = expressions_to_schema(group_by_keys, &schema_before)?;
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
+        let mut group_by_keys_schema = expressions_to_schema(group_by_keys, &schema_before)?;
+        let has_grouping_sets = grouping_sets.is_some();
+        if has_grouping_sets {
+            // GROUPING() refers to the grouping id, which the group-by outputs like a key.
+            Arc::make_mut(&mut schema_before).with_column(GROUPING_ID_NAME, DataType::UInt32);
+            group_by_keys_schema.with_column(GROUPING_ID_NAME, DataType::UInt32);
+        }
 
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
@@ -1365,11 +2390,15 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = match grouping_sets {
+            Some(grouping_sets) => lf.group_by_grouping_sets(group_by_keys, grouping_sets),
+            None => lf.group_by(group_by_keys),
+        }
+        .agg(&aggregation_projection);
         let projection_schema = expressions_to_schema(projections, &schema_before)?;
 
         // A final projection to get the proper order and any deferred transforms/aliases.
-        let final_projection = projection_schema
+        let mut final_projection = projection_schema
             .iter_names()
             .zip(projections)
             .map(|(name, projection_expr)| {
@@ -1385,6 +2414,9 @@ impl SQLContext {
                 }
             })
             .collect::<Vec<_>>();
+        if has_grouping_sets {
+            final_projection.push(col(GROUPING_ID_NAME));
+        }
 
         Ok(aggregated.select(&final_projection))
     }
@@ -1395,43 +2427,11 @@ impl SQLContext {
         limit: &Option<SQLExpr>,
         offset: &Option<Offset>,
     ) -> PolarsResult<LazyFrame> {
-        match (offset, limit) {
-            (
-                Some(Offset {
-                    value: SQLExpr::Value(SQLValue::Number(offset, _)),
-                    ..
-                }),
-                Some(SQLExpr::Value(SQLValue::Number(limit, _))),
-            ) => Ok(lf.slice(
-                offset
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
-                limit
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "LIMIT conversion error: {}", e))?,
-            )),
-            (
-                Some(Offset {
-                    value: SQLExpr::Value(SQLValue::Number(offset, _)),
-                    ..
-                }),
-                None,
-            ) => Ok(lf.slice(
-                offset
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
-                IdxSize::MAX,
-            )),
-            (None, Some(SQLExpr::Value(SQLValue::Number(limit, _)))) => Ok(lf.limit(
-                limit
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "LIMIT conversion error: {}", e))?,
-            )),
-            (None, None) => Ok(lf),
-            _ => polars_bail!(
-                SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported",
-            ),
-        }
+        Ok(match parse_limit_offset(limit, offset)? {
+            (Some(offset), limit) => lf.slice(offset, limit.unwrap_or(IdxSize::MAX)),
+            (None, Some(limit)) => lf.limit(limit),
+            (None, None) => lf,
+        })
     }
 
     fn process_qualified_wildcard(
@@ -1638,6 +2638,274 @@ fn process_join_on(
     }
 }
 
+/// Whether the constraint of a LATERAL join keeps all row combinations (e.g. `ON TRUE`).
+fn is_lateral_constraint(constraint: &JoinConstraint) -> bool {
+    matches!(
+        constraint,
+        JoinConstraint::None | JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(true)))
+    )
+}
+
+fn parse_limit_offset(
+    limit: &Option<SQLExpr>,
+    offset: &Option<Offset>,
+) -> PolarsResult<(Option<i64>, Option<IdxSize>)> {
+    let offset = match offset {
+        Some(Offset {
+            value: SQLExpr::Value(SQLValue::Number(offset, _)),
+            ..
+        }) => Some(
+            offset
+                .parse()
+                .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
+        ),
+        None => None,
+        _ => polars_bail!(SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported"),
+    };
+    let limit = match limit {
+        Some(SQLExpr::Value(SQLValue::Number(limit, _))) => Some(
+            limit
+                .parse()
+                .map_err(|e| polars_err!(SQLInterface: "LIMIT conversion error: {}", e))?,
+        ),
+        None => None,
+        _ => polars_bail!(SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported"),
+    };
+    Ok((offset, limit))
+}
+
+/// Cast the key columns `right_on` of a frame that is joined to rows with `schema` to the types
+/// of the keys `left_on` of those rows.
+fn cast_join_keys(
+    frame: LazyFrame,
+    left_on: &[Expr],
+    right_on: &[PlSmallStr],
+    schema: &Schema,
+) -> PolarsResult<LazyFrame> {
+    let casts = left_on
+        .iter()
+        .zip(right_on)
+        .map(|(left, right)| Ok(col(right.clone()).cast(left.to_field(schema)?.dtype)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(if casts.is_empty() {
+        frame
+    } else {
+        frame.with_columns(casts)
+    })
+}
+
+/// The name that the columns of a relation in a FROM clause are qualified with.
+fn relation_name(relation: &TableFactor) -> Option<String> {
+    match relation {
+        TableFactor::Table {
+            alias: Some(alias), ..
+        }
+        | TableFactor::Derived {
+            alias: Some(alias), ..
+        } => Some(alias.name.value.clone()),
+        TableFactor::Table { name, .. } => name.0.first().map(|ident| ident.value.clone()),
+        _ => None,
+    }
+}
+
+/// The single expression selected by a subquery.
+fn subquery_value(select: &Select) -> PolarsResult<SQLExpr> {
+    match select.projection.as_slice() {
+        [SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. }] => {
+            Ok(expr.clone())
+        },
+        _ => polars_bail!(SQLSyntax: "SQL subquery must select a single expression"),
+    }
+}
+
+/// Split the predicates of a conjunction (`a AND b AND ...`).
+fn split_conjunction<'a>(expr: &'a SQLExpr, predicates: &mut Vec<&'a SQLExpr>) {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_conjunction(left, predicates);
+            split_conjunction(right, predicates);
+        },
+        SQLExpr::Nested(nested)
+            if matches!(
+                nested.as_ref(),
+                SQLExpr::BinaryOp {
+                    op: BinaryOperator::And,
+                    ..
+                }
+            ) =>
+        {
+            split_conjunction(nested, predicates)
+        },
+        _ => predicates.push(expr),
+    }
+}
+
+/// Whether an expression refers to the outer query and whether it refers to the subquery, as
+/// decided by `is_outer` for each of its (compound) identifiers.
+fn references(expr: &SQLExpr, is_outer: &impl Fn(&[Ident]) -> bool) -> PolarsResult<(bool, bool)> {
+    let mut idents = vec![];
+    collect_identifiers(expr, &mut idents)?;
+    Ok((
+        idents.iter().any(|idents| is_outer(idents)),
+        idents.iter().any(|idents| !is_outer(idents)),
+    ))
+}
+
+/// Collect the (compound) identifiers of an expression, without those of nested subqueries.
+///
+/// Errors on expressions whose identifiers cannot be collected, as treating those as free of
+/// identifiers could silently drop a reference to the outer query.
+fn collect_identifiers<'a>(expr: &'a SQLExpr, idents: &mut Vec<&'a [Ident]>) -> PolarsResult<()> {
+    fn collect_all<'a>(
+        exprs: impl IntoIterator<Item = &'a SQLExpr>,
+        idents: &mut Vec<&'a [Ident]>,
+    ) -> PolarsResult<()> {
+        exprs
+            .into_iter()
+            .try_for_each(|e| collect_identifiers(e, idents))
+    }
+
+    match expr {
+        SQLExpr::Identifier(ident) => idents.push(std::slice::from_ref(ident)),
+        SQLExpr::CompoundIdentifier(compound) => idents.push(compound),
+        SQLExpr::Value(_) | SQLExpr::TypedString { .. } | SQLExpr::Wildcard(_) => {},
+        // nested subqueries are decorrelated on their own
+        SQLExpr::Subquery(_) | SQLExpr::Exists { .. } => {},
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right)
+        | SQLExpr::AnyOp { left, right, .. }
+        | SQLExpr::AllOp { left, right, .. } => collect_all([&**left, &**right], idents)?,
+        SQLExpr::Like { expr, pattern, .. }
+        | SQLExpr::ILike { expr, pattern, .. }
+        | SQLExpr::RLike { expr, pattern, .. } => collect_all([&**expr, &**pattern], idents)?,
+        SQLExpr::Position { expr, r#in } => collect_all([&**expr, &**r#in], idents)?,
+        SQLExpr::AtTimeZone {
+            timestamp,
+            time_zone,
+        } => collect_all([&**timestamp, &**time_zone], idents)?,
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::Ceil { expr, .. }
+        | SQLExpr::Floor { expr, .. }
+        | SQLExpr::Extract { expr, .. }
+        | SQLExpr::Collate { expr, .. }
+        | SQLExpr::InSubquery { expr, .. } => collect_identifiers(expr, idents)?,
+        SQLExpr::Interval(interval) => collect_identifiers(&interval.value, idents)?,
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => collect_all([&**expr, &**low, &**high], idents)?,
+        SQLExpr::Substring {
+            expr,
+            substring_from,
+            substring_for,
+            ..
+        } => collect_all(
+            std::iter::once(&**expr)
+                .chain(substring_from.as_deref())
+                .chain(substring_for.as_deref()),
+            idents,
+        )?,
+        SQLExpr::Trim {
+            expr,
+            trim_what,
+            trim_characters,
+            ..
+        } => collect_all(
+            std::iter::once(&**expr)
+                .chain(trim_what.as_deref())
+                .chain(trim_characters.iter().flatten()),
+            idents,
+        )?,
+        SQLExpr::Subscript { expr, subscript } => {
+            collect_identifiers(expr, idents)?;
+            match &**subscript {
+                Subscript::Index { index } => collect_identifiers(index, idents)?,
+                Subscript::Slice {
+                    lower_bound,
+                    upper_bound,
+                    stride,
+                } => collect_all(lower_bound.iter().chain(upper_bound).chain(stride), idents)?,
+            }
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            collect_all(std::iter::once(&**expr).chain(list), idents)?
+        },
+        SQLExpr::Tuple(exprs) => collect_all(exprs.iter(), idents)?,
+        SQLExpr::Array(arr) => collect_all(arr.elem.iter(), idents)?,
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => collect_all(
+            operand
+                .as_deref()
+                .into_iter()
+                .chain(else_result.as_deref())
+                .chain(conditions)
+                .chain(results),
+            idents,
+        )?,
+        SQLExpr::Function(func) => {
+            match &func.args {
+                FunctionArguments::None => {},
+                FunctionArguments::List(list) => {
+                    for arg in &list.args {
+                        let (FunctionArg::Named { arg, .. }
+                        | FunctionArg::ExprNamed { arg, .. }
+                        | FunctionArg::Unnamed(arg)) = arg;
+                        match arg {
+                            FunctionArgExpr::Expr(e) => collect_identifiers(e, idents)?,
+                            FunctionArgExpr::Wildcard => {},
+                            FunctionArgExpr::QualifiedWildcard(_) => polars_bail!(
+                                SQLInterface: "qualified wildcards are not supported in subqueries (found {})", expr
+                            ),
+                        }
+                    }
+                },
+                FunctionArguments::Subquery(_) => polars_bail!(
+                    SQLInterface: "subquery function arguments are not supported in subqueries (found {})", expr
+                ),
+            }
+            collect_all(
+                func.filter
+                    .as_deref()
+                    .into_iter()
+                    .chain(func.within_group.iter().map(|o| &o.expr)),
+                idents,
+            )?;
+            match &func.over {
+                None => {},
+                Some(WindowType::WindowSpec(spec)) => collect_all(
+                    spec.partition_by
+                        .iter()
+                        .chain(spec.order_by.iter().map(|o| &o.expr)),
+                    idents,
+                )?,
+                Some(WindowType::NamedWindow(_)) => polars_bail!(
+                    SQLInterface: "named windows are not supported in subqueries (found {})", expr
+                ),
+            }
+        },
+        _ => polars_bail!(
+            SQLInterface: "unsupported expression in subquery (found {})", expr
+        ),
+    }
+    Ok(())
+}
+
 fn process_join_constraint(
     constraint: &JoinConstraint,
     tbl_left: &TableInfo,
@@ -2,25 +2,34 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, IdxSize, PolarsResult, QuantileMethod,
+    RollingOptionsFixedWindow, Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 use polars_ops::chunked_array::UnicodeForm;
-use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
-use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
+use polars_ops::series::{RankMethod, RankOptions, RoundMode};
+use polars_plan::dsl::{
+    as_struct, coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when,
+};
+use polars_plan::plans::{DynLiteralValue, LiteralValue, NULL, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_time::prelude::RollingOptionsDynamicWindow;
+use polars_time::{ClosedWindow, Duration};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::context::GROUPING_ID_NAME;
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -555,6 +564,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function.
+    /// Returns a bit mask of the given GROUP BY keys that are not part of the grouping set of
+    /// the row, with the first key as the most significant bit.
+    /// ```sql
+    /// SELECT GROUPING(column_1) FROM df GROUP BY ROLLUP(column_1);
+    /// ```
+    Grouping,
     /// SQL 'last' function.
     /// Returns the last element of the grouping.
     /// ```sql
@@ -611,6 +627,70 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT VARIANCE(column_1) FROM df;
     /// ```
     Variance,
+
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function.
+    /// Returns the number of the row within its window partition, starting at 1.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function.
+    /// Returns the rank of the row within its window partition, with gaps for ties.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function.
+    /// Returns the rank of the row within its window partition, without gaps for ties.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'percent_rank' function.
+    /// Returns the relative rank of the row within its window partition, (rank - 1) / (rows - 1).
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    PercentRank,
+    /// SQL 'ntile' function.
+    /// Divides the rows of the window partition into `n` buckets of (almost) equal size and
+    /// returns the bucket of the row, starting at 1.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    NTile,
+    /// SQL 'lag' function.
+    /// Returns the value of the row `offset` rows before the row within its window partition,
+    /// or `default` if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LAG(column_1, 2, 0) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function.
+    /// Returns the value of the row `offset` rows after the row within its window partition,
+    /// or `default` if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LEAD(column_1, 2, 0) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'first_value' function.
+    /// Returns the value of the first row of the window frame.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function.
+    /// Returns the value of the last row of the window frame.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    LastValue,
+
     // ----
     // Array functions
     // ----
@@ -748,15 +828,21 @@ impl PolarsSQLFunctions {
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -769,6 +855,8 @@ impl PolarsSQLFunctions {
             "ltrim",
             "max",
             "median",
+            "ntile",
+            "percent_rank",
             "quantile_disc",
             "min",
             "mod",
@@ -780,11 +868,13 @@ impl PolarsSQLFunctions {
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -923,6 +1013,7 @@ impl PolarsSQLFunctions {
             "covar_pop" => Self::CovarPop,
             "covar" | "covar_samp" => Self::CovarSamp,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -933,6 +1024,19 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "row_number" => Self::RowNumber,
+            "rank" => Self::Rank,
+            "dense_rank" => Self::DenseRank,
+            "percent_rank" => Self::PercentRank,
+            "ntile" => Self::NTile,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "first_value" => Self::FirstValue,
+            "last_value" => Self::LastValue,
+
             // ----
             // Array functions
             // ----
@@ -982,6 +1086,32 @@ impl SQLFunctionVisitor<'_> {
             polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
         }
 
+        // Aggregations over a window frame are evaluated with cumulative and rolling functions
+        if let Some(WindowSpec {
+            window_frame: Some(window_frame),
+            order_by,
+            ..
+        }) = self.window_spec()?
+        {
+            let agg = match function_name {
+                Avg => Some(FrameAgg::Mean),
+                Count => Some(FrameAgg::Count),
+                First | FirstValue => Some(FrameAgg::First),
+                Last | LastValue => Some(FrameAgg::Last),
+                Max => Some(FrameAgg::Max),
+                Min => Some(FrameAgg::Min),
+                Sum => Some(FrameAgg::Sum),
+                // the frame does not apply to ranking and offset functions
+                RowNumber | Rank | DenseRank | PercentRank | NTile | Lag | Lead => None,
+                _ => polars_bail!(
+                    SQLInterface: "window frames are not supported for {}", function.name
+                ),
+            };
+            if let Some(agg) = agg {
+                return self.visit_framed_window(agg, &window_frame, &order_by);
+            }
+        }
+
         match function_name {
             // ----
             // Bitwise functions
@@ -1434,6 +1564,7 @@ impl SQLFunctionVisitor<'_> {
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1494,6 +1625,39 @@ impl SQLFunctionVisitor<'_> {
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            RowNumber => self.visit_ranking(|_| window_row_index() + lit(1)),
+            Rank => self.visit_ranking(window_rank),
+            DenseRank => self.visit_ranking(|order_key| {
+                window_peer_start(order_key).cast(IDX_DTYPE).cum_sum(false)
+            }),
+            PercentRank => self.visit_ranking(|order_key| {
+                let rank = window_rank(order_key).cast(DataType::Float64);
+                let n_rows = len().cast(DataType::Float64);
+                when(n_rows.clone().gt(lit(1.0)))
+                    .then((rank - lit(1.0)) / (n_rows - lit(1.0)))
+                    .otherwise(lit(0.0))
+            }),
+            NTile => self.visit_ntile(),
+            Lag => self.visit_offset_window(false),
+            Lead => self.visit_offset_window(true),
+            FirstValue => {
+                let expr = self.visit_unary_no_window(|e| e)?;
+                self.apply_ordered_window(|_| Ok(expr.first()))
+            },
+            LastValue => {
+                let expr = self.visit_unary_no_window(|e| e)?;
+                self.apply_ordered_window(|order_key| {
+                    Ok(match order_key {
+                        // The frame ends at the last peer of the row.
+                        Some(order_key) => expr.gather(window_last_peer(Some(order_key))),
+                        None => expr.last(),
+                    })
+                })
+            },
+
             // ----
             // Array functions
             // ----
@@ -1641,7 +1805,7 @@ impl SQLFunctionVisitor<'_> {
             )?),
             _ => self.not_supported_error(),
         }
-        .and_then(|e| self.apply_window_spec(e, &self.func.over))
+        .and_then(|e| self.apply_window_spec(e))
     }
 
     /// Some functions have cumulative equivalents that can be applied to window specs
@@ -1654,15 +1818,9 @@ impl SQLFunctionVisitor<'_> {
         f: impl Fn(Expr) -> Expr,
         cumulative_f: impl Fn(Expr, bool) -> Expr,
     ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
-            },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
-            _ => self.visit_unary(f),
+        match self.window_spec()? {
+            Some(spec) => self.apply_cumulative_window(f, cumulative_f, &spec),
+            None => self.visit_unary(f),
         }
     }
 
@@ -1838,7 +1996,377 @@ impl SQLFunctionVisitor<'_> {
             },
             _ => self.not_supported_error()?,
         };
-        self.apply_window_spec(count_expr, &self.func.over)
+        self.apply_window_spec(count_expr)
+    }
+
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        polars_ensure!(
+            !args.is_empty() && args.len() <= 32,
+            SQLSyntax: "GROUPING expects 1-32 arguments (found {})", args.len()
+        );
+        // Gather the bits of the keys from the grouping id, the first key is the highest bit.
+        let n_args = args.len();
+        let mut grouping = lit(0u32);
+        for (i, arg) in args.iter().enumerate() {
+            let FunctionArgExpr::Expr(sql_expr) = arg else {
+                return self.not_supported_error();
+            };
+            let bit = self.ctx.get_grouping_bit(sql_expr)?;
+            let is_set = col(GROUPING_ID_NAME)
+                .and(lit(bit))
+                .neq(lit(0u32))
+                .cast(DataType::UInt32);
+            grouping = grouping + is_set * lit(1u32 << (n_args - 1 - i));
+        }
+        Ok(grouping.alias("grouping"))
+    }
+
+    fn visit_ranking(&mut self, f: impl FnOnce(Option<Expr>) -> Expr) -> PolarsResult<Expr> {
+        if !extract_args(self.func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        self.apply_ordered_window(|order_key| Ok(f(order_key)))
+    }
+
+    fn visit_ntile(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let n = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+                    Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n > 0 => n as i64,
+                    _ => {
+                        polars_bail!(
+                            SQLSyntax: "NTILE expects a positive integer (found {})", args[0]
+                        )
+                    },
+                }
+            },
+            _ => return self.not_supported_error(),
+        };
+        self.apply_ordered_window(|_| {
+            // The first `n_rows % n` buckets hold one row more than the others.
+            let row = window_row_index().cast(DataType::Int64);
+            let n_rows = len().cast(DataType::Int64);
+            let size = n_rows.clone().floor_div(lit(n));
+            let n_larger = n_rows % lit(n);
+            let bucket = when(row.clone().lt(n_larger.clone() * (size.clone() + lit(1))))
+                .then(row.clone().floor_div(size.clone() + lit(1)))
+                .otherwise((row - n_larger).floor_div(size));
+            Ok((bucket + lit(1)).cast(IDX_DTYPE))
+        })
+    }
+
+    /// LAG(expr [, offset [, default]]) and LEAD(expr [, offset [, default]])
+    fn visit_offset_window(&mut self, lead: bool) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let mut exprs = vec![];
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => {
+                    exprs.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+                },
+                _ => return self.not_supported_error(),
+            }
+        }
+        if !(1..=3).contains(&exprs.len()) {
+            polars_bail!(
+                SQLSyntax: "{} expects 1-3 arguments (found {})", self.func.name, args.len()
+            )
+        }
+        let mut exprs = exprs.into_iter();
+        let expr = exprs.next().unwrap();
+        let offset = match exprs.next() {
+            None => 1,
+            Some(Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n)))) => n as i64,
+            Some(_) => {
+                polars_bail!(
+                    SQLSyntax: "{} expects an integer offset (found {})", self.func.name, args[1]
+                )
+            },
+        };
+        let offset = lit(if lead { -offset } else { offset });
+        let default = exprs.next();
+
+        self.apply_ordered_window(|_| {
+            Ok(match default {
+                Some(default) => expr.shift_and_fill(offset, default),
+                None => expr.shift(offset),
+            })
+        })
+    }
+
+    /// Evaluates the output of `f` over the rows of each window partition, in the order of the
+    /// window's ORDER BY. `f` is given the key that is equal for the peers of a row, the rows
+    /// that are not distinct in the ORDER BY, or `None` if all rows of a partition are peers.
+    fn apply_ordered_window(
+        &mut self,
+        f: impl FnOnce(Option<Expr>) -> PolarsResult<Expr>,
+    ) -> PolarsResult<Expr> {
+        let Some(window_spec) = self.window_spec()? else {
+            polars_bail!(SQLSyntax: "{} requires an OVER clause", self.func.name)
+        };
+        let partition_by = window_spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let order_by = self.parse_window_order_by(&window_spec.order_by)?;
+
+        let order_key = order_by.as_ref().map(|(keys, _)| match keys.as_slice() {
+            [key] => key.clone(),
+            _ => as_struct(keys.clone()),
+        });
+        let expr = f(order_key)?;
+
+        if partition_by.is_empty() && order_by.is_none() {
+            return Ok(expr);
+        }
+        let partition_by = (!partition_by.is_empty()).then_some(partition_by);
+        expr.over_with_options(partition_by, order_by, Default::default())
+    }
+
+    /// Evaluates an aggregation over the window frame of each row, e.g.
+    /// `SUM(a) OVER (ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)`.
+    fn visit_framed_window(
+        &mut self,
+        agg: FrameAgg,
+        window_frame: &WindowFrame,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<Expr> {
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        let expr = match (is_distinct, args.as_slice()) {
+            // count(*), count()
+            (false, [FunctionArgExpr::Wildcard] | []) if agg == FrameAgg::Count => {
+                window_row_index()
+            },
+            (false, [FunctionArgExpr::Expr(sql_expr)]) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            _ => return self.not_supported_error(),
+        };
+        let bounds = self.parse_window_frame(window_frame, agg, order_by)?;
+
+        let frame_expr = self.apply_ordered_window(|order_key| {
+            let row = window_row_index().cast(DataType::Int64);
+            let n_rows = len().cast(DataType::Int64);
+            Ok(match bounds {
+                WindowFrameBounds::Rows { start, end } if agg.is_positional() => {
+                    let first = start.map_or(lit(0), |s| row.clone() + lit(s));
+                    let last = end.map_or(n_rows.clone() - lit(1), |e| row + lit(e));
+                    let is_empty = first
+                        .clone()
+                        .gt_eq(n_rows.clone())
+                        .or(last.clone().lt(lit(0)));
+                    let idx = if agg == FrameAgg::First { first } else { last };
+                    let idx = idx.clip(lit(0), n_rows - lit(1)).cast(IDX_DTYPE);
+                    when(is_empty).then(lit(NULL)).otherwise(expr.gather(idx))
+                },
+                WindowFrameBounds::Rows { start, end } => {
+                    // Rows outside of the window partition are null after the shift, and
+                    // frames that extend past both ends cover the whole partition.
+                    let whole = agg.whole(expr.clone());
+                    match (start, end) {
+                        (None, None) => whole,
+                        (None, Some(e)) => {
+                            let frame = agg.cumulative(expr, false).shift(lit(-e));
+                            if e > 0 {
+                                when((row + lit(e)).lt(n_rows)).then(frame).otherwise(whole)
+                            } else {
+                                frame
+                            }
+                        },
+                        (Some(s), None) => {
+                            let frame = agg.cumulative(expr, true).shift(lit(-s));
+                            if s < 0 {
+                                when((row + lit(s)).gt_eq(lit(0)))
+                                    .then(frame)
+                                    .otherwise(whole)
+                            } else {
+                                frame
+                            }
+                        },
+                        (Some(s), Some(e)) => {
+                            let window_size = (e - s + 1) as usize;
+                            let behind = agg.rolling(expr.clone(), window_size).shift(lit(-e));
+                            let ahead = agg
+                                .rolling(expr.reverse(), window_size)
+                                .reverse()
+                                .shift(lit(-s));
+                            if e <= 0 {
+                                behind
+                            } else if s >= 0 {
+                                ahead
+                            } else {
+                                when((row.clone() + lit(e)).lt(n_rows))
+                                    .then(behind)
+                                    .when((row + lit(s)).gt_eq(lit(0)))
+                                    .then(ahead)
+                                    .otherwise(whole)
+                            }
+                        },
+                    }
+                },
+                WindowFrameBounds::RangeUnbounded => agg.whole(expr),
+                WindowFrameBounds::RangeToCurrentRow => match (agg, order_key) {
+                    (FrameAgg::First, _) | (_, None) => agg.whole(expr),
+                    (FrameAgg::Last, order_key) => expr.gather(window_last_peer(order_key)),
+                    (_, order_key) => agg
+                        .cumulative(expr, false)
+                        .gather(window_last_peer(order_key)),
+                },
+                WindowFrameBounds::RangeFromCurrentRow => match (agg, order_key) {
+                    (FrameAgg::Last, _) | (_, None) => agg.whole(expr),
+                    (FrameAgg::First, order_key) => expr.gather(window_first_peer(order_key)),
+                    (_, order_key) => agg
+                        .cumulative(expr, true)
+                        .gather(window_first_peer(order_key)),
+                },
+                WindowFrameBounds::RangePreceding(offset) => {
+                    agg.rolling_by(expr, order_key.unwrap(), offset)
+                },
+            })
+        })?;
+
+        // note: COUNT over an empty frame is 0, all other aggregations are NULL
+        Ok(if agg == FrameAgg::Count {
+            frame_expr.fill_null(lit(0 as IdxSize))
+        } else {
+            frame_expr
+        })
+    }
+
+    /// Parses the bounds of a window frame relative to the current row.
+    fn parse_window_frame(
+        &mut self,
+        window_frame: &WindowFrame,
+        agg: FrameAgg,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<WindowFrameBounds> {
+        use WindowFrameBound::*;
+
+        // note: a frame without an end bound ends at the current row
+        let start_bound = &window_frame.start_bound;
+        let end_bound = window_frame.end_bound.as_ref().unwrap_or(&CurrentRow);
+        polars_ensure!(
+            !matches!(start_bound, Following(None)) && !matches!(end_bound, Preceding(None)),
+            SQLSyntax: "window frames cannot start at UNBOUNDED FOLLOWING or end at UNBOUNDED PRECEDING"
+        );
+
+        match window_frame.units {
+            WindowFrameUnits::Rows => {
+                let start = self.parse_rows_bound(start_bound)?;
+                let end = self.parse_rows_bound(end_bound)?;
+                if let (Some(start), Some(end)) = (start, end) {
+                    polars_ensure!(
+                        start <= end,
+                        SQLSyntax: "window frames cannot start after their end ({} to {})",
+                        start_bound, end_bound
+                    );
+                }
+                Ok(WindowFrameBounds::Rows { start, end })
+            },
+            WindowFrameUnits::Range => match (start_bound, end_bound) {
+                (Preceding(None), Following(None)) => Ok(WindowFrameBounds::RangeUnbounded),
+                (Preceding(None), CurrentRow) => Ok(WindowFrameBounds::RangeToCurrentRow),
+                (CurrentRow, Following(None)) => Ok(WindowFrameBounds::RangeFromCurrentRow),
+                (Preceding(Some(offset)), CurrentRow) => {
+                    polars_ensure!(
+                        !agg.is_positional(),
+                        SQLInterface: "{} does not support RANGE frames with an offset", self.func.name
+                    );
+                    polars_ensure!(
+                        order_by.len() == 1 && order_by[0].asc != Some(false),
+                        SQLSyntax: "RANGE frames with an offset require a single ascending ORDER BY key"
+                    );
+                    let offset = match offset.as_ref() {
+                        SQLExpr::Interval(interval) => interval_to_duration(interval, false)?,
+                        _ => match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+                            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n > 0 => {
+                                Duration::parse(&format!("{n}i"))
+                            },
+                            _ => polars_bail!(
+                                SQLSyntax: "RANGE frame offset must be an interval or a positive integer (found {})",
+                                offset
+                            ),
+                        },
+                    };
+                    Ok(WindowFrameBounds::RangePreceding(offset))
+                },
+                _ => polars_bail!(
+                    SQLInterface: "RANGE frames must be unbounded, or run from UNBOUNDED or <offset> PRECEDING to CURRENT ROW, or from CURRENT ROW to UNBOUNDED FOLLOWING"
+                ),
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not currently supported")
+            },
+        }
+    }
+
+    /// The offset of a ROWS frame bound from the current row, or `None` if it is unbounded.
+    fn parse_rows_bound(&mut self, bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+        let (offset, sign) = match bound {
+            WindowFrameBound::CurrentRow => return Ok(Some(0)),
+            WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => {
+                return Ok(None);
+            },
+            WindowFrameBound::Preceding(Some(offset)) => (offset, -1),
+            WindowFrameBound::Following(Some(offset)) => (offset, 1),
+        };
+        match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => {
+                Ok(Some(sign * n as i64))
+            },
+            _ => polars_bail!(
+                SQLSyntax: "ROWS frame offset must be a non-negative integer (found {})", offset
+            ),
+        }
+    }
+
+    /// Parses the ORDER BY of a window into the sort keys and options of `over`.
+    fn parse_window_order_by(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<Option<(Vec<Expr>, SortOptions)>> {
+        // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+        let sort_options = |ob: &OrderByExpr| {
+            let descending = !ob.asc.unwrap_or(true);
+            SortOptions::default()
+                .with_order_descending(descending)
+                .with_nulls_last(!ob.nulls_first.unwrap_or(descending))
+        };
+        let Some(options) = order_by.first().map(sort_options) else {
+            return Ok(None);
+        };
+        let mixed_options = order_by.iter().any(|ob| sort_options(ob) != options);
+
+        let mut keys = Vec::with_capacity(order_by.len());
+        for ob in order_by {
+            let key = parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?;
+            keys.push(if mixed_options {
+                // `over` sorts all keys in the same order, so keys with different orders are
+                // replaced by their dense rank in ascending order.
+                let key_options = sort_options(ob);
+                let rank_options = RankOptions {
+                    method: RankMethod::Dense,
+                    descending: key_options.descending,
+                };
+                let null_rank = if key_options.nulls_last {
+                    IdxSize::MAX
+                } else {
+                    0
+                };
+                key.rank(rank_options, None).fill_null(lit(null_rank))
+            } else {
+                key
+            });
+        }
+        let options = if mixed_options {
+            SortOptions::default()
+        } else {
+            options
+        };
+        Ok(Some((keys, options)))
     }
 
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
@@ -1863,13 +2391,20 @@ impl SQLFunctionVisitor<'_> {
         ))
     }
 
-    fn apply_window_spec(
-        &mut self,
-        expr: Expr,
-        window_type: &Option<WindowType>,
-    ) -> PolarsResult<Expr> {
-        Ok(match &window_type {
+    /// The window specification of the OVER clause, with named windows resolved.
+    fn window_spec(&self) -> PolarsResult<Option<WindowSpec>> {
+        match &self.func.over {
             Some(WindowType::WindowSpec(window_spec)) => {
+                self.ctx.resolve_window_spec(window_spec).map(Some)
+            },
+            Some(WindowType::NamedWindow(name)) => self.ctx.get_named_window(name).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn apply_window_spec(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        Ok(match self.window_spec()? {
+            Some(window_spec) => {
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1892,10 +2427,6 @@ impl SQLFunctionVisitor<'_> {
                     expr.over(partition_by)
                 }
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             None => expr,
         })
     }
@@ -1909,6 +2440,154 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// The position of the row within its window partition, starting at 0.
+pub(crate) fn window_row_index() -> Expr {
+    int_range(lit(0 as IdxSize), len().cast(IDX_DTYPE), 1, IDX_DTYPE)
+}
+
+/// Whether the row is the first of its peers within its ordered window partition.
+fn window_peer_start(order_key: Option<Expr>) -> Expr {
+    let first_row = window_row_index().eq(lit(0 as IdxSize));
+    match order_key {
+        Some(order_key) => first_row.or(order_key.clone().neq_missing(order_key.shift(lit(1)))),
+        None => first_row,
+    }
+}
+
+/// The position of the first peer of the row within its ordered window partition.
+fn window_first_peer(order_key: Option<Expr>) -> Expr {
+    when(window_peer_start(order_key))
+        .then(window_row_index())
+        .otherwise(lit(0 as IdxSize))
+        .cum_max(false)
+}
+
+/// The position of the last peer of the row within its ordered window partition.
+fn window_last_peer(order_key: Option<Expr>) -> Expr {
+    let peer_end = window_peer_start(order_key)
+        .shift(lit(-1))
+        .fill_null(lit(true));
+    when(peer_end)
+        .then(window_row_index())
+        .otherwise(lit(IdxSize::MAX))
+        .cum_min(true)
+}
+
+/// The rank of the row within its ordered window partition, with gaps for ties.
+fn window_rank(order_key: Option<Expr>) -> Expr {
+    window_first_peer(order_key) + lit(1)
+}
+
+/// The bounds of a window frame, relative to the current row.
+enum WindowFrameBounds {
+    /// The offsets of the first and last row of the frame, `None` if unbounded.
+    Rows {
+        start: Option<i64>,
+        end: Option<i64>,
+    },
+    /// RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+    RangeUnbounded,
+    /// RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+    RangeToCurrentRow,
+    /// RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+    RangeFromCurrentRow,
+    /// RANGE BETWEEN <offset> PRECEDING AND CURRENT ROW
+    RangePreceding(Duration),
+}
+
+/// Aggregations that can be evaluated over a window frame.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FrameAgg {
+    Sum,
+    Min,
+    Max,
+    Mean,
+    Count,
+    First,
+    Last,
+}
+
+impl FrameAgg {
+    /// Whether the aggregation takes the value at a position of the frame.
+    fn is_positional(self) -> bool {
+        matches!(self, FrameAgg::First | FrameAgg::Last)
+    }
+
+    /// The aggregation over all rows of the window partition.
+    fn whole(self, expr: Expr) -> Expr {
+        match self {
+            FrameAgg::Sum => expr.sum(),
+            FrameAgg::Min => expr.min(),
+            FrameAgg::Max => expr.max(),
+            FrameAgg::Mean => expr.mean(),
+            FrameAgg::Count => expr.count(),
+            FrameAgg::First => expr.first(),
+            FrameAgg::Last => expr.last(),
+        }
+    }
+
+    /// The aggregation over the rows up to each row, or from each row if `reverse`.
+    fn cumulative(self, expr: Expr, reverse: bool) -> Expr {
+        // cumulative functions are null at null values, these take the preceding result
+        let fill = if reverse {
+            FillNullStrategy::Backward(None)
+        } else {
+            FillNullStrategy::Forward(None)
+        };
+        match self {
+            FrameAgg::Sum => expr.cum_sum(reverse).fill_null_with_strategy(fill),
+            FrameAgg::Min => expr.cum_min(reverse).fill_null_with_strategy(fill),
+            FrameAgg::Max => expr.cum_max(reverse).fill_null_with_strategy(fill),
+            FrameAgg::Mean => {
+                let sum = FrameAgg::Sum.cumulative(expr.clone().cast(DataType::Float64), reverse);
+                let count = FrameAgg::Count.cumulative(expr, reverse);
+                sum / count.cast(DataType::Float64)
+            },
+            FrameAgg::Count => expr.is_not_null().cast(IDX_DTYPE).cum_sum(reverse),
+            FrameAgg::First | FrameAgg::Last => unreachable!("positional aggregation"),
+        }
+    }
+
+    /// The aggregation over the `window_size` rows up to each row.
+    fn rolling(self, expr: Expr, window_size: usize) -> Expr {
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods: 1,
+            ..Default::default()
+        };
+        match self {
+            FrameAgg::Sum => expr.rolling_sum(options),
+            FrameAgg::Min => expr.rolling_min(options),
+            FrameAgg::Max => expr.rolling_max(options),
+            FrameAgg::Mean => expr.rolling_mean(options),
+            FrameAgg::Count => expr.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+            FrameAgg::First | FrameAgg::Last => unreachable!("positional aggregation"),
+        }
+    }
+
+    /// The aggregation over the rows whose `by` value is at most `offset` before the value of
+    /// each row.
+    fn rolling_by(self, expr: Expr, by: Expr, offset: Duration) -> Expr {
+        let options = RollingOptionsDynamicWindow {
+            window_size: offset,
+            min_periods: 1,
+            closed_window: ClosedWindow::Both,
+            fn_params: None,
+        };
+        match self {
+            FrameAgg::Sum => expr.rolling_sum_by(by, options),
+            FrameAgg::Min => expr.rolling_min_by(by, options),
+            FrameAgg::Max => expr.rolling_max_by(by, options),
+            FrameAgg::Mean => expr.rolling_mean_by(by, options),
+            FrameAgg::Count => expr
+                .is_not_null()
+                .cast(IDX_DTYPE)
+                .rolling_sum_by(by, options),
+            FrameAgg::First | FrameAgg::Last => unreachable!("positional aggregation"),
+        }
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -108,6 +108,12 @@ impl SQLExprVisitor<'_> {
             } => self.visit_cast(expr, data_type, format, kind),
             SQLExpr::Ceil { expr, .. } => Ok(self.visit_expr(expr)?.ceil()),
             SQLExpr::CompoundIdentifier(idents) => self.visit_compound_identifier(idents),
+            SQLExpr::Exists { subquery, negated } => {
+                let exists = self
+                    .ctx
+                    .join_exists_subquery(subquery, self.active_schema)?;
+                Ok(if *negated { exists.not() } else { exists })
+            },
             SQLExpr::Extract {
                 field,
                 syntax: _,
@@ -192,7 +198,9 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(subquery) => {
+                self.ctx.join_scalar_subquery(subquery, self.active_schema)
+            },
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -942,14 +950,22 @@ impl SQLExprVisitor<'_> {
     }
 
     /// Visit a SQL subquery inside and `IN` expression.
+    ///
+    /// A subquery that refers to the outer query is joined on its correlation keys.
     fn visit_in_subquery(
         &mut self,
         expr: &SQLExpr,
         subquery: &Subquery,
         negated: bool,
     ) -> PolarsResult<Expr> {
-        let subquery_result = self.visit_subquery(subquery, SubqueryRestriction::SingleColumn)?;
         let expr = self.visit_expr(expr)?;
+        if let Some(is_in) =
+            self.ctx
+                .join_in_subquery(expr.clone(), subquery, self.active_schema)?
+        {
+            return Ok(if negated { is_in.not() } else { is_in });
+        }
+        let subquery_result = self.visit_subquery(subquery, SubqueryRestriction::SingleColumn)?;
         Ok(if negated {
             expr.is_in(subquery_result, false).not()
         } else {
@@ -0,0 +1,291 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "Id" => [1, 2, 3, 4, 5, 6, 7],
+      "Region" => ["a", "a", "a", "a", "b", "b", "b"],
+      "Sales" => [10, 20, 20, 30, 5, 5, 7]
+    }
+    .unwrap()
+    .lazy();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_ranking_functions() {
+    let actual = execute(
+        r#"
+      SELECT
+        Id,
+        ROW_NUMBER() OVER (PARTITION BY Region ORDER BY Sales, Id) AS row_number,
+        RANK() OVER (PARTITION BY Region ORDER BY Sales) AS rank,
+        DENSE_RANK() OVER (PARTITION BY Region ORDER BY Sales) AS dense_rank,
+        PERCENT_RANK() OVER (PARTITION BY Region ORDER BY Sales) AS percent_rank,
+        NTILE(3) OVER (PARTITION BY Region ORDER BY Sales, Id) AS ntile
+      FROM df
+      ORDER BY Id
+      "#,
+    );
+
+    let expected = DataFrame::new(vec![
+        Column::new("Id".into(), [1, 2, 3, 4, 5, 6, 7]),
+        IdxCa::from_slice("row_number".into(), &[1, 2, 3, 4, 1, 2, 3]).into_column(),
+        IdxCa::from_slice("rank".into(), &[1, 2, 2, 4, 1, 1, 3]).into_column(),
+        IdxCa::from_slice("dense_rank".into(), &[1, 2, 2, 3, 1, 1, 2]).into_column(),
+        Column::new(
+            "percent_rank".into(),
+            [0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0, 0.0, 0.0, 1.0],
+        ),
+        IdxCa::from_slice("ntile".into(), &[1, 1, 2, 3, 1, 2, 3]).into_column(),
+    ])
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_ranking_functions_mixed_order() {
+    let actual = execute(
+        r#"
+      SELECT
+        Id,
+        RANK() OVER (ORDER BY Region DESC, Sales) AS rank,
+        DENSE_RANK() OVER (ORDER BY Region DESC, Sales) AS dense_rank
+      FROM df
+      ORDER BY Id
+      "#,
+    );
+
+    let expected = DataFrame::new(vec![
+        Column::new("Id".into(), [1, 2, 3, 4, 5, 6, 7]),
+        IdxCa::from_slice("rank".into(), &[4, 5, 5, 7, 1, 1, 3]).into_column(),
+        IdxCa::from_slice("dense_rank".into(), &[3, 4, 4, 5, 1, 1, 2]).into_column(),
+    ])
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_offset_functions() {
+    let actual = execute(
+        r#"
+      SELECT
+        Id,
+        LAG(Sales) OVER (PARTITION BY Region ORDER BY Id) AS lag,
+        LEAD(Sales, 2, 0) OVER (PARTITION BY Region ORDER BY Id) AS lead,
+        LAG(Id, 1, -1) OVER (ORDER BY Id DESC) AS lag_desc
+      FROM df
+      ORDER BY Id
+      "#,
+    );
+
+    let expected = df! {
+        "Id" => [1, 2, 3, 4, 5, 6, 7],
+        "lag" => [None, Some(10), Some(20), Some(20), None, Some(5), Some(5)],
+        "lead" => [20, 30, 0, 0, 7, 0, 0],
+        "lag_desc" => [2, 3, 4, 5, 6, 7, -1],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_value_functions() {
+    let actual = execute(
+        r#"
+      SELECT
+        Id,
+        FIRST_VALUE(Id) OVER (PARTITION BY Region ORDER BY Sales DESC) AS first_value,
+        LAST_VALUE(Sales) OVER (PARTITION BY Region ORDER BY Sales) AS last_value,
+        LAST_VALUE(Id) OVER (PARTITION BY Region) AS last_id
+      FROM df
+      ORDER BY Id
+      "#,
+    );
+
+    let expected = df! {
+        "Id" => [1, 2, 3, 4, 5, 6, 7],
+        "first_value" => [4, 4, 4, 4, 7, 7, 7],
+        "last_value" => [10, 20, 20, 30, 5, 5, 7],
+        "last_id" => [4, 4, 4, 4, 7, 7, 7],
+    }
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_window_frames_rows() {
+    let actual = execute(
+        r#"
+      SELECT
+        Id,
+        SUM(Sales) OVER (
+          PARTITION BY Region ORDER BY Id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+        ) AS sum_2,
+        AVG(Sales) OVER (
+          PARTITION BY Region ORDER BY Id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+        ) AS avg_3,
+        MAX(Sales) OVER (
+          ORDER BY Id ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+        ) AS max_rest,
+        COUNT(*) OVER (
+          PARTITION BY Region ORDER BY Id ROWS BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING
+        ) AS count,
+        FIRST_VALUE(Sales) OVER (
+          PARTITION BY Region ORDER BY Id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING
+        ) AS first,
+        LAST_VALUE(Id) OVER (
+          PARTITION BY Region ORDER BY Id ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+        ) AS last
+      FROM df
+      ORDER BY Id
+      "#,
+    );
+
+    let expected = DataFrame::new(vec![
+        Column::new("Id".into(), [1, 2, 3, 4, 5, 6, 7]),
+        Column::new("sum_2".into(), [10, 30, 40, 50, 5, 10, 12]),
+        Column::new(
+            "avg_3".into(),
+            [15.0, 50.0 / 3.0, 70.0 / 3.0, 25.0, 5.0, 17.0 / 3.0, 6.0],
+        ),
+        Column::new("max_rest".into(), [30, 30, 30, 30, 7, 7, 7]),
+        IdxCa::from_slice("count".into(), &[2, 3, 4, 4, 2, 3, 3]).into_column(),
+        Column::new(
+            "first".into(),
+            [None, Some(10), Some(10), Some(20), None, Some(5), Some(5)],
+        ),
+        Column::new("last".into(), [4, 4, 4, 4, 7, 7, 7]),
+    ])
+    .unwrap();
+
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_window_frames_range() {
+    let df = df! {
+      "Day" => [0, 1, 3, 8, 9, 9],
+      "Value" => [1, 2, 3, 4, 5, 6],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("Day").cast(DataType::Date));
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df);
+    let actual = ctx
+        .execute(
+            r#"
+          SELECT
+            Value,
+            SUM(Value) OVER (
+              ORDER BY Day RANGE BETWEEN INTERVAL '2 days' PRECEDING AND CURRENT ROW
+            ) AS sum_2d,
+            SUM(Value) OVER (
+              ORDER BY Day RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+            ) AS sum_to_peers,
+            MIN(Value) OVER (
+              ORDER BY Day RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+            ) AS min_from_peers,
+            COUNT(Value) OVER (ORDER BY Value RANGE 2 PRECEDING) AS count_2
+          FROM df
+          ORDER BY Value
+          "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let expected = DataFrame::new(vec![
+        Column::new("Value".into(), [1, 2, 3, 4, 5, 6]),
+        Column::new("sum_2d".into(), [1, 3, 5, 4, 15, 15]),
+        Column::new("sum_to_peers".into(), [1, 3, 6, 10, 21, 21]),
+        Column::new("min_from_peers".into(), [1, 2, 3, 4, 5, 5]),
+        IdxCa::from_slice("count_2".into(), &[1, 2, 3, 3, 3, 3]).into_column(),
+    ])
+    .unwrap();
+
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_named_windows() {
+    let actual = execute(
+        r#"
+      SELECT
+        Id,
+        ROW_NUMBER() OVER w AS n,
+        SUM(Sales) OVER (w ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS running,
+        LAG(Id) OVER w AS prev
+      FROM df
+      WINDOW r AS (PARTITION BY Region), w AS (r ORDER BY Id)
+      ORDER BY Id
+      "#,
+    );
+
+    let expected = DataFrame::new(vec![
+        Column::new("Id".into(), [1, 2, 3, 4, 5, 6, 7]),
+        IdxCa::from_slice("n".into(), &[1, 2, 3, 4, 1, 2, 3]).into_column(),
+        Column::new("running".into(), [10, 30, 50, 80, 5, 10, 17]),
+        Column::new(
+            "prev".into(),
+            [None, Some(1), Some(2), Some(3), None, Some(5), Some(6)],
+        ),
+    ])
+    .unwrap();
+
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_window_function_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT ROW_NUMBER() FROM df",
+        "SELECT NTILE(0) OVER (ORDER BY Id) FROM df",
+        "SELECT LAG(Sales, Id) OVER (ORDER BY Id) FROM df",
+        "SELECT RANK(Sales) OVER (ORDER BY Id) FROM df",
+        "SELECT SUM(Sales) OVER w FROM df",
+        "SELECT SUM(Sales) OVER (w ORDER BY Id) FROM df WINDOW w AS (ORDER BY Region)",
+        "SELECT SUM(Sales) OVER (ORDER BY Id ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id, Region RANGE 2 PRECEDING) FROM df",
+        "SELECT SUM(Sales) OVER (ORDER BY Id GROUPS 1 PRECEDING) FROM df",
+        "SELECT STDDEV(Sales) OVER (ORDER BY Id ROWS 1 PRECEDING) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected an error for {sql}");
+    }
+}
@@ -154,6 +154,87 @@ fn test_group_by_expression_key() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df! {
+        "Region" => ["a", "a", "b"],
+        "City" => ["x", "y", "x"],
+        "Sales" => [1, 2, 3],
+    }?;
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+
+    let df_sql = context
+        .execute(
+            r#"
+            SELECT Region, City, SUM(Sales) AS total, GROUPING(Region, City) AS level
+            FROM df
+            GROUP BY ROLLUP(Region, City)
+            ORDER BY level, Region, City
+        "#,
+        )?
+        .collect()?;
+    let df_expected = df! {
+        "Region" => [Some("a"), Some("a"), Some("b"), Some("a"), Some("b"), None],
+        "City" => [Some("x"), Some("y"), Some("x"), None, None, None],
+        "total" => [1, 2, 3, 3, 3, 6],
+        "level" => [0u32, 0, 0, 1, 1, 3],
+    }?;
+    assert!(df_sql.equals_missing(&df_expected), "{df_sql:?}");
+
+    let df_sql = context
+        .execute(
+            r#"
+            SELECT City, SUM(Sales) AS total
+            FROM df
+            GROUP BY CUBE(Region, City)
+            HAVING GROUPING(Region) = 1
+            ORDER BY City NULLS LAST
+        "#,
+        )?
+        .collect()?;
+    let df_expected = df! {
+        "City" => [Some("x"), Some("y"), None],
+        "total" => [4, 2, 6],
+    }?;
+    assert!(df_sql.equals_missing(&df_expected), "{df_sql:?}");
+
+    let df_sql = context
+        .execute(
+            r#"
+            SELECT Region, City, COUNT(*) AS n
+            FROM df
+            GROUP BY Region, GROUPING SETS ((City), ())
+            ORDER BY Region, City NULLS FIRST
+        "#,
+        )?
+        .collect()?;
+    let df_expected = DataFrame::new(vec![
+        Column::new("Region".into(), ["a", "a", "a", "b", "b"]),
+        Column::new("City".into(), [None, Some("x"), Some("y"), None, Some("x")]),
+        IdxCa::from_slice("n".into(), &[2, 1, 1, 1, 1]).into_column(),
+    ])?;
+    assert!(df_sql.equals_missing(&df_expected), "{df_sql:?}");
+
+    let df_sql = context
+        .execute("SELECT Region, SUM(Sales) AS total FROM df GROUP BY Region WITH ROLLUP ORDER BY Region NULLS LAST")?
+        .collect()?;
+    let df_expected = df! {
+        "Region" => [Some("a"), Some("b"), None],
+        "total" => [3, 3, 6],
+    }?;
+    assert!(df_sql.equals_missing(&df_expected), "{df_sql:?}");
+
+    for sql in [
+        "SELECT Region, GROUPING(Region) FROM df GROUP BY Region",
+        "SELECT Region, GROUPING(City) FROM df GROUP BY ROLLUP(Region)",
+        "SELECT Region, SUM(Sales) FROM df GROUP BY Region WITH TOTALS",
+    ] {
+        assert!(context.execute(sql).is_err(), "expected an error for {sql}");
+    }
+    Ok(())
+}
+
 #[test]
 fn test_cast_exprs() {
     let df = create_sample_df();
@@ -564,6 +645,84 @@ fn test_ctes() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_recursive_ctes() -> PolarsResult<()> {
+    let employees = df! {
+        "id" => [1, 2, 3, 4],
+        "manager_id" => [None, Some(1), Some(1), Some(3)],
+        "name" => ["ann", "bob", "cat", "dan"],
+    }?;
+    let edges = df! {
+        "src" => ["a", "b", "b"],
+        "dst" => ["b", "a", "c"],
+    }?;
+    let mut context = SQLContext::new().with_recursive_cte_max_iterations(10);
+    context.register("employees", employees.lazy());
+    context.register("edges", edges.lazy());
+
+    let df_sql = context
+        .execute(
+            r#"
+            WITH RECURSIVE counter(n) AS (
+                SELECT 1
+                UNION ALL
+                SELECT n + 1 FROM counter WHERE n < 5
+            )
+            SELECT n FROM counter
+        "#,
+        )?
+        .collect()?;
+    assert!(df_sql.equals(&df!("n" => [1, 2, 3, 4, 5])?), "{df_sql:?}");
+
+    let df_sql = context
+        .execute(
+            r#"
+            WITH RECURSIVE chain(id, name, depth) AS (
+                SELECT id, name, 0 FROM employees WHERE manager_id IS NULL
+                UNION ALL
+                SELECT e.id, e.name, c.depth + 1
+                FROM employees e JOIN chain c ON e.manager_id = c.id
+            )
+            SELECT * FROM chain ORDER BY id
+        "#,
+        )?
+        .collect()?;
+    let df_expected = df! {
+        "id" => [1, 2, 3, 4],
+        "name" => ["ann", "bob", "cat", "dan"],
+        "depth" => [0, 1, 1, 2],
+    }?;
+    assert!(df_sql.equals(&df_expected), "{df_sql:?}");
+
+    // UNION removes duplicate rows, so that a cycle reaches a fixed point.
+    let sql = |union: &str| {
+        format!(
+            r#"
+            WITH RECURSIVE reachable(node) AS (
+                SELECT 'a'
+                {union}
+                SELECT dst FROM edges JOIN reachable r ON edges.src = r.node
+            )
+            SELECT node FROM reachable ORDER BY node
+        "#
+        )
+    };
+    let df_sql = context.execute(&sql("UNION"))?.collect()?;
+    assert!(
+        df_sql.equals(&df!("node" => ["a", "b", "c"])?),
+        "{df_sql:?}"
+    );
+    assert!(context.execute(&sql("UNION ALL"))?.collect().is_err());
+
+    // A CTE that does not refer to itself is a plain UNION.
+    let df_sql = context
+        .execute("WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT 1) SELECT n FROM t")?
+        .collect()?;
+    assert!(df_sql.equals(&df!("n" => [1, 1])?), "{df_sql:?}");
+
+    Ok(())
+}
+
 #[test]
 fn test_cte_values() -> PolarsResult<()> {
     let sql = r#"
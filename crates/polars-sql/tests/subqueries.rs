@@ -0,0 +1,330 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let customers = df! {
+      "id" => [1, 2, 3, 4, 5, 6],
+      "name" => ["a", "b", "c", "d", "e", "f"],
+      "budget" => [Some(100), Some(25), Some(5), Some(10), None, None],
+    }
+    .unwrap()
+    .lazy();
+    let orders = df! {
+      "id" => [10, 11, 12, 13, 14, 15, 16, 17],
+      "customer_id" => [1, 1, 2, 2, 2, 3, 4, 5],
+      "amount" => [Some(100), Some(50), Some(20), Some(70), Some(30), Some(5), None, Some(40)],
+    }
+    .unwrap()
+    .lazy();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("customers", customers);
+    ctx.register("orders", orders);
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+fn names(sql: &str) -> Vec<Option<String>> {
+    execute(sql)
+        .column("name")
+        .unwrap()
+        .str()
+        .unwrap()
+        .iter()
+        .map(|name| name.map(str::to_string))
+        .collect()
+}
+
+fn some(names: &[&str]) -> Vec<Option<String>> {
+    names.iter().map(|name| Some(name.to_string())).collect()
+}
+
+#[test]
+fn test_exists_subqueries() {
+    let exists = names(
+        r#"
+      SELECT name FROM customers c
+      WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id)
+      ORDER BY name
+      "#,
+    );
+    assert_eq!(exists, some(&["a", "b", "c", "d", "e"]));
+
+    let not_exists = names(
+        r#"
+      SELECT name FROM customers c
+      WHERE NOT EXISTS (SELECT * FROM orders o WHERE o.customer_id = c.id AND o.amount > 60)
+      ORDER BY name
+      "#,
+    );
+    assert_eq!(not_exists, some(&["c", "d", "e", "f"]));
+
+    let uncorrelated = names(
+        r#"
+      SELECT name FROM customers
+      WHERE EXISTS (SELECT 1 FROM orders WHERE amount > 90)
+        AND NOT EXISTS (SELECT 1 FROM orders WHERE amount > 1000)
+      ORDER BY name
+      "#,
+    );
+    assert_eq!(uncorrelated, some(&["a", "b", "c", "d", "e", "f"]));
+}
+
+#[test]
+fn test_in_subqueries() {
+    let is_in = names(
+        r#"
+      SELECT name FROM customers c
+      WHERE budget IN (SELECT amount FROM orders o WHERE o.customer_id = c.id)
+      ORDER BY name
+      "#,
+    );
+    assert_eq!(is_in, some(&["a", "c"]));
+
+    let not_in = names(
+        r#"
+      SELECT name FROM customers c
+      WHERE budget NOT IN (SELECT o.amount FROM orders AS o WHERE c.id = o.customer_id)
+      ORDER BY name
+      "#,
+    );
+    // NULL rather than TRUE for "d" (a NULL in the subquery) and "e" (a NULL budget), but TRUE
+    // for "f" as its subquery has no rows
+    assert_eq!(not_in, some(&["b", "f"]));
+
+    let actual = execute(
+        r#"
+      SELECT
+        name,
+        budget IN (SELECT amount FROM orders o WHERE o.customer_id = c.id) AS is_in,
+        budget NOT IN (SELECT amount FROM orders o WHERE o.customer_id = c.id) AS not_in
+      FROM customers c
+      ORDER BY name
+      "#,
+    );
+    let expected = df! {
+        "name" => ["a", "b", "c", "d", "e", "f"],
+        "is_in" => [Some(true), Some(false), Some(true), None, None, Some(false)],
+        "not_in" => [Some(false), Some(true), Some(false), None, None, Some(true)],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_scalar_subqueries() {
+    let filtered = names(
+        r#"
+      SELECT name FROM customers c
+      WHERE (SELECT SUM(amount) FROM orders o WHERE o.customer_id = c.id) > 100
+        AND budget >= (SELECT MIN(amount) FROM orders)
+      ORDER BY name
+      "#,
+    );
+    assert_eq!(filtered, some(&["a", "b"]));
+
+    let actual = execute(
+        r#"
+      SELECT
+        name,
+        (SELECT COUNT(*) FROM orders o WHERE o.customer_id = c.id) AS n_orders,
+        (SELECT MAX(amount) FROM orders o WHERE o.customer_id = c.id) AS max_amount,
+        (
+          SELECT o.id FROM orders o WHERE o.customer_id = c.id ORDER BY o.amount LIMIT 1
+        ) AS smallest_order,
+        (SELECT COUNT(*) FROM orders) AS total_orders
+      FROM customers c
+      ORDER BY name
+      "#,
+    );
+    let expected = DataFrame::new(vec![
+        Column::new("name".into(), ["a", "b", "c", "d", "e", "f"]),
+        IdxCa::from_slice("n_orders".into(), &[2, 3, 1, 1, 1, 0]).into_column(),
+        Column::new(
+            "max_amount".into(),
+            [Some(100), Some(70), Some(5), None, Some(40), None],
+        ),
+        Column::new(
+            "smallest_order".into(),
+            [Some(11), Some(12), Some(15), Some(16), Some(17), None],
+        ),
+        IdxCa::from_slice("total_orders".into(), &[8, 8, 8, 8, 8, 8]).into_column(),
+    ])
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    // aggregations over all subquery rows have their value over no rows for outer rows without
+    // subquery rows
+    let actual = execute(
+        r#"
+      SELECT
+        name,
+        (SELECT COUNT(*) + 1 FROM orders o WHERE o.customer_id = c.id) AS n_plus_one,
+        (SELECT COALESCE(COUNT(*), 0) * 2 FROM orders o WHERE o.customer_id = c.id) AS n_doubled,
+        (SELECT COUNT(amount) FROM orders o WHERE o.customer_id = c.id) AS n_amounts
+      FROM customers c
+      ORDER BY name
+      "#,
+    );
+    let counts = |name: &str| -> Vec<Option<i64>> {
+        let counts = actual.column(name).unwrap().cast(&DataType::Int64).unwrap();
+        counts.i64().unwrap().iter().collect()
+    };
+    assert_eq!(
+        counts("n_plus_one"),
+        [Some(3), Some(4), Some(2), Some(2), Some(2), Some(1)]
+    );
+    assert_eq!(
+        counts("n_doubled"),
+        [Some(4), Some(6), Some(2), Some(2), Some(2), Some(0)]
+    );
+    assert_eq!(
+        counts("n_amounts"),
+        [Some(2), Some(3), Some(1), Some(0), Some(1), Some(0)]
+    );
+
+    let actual = execute("SELECT (SELECT MAX(amount) FROM orders) AS max_amount");
+    let expected = df! { "max_amount" => [100] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_lateral_joins() {
+    let actual = execute(
+        r#"
+      SELECT c.name, t.amount
+      FROM customers c
+      CROSS JOIN LATERAL (
+        SELECT amount FROM orders o
+        WHERE o.customer_id = c.id
+        ORDER BY amount DESC
+        LIMIT 2
+      ) AS t
+      ORDER BY c.name, t.amount DESC
+      "#,
+    );
+    let expected = df! {
+        "name" => ["a", "a", "b", "b", "c", "d", "e"],
+        "amount" => [Some(100), Some(50), Some(70), Some(30), Some(5), None, Some(40)],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    let actual = execute(
+        r#"
+      SELECT c.name, t.n, t.total, t.id
+      FROM customers c
+      LEFT JOIN LATERAL (
+        SELECT COUNT(*) AS n, SUM(o.amount) AS total, MAX(o.id) AS id
+        FROM orders o WHERE o.customer_id = c.id
+      ) AS t ON TRUE
+      ORDER BY c.name
+      "#,
+    );
+    // "f" has no orders, so takes the aggregations over no rows
+    let expected = DataFrame::new(vec![
+        Column::new("name".into(), ["a", "b", "c", "d", "e", "f"]),
+        IdxCa::from_slice("n".into(), &[2, 3, 1, 1, 1, 0]).into_column(),
+        Column::new(
+            "total".into(),
+            [Some(150), Some(120), Some(5), Some(0), Some(40), Some(0)],
+        ),
+        Column::new(
+            "id".into(),
+            [Some(11), Some(14), Some(15), Some(16), Some(17), None],
+        ),
+    ])
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    let actual = execute(
+        r#"
+      SELECT c.name, big.amt
+      FROM customers c, LATERAL (
+        SELECT amount FROM orders o WHERE o.customer_id = c.id AND o.amount > 40
+      ) AS big (amt)
+      ORDER BY big.amt DESC
+      "#,
+    );
+    let expected = df! {
+        "name" => ["a", "b", "a"],
+        "amt" => [100, 70, 50],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    // An aggregation over all rows keeps outer rows without subquery rows, also in a cross join
+    let actual = execute(
+        r#"
+      SELECT c.name, t.id AS n_big
+      FROM customers c
+      CROSS JOIN LATERAL (
+        SELECT COUNT(*) AS id FROM orders o WHERE o.customer_id = c.id AND o.amount > 40
+      ) AS t
+      ORDER BY c.name
+      "#,
+    );
+    let expected = DataFrame::new(vec![
+        Column::new("name".into(), ["a", "b", "c", "d", "e", "f"]),
+        IdxCa::from_slice("n_big".into(), &[2, 1, 0, 0, 0, 0]).into_column(),
+    ])
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn test_subquery_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        // correlated predicates must be equalities
+        "SELECT name FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.amount > c.budget)",
+        // outer references are only supported in the WHERE clause of the subquery
+        "SELECT (SELECT c.budget FROM orders o WHERE o.customer_id = c.id) FROM customers c",
+        // subqueries are only joined in the SELECT and WHERE clauses
+        "SELECT name FROM customers c ORDER BY (SELECT MAX(amount) FROM orders)",
+        // LATERAL joins keep all row combinations
+        "SELECT * FROM customers c LEFT JOIN LATERAL (SELECT amount FROM orders o WHERE o.customer_id = c.id) t ON t.amount > 10",
+        "SELECT * FROM LATERAL (SELECT amount FROM orders) t",
+        // expressions whose identifiers can't be collected
+        "SELECT name FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.customer_id = c.id AND c.name SIMILAR TO 'a')",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected an error for {sql}");
+    }
+
+    // a scalar subquery must have at most one row for each outer row
+    for sql in [
+        "SELECT name, (SELECT amount FROM orders o WHERE o.customer_id = c.id) FROM customers c",
+        "SELECT name, (SELECT SUM(amount) FROM orders o WHERE o.customer_id = c.id GROUP BY o.amount) FROM customers c",
+        "SELECT name, (SELECT amount FROM orders) FROM customers",
+    ] {
+        assert!(
+            ctx.execute(sql).and_then(|lf| lf.collect()).is_err(),
+            "expected an error for {sql}"
+        );
+    }
+}
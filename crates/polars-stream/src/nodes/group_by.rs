@@ -1,19 +1,19 @@
 use std::sync::Arc;
 
-use polars_core::POOL;
 use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::{POOL, config};
 use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::sparse_init_vec::SparseInitVec;
+use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
 use super::compute_node_prelude::*;
@@ -22,6 +22,7 @@ use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{PartitionedSpiller, SpilledPartition, can_spill};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
@@ -43,6 +44,12 @@ struct LocalGroupBySinkState {
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
 
+    // If we may spill, the key columns of cold_morsels[i] are kept in
+    // cold_key_dfs[i], as the hash keys can't be turned back into columns.
+    cold_key_dfs: Vec<DataFrame>,
+    cold_bytes: usize,
+    spiller: Option<PartitionedSpiller>,
+
     // Similar to the above, but for (evicted) pre-aggregates.
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
@@ -67,6 +74,10 @@ impl LocalGroupBySinkState {
             morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
             morsel_idxs_offsets_per_p: vec![0; num_partitions],
 
+            cold_key_dfs: Vec::new(),
+            cold_bytes: 0,
+            spiller: None,
+
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
@@ -99,6 +110,61 @@ impl LocalGroupBySinkState {
             .extend(self.pre_agg_idxs_values_per_p.iter().map(|vp| vp.len()));
         self.pre_aggs.push((hash_keys, reductions));
     }
+
+    /// Writes all cold morsels to disk, partitioned.
+    fn spill_cold_morsels(
+        &mut self,
+        uniq_grouped_reduction_cols: &[PlSmallStr],
+    ) -> PolarsResult<()> {
+        let num_partitions = self.sketch_per_p.len();
+        for (i, ((seq, _keys, df), key_df)) in self
+            .cold_morsels
+            .drain(..)
+            .zip(self.cold_key_dfs.drain(..))
+            .enumerate()
+        {
+            // Key columns go first and are renamed to avoid clashing with
+            // the reduction inputs.
+            let mut spill_df: DataFrame = key_df
+                .take_columns()
+                .into_iter()
+                .enumerate()
+                .map(|(k, c)| c.with_name(spill_key_name(k)))
+                .collect();
+            let values_df = df._select_impl(uniq_grouped_reduction_cols)?;
+            unsafe { spill_df.hstack_mut_unchecked(values_df.get_columns()) };
+
+            let spiller = match &mut self.spiller {
+                Some(spiller) => spiller,
+                slot @ None => {
+                    slot.insert(PartitionedSpiller::new(spill_df.schema(), num_partitions)?)
+                },
+            };
+
+            for p in 0..num_partitions {
+                let start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let stop = self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs = &self.morsel_idxs_values_per_p[p][start..stop];
+                if p_morsel_idxs.is_empty() {
+                    continue;
+                }
+                let p_df = unsafe { spill_df.take_slice_unchecked_impl(p_morsel_idxs, false) };
+                spiller.spill(p, &p_df, seq)?;
+            }
+        }
+
+        for vp in &mut self.morsel_idxs_values_per_p {
+            vp.clear();
+        }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.cold_bytes = 0;
+        Ok(())
+    }
+}
+
+fn spill_key_name(k: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_GB_SPILL_KEY{k}")
 }
 
 struct GroupBySinkState {
@@ -110,6 +176,9 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    // Once the cold morsels of a local state exceed this many bytes they are
+    // spilled to disk.
+    spill_budget_per_local: Option<usize>,
 }
 
 impl GroupBySinkState {
@@ -126,6 +195,7 @@ impl GroupBySinkState {
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let spill_budget = self.spill_budget_per_local;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -191,10 +261,21 @@ impl GroupBySinkState {
                             local
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+                            local.cold_bytes += cold_df.estimated_size();
                             local.cold_morsels.push((seq, cold_keys, cold_df));
+
+                            if spill_budget.is_some() {
+                                let cold_key_df = keys.take_slice_unchecked_impl(&cold_idxs, false);
+                                local.cold_bytes += cold_key_df.estimated_size();
+                                local.cold_key_dfs.push(cold_key_df);
+                            }
                         }
                     }
 
+                    if spill_budget.is_some_and(|budget| local.cold_bytes > budget) {
+                        local.spill_cold_morsels(uniq_grouped_reduction_cols)?;
+                    }
+
                     // If we have too many evicted rows, flush them.
                     if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
                         local.flush_evictions(&partitioner);
@@ -205,7 +286,12 @@ impl GroupBySinkState {
         }
     }
 
-    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
+    /// Combines the in-memory state of the locals per partition. The cold
+    /// morsels spilled for each partition are returned separately, to be
+    /// inserted one partition at a time.
+    fn combine_locals(
+        &mut self,
+    ) -> PolarsResult<(Vec<GroupByPartition>, Vec<Vec<SpilledPartition>>)> {
         // Finalize pre-aggregations.
         POOL.install(|| {
             self.locals
@@ -236,6 +322,18 @@ impl GroupBySinkState {
             .iter_mut()
             .map(|l| Arc::new(core::mem::take(&mut l.pre_aggs)))
             .collect_vec();
+        let spilled_per_local = self
+            .locals
+            .iter_mut()
+            .filter_map(|l| l.spiller.take())
+            .map(|spiller| spiller.finish())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if config::verbose() && !spilled_per_local.is_empty() {
+            eprintln!(
+                "[group-by]: merging cold morsels spilled by {} local states, one partition at a time",
+                spilled_per_local.len()
+            );
+        }
         enum ToDrop<A, B> {
             A(A),
             B(B),
@@ -421,7 +519,110 @@ impl GroupBySinkState {
                 .for_each(drop);
         });
 
-        Ok(output_per_partition.try_assume_init().ok().unwrap())
+        let mut spilled_per_p = (0..num_partitions).map(|_| Vec::new()).collect_vec();
+        for l_spilled in spilled_per_local {
+            for (p, spilled) in l_spilled.into_iter().enumerate() {
+                spilled_per_p[p].push(spilled);
+            }
+        }
+
+        Ok((
+            output_per_partition.try_assume_init().ok().unwrap(),
+            spilled_per_p,
+        ))
+    }
+}
+
+/// Inserts the cold morsels spilled for a single partition into its grouper
+/// and reductions. The first `num_keys` columns of the spilled morsels are the
+/// group keys.
+fn insert_spilled_partition(
+    spilled: &SpilledPartition,
+    num_keys: usize,
+    grouper: &mut dyn Grouper,
+    reductions: &mut [Box<dyn GroupedReduction>],
+    grouped_reduction_cols: &[PlSmallStr],
+    random_state: PlRandomState,
+    group_idxs: &mut Vec<IdxSize>,
+) -> PolarsResult<()> {
+    let mut subset = Vec::new();
+    for spilled_morsel in spilled.read()? {
+        let (seq_id, df) = spilled_morsel?;
+        let key_df = df.select_by_range(0..num_keys)?;
+        let keys = HashKeys::from_df(&key_df, random_state, true, false);
+
+        subset.clear();
+        subset.extend(0..df.height() as IdxSize);
+        group_idxs.clear();
+        unsafe {
+            grouper.insert_keys_subset(&keys, &subset, Some(group_idxs));
+            for (c, r) in grouped_reduction_cols.iter().zip(reductions.iter_mut()) {
+                let values = df.column(c.as_str()).unwrap();
+                r.resize(grouper.num_groups());
+                r.update_groups_subset(values, &subset, group_idxs, seq_id)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Emits the groups one partition at a time, after inserting the cold morsels
+/// spilled for that partition. Only the spilled morsels of a single partition
+/// are loaded at once, and they are freed before the next partition is loaded.
+struct SpilledGroupBySource {
+    partitions: std::vec::IntoIter<(GroupByPartition, Vec<SpilledPartition>)>,
+    num_keys: usize,
+    grouped_reduction_cols: Vec<PlSmallStr>,
+    random_state: PlRandomState,
+    source: Option<InMemorySourceNode>,
+    seq_offset: MorselSeq,
+}
+
+impl SpilledGroupBySource {
+    /// Updates the state of the source of the current partition, moving on to
+    /// the next partition once it is exhausted. Returns false once all
+    /// partitions have been emitted.
+    fn update_state(
+        &mut self,
+        send: &mut [PortState],
+        key_schema: &Schema,
+        output_schema: &Schema,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<bool> {
+        let send_state = send[0];
+        loop {
+            if let Some(source) = &mut self.source {
+                source.update_state(&mut [], send, state)?;
+                if send[0] != PortState::Done {
+                    return Ok(true);
+                }
+                self.source = None;
+                send[0] = send_state;
+            }
+
+            let Some((mut partition, spilled)) = self.partitions.next() else {
+                return Ok(false);
+            };
+            let mut group_idxs = Vec::new();
+            for l_spilled in spilled {
+                insert_spilled_partition(
+                    &l_spilled,
+                    self.num_keys,
+                    &mut *partition.grouper,
+                    &mut partition.grouped_reductions,
+                    &self.grouped_reduction_cols,
+                    self.random_state,
+                    &mut group_idxs,
+                )?;
+            }
+
+            let df = partition.into_df(key_schema, output_schema)?;
+            // The in-memory source uses at most one sequence id per row and
+            // an extra one per pipeline.
+            let num_seqs = (df.height() + state.num_pipelines + 1) as u64;
+            self.source = Some(InMemorySourceNode::new(Arc::new(df), self.seq_offset));
+            self.seq_offset = self.seq_offset.offset_by_u64(num_seqs);
+        }
     }
 }
 
@@ -446,6 +647,7 @@ impl GroupByPartition {
 enum GroupByState {
     Sink(GroupBySinkState),
     Source(InMemorySourceNode),
+    SpilledSource(SpilledGroupBySource),
     Done,
 }
 
@@ -458,6 +660,7 @@ pub struct GroupByNode {
 impl GroupByNode {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
+        input_schema: Arc<Schema>,
         key_schema: Arc<Schema>,
         key_selectors: Vec<StreamExpr>,
         grouper: Box<dyn Grouper>,
@@ -465,6 +668,7 @@ impl GroupByNode {
         grouped_reductions: Vec<Box<dyn GroupedReduction>>,
         output_schema: Arc<Schema>,
         random_state: PlRandomState,
+        spill_memory_budget: Option<usize>,
         num_pipelines: usize,
     ) -> Self {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
@@ -489,6 +693,9 @@ impl GroupByNode {
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
+        let spill_budget_per_local = spill_memory_budget
+            .filter(|_| can_spill(&input_schema) && can_spill(&key_schema))
+            .map(|budget| budget / num_pipelines);
         Self {
             state: GroupByState::Sink(GroupBySinkState {
                 key_selectors,
@@ -499,6 +706,7 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                spill_budget_per_local,
             }),
             key_schema,
             output_schema,
@@ -532,17 +740,40 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
-                let dfs = POOL.install(|| {
-                    partitions
-                        .into_par_iter()
-                        .map(|p| p.into_df(&self.key_schema, &self.output_schema))
-                        .collect::<Result<Vec<_>, _>>()
-                })?;
-
-                let df = accumulate_dataframes_vertical_unchecked(dfs);
-                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
-                self.state = GroupByState::Source(source);
+                let (partitions, spilled_per_p) = sink.combine_locals()?;
+                if spilled_per_p.iter().all(|spilled| spilled.is_empty()) {
+                    let dfs = POOL.install(|| {
+                        partitions
+                            .into_par_iter()
+                            .map(|p| p.into_df(&self.key_schema, &self.output_schema))
+                            .collect::<Result<Vec<_>, _>>()
+                    })?;
+
+                    let df = accumulate_dataframes_vertical_unchecked(dfs);
+                    let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                    self.state = GroupByState::Source(source);
+                } else {
+                    let mut src = SpilledGroupBySource {
+                        partitions: partitions
+                            .into_iter()
+                            .zip(spilled_per_p)
+                            .collect_vec()
+                            .into_iter(),
+                        num_keys: sink.key_selectors.len(),
+                        grouped_reduction_cols: sink.grouped_reduction_cols,
+                        random_state: sink.random_state,
+                        source: None,
+                        seq_offset: MorselSeq::new(0),
+                    };
+                    // Load the first partition right away, the source spawns
+                    // from the current partition.
+                    self.state =
+                        if src.update_state(send, &self.key_schema, &self.output_schema, state)? {
+                            GroupByState::SpilledSource(src)
+                        } else {
+                            GroupByState::Done
+                        };
+                }
             },
             // Defer to source node implementation.
             GroupByState::Source(src) => {
@@ -551,6 +782,11 @@ impl ComputeNode for GroupByNode {
                     self.state = GroupByState::Done;
                 }
             },
+            GroupByState::SpilledSource(src) => {
+                if !src.update_state(send, &self.key_schema, &self.output_schema, state)? {
+                    self.state = GroupByState::Done;
+                }
+            },
             // Nothing to change.
             GroupByState::Done | GroupByState::Sink(_) => {},
         }
@@ -561,7 +797,7 @@ impl ComputeNode for GroupByNode {
                 send[0] = PortState::Blocked;
                 recv[0] = PortState::Ready;
             },
-            GroupByState::Source(..) => {
+            GroupByState::Source(..) | GroupByState::SpilledSource(..) => {
                 recv[0] = PortState::Done;
                 send[0] = PortState::Ready;
             },
@@ -596,6 +832,11 @@ impl ComputeNode for GroupByNode {
                 assert!(recv_ports[0].is_none());
                 source.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            GroupByState::SpilledSource(src) => {
+                assert!(recv_ports[0].is_none());
+                let source = src.source.as_mut().unwrap();
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
             GroupByState::Done => unreachable!(),
         }
     }
@@ -0,0 +1,231 @@
+use std::cmp::Reverse;
+use std::pin::Pin;
+
+use polars_core::frame::DataFrame;
+use polars_core::prelude::CompatLevel;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroEncoder, AvroWriterOptions};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{SinkOptions, SinkTarget};
+use polars_utils::priority::Priority;
+
+use super::{
+    DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_SINK_LINEARIZER_BUFFER_SIZE, SinkInputPort,
+    SinkNode,
+};
+use crate::async_executor::spawn;
+use crate::async_primitives::connector::{Receiver, Sender, connector};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sinks::phase::PhaseOutcome;
+use crate::nodes::{JoinHandle, TaskPriority};
+
+type IOSend = Linearizer<Priority<Reverse<usize>, Vec<u8>>>;
+
+pub struct AvroSinkNode {
+    target: SinkTarget,
+
+    input_schema: SchemaRef,
+    encoder: AvroEncoder,
+    write_options: AvroWriterOptions,
+    sink_options: SinkOptions,
+    cloud_options: Option<CloudOptions>,
+
+    io_tx: Option<Sender<IOSend>>,
+    io_task: Option<tokio_util::task::AbortOnDropHandle<PolarsResult<()>>>,
+}
+
+impl AvroSinkNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        target: SinkTarget,
+        sink_options: SinkOptions,
+        write_options: AvroWriterOptions,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let encoder = AvroEncoder::new(
+            &input_schema,
+            &write_options.name,
+            write_options.compression,
+            write_options.sync_marker,
+        )?;
+
+        Ok(Self {
+            target,
+
+            input_schema,
+            encoder,
+            write_options,
+            sink_options,
+            cloud_options,
+
+            io_tx: None,
+            io_task: None,
+        })
+    }
+}
+
+impl SinkNode for AvroSinkNode {
+    fn name(&self) -> &str {
+        "avro-sink"
+    }
+
+    fn is_sink_input_parallel(&self) -> bool {
+        false
+    }
+    fn do_maintain_order(&self) -> bool {
+        self.sink_options.maintain_order
+    }
+
+    fn initialize(&mut self, _state: &StreamingExecutionState) -> PolarsResult<()> {
+        let (io_tx, mut io_rx) = connector::<IOSend>();
+
+        // IO task.
+        //
+        // Task that writes the header and then the encoded blocks to the target file.
+        let target = self.target.clone();
+        let sink_options = self.sink_options.clone();
+        let cloud_options = self.cloud_options.clone();
+        let encoder = self.encoder.clone();
+        let io_task = polars_io::pl_async::get_runtime().spawn(async move {
+            use tokio::io::AsyncWriteExt;
+
+            let mut file = target
+                .open_into_writeable_async(&sink_options, cloud_options.as_ref())
+                .await?
+                .try_into_async_writeable()?;
+
+            let mut header = Vec::new();
+            encoder.encode_header(&mut header)?;
+            file.write_all(&header).await?;
+
+            while let Ok(mut lin_rx) = io_rx.recv().await {
+                while let Some(Priority(_, buffer)) = lin_rx.get().await {
+                    file.write_all(&buffer).await?;
+                }
+            }
+
+            file.sync_on_close(sink_options.sync_on_close).await?;
+            file.close().await?;
+
+            PolarsResult::Ok(())
+        });
+
+        self.io_tx = Some(io_tx);
+        self.io_task = Some(tokio_util::task::AbortOnDropHandle::new(io_task));
+
+        Ok(())
+    }
+
+    fn spawn_sink(
+        &mut self,
+        mut recv_port_rx: Receiver<(PhaseOutcome, SinkInputPort)>,
+        state: &StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        // Buffer task -> Encode tasks
+        let (mut dist_tx, dist_rxs) = distributor_channel::<(usize, DataFrame)>(
+            state.num_pipelines,
+            *DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE,
+        );
+        // Encode tasks -> IO task
+        let (lin_rx, lin_txs) =
+            Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
+        let mut io_tx = self
+            .io_tx
+            .take()
+            .expect("not initialized / spawn called more than once");
+
+        // Buffer task.
+        //
+        // Buffers the morsels into blocks of `block_size` rows and distributes them to the encode
+        // tasks.
+        let block_size = (self.write_options.block_size as usize).max(1);
+        let input_schema = self.input_schema.clone();
+        join_handles.push(spawn(TaskPriority::High, async move {
+            if io_tx.send(lin_rx).await.is_err() {
+                return Ok(());
+            }
+
+            let mut seq = 0usize;
+            let mut buffer = DataFrame::empty_with_schema(input_schema.as_ref());
+
+            while let Ok((outcome, rx)) = recv_port_rx.recv().await {
+                let mut rx = rx.serial();
+                while let Ok(morsel) = rx.recv().await {
+                    let (df, _, _, consume_token) = morsel.into_inner();
+
+                    // @NOTE: This also performs schema validation.
+                    buffer.vstack_mut(&df)?;
+
+                    while buffer.height() >= block_size {
+                        let df;
+                        (df, buffer) = buffer.split_at(block_size as i64);
+                        if dist_tx.send((seq, df)).await.is_err() {
+                            return Ok(());
+                        }
+                        seq += 1;
+                    }
+                    drop(consume_token); // Increase the backpressure.
+                }
+
+                outcome.stopped();
+            }
+
+            // The remaining rows are written as a smaller last block.
+            if !buffer.is_empty() {
+                _ = dist_tx.send((seq, buffer)).await;
+            }
+
+            Ok(())
+        }));
+
+        // Encode tasks.
+        //
+        // Every block is serialized and compressed independently.
+        join_handles.extend(
+            dist_rxs
+                .into_iter()
+                .zip(lin_txs)
+                .map(|(mut dist_rx, mut lin_tx)| {
+                    let encoder = self.encoder.clone();
+                    spawn(TaskPriority::High, async move {
+                        while let Ok((seq, df)) = dist_rx.recv().await {
+                            let batch = df.rechunk_to_record_batch(CompatLevel::oldest());
+                            let mut buffer = Vec::new();
+                            encoder.encode_block(&batch, &mut buffer)?;
+
+                            if lin_tx.insert(Priority(Reverse(seq), buffer)).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+
+                        PolarsResult::Ok(())
+                    })
+                }),
+        );
+    }
+
+    fn finalize(
+        &mut self,
+        _state: &StreamingExecutionState,
+    ) -> Option<Pin<Box<dyn Future<Output = PolarsResult<()>> + Send>>> {
+        // If we were never spawned, we need to make sure that the `tx` is taken. This signals to
+        // the IO task that it is done and prevents deadlocks.
+        drop(self.io_tx.take());
+
+        let io_task = self
+            .io_task
+            .take()
+            .expect("not initialized / finish called more than once");
+
+        // Wait for the IO task to complete.
+        Some(Box::pin(async move {
+            io_task
+                .await
+                .unwrap_or_else(|e| Err(std::io::Error::from(e).into()))
+        }))
+    }
+}
@@ -85,6 +85,7 @@ impl SinkNode for CsvSinkNode {
                 let mut writer = CsvWriter::new(&mut *file)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
+                    .with_encoding(options.encoding)
                     .with_separator(options.serialize_options.separator)
                     .with_line_terminator(options.serialize_options.line_terminator.clone())
                     .with_quote_char(options.serialize_options.quote_char)
@@ -163,6 +164,7 @@ impl SinkNode for CsvSinkNode {
                         let mut writer = CsvWriter::new(&mut buffer)
                             .include_bom(false) // Handled once in the IO task.
                             .include_header(false) // Handled once in the IO task.
+                            .with_encoding(options.encoding)
                             .with_separator(options.serialize_options.separator)
                             .with_line_terminator(options.serialize_options.line_terminator.clone())
                             .with_quote_char(options.serialize_options.quote_char)
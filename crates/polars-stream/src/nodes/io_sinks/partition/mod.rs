@@ -92,11 +92,23 @@ pub fn get_create_new_fn(
             )) as Box<dyn SinkNode + Send>;
             Ok(sink)
         }) as _,
+        #[cfg(feature = "avro")]
+        FileType::Avro(avro_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::avro::AvroSinkNode::new(
+                input_schema,
+                target,
+                sink_options.clone(),
+                avro_writer_options.clone(),
+                cloud_options.clone(),
+            )?) as Box<dyn SinkNode + Send>;
+            Ok(sink)
+        }) as _,
         #[cfg(not(any(
             feature = "csv",
             feature = "parquet",
             feature = "json",
-            feature = "ipc"
+            feature = "ipc",
+            feature = "avro"
         )))]
         _ => {
             panic!("activate source feature")
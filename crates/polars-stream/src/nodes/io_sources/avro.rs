@@ -0,0 +1,375 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::TryExtend;
+use arrow::datatypes::ArrowSchemaRef;
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::DataType;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::avro::AvroFileMetadata;
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    FileReader, FileReaderCallbacks, Projection,
+};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::avro::AvroFileMetadata;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder {
+        pub first_metadata: Option<Arc<AvroFileMetadata>>,
+    }
+
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = AvroFileReader {
+                scan_source: source,
+                cloud_options,
+                metadata: if scan_source_idx == 0 {
+                    self.first_metadata.clone()
+                } else {
+                    None
+                },
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    metadata: Option<Arc<AvroFileMetadata>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<AvroFileMetadata>,
+    n_rows_in_file: IdxSize,
+}
+
+/// A range of consecutive blocks that is decoded into morsels by a single decoder task.
+struct BlockBatch {
+    blocks: Range<usize>,
+    /// Position of the first row of the first block in the file.
+    row_offset: usize,
+    /// The rows of the batch to output, relative to `row_offset`.
+    slice: Range<usize>,
+    morsel_seq_base: u64,
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // The block locations are not stored in the file, so the entire file is required.
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(addr.to_str())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let metadata = match self.metadata.take() {
+            Some(metadata) => metadata,
+            None => Arc::new(AvroFileMetadata::read(&memslice)?),
+        };
+
+        let n_rows = metadata.num_rows();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            metadata,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let normalized_pre_slice = pre_slice_arg
+            .clone()
+            .map(|pre_slice| pre_slice.restrict_to_bounds(n_rows_in_file as usize));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(
+                metadata.schema.as_ref(),
+            )));
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        let slice: Range<usize> =
+            normalized_pre_slice.map_or(0..n_rows_in_file as usize, Range::<usize>::from);
+
+        let projection = metadata.projection_mask(&projected_schema);
+        let pl_schema = metadata
+            .schema
+            .iter()
+            .zip(projection.iter())
+            .filter(|(_, projected)| **projected)
+            .map(|((name, field), _)| (name.clone(), DataType::from_arrow_field(field)))
+            .collect::<Schema>();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                blocks: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                pl_schema.len(),
+                metadata.schema.len(),
+                metadata.blocks.len(),
+                pre_slice_arg,
+                slice,
+            )
+        }
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<BlockBatch>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        // Decoder tasks.
+        //
+        // Every batch of blocks is decompressed and deserialized independently, which makes the
+        // decoding parallel over the blocks of the file.
+        let decoder_handles = batch_rxs
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(mut batch_rx, mut morsel_tx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let projection = projection.clone();
+                let pl_schema = pl_schema.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(BlockBatch {
+                        blocks,
+                        row_offset,
+                        slice,
+                        morsel_seq_base,
+                    }) = batch_rx.recv().await
+                    {
+                        // If we don't project any columns we don't have to decode the blocks.
+                        let mut df = if pl_schema.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let mut df = DataFrame::empty_with_schema(&pl_schema);
+                            df.try_extend(metadata.blocks[blocks].iter().map(|block| {
+                                metadata.decode_block(&memslice, block, &projection)
+                            }))?;
+                            df.slice(slice.start as i64, slice.len())
+                        };
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = *offset + (row_offset + slice.start) as IdxSize;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            let morsel = Morsel::new(morsel_df, seq, source_token.clone());
+
+                            if morsel_tx.send_morsel(morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Groups the blocks that overlap with the slice into batches of about a morsel.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let mut morsel_seq: u64 = 0;
+            let mut block_row_offset: usize = 0;
+            let mut batch: Option<BlockBatch> = None;
+
+            for (i, block) in metadata.blocks.iter().enumerate() {
+                let block_rows = block_row_offset..block_row_offset + block.num_rows;
+                block_row_offset = block_rows.end;
+
+                if block_rows.end <= slice.start || block.num_rows == 0 {
+                    continue;
+                }
+                if block_rows.start >= slice.end {
+                    break;
+                }
+
+                let current = batch.get_or_insert_with(|| BlockBatch {
+                    blocks: i..i,
+                    row_offset: block_rows.start,
+                    slice: slice.start.saturating_sub(block_rows.start)..0,
+                    morsel_seq_base: morsel_seq,
+                });
+                current.blocks.end = i + 1;
+                current.slice.end = block_rows.end.min(slice.end) - current.row_offset;
+
+                // Send the batch once it fills a morsel, or at the end of the slice.
+                if current.slice.len() < max_morsel_size && block_rows.end < slice.end {
+                    continue;
+                }
+
+                let batch = batch.take().unwrap();
+                morsel_seq += batch.slice.len().div_ceil(max_morsel_size) as u64;
+
+                if batch_tx.send(batch).await.is_err() {
+                    // This should only happen if the receiver of the decoder has broken off,
+                    // meaning no further input will be needed.
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                walker_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_arrow_schema(&mut self) -> PolarsResult<Option<ArrowSchemaRef>> {
+        Ok(Some(
+            self.init_data.as_ref().unwrap().metadata.schema.clone(),
+        ))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -14,6 +15,7 @@ use polars_io::prelude::_csv_read_internal::{
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    maybe_rewrite_dialect, maybe_transcode_bytes,
 };
 use polars_io::utils::compression::maybe_decompress_bytes;
 use polars_io::utils::slice::SplitSlicePosition;
@@ -408,6 +410,9 @@ impl FileReader for CsvFileReader {
 }
 
 impl CsvFileReader {
+    /// Decompresses the bytes, transcodes them to utf8 and rewrites them to standard CSV if
+    /// needed.
+    ///
     /// # Panics
     /// Panics if `self.cached_bytes` is None.
     fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
@@ -418,6 +423,35 @@ impl CsvFileReader {
             self.cached_bytes = Some(MemSlice::from_vec(out));
         }
 
+        if let Cow::Owned(out) = maybe_transcode_bytes(
+            self.cached_bytes.as_deref().unwrap(),
+            self.options.parse_options.encoding,
+        )? {
+            self.cached_bytes = Some(MemSlice::from_vec(out));
+            // The cached bytes are utf8 now and must not be transcoded again.
+            self.options = Arc::new(
+                (*self.options)
+                    .clone()
+                    .map_parse_options(|opts| opts.with_encoding(CsvEncoding::Utf8)),
+            );
+        }
+
+        if !self.options.parse_options.is_standard_dialect() {
+            let mut out = vec![];
+            maybe_rewrite_dialect(
+                self.cached_bytes.as_deref().unwrap(),
+                &self.options.parse_options,
+                &mut out,
+            )?;
+            self.cached_bytes = Some(MemSlice::from_vec(out));
+            // Like the encoding, the options now describe the rewritten bytes.
+            self.options = Arc::new(
+                (*self.options)
+                    .clone()
+                    .map_parse_options(|opts| opts.to_standard_dialect()),
+            );
+        }
+
         Ok(self.cached_bytes.clone().unwrap())
     }
 }
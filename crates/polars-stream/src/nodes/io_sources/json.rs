@@ -0,0 +1,318 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::json::{JsonArrayElements, parse_json_array_elements};
+use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_plan::dsl::{NDJsonReadOptions, ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, FileReader, FileReaderCallbacks};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
+
+    use super::JsonFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct JsonReaderBuilder {
+        pub options: Arc<NDJsonReadOptions>,
+    }
+
+    impl FileReaderBuilder for JsonReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "json"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            // The records are only found by tokenizing the array from the start, so a negative
+            // slice is left to the multi-scan.
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = JsonFileReader {
+                scan_source: source,
+                cloud_options,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+                cached_bytes: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct JsonFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    options: Arc<NDJsonReadOptions>,
+    verbose: bool,
+    // Cached on first access - we may be called multiple times e.g. to count the rows.
+    cached_bytes: Option<MemSlice>,
+}
+
+/// A batch of consecutive records that is parsed into a morsel by a decoder task.
+struct RecordBatch {
+    /// The byte ranges of the records in the file.
+    records: Vec<Range<usize>>,
+    /// Position of the first record in the file.
+    row_offset: usize,
+    morsel_seq: MorselSeq,
+}
+
+#[async_trait]
+impl FileReader for JsonFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.cached_bytes.is_some() {
+            return Ok(());
+        }
+
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(addr.to_str())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let source = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let mut out = vec![];
+        maybe_decompress_bytes(&source, &mut out)?;
+
+        self.cached_bytes = Some(if out.is_empty() {
+            source
+        } else {
+            MemSlice::from_vec(out)
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+        let memslice = self.cached_bytes.clone().unwrap();
+        let ignore_errors = self.options.ignore_errors;
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        // Like NDJSON, we just use the projected schema - the parser appends NULL for fields that
+        // are not in a record.
+        let schema = projected_schema;
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(schema.clone());
+        }
+
+        let slice: Range<usize> = match pre_slice.clone() {
+            None => 0..usize::MAX,
+            Some(Slice::Positive { offset, len }) => offset..offset.saturating_add(len),
+            Some(Slice::Negative { .. }) => unreachable!(),
+        };
+
+        // The records past the end of the slice only have to be tokenized to count the rows.
+        let count_all_rows = n_rows_in_file_tx.is_some();
+
+        if verbose {
+            eprintln!(
+                "[JsonFileReader]: \
+                project: {}, \
+                pre_slice: {:?}, \
+                row_index: {:?}, \
+                count_all_rows: {}",
+                schema.len(),
+                pre_slice,
+                &row_index,
+                count_all_rows,
+            )
+        }
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        let (batch_tx, batch_rxs) =
+            distributor_channel::<RecordBatch>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        // Decoder tasks.
+        //
+        // The records of every batch are parsed independently, which makes the parsing parallel
+        // while the walker tokenizes the rest of the array.
+        let decoder_handles = batch_rxs
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(mut batch_rx, mut morsel_tx)| {
+                let memslice = memslice.clone();
+                let schema = schema.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(RecordBatch {
+                        records,
+                        row_offset,
+                        morsel_seq,
+                    }) = batch_rx.recv().await
+                    {
+                        // If we don't project any columns we don't have to parse the records.
+                        let mut df = if schema.is_empty() {
+                            DataFrame::empty_with_height(records.len())
+                        } else {
+                            parse_json_array_elements(&memslice, &records, &schema, ignore_errors)?
+                        };
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = *offset + row_offset as IdxSize;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        let morsel = Morsel::new(df, morsel_seq, source_token.clone());
+
+                        if morsel_tx.send_morsel(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let slice_end = slice.end;
+
+        // Walker task.
+        //
+        // Tokenizes the array and sends the records within the slice in batches of a morsel.
+        // Returns the number of records it has walked over.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let mut batch_tx = Some(batch_tx);
+            let mut records = JsonArrayElements::try_new(&memslice)?;
+            let mut batch: Vec<Range<usize>> = vec![];
+            let mut batch_row_offset: usize = 0;
+            let mut morsel_seq: u64 = 0;
+            let mut n_rows: usize = 0;
+
+            while (batch_tx.is_some() && n_rows < slice.end) || count_all_rows {
+                let Some(record) = records.next().transpose()? else {
+                    break;
+                };
+
+                if slice.contains(&n_rows) && batch_tx.is_some() {
+                    if batch.is_empty() {
+                        batch_row_offset = n_rows;
+                    }
+                    batch.push(record);
+                }
+                n_rows += 1;
+
+                // Send the batch once it fills a morsel, or at the end of the slice.
+                if batch.len() < max_morsel_size && n_rows < slice.end {
+                    continue;
+                }
+
+                if let Some(tx) = batch_tx.as_mut().filter(|_| !batch.is_empty()) {
+                    let batch = RecordBatch {
+                        records: std::mem::take(&mut batch),
+                        row_offset: batch_row_offset,
+                        morsel_seq: MorselSeq::new(morsel_seq),
+                    };
+                    morsel_seq += 1;
+
+                    if tx.send(batch).await.is_err() {
+                        // This should only happen if the receiver of the decoder has broken off,
+                        // meaning no further records will be needed.
+                        batch_tx = None;
+                    }
+                }
+            }
+
+            if let Some(tx) = batch_tx.as_mut().filter(|_| !batch.is_empty()) {
+                _ = tx
+                    .send(RecordBatch {
+                        records: batch,
+                        row_offset: batch_row_offset,
+                        morsel_seq: MorselSeq::new(morsel_seq),
+                    })
+                    .await;
+            }
+
+            PolarsResult::Ok(n_rows)
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                let n_rows = walker_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                let to_idx_size = |n: usize| {
+                    IdxSize::try_from(n)
+                        .map_err(|_| polars_err!(bigidx, ctx = "json file", size = n))
+                };
+
+                if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                    _ = row_position_on_end_tx.try_send(to_idx_size(n_rows.min(slice_end))?);
+                }
+
+                if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                    _ = n_rows_in_file_tx.try_send(to_idx_size(n_rows)?);
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+}
@@ -3,12 +3,15 @@ use std::sync::Arc;
 use arrow::bitmap::bitmask::BitMask;
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{BooleanChunked, ChunkAgg, DataType, PlIndexMap};
+use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::{BooleanChunked, ChunkAgg, DataType, PlHashSet, PlIndexMap, PlIndexSet};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
-use polars_error::{PolarsResult, feature_gated};
+use polars_error::{PolarsResult, feature_gated, polars_ensure, polars_err};
 use polars_io::cloud::CloudOptions;
-use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::deletion::{
+    DeletionFilesList, DeltaDeletionVectorDescriptor, IcebergEqualityDeleteFile,
+};
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -17,7 +20,9 @@ use polars_utils::slice_enum::Slice;
 
 use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
-use crate::nodes::io_sources::multi_scan::reader_interface::{BeginReadArgs, FileReaderCallbacks};
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks,
+};
 #[cfg(feature = "parquet")]
 use crate::nodes::io_sources::parquet::builder::ParquetReaderBuilder;
 
@@ -32,6 +37,21 @@ pub enum DeletionFilesProvider {
         reader_builder: ParquetReaderBuilder,
         projected_schema: SchemaRef,
     },
+
+    #[cfg(feature = "parquet")]
+    IcebergEqualityDelete {
+        position_deletes: Arc<PlIndexMap<usize, Arc<[String]>>>,
+        equality_deletes: Arc<PlIndexMap<usize, Arc<[IcebergEqualityDeleteFile]>>>,
+        // Amortized allocations
+        reader_builder: ParquetReaderBuilder,
+        projected_schema: SchemaRef,
+    },
+
+    #[cfg(feature = "delta")]
+    DeltaDeletionVector {
+        table_root: PlSmallStr,
+        vectors: Arc<PlIndexMap<usize, DeltaDeletionVectorDescriptor>>,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -41,27 +61,36 @@ impl DeletionFilesProvider {
         }
 
         match deletion_files.unwrap() {
-            DeletionFilesList::IcebergPositionDelete(paths) => feature_gated!(
-                "parquet",
+            DeletionFilesList::IcebergPositionDelete(paths) => feature_gated!("parquet", {
+                let (reader_builder, projected_schema) = iceberg_position_delete_reader();
+
                 Self::IcebergPositionDelete {
                     paths,
-                    reader_builder: ParquetReaderBuilder {
-                        first_metadata: None,
-                        options: Arc::new(polars_io::prelude::ParquetOptions {
-                            schema: Some(Arc::new(Schema::from_iter([
-                                (PlSmallStr::from_static("file_path"), DataType::String),
-                                (PlSmallStr::from_static("pos"), DataType::Int64),
-                            ]))),
-
-                            parallel: polars_io::prelude::ParallelStrategy::Auto,
-                            low_memory: false,
-                            use_statistics: false,
-                        }),
-                    },
-                    projected_schema: Arc::new(Schema::from_iter([
-                        (PlSmallStr::from_static("file_path"), DataType::String),
-                        (PlSmallStr::from_static("pos"), DataType::Int64),
-                    ])),
+                    reader_builder,
+                    projected_schema,
+                }
+            }),
+            DeletionFilesList::IcebergEqualityDelete {
+                position_deletes,
+                equality_deletes,
+            } => feature_gated!("parquet", {
+                let (reader_builder, projected_schema) = iceberg_position_delete_reader();
+
+                Self::IcebergEqualityDelete {
+                    position_deletes,
+                    equality_deletes,
+                    reader_builder,
+                    projected_schema,
+                }
+            }),
+            DeletionFilesList::DeltaDeletionVector {
+                table_root,
+                vectors,
+            } => feature_gated!(
+                "delta",
+                Self::DeltaDeletionVector {
+                    table_root,
+                    vectors
                 }
             ),
         }
@@ -69,6 +98,7 @@ impl DeletionFilesProvider {
 
     pub fn spawn_row_deletions_init(
         &self,
+        scan_source: &ScanSource,
         scan_source_idx: usize,
         cloud_options: Option<Arc<CloudOptions>>,
         num_pipelines: usize,
@@ -93,144 +123,167 @@ impl DeletionFilesProvider {
                     )
                 }
 
-                // We create the readers and immediately spawn off tasks to initialize all of them.
-                let file_readers = paths
-                    .iter()
-                    .enumerate()
-                    .map(|(deletion_file_idx, path)| {
-                        let source = ScanSource::Path(PlPath::new(path));
-                        let mut reader = reader_builder.build_file_reader(
-                            source,
-                            cloud_options.clone(),
-                            deletion_file_idx,
-                        );
+                let load_mask = load_iceberg_position_deletes(
+                    paths,
+                    reader_builder,
+                    projected_schema.clone(),
+                    scan_source_idx,
+                    cloud_options,
+                    num_pipelines,
+                    verbose,
+                );
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let bitmap = load_mask.await?;
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        let mask = ExternalFilterMask::IcebergPositionDelete { mask };
 
                         if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+                            let max_index = mask.len().checked_sub(1);
+
                             eprintln!(
-                                "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
-                                deletion_file_idx: {deletion_file_idx}, \
-                                deletion_file_path: {path}"
+                                "[DeletionFilesProvider[Iceberg]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}, \
+                                max_index: {max_index:?}",
                             )
                         }
 
-                        AbortOnDropHandle::new(async_executor::spawn(
-                            TaskPriority::Low,
-                            async move {
-                                reader.initialize().await?;
-                                PolarsResult::Ok(reader)
-                            },
-                        ))
-                    })
-                    .collect::<Vec<_>>();
-
-                let projected_schema = projected_schema.clone();
-
-                // We choose to load deletion files immediately during the initialization phase -
-                // the main driver loop of the multi file may need to serially `.await` on this
-                // between initializing readers when there is a slice.
-                //
-                // This does mean deletion file loads are tied to `NUM_READERS_PRE_INIT`, but this
-                // should be fine as the size of the data should not be too big.
-                let handle = AbortOnDropHandle::new(async_executor::spawn(
-                    TaskPriority::Low,
-                    async move {
-                        let handles = file_readers
-                            .into_iter()
-                            .map(|init_fut| {
-                                use crate::nodes::io_sources::multi_scan::components::projection::Projection;
-
-                                let begin_read_args = BeginReadArgs {
-                                    projection: Projection::Plain(projected_schema.clone()),
-                                    row_index: None,
-                                    pre_slice: None,
-                                    predicate: None,
-                                    cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
+
+            #[cfg(feature = "parquet")]
+            Self::IcebergEqualityDelete {
+                position_deletes,
+                equality_deletes,
+                reader_builder,
+                projected_schema,
+            } => {
+                let position_delete_paths = position_deletes.get(&scan_source_idx);
+                let equality_delete_files = equality_deletes.get(&scan_source_idx).cloned();
+
+                if position_delete_paths.is_none() && equality_delete_files.is_none() {
+                    return None;
+                }
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {}, \
+                        {} position delete files, {} equality delete files",
+                        scan_source_idx,
+                        position_delete_paths.map_or(0, |x| x.len()),
+                        equality_delete_files.as_ref().map_or(0, |x| x.len()),
+                    )
+                }
+
+                let load_position_mask = position_delete_paths.map(|paths| {
+                    load_iceberg_position_deletes(
+                        paths,
+                        reader_builder,
+                        projected_schema.clone(),
+                        scan_source_idx,
+                        cloud_options.clone(),
+                        num_pipelines,
+                        verbose,
+                    )
+                });
+
+                let scan_source = scan_source.clone();
+                let reader_builder = reader_builder.clone();
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let position_mask = match load_position_mask {
+                            Some(fut) => Some(fut.await?),
+                            None => None,
+                        };
+
+                        let equality_mask = match equality_delete_files {
+                            Some(files) => Some(
+                                load_iceberg_equality_deletes(
+                                    scan_source,
+                                    files,
+                                    reader_builder,
+                                    cloud_options,
                                     num_pipelines,
-                                    callbacks: FileReaderCallbacks {
-                                        file_schema_tx: None,
-                                        n_rows_in_file_tx: None,
-                                        row_position_on_end_tx: None,
-                                    },
-                                };
-
-                                AbortOnDropHandle::new(async_executor::spawn(
-                                    TaskPriority::Low,
-                                    async move {
-                                        let mut reader = init_fut.await?;
-
-                                        let (mut rx, handle) =
-                                            reader.begin_read(begin_read_args)?;
-
-                                        let mut dfs = vec![];
-
-                                        while let Ok(morsel) = rx.recv().await {
-                                            dfs.push(morsel.into_df());
-                                        }
-
-                                        handle.await?;
-
-                                        let df = accumulate_dataframes_vertical_unchecked(dfs);
-
-                                        // Some quick testing on AWS Athena showed that it doesn't
-                                        // write deletion files that reference multiple distinct
-                                        // file paths, so we don't handle that for now.
-                                        assert!(
-                                            df.column("file_path")?.n_unique()? <= 1,
-                                            "assertion failed: iceberg position delete file: \
-                                            n_unique(data_file_paths) <= 1. \
-                                            This is a bug, please open an issue"
-                                        );
-
-                                        let positions_col = df.column("pos")?.clone();
-                                        let max_idx = usize::try_from(
-                                            positions_col
-                                                .as_materialized_series_maintain_scalar()
-                                                .i64()
-                                                .unwrap()
-                                                .max()
-                                                .unwrap_or(0),
-                                        )
-                                        .unwrap();
-
-                                        PolarsResult::Ok((positions_col, max_idx))
-                                    },
-                                ))
-                            })
-                            .collect::<Vec<_>>();
-
-                        let mut position_columns = Vec::with_capacity(handles.len());
-                        let mut filter_mask_len: usize = 0;
-
-                        for handle in handles {
-                            let (positions_col, max_idx) = handle.await?;
-                            filter_mask_len = filter_mask_len.max(max_idx.saturating_add(1));
-                            position_columns.push(positions_col);
-                        }
+                                )
+                                .await?,
+                            ),
+                            None => None,
+                        };
+
+                        let bitmap = match (position_mask, equality_mask) {
+                            (Some(l), Some(r)) => {
+                                // The position mask can be shorter than the data.
+                                let len = l.len().max(r.len());
+                                &extend_set(l, len) & &extend_set(r, len)
+                            },
+                            (Some(v), None) | (None, Some(v)) => v,
+                            (None, None) => unreachable!(),
+                        };
 
-                        let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
+                        // Also trigger the bitcount to reduce blocking later down.
+                        bitmap.unset_bits();
 
-                        for c in position_columns {
-                            for idx in c.as_materialized_series_maintain_scalar().i64().unwrap() {
-                                let idx = usize::try_from(idx.unwrap()).unwrap();
-                                filter_mask.set(idx, false);
-                            }
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        let mask = ExternalFilterMask::IcebergEqualityDelete { mask };
+
+                        if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+
+                            eprintln!(
+                                "[DeletionFilesProvider[Iceberg]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}",
+                            )
                         }
 
-                        let bitmap = filter_mask.freeze();
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
+
+            #[cfg(feature = "delta")]
+            Self::DeltaDeletionVector {
+                table_root,
+                vectors,
+            } => {
+                let descriptor = vectors.get(&scan_source_idx)?.clone();
+                let table_root = table_root.clone();
+
+                if verbose {
+                    eprintln!(
+                        "[DeletionFilesProvider[Delta]]: scan_source_idx: {}, \
+                        storage_type: {:?}, cardinality: {}",
+                        scan_source_idx, descriptor.storage_type, descriptor.cardinality
+                    )
+                }
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let bitmap =
+                            load_delta_deletion_vector(table_root, descriptor, cloud_options)
+                                .await?;
 
                         // Also trigger the bitcount to reduce blocking later down.
                         bitmap.unset_bits();
-                        debug_assert!(bitmap.lazy_unset_bits().is_some());
 
                         let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
-                        let mask = ExternalFilterMask::IcebergPositionDelete { mask };
+                        let mask = ExternalFilterMask::DeltaDeletionVector { mask };
 
                         if verbose {
                             let num_deleted_rows = mask.num_deleted_rows();
                             let max_index = mask.len().checked_sub(1);
 
                             eprintln!(
-                                "[DeletionFilesProvider[Iceberg]]: \
+                                "[DeletionFilesProvider[Delta]]: \
                                 scan_source_idx: {scan_source_idx}, \
                                 num_deleted_rows: {num_deleted_rows}, \
                                 max_index: {max_index:?}",
@@ -238,8 +291,7 @@ impl DeletionFilesProvider {
                         }
 
                         Ok(mask)
-                    },
-                ));
+                    }));
 
                 Some(RowDeletionsInit::Initializing(handle))
             },
@@ -247,6 +299,438 @@ impl DeletionFilesProvider {
     }
 }
 
+#[cfg(feature = "parquet")]
+fn iceberg_position_delete_reader() -> (ParquetReaderBuilder, SchemaRef) {
+    let projected_schema = Arc::new(Schema::from_iter([
+        (PlSmallStr::from_static("file_path"), DataType::String),
+        (PlSmallStr::from_static("pos"), DataType::Int64),
+    ]));
+
+    let reader_builder = ParquetReaderBuilder {
+        first_metadata: None,
+        options: Arc::new(polars_io::prelude::ParquetOptions {
+            schema: Some(projected_schema.clone()),
+            parallel: polars_io::prelude::ParallelStrategy::Auto,
+            low_memory: false,
+            use_statistics: false,
+            decryption: None,
+        }),
+    };
+
+    (reader_builder, projected_schema)
+}
+
+/// Extends `bitmap` to `len` with set bits.
+fn extend_set(bitmap: Bitmap, len: usize) -> Bitmap {
+    if bitmap.len() >= len {
+        return bitmap;
+    }
+
+    let mut out = MutableBitmap::with_capacity(len);
+    out.extend_from_bitmap(&bitmap);
+    out.extend_constant(len - bitmap.len(), true);
+    out.freeze()
+}
+
+/// Loads Iceberg positional deletes into a mask in which the deleted rows are unset.
+///
+/// Note: The mask ends at the last deleted row, so it can be shorter than the actual data.
+#[cfg(feature = "parquet")]
+fn load_iceberg_position_deletes(
+    paths: &[String],
+    reader_builder: &ParquetReaderBuilder,
+    projected_schema: SchemaRef,
+    scan_source_idx: usize,
+    cloud_options: Option<Arc<CloudOptions>>,
+    num_pipelines: usize,
+    verbose: bool,
+) -> impl Future<Output = PolarsResult<Bitmap>> + Send + 'static {
+    // We create the readers and immediately spawn off tasks to initialize all of them.
+    let file_readers = paths
+        .iter()
+        .enumerate()
+        .map(|(deletion_file_idx, path)| {
+            let source = ScanSource::Path(PlPath::new(path));
+            let mut reader =
+                reader_builder.build_file_reader(source, cloud_options.clone(), deletion_file_idx);
+
+            if verbose {
+                eprintln!(
+                    "[DeletionFilesProvider[Iceberg]]: scan_source_idx: {scan_source_idx}, \
+                    deletion_file_idx: {deletion_file_idx}, \
+                    deletion_file_path: {path}"
+                )
+            }
+
+            AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                reader.initialize().await?;
+                PolarsResult::Ok(reader)
+            }))
+        })
+        .collect::<Vec<_>>();
+
+    // We choose to load deletion files immediately during the initialization phase -
+    // the main driver loop of the multi file may need to serially `.await` on this
+    // between initializing readers when there is a slice.
+    //
+    // This does mean deletion file loads are tied to `NUM_READERS_PRE_INIT`, but this
+    // should be fine as the size of the data should not be too big.
+    async move {
+        let handles = file_readers
+            .into_iter()
+            .map(|init_fut| {
+                use crate::nodes::io_sources::multi_scan::components::projection::Projection;
+
+                let begin_read_args = BeginReadArgs {
+                    projection: Projection::Plain(projected_schema.clone()),
+                    row_index: None,
+                    pre_slice: None,
+                    predicate: None,
+                    cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+                    num_pipelines,
+                    callbacks: FileReaderCallbacks {
+                        file_schema_tx: None,
+                        n_rows_in_file_tx: None,
+                        row_position_on_end_tx: None,
+                    },
+                };
+
+                AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                    let mut reader = init_fut.await?;
+
+                    let (mut rx, handle) = reader.begin_read(begin_read_args)?;
+
+                    let mut dfs = vec![];
+
+                    while let Ok(morsel) = rx.recv().await {
+                        dfs.push(morsel.into_df());
+                    }
+
+                    handle.await?;
+
+                    let df = accumulate_dataframes_vertical_unchecked(dfs);
+
+                    // Some quick testing on AWS Athena showed that it doesn't
+                    // write deletion files that reference multiple distinct
+                    // file paths, so we don't handle that for now.
+                    assert!(
+                        df.column("file_path")?.n_unique()? <= 1,
+                        "assertion failed: iceberg position delete file: \
+                        n_unique(data_file_paths) <= 1. \
+                        This is a bug, please open an issue"
+                    );
+
+                    let positions_col = df.column("pos")?.clone();
+                    let max_idx = usize::try_from(
+                        positions_col
+                            .as_materialized_series_maintain_scalar()
+                            .i64()
+                            .unwrap()
+                            .max()
+                            .unwrap_or(0),
+                    )
+                    .unwrap();
+
+                    PolarsResult::Ok((positions_col, max_idx))
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let mut position_columns = Vec::with_capacity(handles.len());
+        let mut filter_mask_len: usize = 0;
+
+        for handle in handles {
+            let (positions_col, max_idx) = handle.await?;
+            filter_mask_len = filter_mask_len.max(max_idx.saturating_add(1));
+            position_columns.push(positions_col);
+        }
+
+        let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
+
+        for c in position_columns {
+            for idx in c.as_materialized_series_maintain_scalar().i64().unwrap() {
+                let idx = usize::try_from(idx.unwrap()).unwrap();
+                filter_mask.set(idx, false);
+            }
+        }
+
+        let bitmap = filter_mask.freeze();
+
+        // Also trigger the bitcount to reduce blocking later down.
+        bitmap.unset_bits();
+        debug_assert!(bitmap.lazy_unset_bits().is_some());
+
+        Ok(bitmap)
+    }
+}
+
+/// Loads Iceberg equality deletes into a mask in which the deleted rows are unset.
+///
+/// The columns referenced by the equality field IDs are read from the data file and anti-joined
+/// against the rows of the delete files. Following the Iceberg spec, null values match each other.
+/// The mask has the same length as the data file.
+#[cfg(feature = "parquet")]
+async fn load_iceberg_equality_deletes(
+    data_file: ScanSource,
+    delete_files: Arc<[IcebergEqualityDeleteFile]>,
+    reader_builder: ParquetReaderBuilder,
+    cloud_options: Option<Arc<CloudOptions>>,
+    num_pipelines: usize,
+) -> PolarsResult<Bitmap> {
+    // Delete files can use different sets of equality fields, these are matched separately.
+    let mut delete_files_by_ids: PlIndexMap<Arc<[u32]>, Vec<String>> = PlIndexMap::default();
+
+    for file in delete_files.iter() {
+        polars_ensure!(
+            !file.equality_ids.is_empty(),
+            ComputeError: "iceberg equality delete file has no equality field IDs: {}", file.path
+        );
+
+        delete_files_by_ids
+            .entry(file.equality_ids.clone())
+            .or_default()
+            .push(file.path.clone());
+    }
+
+    let all_ids: Vec<u32> = delete_files_by_ids
+        .keys()
+        .flat_map(|ids| ids.iter().copied())
+        .collect::<PlIndexSet<_>>()
+        .into_iter()
+        .collect();
+
+    let read = |source: ScanSource, ids: Arc<[u32]>| {
+        let reader = reader_builder.build_file_reader(source, cloud_options.clone(), 0);
+
+        AbortOnDropHandle::new(async_executor::spawn(
+            TaskPriority::Low,
+            read_iceberg_field_ids(reader, ids, num_pipelines),
+        ))
+    };
+
+    let delete_handles = delete_files_by_ids
+        .into_iter()
+        .map(|(ids, paths)| {
+            let handles = paths
+                .into_iter()
+                .map(|path| read(ScanSource::Path(PlPath::new(&path)), ids.clone()))
+                .collect::<Vec<_>>();
+
+            (ids, handles)
+        })
+        .collect::<Vec<_>>();
+
+    let data_df = read(data_file, all_ids.into()).await?;
+    let mut mask = MutableBitmap::from_len_set(data_df.height());
+
+    for (ids, handles) in delete_handles {
+        let mut dfs = Vec::with_capacity(handles.len());
+
+        for handle in handles {
+            dfs.push(handle.await?);
+        }
+
+        let deletes_df = accumulate_dataframes_vertical_unchecked(dfs);
+
+        if deletes_df.height() == 0 {
+            continue;
+        }
+
+        // Bring the keys of both sides to the same types before encoding them.
+        let mut data_keys = Vec::with_capacity(ids.len());
+        let mut delete_keys = Vec::with_capacity(ids.len());
+
+        for id in ids.iter() {
+            let name = format_pl_smallstr!("{id}");
+            let data_col = data_df.column(&name)?;
+            let delete_col = deletes_df.column(&name)?;
+
+            if data_col.dtype().is_null() {
+                // The field is missing from the data file.
+                data_keys.push(data_col.cast(delete_col.dtype())?);
+                delete_keys.push(delete_col.clone());
+            } else {
+                data_keys.push(data_col.clone());
+                delete_keys.push(delete_col.cast(data_col.dtype())?);
+            }
+        }
+
+        let data_rows = _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &data_keys)?;
+        let delete_rows = _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, &delete_keys)?;
+
+        let deleted: PlHashSet<&[u8]> = delete_rows.into_no_null_iter().collect();
+
+        for (i, row) in data_rows.into_no_null_iter().enumerate() {
+            if deleted.contains(row) {
+                mask.set(i, false);
+            }
+        }
+    }
+
+    Ok(mask.freeze())
+}
+
+/// Reads the top-level columns with the given Iceberg field IDs from a Parquet file, naming the
+/// output columns by their field ID. Fields that are missing from the file are loaded as nulls.
+#[cfg(feature = "parquet")]
+async fn read_iceberg_field_ids(
+    mut reader: Box<dyn FileReader>,
+    ids: Arc<[u32]>,
+    num_pipelines: usize,
+) -> PolarsResult<DataFrame> {
+    use polars_core::prelude::Column;
+    use polars_core::schema::iceberg::{IcebergColumnType, IcebergSchema};
+
+    use crate::nodes::io_sources::multi_scan::components::projection::Projection;
+
+    fn contains_nested_id(schema: &IcebergSchema, id: u32) -> bool {
+        schema.values().any(|c| {
+            let mut type_ = &c.type_;
+
+            loop {
+                match type_ {
+                    IcebergColumnType::Primitive { .. } => return false,
+                    IcebergColumnType::List(inner) | IcebergColumnType::FixedSizeList(inner, _) => {
+                        if inner.physical_id == id {
+                            return true;
+                        }
+                        type_ = &inner.type_
+                    },
+                    IcebergColumnType::Struct(fields) => {
+                        return fields.contains_key(&id) || contains_nested_id(fields, id);
+                    },
+                }
+            }
+        })
+    }
+
+    reader.initialize().await?;
+
+    let file_schema = reader.file_schema().await?;
+    let arrow_schema = reader.file_arrow_schema().await?.ok_or_else(
+        || polars_err!(ComputeError: "iceberg equality delete file has no arrow schema"),
+    )?;
+    let iceberg_schema = IcebergSchema::from_arrow_schema(arrow_schema.as_ref())?;
+
+    let mut projected_schema = Schema::with_capacity(ids.len());
+
+    for id in ids.iter() {
+        if let Some(c) = iceberg_schema.get(id) {
+            projected_schema.insert(c.name.clone(), file_schema.get(&c.name).unwrap().clone());
+        } else {
+            polars_ensure!(
+                !contains_nested_id(&iceberg_schema, *id),
+                ComputeError: "iceberg equality deletes on nested field ID {} are not supported", id
+            );
+        }
+    }
+
+    let (mut rx, handle) = reader.begin_read(BeginReadArgs {
+        projection: Projection::Plain(Arc::new(projected_schema)),
+        row_index: None,
+        pre_slice: None,
+        predicate: None,
+        cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
+        num_pipelines,
+        callbacks: FileReaderCallbacks {
+            file_schema_tx: None,
+            n_rows_in_file_tx: None,
+            row_position_on_end_tx: None,
+        },
+    })?;
+
+    let mut dfs = vec![];
+
+    while let Ok(morsel) = rx.recv().await {
+        dfs.push(morsel.into_df());
+    }
+
+    handle.await?;
+
+    let df = accumulate_dataframes_vertical_unchecked(dfs);
+    let height = df.height();
+
+    let columns = ids
+        .iter()
+        .map(|id| {
+            let name = format_pl_smallstr!("{id}");
+
+            match iceberg_schema.get(id) {
+                Some(c) => df.column(&c.name).unwrap().clone().with_name(name),
+                None => Column::full_null(name, height, &DataType::Null),
+            }
+        })
+        .collect();
+
+    Ok(unsafe { DataFrame::new_no_checks(height, columns) })
+}
+
+/// Loads a Delta deletion vector into a mask in which the deleted rows are unset.
+#[cfg(feature = "delta")]
+async fn load_delta_deletion_vector(
+    table_root: PlSmallStr,
+    descriptor: DeltaDeletionVectorDescriptor,
+    cloud_options: Option<Arc<CloudOptions>>,
+) -> PolarsResult<Bitmap> {
+    use polars_io::delta::deletion_vector::{
+        deletion_vector_from_file_bytes, deletion_vector_to_mask, relative_deletion_vector_path,
+        z85_decode,
+    };
+    use polars_io::pl_async;
+    use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+    use polars_plan::dsl::deletion::DeltaDeletionVectorStorageType as S;
+
+    let DeltaDeletionVectorDescriptor {
+        storage_type,
+        path_or_inline_dv,
+        offset,
+        size_in_bytes,
+        cardinality,
+    } = descriptor;
+
+    let path = match storage_type {
+        S::Inline => {
+            let bytes = z85_decode(&path_or_inline_dv)?;
+            polars_ensure!(
+                size_in_bytes <= bytes.len(),
+                ComputeError: "invalid inline deletion vector: expected {} bytes, got {}",
+                size_in_bytes, bytes.len()
+            );
+            return deletion_vector_to_mask(&bytes[..size_in_bytes], cardinality);
+        },
+        S::RelativePath => relative_deletion_vector_path(&table_root, &path_or_inline_dv)?,
+        S::AbsolutePath => path_or_inline_dv.to_string(),
+    };
+
+    // The first byte of a deletion vector file is the format version.
+    let offset = offset.unwrap_or(1);
+    // The deletion vector is prefixed by its size and followed by its checksum.
+    let range = offset..offset + 4 + size_in_bytes + 4;
+
+    let bytes = pl_async::get_runtime()
+        .spawn(async move {
+            let byte_source_builder =
+                if PlPath::new(&path).is_cloud_url() || polars_core::config::force_async() {
+                    DynByteSourceBuilder::ObjectStore
+                } else {
+                    DynByteSourceBuilder::Mmap
+                };
+
+            byte_source_builder
+                .try_build_from_path(&path, cloud_options.as_deref())
+                .await?
+                .get_range(range)
+                .await
+        })
+        .await
+        .unwrap()?;
+
+    deletion_vector_to_mask(
+        deletion_vector_from_file_bytes(&bytes, size_in_bytes)?,
+        cardinality,
+    )
+}
+
 pub enum RowDeletionsInit {
     Initializing(AbortOnDropHandle<PolarsResult<ExternalFilterMask>>),
 
@@ -270,6 +754,10 @@ impl RowDeletionsInit {
 pub enum ExternalFilterMask {
     /// Note: Iceberg positional deletes can have a mask length shorter than the actual data.
     IcebergPositionDelete { mask: BooleanChunked },
+    /// Rows removed by Iceberg equality deletes, combined with any positional deletes of the file.
+    IcebergEqualityDelete { mask: BooleanChunked },
+    /// Note: The mask ends at the last deleted row, so it can be shorter than the actual data.
+    DeltaDeletionVector { mask: BooleanChunked },
 }
 
 impl ExternalFilterMask {
@@ -277,6 +765,8 @@ impl ExternalFilterMask {
         use ExternalFilterMask::*;
         match self {
             IcebergPositionDelete { .. } => "IcebergPositionDelete",
+            IcebergEqualityDelete { .. } => "IcebergEqualityDelete",
+            DeltaDeletionVector { .. } => "DeltaDeletionVector",
         }
     }
 
@@ -295,7 +785,9 @@ impl ExternalFilterMask {
 
     pub fn filter_df(&self, df: &mut DataFrame) -> PolarsResult<()> {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => {
                 if !mask.is_empty() {
                     *df = if mask.len() < df.height() {
                         accumulate_dataframes_vertical_unchecked([
@@ -313,23 +805,34 @@ impl ExternalFilterMask {
     }
 
     pub fn slice(&self, offset: usize, len: usize) -> Self {
-        match self {
-            Self::IcebergPositionDelete { mask } => {
-                // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
-                assert_ne!(offset, usize::MAX);
-                let offset = offset.min(mask.len());
-                let len = len.min(mask.len() - offset);
+        // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
+        assert_ne!(offset, usize::MAX);
 
-                let mask = mask.slice(i64::try_from(offset).unwrap(), len);
+        let slice_mask = |mask: &BooleanChunked| {
+            let offset = offset.min(mask.len());
+            let len = len.min(mask.len() - offset);
 
-                Self::IcebergPositionDelete { mask }
+            mask.slice(i64::try_from(offset).unwrap(), len)
+        };
+
+        match self {
+            Self::IcebergPositionDelete { mask } => Self::IcebergPositionDelete {
+                mask: slice_mask(mask),
+            },
+            Self::IcebergEqualityDelete { mask } => Self::IcebergEqualityDelete {
+                mask: slice_mask(mask),
+            },
+            Self::DeltaDeletionVector { mask } => Self::DeltaDeletionVector {
+                mask: slice_mask(mask),
             },
         }
     }
 
     pub fn num_deleted_rows(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => mask
                 .rechunk()
                 .downcast_get(0)
                 .unwrap()
@@ -386,7 +889,9 @@ impl ExternalFilterMask {
 
     fn get_mask(&self) -> Bitmap {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => {
                 mask.rechunk().downcast_get(0).unwrap().values().clone()
             },
         }
@@ -394,7 +899,9 @@ impl ExternalFilterMask {
 
     pub fn len(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask.len(),
+            Self::IcebergPositionDelete { mask }
+            | Self::IcebergEqualityDelete { mask }
+            | Self::DeltaDeletionVector { mask } => mask.len(),
         }
     }
 }
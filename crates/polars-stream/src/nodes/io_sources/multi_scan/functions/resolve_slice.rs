@@ -89,24 +89,19 @@ async fn resolve_negative_slice(config: &MultiScanConfig) -> PolarsResult<Resolv
             let deletion_files_provider = deletion_files_provider.clone();
 
             AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                let mut reader =
-                    sources
-                        .get(scan_source_idx)
-                        .unwrap()
-                        .into_owned()
-                        .map(|source| {
-                            file_reader_builder.build_file_reader(
-                                source,
-                                cloud_options.clone(),
-                                scan_source_idx,
-                            )
-                        })?;
+                let source = sources.get(scan_source_idx).unwrap().into_owned()?;
+                let mut reader = file_reader_builder.build_file_reader(
+                    source.clone(),
+                    cloud_options.clone(),
+                    scan_source_idx,
+                );
 
                 if verbose {
                     eprintln!("resolve_negative_slice(): init scan source {scan_source_idx}");
                 }
 
                 let row_deletions = deletion_files_provider.spawn_row_deletions_init(
+                    &source,
                     scan_source_idx,
                     cloud_options,
                     num_pipelines,
@@ -306,6 +306,7 @@ async fn finish_initialize_multi_scan_pipeline(
                         .map(|x| RowDeletionsInit::Initialized(x.clone()))
                         .or_else(|| {
                             deletion_files_provider.spawn_row_deletions_init(
+                                &scan_source,
                                 scan_source_idx,
                                 cloud_options,
                                 num_pipelines,
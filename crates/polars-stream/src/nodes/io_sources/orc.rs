@@ -0,0 +1,646 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::Bitmap;
+use async_trait::async_trait;
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::{OrcColumnStatistics, OrcFileMetadata};
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource, DynByteSourceBuilder};
+use polars_io::{RowIndex, pl_async};
+use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
+use polars_utils::format_pl_smallstr;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::components::column_selector::ColumnSelector;
+use super::multi_scan::components::projection::MappedProjectionRef;
+use super::multi_scan::components::projection::builder::ProjectionBuilder;
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_executor::{self, AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    FileReader, FileReaderCallbacks, Projection,
+};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::orc::OrcFileMetadata;
+    use polars_io::utils::byte_source::DynByteSourceBuilder;
+    use polars_plan::dsl::ScanSource;
+
+    use super::OrcFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct OrcReaderBuilder {
+        pub first_metadata: Option<Arc<OrcFileMetadata>>,
+    }
+
+    impl FileReaderBuilder for OrcReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "orc"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX
+                | RC::PRE_SLICE
+                | RC::NEGATIVE_PRE_SLICE
+                | RC::PARTIAL_FILTER
+                | RC::MAPPED_COLUMN_PROJECTION
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let byte_source_builder = if source.is_cloud_url() || config::force_async() {
+                DynByteSourceBuilder::ObjectStore
+            } else {
+                DynByteSourceBuilder::Mmap
+            };
+
+            let reader = OrcFileReader {
+                scan_source: source,
+                cloud_options,
+                byte_source_builder,
+                metadata: if scan_source_idx == 0 {
+                    self.first_metadata.clone()
+                } else {
+                    None
+                },
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct OrcFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    metadata: Option<Arc<OrcFileMetadata>>,
+    byte_source_builder: DynByteSourceBuilder,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    byte_source: Arc<DynByteSource>,
+    metadata: Arc<OrcFileMetadata>,
+    n_rows_in_file: IdxSize,
+}
+
+/// A file column to decode, together with the transform that maps it to the output column.
+struct OrcColumnProjection {
+    index: usize,
+    output_name: PlSmallStr,
+    transform: Option<ColumnSelector>,
+}
+
+impl OrcColumnProjection {
+    fn apply_transform(&self, column: Column) -> PolarsResult<Column> {
+        match &self.transform {
+            None => Ok(column),
+            Some(transform) => {
+                let output_height = column.len();
+                transform.select_from_columns(&[column], output_height)
+            },
+        }
+    }
+}
+
+/// A stripe that is decoded into morsels by a single decoder task.
+struct StripeBatch {
+    stripe: usize,
+    /// Position of the first row of the stripe in the file.
+    row_offset: usize,
+    /// The rows of the stripe to output.
+    slice: Range<usize>,
+    morsel_seq_base: u64,
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let scan_source = self.scan_source.clone();
+        let byte_source_builder = self.byte_source_builder.clone();
+        let cloud_options = self.cloud_options.clone();
+        let metadata = self.metadata.take();
+        let verbose = self.verbose;
+
+        let (byte_source, metadata) = pl_async::get_runtime()
+            .spawn(async move {
+                let byte_source = scan_source
+                    .as_scan_source_ref()
+                    .to_dyn_byte_source(&byte_source_builder, cloud_options.as_deref())
+                    .await?;
+
+                let metadata = match metadata {
+                    Some(metadata) => metadata,
+                    None => Arc::new(read_orc_metadata(&byte_source, verbose).await?),
+                };
+
+                PolarsResult::Ok((Arc::new(byte_source), metadata))
+            })
+            .await
+            .unwrap()?;
+
+        let n_rows = metadata.num_rows();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "orc file", size = n_rows))?;
+
+        self.init_data = Some(InitializedState {
+            byte_source,
+            metadata,
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            byte_source,
+            metadata,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate,
+            cast_columns_policy,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        let normalized_pre_slice = pre_slice_arg
+            .clone()
+            .map(|pre_slice| pre_slice.restrict_to_bounds(n_rows_in_file as usize));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(metadata.schema.clone());
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        let slice: Range<usize> =
+            normalized_pre_slice.map_or(0..n_rows_in_file as usize, Range::<usize>::from);
+
+        let projection =
+            resolve_orc_column_projections(&metadata, projection, cast_columns_policy)?;
+        let column_indices: Arc<[usize]> = projection.iter().map(|p| p.index).collect();
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: \
+                project: {} / {}, \
+                stripes: {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?}, \
+                predicate: {:?} \
+                ",
+                projection.len(),
+                metadata.schema.len(),
+                metadata.stripes.len(),
+                pre_slice_arg,
+                slice,
+                predicate.as_ref().map(|_| "<predicate>"),
+            )
+        }
+
+        let max_morsel_size = get_ideal_morsel_size();
+
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<StripeBatch>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        // Decoder tasks.
+        //
+        // Every stripe is decompressed and decoded independently, which makes the decoding parallel
+        // over the stripes of the file.
+        let decoder_handles = batch_rxs
+            .into_iter()
+            .zip(morsel_senders)
+            .map(|(mut batch_rx, mut morsel_tx)| {
+                let byte_source = byte_source.clone();
+                let metadata = metadata.clone();
+                let projection = projection.clone();
+                let column_indices = column_indices.clone();
+                let row_index = row_index.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(StripeBatch {
+                        stripe,
+                        row_offset,
+                        slice,
+                        morsel_seq_base,
+                    }) = batch_rx.recv().await
+                    {
+                        // If we don't project any columns we don't have to decode the stripe.
+                        let mut df = if projection.is_empty() {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let df = fetch_and_decode_stripe(
+                                byte_source.clone(),
+                                metadata.clone(),
+                                stripe,
+                                column_indices.clone(),
+                            )
+                            .await?;
+                            let height = slice.len();
+                            let columns = df
+                                .take_columns()
+                                .into_iter()
+                                .zip(projection.iter())
+                                .map(|(column, projection)| {
+                                    projection
+                                        .apply_transform(column.slice(slice.start as i64, height))
+                                })
+                                .collect::<PolarsResult<Vec<_>>>()?;
+                            DataFrame::new_with_height(height, columns)?
+                        };
+
+                        if let Some(RowIndex { name, offset }) = &row_index {
+                            let offset = *offset + (row_offset + slice.start) as IdxSize;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            let morsel = Morsel::new(morsel_df, seq, source_token.clone());
+
+                            if morsel_tx.send_morsel(morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Sends the stripes that overlap with the slice and that are not pruned by the predicate.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let skip_mask = calculate_stripe_pred_pushdown_skip_mask(
+                predicate.as_ref(),
+                &metadata,
+                &projection,
+                row_index.as_ref(),
+                verbose,
+            )
+            .await?;
+
+            let mut morsel_seq: u64 = 0;
+            let mut stripe_row_offset: usize = 0;
+
+            for (i, stripe) in metadata.stripes.iter().enumerate() {
+                let stripe_rows = stripe_row_offset..stripe_row_offset + stripe.num_rows as usize;
+                stripe_row_offset = stripe_rows.end;
+
+                if stripe_rows.end <= slice.start || stripe_rows.is_empty() {
+                    continue;
+                }
+                if stripe_rows.start >= slice.end {
+                    break;
+                }
+                if skip_mask.as_ref().is_some_and(|mask| mask.get_bit(i)) {
+                    continue;
+                }
+
+                let batch = StripeBatch {
+                    stripe: i,
+                    row_offset: stripe_rows.start,
+                    slice: slice.start.saturating_sub(stripe_rows.start)
+                        ..stripe_rows.end.min(slice.end) - stripe_rows.start,
+                    morsel_seq_base: morsel_seq,
+                };
+                morsel_seq += batch.slice.len().div_ceil(max_morsel_size) as u64;
+
+                if batch_tx.send(batch).await.is_err() {
+                    // This should only happen if the receiver of the decoder has broken off,
+                    // meaning no further input will be needed.
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                walker_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().metadata.schema.clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// Reads the file tail, fetching only the last bytes of the file.
+async fn read_orc_metadata(
+    byte_source: &DynByteSource,
+    verbose: bool,
+) -> PolarsResult<OrcFileMetadata> {
+    let file_size = byte_source.get_size().await?;
+
+    let estimated_tail_size = if let DynByteSource::MemSlice(_) = byte_source {
+        // Mmapped or in-memory, reads are free.
+        file_size
+    } else {
+        (file_size / 2048).clamp(16_384, 131_072).min(file_size)
+    };
+
+    let mut tail = byte_source
+        .get_range(file_size - estimated_tail_size..file_size)
+        .await?;
+
+    let postscript_start = tail
+        .len()
+        .saturating_sub(OrcFileMetadata::MAX_POSTSCRIPT_LENGTH);
+    let tail_size = OrcFileMetadata::tail_length(&tail[postscript_start..])?;
+
+    if tail.len() < tail_size {
+        polars_ensure!(
+            tail_size <= file_size,
+            ComputeError: "corrupt orc file: invalid file tail"
+        );
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: Extra {} bytes need to be fetched for the file tail \
+                (initial estimate = {}, actual size = {})",
+                tail_size - tail.len(),
+                tail.len(),
+                tail_size,
+            );
+        }
+
+        tail = byte_source
+            .get_range(file_size - tail_size..file_size)
+            .await?;
+    }
+
+    OrcFileMetadata::read_tail(&tail, file_size)
+}
+
+/// Fetches the footer of the stripe and then the streams of the projected columns.
+async fn fetch_and_decode_stripe(
+    byte_source: Arc<DynByteSource>,
+    metadata: Arc<OrcFileMetadata>,
+    stripe: usize,
+    projection: Arc<[usize]>,
+) -> PolarsResult<DataFrame> {
+    let (streams, data) = pl_async::get_runtime()
+        .spawn({
+            let metadata = metadata.clone();
+            async move {
+                let footer = byte_source
+                    .get_range(metadata.stripe_footer_range(stripe)?)
+                    .await?;
+                let streams = metadata.stripe_streams(stripe, &footer, &projection)?;
+
+                let ranges = streams.byte_ranges().collect::<Vec<_>>();
+                let mut fetched = byte_source.get_ranges(&mut ranges.clone()).await?;
+                let data = ranges
+                    .iter()
+                    .map(|range| fetched.remove(&range.start).unwrap())
+                    .collect::<Vec<_>>();
+
+                PolarsResult::Ok((streams, data))
+            }
+        })
+        .await
+        .unwrap()?;
+
+    metadata.decode_stripe_streams(&streams, &data)
+}
+
+fn resolve_orc_column_projections(
+    metadata: &OrcFileMetadata,
+    projection: Projection,
+    cast_columns_policy: CastColumnsPolicy,
+) -> PolarsResult<Arc<[OrcColumnProjection]>> {
+    let file_schema = metadata.schema.as_ref();
+
+    let projection: Projection = match projection {
+        Projection::Plain(projected_schema) => ProjectionBuilder::new(projected_schema, None, None)
+            .build_projection(Some(file_schema), None, cast_columns_policy, usize::MAX)?,
+        Projection::Mapped { .. } => projection,
+    };
+
+    Ok(projection
+        .iter_non_missing_columns()
+        .map(
+            |MappedProjectionRef {
+                 source_name,
+                 output_name,
+                 output_dtype: _,
+                 resolved_transform,
+             }| OrcColumnProjection {
+                index: file_schema.index_of(source_name).unwrap(),
+                output_name: output_name.clone(),
+                transform: resolved_transform
+                    .map(|transform| transform.attach_transforms(ColumnSelector::Position(0))),
+            },
+        )
+        .collect())
+}
+
+/// Evaluates the skip batch predicate on the stripe statistics. A set bit in the returned mask
+/// means that the stripe does not contain any matching rows.
+async fn calculate_stripe_pred_pushdown_skip_mask(
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &Arc<OrcFileMetadata>,
+    projection: &Arc<[OrcColumnProjection]>,
+    row_index: Option<&RowIndex>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    let Some(predicate) = predicate else {
+        return Ok(None);
+    };
+
+    let Some(sbp) = predicate.skip_batch_predicate.clone() else {
+        return Ok(None);
+    };
+
+    let num_stripes = metadata.stripes.len();
+    let metadata = metadata.clone();
+    let projection = projection.clone();
+    let live_columns = predicate.live_columns.clone();
+    let row_index = row_index.cloned();
+
+    let skip_stripe_mask = async_executor::spawn(TaskPriority::High, async move {
+        let stripes = (0..num_stripes).collect::<Vec<_>>();
+        let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+
+        let lengths: Vec<IdxSize> = metadata
+            .stripes
+            .iter()
+            .map(|stripe| stripe.num_rows as IdxSize)
+            .collect();
+
+        columns.push(Column::new("len".into(), lengths));
+
+        for column in projection.iter() {
+            let c = &column.output_name;
+
+            if !live_columns.contains(c) {
+                continue;
+            }
+
+            let OrcColumnStatistics {
+                min,
+                max,
+                null_count,
+            } = match metadata.column_statistics(column.index, &stripes)? {
+                Some(statistics) => statistics,
+                None => {
+                    let dtype = metadata.schema.get_at_index(column.index).unwrap().1;
+                    OrcColumnStatistics {
+                        min: Column::full_null(PlSmallStr::EMPTY, num_stripes, dtype),
+                        max: Column::full_null(PlSmallStr::EMPTY, num_stripes, dtype),
+                        null_count: Column::full_null(PlSmallStr::EMPTY, num_stripes, &IDX_DTYPE),
+                    }
+                },
+            };
+
+            // Note: Order is important here. We re-use the transform for the output column, meaning
+            // that it may set the column name.
+            let min = column.apply_transform(min)?;
+            let max = column.apply_transform(max)?;
+
+            columns.extend([
+                min.with_name(format_pl_smallstr!("{c}_min")),
+                max.with_name(format_pl_smallstr!("{c}_max")),
+                null_count.with_name(format_pl_smallstr!("{c}_nc")),
+            ]);
+        }
+
+        if let Some(RowIndex { name, offset }) = row_index {
+            let mut min = Vec::with_capacity(num_stripes);
+            let mut max = Vec::with_capacity(num_stripes);
+            let mut offset = Some(offset);
+
+            for stripe in metadata.stripes.iter() {
+                let n_rows = IdxSize::try_from(stripe.num_rows).unwrap_or(IdxSize::MAX);
+                let end = offset.and_then(|offset| offset.checked_add(n_rows));
+
+                if n_rows == 0 || end.is_none() {
+                    min.push(None);
+                    max.push(None);
+                } else {
+                    min.push(offset);
+                    max.push(end.map(|end| end - 1));
+                }
+
+                offset = end;
+            }
+
+            columns.extend([
+                Column::new(format_pl_smallstr!("{name}_min"), min),
+                Column::new(format_pl_smallstr!("{name}_max"), max),
+                Column::new(
+                    format_pl_smallstr!("{name}_nc"),
+                    vec![0 as IdxSize; num_stripes],
+                ),
+            ]);
+        }
+
+        let statistics_df = DataFrame::new_with_height(num_stripes, columns)?;
+
+        sbp.evaluate_with_stat_df(&statistics_df)
+    })
+    .await?;
+
+    if verbose {
+        eprintln!(
+            "[OrcFileReader]: Predicate pushdown: \
+            reading {} / {} stripes",
+            skip_stripe_mask.unset_bits(),
+            num_stripes,
+        );
+    }
+
+    Ok(Some(skip_stripe_mask))
+}
@@ -0,0 +1,211 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicate};
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::{is_in_set, read_header};
+use polars_parquet::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::write::hash_array_values;
+
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// The hashes of the values of which a row group must contain at least one in a Parquet column
+/// to match the predicate.
+struct BloomFilterProbe {
+    parquet_column_idx: usize,
+    hashes: Vec<u64>,
+}
+
+/// Extends `skip_row_group_mask` with the row groups for which the bloom filter of a column
+/// proves that it contains none of the values of an `==` or `is_in` predicate on that column.
+///
+/// Only the column predicates are used, which are each implied by the full predicate.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &FileMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    skip_row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics {
+        return Ok(skip_row_group_mask);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(skip_row_group_mask);
+    };
+
+    let row_groups = &metadata.row_groups[row_group_slice];
+
+    let Some(first_row_group) = row_groups.first() else {
+        return Ok(skip_row_group_mask);
+    };
+
+    let probes = projected_arrow_fields
+        .iter()
+        .filter_map(|projection| {
+            let (_, specialized) = predicate
+                .column_predicates
+                .predicates
+                .get(projection.output_name())?;
+
+            let scalars = match specialized.as_ref()? {
+                SpecializedColumnPredicate::Equal(scalar) => std::slice::from_ref(scalar),
+                SpecializedColumnPredicate::EqualOneOf(scalars) => scalars.as_ref(),
+                _ => return None,
+            };
+
+            // Nested columns have more than one Parquet column.
+            let &[parquet_column_idx] =
+                first_row_group.columns_idxs_under_root_iter(&projection.arrow_field().name)?
+            else {
+                return None;
+            };
+
+            let physical_type =
+                first_row_group.parquet_columns()[parquet_column_idx].physical_type();
+
+            Some(BloomFilterProbe {
+                parquet_column_idx,
+                hashes: predicate_value_hashes(scalars, projection, physical_type)?,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if probes.is_empty() {
+        return Ok(skip_row_group_mask);
+    }
+
+    let mut skip_row_group_mask = skip_row_group_mask.map_or_else(
+        || MutableBitmap::from_len_zeroed(row_groups.len()),
+        Bitmap::make_mut,
+    );
+
+    // Fetch all bloom filters at once so that the requests can be coalesced.
+    let mut ranges = Vec::new();
+    for (i, row_group) in row_groups.iter().enumerate() {
+        if skip_row_group_mask.get(i) {
+            continue;
+        }
+
+        ranges.extend(
+            probes
+                .iter()
+                .filter_map(|probe| bloom_filter_byte_range(row_group, probe)),
+        );
+    }
+
+    if ranges.is_empty() {
+        return Ok(Some(skip_row_group_mask.freeze()));
+    }
+
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    for (i, row_group) in row_groups.iter().enumerate() {
+        if skip_row_group_mask.get(i) {
+            continue;
+        }
+
+        for probe in probes.iter() {
+            let Some(range) = bloom_filter_byte_range(row_group, probe) else {
+                continue;
+            };
+
+            let Some((header_len, num_bytes)) = read_header(&bytes_map[&range.start])? else {
+                continue;
+            };
+
+            let Some(bitset) = bytes_map[&range.start].get(header_len..header_len + num_bytes)
+            else {
+                continue;
+            };
+
+            if bitset.is_empty() || bitset.len() % 32 != 0 {
+                continue;
+            }
+
+            if !probe.hashes.iter().any(|&hash| is_in_set(bitset, hash)) {
+                skip_row_group_mask.set(i, true);
+                break;
+            }
+        }
+    }
+
+    let skip_row_group_mask = skip_row_group_mask.freeze();
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            reading {} / {} row groups",
+            skip_row_group_mask.unset_bits(),
+            row_groups.len(),
+        );
+    }
+
+    Ok(Some(skip_row_group_mask))
+}
+
+/// Returns the hashes of the values of `scalars`, or `None` if they cannot be checked against the
+/// bloom filters of the column.
+fn predicate_value_hashes(
+    scalars: &[Scalar],
+    projection: &ArrowFieldProjection,
+    physical_type: ParquetPhysicalType,
+) -> Option<Vec<u64>> {
+    let dtype = DataType::from_arrow_field(projection.arrow_field());
+
+    // Casts between the file and output types can map different physical values to the same
+    // output value.
+    if let ArrowFieldProjection::Mapped { output_dtype, .. } = projection {
+        if output_dtype != &dtype {
+            return None;
+        }
+    }
+
+    // Nulls are never in a bloom filter, and floats compare equal with different bit patterns
+    // (e.g. `0.0 == -0.0`).
+    if dtype.is_float() || scalars.iter().any(|s| s.is_null() || s.dtype() != &dtype) {
+        return None;
+    }
+
+    let values = scalars
+        .iter()
+        .map(|s| s.value().clone())
+        .collect::<Vec<_>>();
+    let values = Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, &dtype, true)
+        .ok()?
+        .rechunk()
+        .to_arrow(0, CompatLevel::newest());
+
+    let mut hashes = Vec::with_capacity(scalars.len());
+    let hashed_as = hash_array_values(values.as_ref(), |hash| hashes.push(hash)).ok()?;
+
+    use ParquetPhysicalType as P;
+    match (hashed_as, physical_type) {
+        (P::ByteArray | P::FixedLenByteArray(_), P::ByteArray | P::FixedLenByteArray(_)) => {},
+        (hashed_as, physical_type) if hashed_as == physical_type => {},
+        _ => return None,
+    }
+
+    Some(hashes)
+}
+
+fn bloom_filter_byte_range(
+    row_group: &RowGroupMetadata,
+    probe: &BloomFilterProbe,
+) -> Option<Range<usize>> {
+    let metadata = row_group.parquet_columns()[probe.parquet_column_idx].metadata();
+
+    // Filters written without their length would need a second request to read past the header.
+    let offset = usize::try_from(metadata.bloom_filter_offset?).ok()?;
+    let length = usize::try_from(metadata.bloom_filter_length?).ok()?;
+
+    Some(offset..offset + length)
+}
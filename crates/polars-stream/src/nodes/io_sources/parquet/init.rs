@@ -12,6 +12,7 @@ use super::{AsyncTaskData, ParquetReadImpl};
 use crate::async_executor;
 use crate::morsel::{Morsel, SourceToken, get_ideal_morsel_size};
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::parquet::bloom_filter::calculate_row_group_bloom_filter_skip_mask;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::nodes::io_sources::parquet::statistics::calculate_row_group_pred_pushdown_skip_mask;
 use crate::nodes::{MorselSeq, TaskPriority};
@@ -60,6 +61,7 @@ impl ParquetReadImpl {
             tokio::sync::mpsc::channel(row_group_prefetch_size);
 
         let row_index = self.row_index.clone();
+        let page_index_row_index = self.row_index.clone();
 
         let prefetch_task = AbortOnDropHandle(io_runtime.spawn(async move {
             polars_ensure!(
@@ -127,10 +129,24 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &projected_arrow_fields,
+                &byte_source,
+                row_group_mask,
+                verbose,
+            )
+            .await?;
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
                 predicate,
+                use_statistics,
+                row_index: page_index_row_index,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
@@ -138,6 +154,7 @@ impl ParquetReadImpl {
                 row_group_slice,
                 row_group_mask,
                 row_offset,
+                verbose,
             };
 
             while let Some(prefetch) = row_group_data_fetcher.next().await {
@@ -10,6 +10,7 @@ pub async fn read_parquet_metadata_bytes(
 ) -> PolarsResult<(MemSlice, Option<MemSlice>)> {
     use polars_parquet::parquet::PARQUET_MAGIC;
     use polars_parquet::parquet::error::ParquetError;
+    use polars_parquet::parquet::read::is_parquet_magic;
 
     const FOOTER_HEADER_SIZE: usize = polars_parquet::parquet::FOOTER_SIZE as usize;
 
@@ -38,7 +39,7 @@ pub async fn read_parquet_metadata_bytes(
     let (v, remaining) = footer_header_bytes.split_at(4);
     let footer_size = u32::from_le_bytes(v.try_into().unwrap());
 
-    if remaining != PARQUET_MAGIC {
+    if !is_parquet_magic(remaining) {
         return Err(ParquetError::OutOfSpec(format!(
             r#"expected parquet magic bytes "{}" in footer, got "{}" instead"#,
             std::str::from_utf8(&PARQUET_MAGIC).unwrap(),
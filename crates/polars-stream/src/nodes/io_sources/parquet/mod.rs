@@ -28,9 +28,11 @@ use crate::nodes::io_sources::parquet::projection::{
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::task_handles_ext;
 
+mod bloom_filter;
 pub mod builder;
 mod init;
 mod metadata_utils;
+mod page_index;
 mod projection;
 mod row_group_data_fetch;
 mod row_group_decode;
@@ -100,9 +102,10 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
+            let decryption = self.config.decryption.as_ref().map(|d| d.to_properties());
+            Arc::new(polars_parquet::parquet::read::deserialize_footer(
                 metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
+                decryption.as_ref(),
             )?)
         };
 
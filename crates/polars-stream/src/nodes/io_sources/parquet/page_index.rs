@@ -0,0 +1,487 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_io::RowIndex;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::read::statistics::deserialize_page_statistics;
+use polars_parquet::read::{
+    PageLocation, RowGroupMetadata, deserialize_column_index, deserialize_offset_index,
+};
+use polars_utils::mmap::MemSlice;
+
+use crate::async_executor::{self, TaskPriority};
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+use crate::nodes::io_sources::parquet::statistics::StatisticsColumns;
+
+/// The rows of a row group that remain after skipping the pages that the page index proves to
+/// contain no rows matching the predicate.
+pub(super) struct PageSelection {
+    /// Rows of the row group that may match the predicate.
+    pub(super) row_mask: Bitmap,
+    /// Data pages to fetch and decode per Parquet column index. Columns that are not in here are
+    /// fetched and decoded in full.
+    pub(super) column_pages: PlHashMap<usize, SelectedPages>,
+}
+
+impl PageSelection {
+    /// Translates a mask over the selected rows into a mask over all rows of the row group.
+    pub(super) fn to_row_group_mask(&self, selected_rows_mask: Option<&Bitmap>) -> Bitmap {
+        let Some(selected_rows_mask) = selected_rows_mask else {
+            return self.row_mask.clone();
+        };
+
+        assert_eq!(selected_rows_mask.len(), self.row_mask.set_bits());
+
+        if selected_rows_mask.unset_bits() == 0 {
+            return self.row_mask.clone();
+        }
+
+        let mut selected_rows_mask = selected_rows_mask.iter();
+        let mut out = MutableBitmap::with_capacity(self.row_mask.len());
+
+        for is_selected in self.row_mask.iter() {
+            out.push(is_selected && selected_rows_mask.next().unwrap());
+        }
+
+        out.freeze()
+    }
+}
+
+/// Selected data pages of a column chunk.
+pub(super) struct SelectedPages {
+    /// Byte range of the column chunk before the first data page. This contains the dictionary
+    /// page, if any.
+    pub(super) prefix: Range<usize>,
+    /// Byte ranges of the selected data pages.
+    pub(super) pages: Vec<Range<usize>>,
+    /// Row ranges of the selected data pages.
+    pub(super) rows: Vec<Range<usize>>,
+}
+
+impl SelectedPages {
+    pub(super) fn byte_ranges(&self) -> impl Iterator<Item = Range<usize>> + '_ {
+        std::iter::once(self.prefix.clone())
+            .filter(|range| !range.is_empty())
+            .chain(self.pages.iter().cloned())
+    }
+
+    pub(super) fn num_rows(&self) -> usize {
+        self.rows.iter().map(|rows| rows.len()).sum()
+    }
+
+    /// Restricts a mask over all rows of the row group to the rows of the selected pages.
+    pub(super) fn restrict_mask(&self, row_group_mask: &Bitmap) -> Bitmap {
+        let mut out = MutableBitmap::with_capacity(self.num_rows());
+
+        for rows in self.rows.iter() {
+            out.extend_from_bitmap(&row_group_mask.clone().sliced(rows.start, rows.len()));
+        }
+
+        out.freeze()
+    }
+}
+
+/// Evaluates the skip batch predicate on the page statistics in the column indexes of the live
+/// columns, and selects the pages of the projected columns that contain the rows that can match.
+///
+/// Returns `None` if the row group has no page index for the live columns or if no rows can be
+/// skipped.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_page_selection(
+    metadata: Arc<FileMetadata>,
+    row_group_idx: usize,
+    row_offset: usize,
+    projected_arrow_fields: Arc<[ArrowFieldProjection]>,
+    predicate: &ScanIOPredicate,
+    row_index: Option<RowIndex>,
+    byte_source: &DynByteSource,
+    verbose: bool,
+) -> PolarsResult<Option<PageSelection>> {
+    let Some(sbp) = predicate.skip_batch_predicate.clone() else {
+        return Ok(None);
+    };
+
+    let row_group_metadata = &metadata.row_groups[row_group_idx];
+
+    if row_group_metadata.num_rows() == 0 {
+        return Ok(None);
+    }
+
+    let flat_columns = flat_parquet_columns(row_group_metadata, &projected_arrow_fields);
+
+    // The indexes of encrypted columns are encrypted, and their pages must be decrypted in order.
+    if flat_columns
+        .iter()
+        .any(|&(_, col_idx)| row_group_metadata.parquet_columns()[col_idx].is_encrypted())
+    {
+        return Ok(None);
+    }
+
+    let has_live_column_index = flat_columns.iter().any(|&(i, col_idx)| {
+        let col_md = &row_group_metadata.parquet_columns()[col_idx];
+
+        predicate
+            .live_columns
+            .contains(projected_arrow_fields[i].output_name())
+            && col_md.column_index_range().is_some()
+            && col_md.offset_index_range().is_some()
+    });
+
+    if !has_live_column_index {
+        return Ok(None);
+    }
+
+    // Fetch all indexes at once so that the requests can be coalesced.
+    let mut ranges = Vec::new();
+    for &(i, col_idx) in flat_columns.iter() {
+        let col_md = &row_group_metadata.parquet_columns()[col_idx];
+
+        if let Some(range) = col_md.offset_index_range() {
+            ranges.push(range.start as usize..range.end as usize);
+        }
+
+        if predicate
+            .live_columns
+            .contains(projected_arrow_fields[i].output_name())
+        {
+            if let Some(range) = col_md.column_index_range() {
+                ranges.push(range.start as usize..range.end as usize);
+            }
+        }
+    }
+
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+    let live_columns = predicate.live_columns.clone();
+
+    // Note: We are spawning here onto the computational async runtime because the caller is being
+    // run on a tokio async thread.
+    let page_selection = async_executor::spawn(TaskPriority::High, async move {
+        let row_group_metadata = &metadata.row_groups[row_group_idx];
+        let num_rows = row_group_metadata.num_rows();
+
+        let mut page_locations = PlHashMap::with_capacity(flat_columns.len());
+        for &(_, col_idx) in flat_columns.iter() {
+            let col_md = &row_group_metadata.parquet_columns()[col_idx];
+
+            let Some(range) = col_md.offset_index_range() else {
+                continue;
+            };
+
+            let offset_index = deserialize_offset_index(&bytes_map[&(range.start as usize)])?;
+            let byte_range = col_md.byte_range();
+
+            if is_valid_page_locations(&offset_index.page_locations, byte_range, num_rows) {
+                page_locations.insert(col_idx, offset_index.page_locations);
+            }
+        }
+
+        // The pages of the columns with page statistics split the row group into segments that
+        // each lie within a single page of every one of those columns.
+        let mut stats_columns = Vec::new();
+        for &(i, col_idx) in flat_columns.iter() {
+            let col_md = &row_group_metadata.parquet_columns()[col_idx];
+
+            if !live_columns.contains(projected_arrow_fields[i].output_name()) {
+                continue;
+            }
+
+            let (Some(range), Some(locations)) =
+                (col_md.column_index_range(), page_locations.get(&col_idx))
+            else {
+                continue;
+            };
+
+            let column_index = deserialize_column_index(&bytes_map[&(range.start as usize)])?;
+            let arrow_field = projected_arrow_fields[i].arrow_field();
+
+            let Some(statistics) = deserialize_page_statistics(arrow_field, col_md, &column_index)?
+            else {
+                continue;
+            };
+
+            if statistics.min_value.len() != locations.len() {
+                continue;
+            }
+
+            stats_columns.push((
+                i,
+                locations,
+                StatisticsColumns::from_arrow_statistics(statistics, arrow_field)?,
+            ));
+        }
+
+        if stats_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let mut segment_starts = stats_columns
+            .iter()
+            .flat_map(|(_, locations, _)| locations.iter().map(|l| l.first_row_index as usize))
+            .collect::<Vec<_>>();
+        segment_starts.sort_unstable();
+        segment_starts.dedup();
+
+        let segment_ends = segment_starts[1..]
+            .iter()
+            .copied()
+            .chain(std::iter::once(num_rows))
+            .collect::<Vec<_>>();
+        let num_segments = segment_starts.len();
+
+        let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+
+        let lengths: Vec<IdxSize> = segment_starts
+            .iter()
+            .zip(segment_ends.iter())
+            .map(|(start, end)| (end - start) as IdxSize)
+            .collect();
+
+        columns.push(Column::new("len".into(), lengths));
+
+        for (i, projection) in projected_arrow_fields.iter().enumerate() {
+            let c = projection.output_name();
+
+            if !live_columns.contains(c) {
+                continue;
+            }
+
+            let mut statistics = if let Some((_, locations, statistics)) =
+                stats_columns.iter().find(|(j, _, _)| *j == i)
+            {
+                // The page that contains each segment.
+                let page_idxs = segment_starts
+                    .iter()
+                    .map(|&start| {
+                        (locations.partition_point(|l| l.first_row_index as usize <= start) - 1)
+                            as IdxSize
+                    })
+                    .collect::<Vec<_>>();
+
+                StatisticsColumns {
+                    min: statistics.min.take_slice(&page_idxs)?,
+                    max: statistics.max.take_slice(&page_idxs)?,
+                    null_count: statistics.null_count.take_slice(&page_idxs)?,
+                }
+            } else {
+                StatisticsColumns::new_null(
+                    &DataType::from_arrow_field(projection.arrow_field()),
+                    num_segments,
+                )
+            };
+
+            // Note: Order is important here. We re-use the transform for the output column,
+            // meaning that it may set the column name.
+            statistics.min = projection.apply_transform(statistics.min)?;
+            statistics.max = projection.apply_transform(statistics.max)?;
+
+            let statistics = statistics.with_base_column_name(c);
+
+            columns.extend([statistics.min, statistics.max, statistics.null_count]);
+        }
+
+        if let Some(row_index) = row_index {
+            let offset = row_index
+                .offset
+                .saturating_add(IdxSize::try_from(row_offset).unwrap_or(IdxSize::MAX));
+
+            let statistics = build_row_index_statistics(offset, &segment_starts, &segment_ends)
+                .with_base_column_name(&row_index.name);
+
+            columns.extend([statistics.min, statistics.max, statistics.null_count]);
+        }
+
+        let statistics_df = DataFrame::new_with_height(num_segments, columns)?;
+        let skip_segment_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+        if skip_segment_mask.set_bits() == 0 {
+            return Ok(None);
+        }
+
+        let mut row_mask = MutableBitmap::with_capacity(num_rows);
+        for (i, (start, end)) in segment_starts.iter().zip(segment_ends.iter()).enumerate() {
+            row_mask.extend_constant(end - start, !skip_segment_mask.get_bit(i));
+        }
+        let row_mask = row_mask.freeze();
+
+        let column_pages = page_locations
+            .iter()
+            .filter_map(|(&col_idx, locations)| {
+                let selected_pages =
+                    select_pages(row_group_metadata, col_idx, locations, &row_mask)?;
+                Some((col_idx, selected_pages))
+            })
+            .collect();
+
+        PolarsResult::Ok(Some(PageSelection {
+            row_mask,
+            column_pages,
+        }))
+    })
+    .await?;
+
+    if verbose {
+        if let Some(page_selection) = &page_selection {
+            eprintln!(
+                "[ParquetFileReader]: Page index pushdown: \
+                reading {} / {} rows of row group {}",
+                page_selection.row_mask.set_bits(),
+                page_selection.row_mask.len(),
+                row_group_idx,
+            );
+        }
+    }
+
+    Ok(page_selection)
+}
+
+/// Returns the index of the projected field and the Parquet column index of all projected fields
+/// that consist of a single flat Parquet column.
+fn flat_parquet_columns(
+    row_group_metadata: &RowGroupMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+) -> Vec<(usize, usize)> {
+    projected_arrow_fields
+        .iter()
+        .enumerate()
+        .filter_map(|(i, projection)| {
+            let arrow_field = projection.arrow_field();
+
+            if arrow_field.dtype().is_nested() {
+                return None;
+            }
+
+            let &[col_idx] = row_group_metadata.columns_idxs_under_root_iter(&arrow_field.name)?
+            else {
+                return None;
+            };
+
+            Some((i, col_idx))
+        })
+        .collect()
+}
+
+/// Checks that the pages of an offset index are ordered, lie within the column chunk and start at
+/// the first row of the row group.
+fn is_valid_page_locations(
+    locations: &[PageLocation],
+    byte_range: Range<u64>,
+    num_rows: usize,
+) -> bool {
+    let Some(first) = locations.first() else {
+        return false;
+    };
+
+    let page_byte_range = |l: &PageLocation| {
+        let start = u64::try_from(l.offset).ok()?;
+        let len = u64::try_from(l.compressed_page_size).ok()?;
+        Some(start..start.checked_add(len)?)
+    };
+
+    first.first_row_index == 0
+        && locations.iter().all(|l| {
+            page_byte_range(l).is_some_and(|r| {
+                byte_range.start <= r.start && r.end <= byte_range.end && !r.is_empty()
+            })
+        })
+        && locations.windows(2).all(|w| {
+            w[0].first_row_index < w[1].first_row_index
+                && page_byte_range(&w[0]).unwrap().end <= page_byte_range(&w[1]).unwrap().start
+        })
+        && (locations.last().unwrap().first_row_index as u64) < num_rows as u64
+}
+
+/// Returns the pages of a column chunk that contain selected rows, or `None` if all pages are
+/// needed.
+fn select_pages(
+    row_group_metadata: &RowGroupMetadata,
+    col_idx: usize,
+    locations: &[PageLocation],
+    row_mask: &Bitmap,
+) -> Option<SelectedPages> {
+    let num_rows = row_group_metadata.num_rows();
+
+    let mut pages = Vec::new();
+    let mut rows = Vec::new();
+
+    for (i, l) in locations.iter().enumerate() {
+        let start = l.first_row_index as usize;
+        let end = locations
+            .get(i + 1)
+            .map_or(num_rows, |l| l.first_row_index as usize);
+
+        if row_mask.clone().sliced(start, end - start).set_bits() == 0 {
+            continue;
+        }
+
+        let offset = l.offset as usize;
+        pages.push(offset..offset + l.compressed_page_size as usize);
+        rows.push(start..end);
+    }
+
+    if pages.len() == locations.len() {
+        return None;
+    }
+
+    let chunk_start = row_group_metadata.parquet_columns()[col_idx]
+        .byte_range()
+        .start as usize;
+
+    Some(SelectedPages {
+        prefix: chunk_start..locations[0].offset as usize,
+        pages,
+        rows,
+    })
+}
+
+fn build_row_index_statistics(
+    offset: IdxSize,
+    segment_starts: &[usize],
+    segment_ends: &[usize],
+) -> StatisticsColumns {
+    let (min, max): (Vec<_>, Vec<_>) = segment_starts
+        .iter()
+        .zip(segment_ends.iter())
+        .map(|(&start, &end)| {
+            let min = IdxSize::try_from(start)
+                .ok()
+                .and_then(|start| offset.checked_add(start));
+            let max = IdxSize::try_from(end - 1)
+                .ok()
+                .and_then(|end| offset.checked_add(end));
+
+            match (min, max) {
+                (Some(min), Some(max)) => (Some(min), Some(max)),
+                _ => (None, None),
+            }
+        })
+        .unzip();
+
+    StatisticsColumns {
+        min: IdxCa::from_iter_options(PlSmallStr::EMPTY, min.into_iter()).into_column(),
+        max: IdxCa::from_iter_options(PlSmallStr::EMPTY, max.into_iter()).into_column(),
+        null_count: IdxCa::full(PlSmallStr::EMPTY, 0, segment_starts.len()).into_column(),
+    }
+}
+
+/// Concatenates the selected pages of a column chunk so that they can be read back to back.
+pub(super) fn concat_selected_pages(
+    selected_pages: &SelectedPages,
+    get_range: impl Fn(Range<usize>) -> MemSlice,
+) -> MemSlice {
+    let mut ranges = selected_pages.byte_ranges().collect::<Vec<_>>();
+
+    if ranges.len() == 1 {
+        return get_range(ranges.pop().unwrap());
+    }
+
+    let mut out = Vec::with_capacity(ranges.iter().map(|range| range.len()).sum());
+    for range in ranges {
+        out.extend_from_slice(&get_range(range));
+    }
+
+    MemSlice::from_vec(out)
+}
@@ -5,6 +5,7 @@ use polars_core::prelude::PlHashMap;
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
+use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
@@ -12,6 +13,7 @@ use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use crate::nodes::io_sources::parquet::page_index::{PageSelection, calculate_page_selection};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::task_handles_ext;
 
@@ -22,13 +24,26 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Set if the page index allowed skipping pages of this row group.
+    pub(super) page_selection: Option<PageSelection>,
+}
+
+impl RowGroupData {
+    /// Number of rows of the row group that remain after page skipping.
+    pub(super) fn num_selected_rows(&self) -> usize {
+        self.page_selection.as_ref().map_or_else(
+            || self.row_group_metadata.num_rows(),
+            |page_selection| page_selection.row_mask.set_bits(),
+        )
+    }
 }
 
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Arc<[ArrowFieldProjection]>,
     pub(super) is_full_projection: bool,
-    #[allow(unused)] // TODO: Fix!
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) use_statistics: bool,
+    pub(super) row_index: Option<RowIndex>,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -38,6 +53,7 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) row_group_mask: Option<Bitmap>,
 
     pub(super) row_offset: usize,
+    pub(super) verbose: bool,
 }
 
 impl RowGroupDataFetcher {
@@ -84,7 +100,31 @@ impl RowGroupDataFetcher {
             let memory_prefetch_func = self.memory_prefetch_func;
             let io_runtime = polars_io::pl_async::get_runtime();
 
+            // Page skipping is only done for row groups that are read in full.
+            let page_index_predicate = self
+                .predicate
+                .clone()
+                .filter(|_| self.use_statistics && slice.is_none());
+            let row_index = self.row_index.clone();
+            let verbose = self.verbose;
+
             let handle = io_runtime.spawn(async move {
+                let page_selection = if let Some(predicate) = page_index_predicate {
+                    calculate_page_selection(
+                        metadata.clone(),
+                        idx,
+                        current_row_offset,
+                        projection.clone(),
+                        &predicate,
+                        row_index,
+                        current_byte_source.as_ref(),
+                        verbose,
+                    )
+                    .await?
+                } else {
+                    None
+                };
+
                 let row_group_metadata = &metadata.row_groups[idx];
                 let fetched_bytes =
                     if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
@@ -117,6 +157,16 @@ impl RowGroupDataFetcher {
                             offset: 0,
                             mem_slice,
                         }
+                    } else if let Some(page_selection) = page_selection.as_ref() {
+                        let mut ranges = get_row_group_byte_ranges_for_page_selection(
+                            row_group_metadata,
+                            &mut projection.iter().map(|x| &x.arrow_field().name),
+                            page_selection,
+                        );
+
+                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+
+                        FetchedBytes::BytesMap(bytes_map)
                     } else if !is_full_projection {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
@@ -158,6 +208,7 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_selection,
                 })
             });
 
@@ -208,3 +259,31 @@ fn get_row_group_byte_ranges_for_projection<'a>(
             })
     })
 }
+
+/// Like [`get_row_group_byte_ranges_for_projection`], but only the selected pages are returned for
+/// the columns that have them.
+fn get_row_group_byte_ranges_for_page_selection(
+    row_group_metadata: &RowGroupMetadata,
+    columns: &mut dyn Iterator<Item = &PlSmallStr>,
+    page_selection: &PageSelection,
+) -> Vec<std::ops::Range<usize>> {
+    let mut ranges = Vec::new();
+
+    for col_name in columns {
+        // `None` for the `allow_missing_columns` case.
+        let Some(col_idxs) = row_group_metadata.columns_idxs_under_root_iter(col_name) else {
+            continue;
+        };
+
+        for &col_idx in col_idxs {
+            if let Some(selected_pages) = page_selection.column_pages.get(&col_idx) {
+                ranges.extend(selected_pages.byte_ranges());
+            } else {
+                let byte_range = row_group_metadata.parquet_columns()[col_idx].byte_range();
+                ranges.push(byte_range.start as usize..byte_range.end as usize);
+            }
+        }
+    }
+
+    ranges
+}
@@ -2,8 +2,10 @@ use std::ops::Deref;
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
-use polars_core::series::Series;
+use polars_core::prelude::{
+    ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IdxCa, IntoColumn,
+};
+use polars_core::series::{IsSorted, Series};
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
@@ -13,11 +15,14 @@ use polars_io::predicates::{
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::_internal::calc_prefilter_cost;
 use polars_io::prelude::try_set_sorted_flag;
-use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
+use polars_parquet::read::{
+    ColumnChunkMetadata, Filter, PageMetaData, ParquetType, PredicateFilter, PrimitiveLogicalType,
+};
 use polars_utils::IdxSize;
 use polars_utils::enum_unit_vec::EnumUnitVec;
 use polars_utils::pl_str::PlSmallStr;
 
+use super::page_index::concat_selected_pages;
 use super::row_group_data_fetch::RowGroupData;
 use crate::async_primitives::opt_spawned_future::parallelize_first_to_local;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
@@ -79,15 +84,19 @@ impl RowGroupDecoder {
             out_columns.push(s);
         }
 
-        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        // Pages are only skipped for row groups without a slice.
+        let (filter, projection_height) = if row_group_data.page_selection.is_some() {
+            (None, row_group_data.num_selected_rows())
+        } else {
+            (
+                Some(polars_parquet::read::Filter::Range(slice_range.clone())),
+                slice_range.len(),
+            )
+        };
 
-        let projection_height = slice_range.len();
+        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, filter)
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -122,6 +131,26 @@ impl RowGroupDecoder {
         slice_range: core::ops::Range<usize>,
     ) -> PolarsResult<Option<Column>> {
         if let Some(RowIndex { name, offset }) = self.row_index.clone() {
+            if let Some(page_selection) = row_group_data.page_selection.as_ref() {
+                debug_assert_eq!(slice_range, 0..row_group_data.row_group_metadata.num_rows());
+
+                let offset = offset.saturating_add(
+                    IdxSize::try_from(row_group_data.row_offset).unwrap_or(IdxSize::MAX),
+                );
+
+                let mut row_index = IdxCa::from_vec(
+                    name,
+                    page_selection
+                        .row_mask
+                        .true_idx_iter()
+                        .map(|i| offset.saturating_add(i as IdxSize))
+                        .collect(),
+                );
+                row_index.set_sorted_flag(IsSorted::Ascending);
+
+                return Ok(Some(row_index.into_column()));
+            }
+
             let projection_height = slice_range.len();
 
             let offset = offset.saturating_add(
@@ -151,11 +180,8 @@ impl RowGroupDecoder {
         filter: Option<polars_parquet::read::Filter>,
     ) -> PolarsResult<()> {
         let projected_arrow_fields = &self.projected_arrow_fields;
-        let expected_num_rows = filter
-            .as_ref()
-            .map_or(row_group_data.row_group_metadata.num_rows(), |x| {
-                x.num_rows(row_group_data.row_group_metadata.num_rows())
-            });
+        let num_rows = row_group_data.num_selected_rows();
+        let expected_num_rows = filter.as_ref().map_or(num_rows, |x| x.num_rows(num_rows));
 
         // Ensure we provide the same output column order as the pre-filtered decode.
         let get_projected_field_at_output_index = {
@@ -250,33 +276,15 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
     let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-    let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+    let (mut series, pred_true_mask) =
+        deserialize_series(arrow_field, iter, row_group_data, filter)?;
 
     if !skip_num_rows_check {
-        assert_eq!(array.len(), expected_num_rows);
+        assert_eq!(series.len(), expected_num_rows);
     }
 
-    let mut series = Series::try_from((arrow_field, array))?;
-
     if let Some(col_idxs) = row_group_data
         .row_group_metadata
         .columns_idxs_under_root_iter(&arrow_field.name)
@@ -291,6 +299,101 @@ fn decode_column(
     Ok((series.into_column(), pred_true_mask))
 }
 
+/// Deserializes the Parquet columns of `arrow_field`.
+///
+/// If pages of the row group were skipped, `filter` applies to the selected rows of the row group,
+/// and only the selected pages of the column are decoded if the column has a page selection.
+fn deserialize_series<'a>(
+    arrow_field: &ArrowField,
+    columns: impl Iterator<Item = &'a ColumnChunkMetadata>,
+    row_group_data: &'a RowGroupData,
+    filter: Option<Filter>,
+) -> PolarsResult<(Series, Bitmap)> {
+    let get_full_range = |col_md: &'a ColumnChunkMetadata| {
+        let byte_range = col_md.byte_range();
+
+        (
+            col_md,
+            row_group_data
+                .fetched_bytes
+                .get_range(byte_range.start as usize..byte_range.end as usize),
+        )
+    };
+
+    let Some(page_selection) = row_group_data.page_selection.as_ref() else {
+        let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
+            columns.map(get_full_range).collect(),
+            arrow_field.clone(),
+            filter,
+        )?;
+
+        return Ok((Series::try_from((arrow_field, array))?, pred_true_mask));
+    };
+
+    let selected_rows_mask = match filter {
+        None => None,
+        Some(Filter::Mask(mask)) => Some(mask),
+        Some(Filter::Range(range)) => {
+            let num_rows = row_group_data.num_selected_rows();
+            let mut mask = MutableBitmap::with_capacity(num_rows);
+            mask.extend_constant(range.start, false);
+            mask.extend_constant(range.len(), true);
+            mask.extend_constant(num_rows - range.end, false);
+            Some(mask.freeze())
+        },
+        Some(Filter::Predicate(_)) => {
+            unreachable!("column predicates are not used when pages are skipped")
+        },
+    };
+    let mask = page_selection.to_row_group_mask(selected_rows_mask.as_ref());
+
+    let selected_pages = row_group_data
+        .row_group_metadata
+        .columns_idxs_under_root_iter(&arrow_field.name)
+        .and_then(|col_idxs| match col_idxs {
+            &[col_idx] => Some((col_idx, page_selection.column_pages.get(&col_idx)?)),
+            _ => None,
+        });
+
+    if let Some((col_idx, selected_pages)) = selected_pages {
+        let col_md = &row_group_data.row_group_metadata.parquet_columns()[col_idx];
+
+        let bytes = concat_selected_pages(selected_pages, |range| {
+            row_group_data.fetched_bytes.get_range(range)
+        });
+        let mut page_meta = PageMetaData::from(col_md);
+        page_meta.num_values = selected_pages.num_rows() as i64;
+
+        let (array, pred_true_mask) =
+            polars_io::prelude::_internal::to_deserializer_with_page_meta(
+                vec![(col_md, page_meta, bytes)],
+                arrow_field.clone(),
+                Some(Filter::Mask(selected_pages.restrict_mask(&mask))),
+            )?;
+
+        Ok((Series::try_from((arrow_field, array))?, pred_true_mask))
+    } else if !arrow_field.dtype.is_nested() {
+        let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
+            columns.map(get_full_range).collect(),
+            arrow_field.clone(),
+            Some(Filter::Mask(mask)),
+        )?;
+
+        Ok((Series::try_from((arrow_field, array))?, pred_true_mask))
+    } else {
+        let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
+            columns.map(get_full_range).collect(),
+            arrow_field.clone(),
+            None,
+        )?;
+
+        let series = Series::try_from((arrow_field, array))?
+            .filter(&BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask))?;
+
+        Ok((series, pred_true_mask))
+    }
+}
+
 /// Filters columns, in parallel depending number of rows / columns.
 async fn filter_cols(
     cols: Vec<Column>,
@@ -395,7 +498,7 @@ impl RowGroupDecoder {
 
         let prefilter_setting = self.use_prefiltered.as_ref().unwrap();
         let row_group_data = Arc::new(row_group_data);
-        let projection_height = row_group_data.row_group_metadata.num_rows();
+        let projection_height = row_group_data.num_selected_rows();
 
         let mut live_columns = Vec::with_capacity(
             self.row_index.is_some() as usize
@@ -416,6 +519,7 @@ impl RowGroupDecoder {
         let scan_predicate = self.predicate.as_ref().unwrap();
 
         let use_column_predicates = self.allow_column_predicates
+            && row_group_data.page_selection.is_none()
             && !row_group_data
                 .row_group_metadata
                 .parquet_columns()
@@ -517,9 +621,7 @@ impl RowGroupDecoder {
                 (DataFrame::new(live_columns).unwrap(), mask)
             }
         } else {
-            let mut live_df = unsafe {
-                DataFrame::new_no_checks(row_group_data.row_group_metadata.num_rows(), live_columns)
-            };
+            let mut live_df = unsafe { DataFrame::new_no_checks(projection_height, live_columns) };
 
             let mask = scan_predicate.predicate.evaluate_io(&live_df)?;
             let mask = mask.bool().unwrap();
@@ -644,31 +746,14 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
-    let prefilter = !arrow_field.dtype.is_nested();
+    // With a page selection, nested columns are filtered while deserializing.
+    let prefilter = !arrow_field.dtype.is_nested() || row_group_data.page_selection.is_some();
 
     let deserialize_filter =
         prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
 
-    let (array, _) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        deserialize_filter,
-    )?;
-
-    let mut series = Series::try_from((arrow_field, array))?;
+    let (mut series, _) =
+        deserialize_series(arrow_field, iter, row_group_data, deserialize_filter)?;
 
     if let Some(col_idxs) = row_group_data
         .row_group_metadata
@@ -0,0 +1,444 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::prelude::row_encode::{_get_rows_encoded, _get_rows_encoded_unordered};
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_error::polars_ensure;
+use polars_ops::frame::{AsOfOptions, AsofStrategy};
+use polars_utils::IdxSize;
+
+use crate::async_primitives::connector::{Receiver, Sender};
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::joins::InMemoryJoiner;
+
+struct AsOfJoinParams {
+    left_key: PlSmallStr,
+    right_key: PlSmallStr,
+    left_by: Option<Vec<PlSmallStr>>,
+    right_by: Option<Vec<PlSmallStr>>,
+    strategy: AsofStrategy,
+    check_sortedness: bool,
+    joiner: InMemoryJoiner,
+}
+
+/// Row-encodes the join key such that the encoded keys compare bytewise in
+/// the same order as the key values.
+fn encode_key(df: &DataFrame, key: &str) -> PolarsResult<BinaryArray<i64>> {
+    let key = df.column(key)?.to_physical_repr();
+    Ok(_get_rows_encoded(&[key], &[false], &[false])?.into_array())
+}
+
+/// Splits the rows of the DataFrame by their row-encoded 'by' group, keeping
+/// the order of the rows within each group. Without 'by' groups all rows are
+/// in the empty group.
+fn group_rows(
+    df: &DataFrame,
+    by: Option<&[PlSmallStr]>,
+) -> PolarsResult<PlIndexMap<Vec<u8>, Vec<IdxSize>>> {
+    let Some(by) = by else {
+        return Ok(PlIndexMap::from_iter([(
+            Vec::new(),
+            (0..df.height() as IdxSize).collect(),
+        )]));
+    };
+
+    // Like the in-memory join, groups are matched on their physical values.
+    let by = by
+        .iter()
+        .map(|name| Ok(df.column(name)?.to_physical_repr()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let groups = _get_rows_encoded_unordered(&by)?.into_array();
+    let mut rows_per_group = PlIndexMap::<&[u8], Vec<IdxSize>>::default();
+    for (i, group) in groups.values_iter().enumerate() {
+        rows_per_group.entry(group).or_default().push(i as IdxSize);
+    }
+    Ok(rows_per_group
+        .into_iter()
+        .map(|(group, rows)| (group.to_vec(), rows))
+        .collect())
+}
+
+/// Checks that the encoded keys of the rows of a group are sorted, and sorted
+/// after the previous key of the group. Returns the largest key of the rows.
+fn ensure_sorted<'a>(
+    keys: &'a BinaryArray<i64>,
+    rows: &[IdxSize],
+    prev_last: Option<&[u8]>,
+    check_sortedness: bool,
+) -> PolarsResult<&'a [u8]> {
+    let mut rows_keys = rows.iter().map(|&i| keys.value(i as usize));
+    if !check_sortedness {
+        return Ok(rows_keys.max().unwrap());
+    }
+    let mut last = rows_keys.next().unwrap();
+    let mut sorted = prev_last.is_none_or(|prev| prev <= last);
+    for key in rows_keys {
+        sorted &= last <= key;
+        last = key;
+    }
+    polars_ensure!(
+        sorted,
+        InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first; \
+        with 'by' groups the streaming engine requires each group to be sorted by the join key"
+    );
+    Ok(last)
+}
+
+/// A left morsel which has not been joined yet.
+struct LeftMorsel {
+    df: DataFrame,
+    /// The largest encoded key per encoded 'by' group of the morsel.
+    bounds: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+/// The left morsels which have not been joined yet.
+#[derive(Default)]
+struct LeftBuffer {
+    unjoined: VecDeque<LeftMorsel>,
+    /// The largest encoded key received per encoded 'by' group.
+    last_key_per_group: PlHashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl LeftBuffer {
+    fn push(&mut self, df: DataFrame, params: &AsOfJoinParams) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let keys = encode_key(&df, &params.left_key)?;
+        let mut bounds = Vec::new();
+        for (group, rows) in group_rows(&df, params.left_by.as_deref())? {
+            let prev_last = self.last_key_per_group.get(&group).map(Vec::as_slice);
+            let last = ensure_sorted(&keys, &rows, prev_last, params.check_sortedness)?.to_vec();
+            self.last_key_per_group.insert(group.clone(), last.clone());
+            bounds.push((group, last));
+        }
+        self.unjoined.push_back(LeftMorsel { df, bounds });
+        Ok(())
+    }
+}
+
+/// The rows of a single 'by' group of the right side which may still match
+/// with future left rows.
+struct GroupWindow {
+    df: DataFrame,
+    /// Morsels received since the window was last flushed, appended to `df`
+    /// in one go so it stays a single chunk.
+    pending: Vec<DataFrame>,
+    /// The largest encoded key received.
+    last_key: Vec<u8>,
+}
+
+impl GroupWindow {
+    fn flush(&mut self) -> PolarsResult<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        for df in self.pending.drain(..) {
+            self.df.vstack_mut_owned(df)?;
+        }
+        self.df.rechunk_mut();
+        Ok(())
+    }
+
+    /// Removes the rows which can no longer be matched by left rows with a
+    /// key larger than or equal to `bound`.
+    fn prune(&mut self, bound: &[u8], params: &AsOfJoinParams) -> PolarsResult<()> {
+        if self.df.height() == 0 {
+            return Ok(());
+        }
+        let right_keys = encode_key(&self.df, &params.right_key)?;
+        let (mut cut, mut hi) = (0, right_keys.len());
+        while cut < hi {
+            let mid = cut + (hi - cut) / 2;
+            if right_keys.value(mid) < bound {
+                cut = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        // Of the rows before the bound only the last one can be a backward or
+        // nearest match, and none can be a forward match.
+        let mut keep = if params.strategy == AsofStrategy::Forward {
+            Vec::new()
+        } else {
+            Vec::from_iter(cut.checked_sub(1).map(|i| i as IdxSize))
+        };
+        if keep.len() == cut {
+            return Ok(());
+        }
+        keep.extend(cut as IdxSize..self.df.height() as IdxSize);
+        self.df = unsafe { self.df.take_slice_unchecked(&keep) };
+        Ok(())
+    }
+}
+
+/// The rows of the right side which may still match with future left rows,
+/// with a window per encoded 'by' group.
+struct RightWindow {
+    schema: Arc<Schema>,
+    groups: PlHashMap<Vec<u8>, GroupWindow>,
+    done: bool,
+}
+
+impl RightWindow {
+    fn push(&mut self, df: DataFrame, params: &AsOfJoinParams) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        let keys = encode_key(&df, &params.right_key)?;
+        let rows_per_group = group_rows(&df, params.right_by.as_deref())?;
+        let single_group = rows_per_group.len() == 1;
+        for (group, rows) in rows_per_group {
+            let window = self.groups.get(&group);
+            let prev_last = window.map(|w| w.last_key.as_slice());
+            let last = ensure_sorted(&keys, &rows, prev_last, params.check_sortedness)?.to_vec();
+            let group_df = if single_group {
+                df.clone()
+            } else {
+                unsafe { df.take_slice_unchecked(&rows) }
+            };
+
+            match self.groups.get_mut(&group) {
+                Some(window) => {
+                    window.last_key = last;
+                    window.pending.push(group_df);
+                },
+                None => {
+                    let window = GroupWindow {
+                        df: DataFrame::empty_with_schema(&self.schema),
+                        pending: vec![group_df],
+                        last_key: last,
+                    };
+                    self.groups.insert(group, window);
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the window contains all right rows the given left rows can
+    /// match with.
+    fn covers(&self, left: &LeftMorsel) -> bool {
+        if self.done {
+            return true;
+        }
+        // All right rows of a group with a key equal to the last left key of
+        // the group must be seen.
+        left.bounds.iter().all(|(group, bound)| {
+            self.groups
+                .get(group)
+                .is_some_and(|w| bound.as_slice() < w.last_key.as_slice())
+        })
+    }
+
+    /// The rows of the groups of the left morsel the morsel can match with.
+    fn window_for(&mut self, left: &LeftMorsel) -> PolarsResult<DataFrame> {
+        let mut out: Option<DataFrame> = None;
+        for (group, _) in &left.bounds {
+            let Some(window) = self.groups.get_mut(group) else {
+                continue;
+            };
+            window.flush()?;
+            match &mut out {
+                Some(out) => out.vstack_mut(&window.df)?,
+                None => out = Some(window.df.clone()),
+            };
+        }
+        let mut out = out.unwrap_or_else(|| DataFrame::empty_with_schema(&self.schema));
+        out.rechunk_mut();
+        Ok(out)
+    }
+
+    fn prune(&mut self, left: &LeftMorsel, params: &AsOfJoinParams) -> PolarsResult<()> {
+        for (group, bound) in &left.bounds {
+            if let Some(window) = self.groups.get_mut(group) {
+                window.prune(bound, params)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A streaming as-of join of two inputs sorted by the join key, or with 'by'
+/// groups, sorted by the join key within each group.
+///
+/// Left morsels are joined one at a time against windows of the right side
+/// per group, which only retain the rows that can still match. A left morsel
+/// waits until the right side has passed its keys in each of its groups, so
+/// with 'by' groups that are far apart in the two inputs many left morsels
+/// may be buffered. The windows of groups which never appear on the left side
+/// are not pruned.
+pub struct AsOfJoinNode {
+    params: AsOfJoinParams,
+    seq: MorselSeq,
+    left: LeftBuffer,
+    right: RightWindow,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key: PlSmallStr,
+        right_key: PlSmallStr,
+        options: &AsOfOptions,
+        joiner: InMemoryJoiner,
+    ) -> Self {
+        Self {
+            params: AsOfJoinParams {
+                left_key,
+                right_key,
+                left_by: options.left_by.clone(),
+                right_by: options.right_by.clone(),
+                strategy: options.strategy,
+                check_sortedness: options.check_sortedness,
+                joiner,
+            },
+            seq: MorselSeq::default(),
+            left: LeftBuffer::default(),
+            right: RightWindow {
+                schema: right_input_schema,
+                groups: PlHashMap::new(),
+                done: false,
+            },
+        }
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        if recv[1] == PortState::Done {
+            self.right.done = true;
+        }
+
+        // Every left row produces output, so we're done once the left side is.
+        let left_done = recv[0] == PortState::Done && self.left.unjoined.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            return Ok(());
+        }
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.left.unjoined.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+        let left = recv_ports[0].take().map(|p| p.serial());
+        let right = recv_ports[1].take().map(|p| p.serial());
+        let send = send_ports[0].take().unwrap().serial();
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            self.join(left, right, send).await
+        }));
+    }
+}
+
+impl AsOfJoinNode {
+    async fn join(
+        &mut self,
+        mut left: Option<Receiver<Morsel>>,
+        mut right: Option<Receiver<Morsel>>,
+        mut send: Sender<Morsel>,
+    ) -> PolarsResult<()> {
+        let params = &self.params;
+        let source_token = SourceToken::new();
+        'join: loop {
+            let Some(left_morsel) = self.left.unjoined.pop_front() else {
+                let morsel = match &mut left {
+                    Some(left_recv) => left_recv.recv().await.ok(),
+                    None => None,
+                };
+                let Some(morsel) = morsel else {
+                    break;
+                };
+                self.left.push(morsel.into_df(), params)?;
+                continue;
+            };
+
+            while !self.right.covers(&left_morsel) {
+                let morsel = match &mut right {
+                    Some(right_recv) => right_recv.recv().await.ok(),
+                    None => None,
+                };
+                let Some(morsel) = morsel else {
+                    // We can't make progress until the next phase.
+                    self.left.unjoined.push_front(left_morsel);
+                    break 'join;
+                };
+                self.right.push(morsel.into_df(), params)?;
+            }
+
+            let right_df = self.right.window_for(&left_morsel)?;
+            self.right.prune(&left_morsel, params)?;
+            let out = (params.joiner)(left_morsel.df, right_df)?;
+            let morsel = Morsel::new(out, self.seq, source_token.clone());
+            self.seq = self.seq.successor();
+            if send.send(morsel).await.is_err() {
+                return Ok(());
+            }
+            if source_token.stop_requested() {
+                break;
+            }
+        }
+
+        // The phase ends while the inputs may still be sending, stop them and
+        // buffer the morsels in flight for the next phase.
+        if let Some(left_recv) = &mut left {
+            while let Ok(morsel) = left_recv.recv().await {
+                morsel.source_token().stop();
+                self.left.push(morsel.into_df(), params)?;
+            }
+        }
+        if let Some(right_recv) = &mut right {
+            while let Ok(morsel) = right_recv.recv().await {
+                morsel.source_token().stop();
+                self.right.push(morsel.into_df(), params)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
@@ -31,6 +32,7 @@ use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{PartitionedSpiller, SpilledPartition, can_spill};
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -47,6 +49,9 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    // The amount of memory the build side may use before partitions of it get
+    // spilled to disk, if spilling is possible for this join.
+    spill_budget: Option<usize>,
 }
 
 impl EquiJoinParams {
@@ -171,23 +176,60 @@ fn select_schema(schema: &Schema, selector: &[Option<PlSmallStr>]) -> Schema {
         .collect()
 }
 
-async fn select_keys(
+async fn select_key_df(
     df: &DataFrame,
     key_selectors: &[StreamExpr],
-    params: &EquiJoinParams,
     state: &ExecutionState,
-) -> PolarsResult<HashKeys> {
+) -> PolarsResult<DataFrame> {
     let mut key_columns = Vec::new();
     for selector in key_selectors {
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
-    let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
-        &keys,
-        params.random_state,
-        params.args.nulls_equal,
-        false,
-    ))
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+fn hash_keys_from_df(keys: &DataFrame, params: &EquiJoinParams) -> HashKeys {
+    HashKeys::from_df(keys, params.random_state, params.args.nulls_equal, false)
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<HashKeys> {
+    let keys = select_key_df(df, key_selectors, state).await?;
+    Ok(hash_keys_from_df(&keys, params))
+}
+
+fn spill_key_name(k: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_JOIN_SPILL_KEY{k}")
+}
+
+/// Combines keys and payload into a single DataFrame for spilling, with the
+/// (renamed) key columns first.
+fn to_spill_df(keys: &DataFrame, payload: &DataFrame) -> DataFrame {
+    let mut spill_df: DataFrame = keys
+        .get_columns()
+        .iter()
+        .enumerate()
+        .map(|(k, c)| c.clone().with_name(spill_key_name(k)))
+        .collect();
+    unsafe { spill_df.hstack_mut_unchecked(payload.get_columns()) };
+    spill_df
+}
+
+/// Splits a spilled DataFrame back into its keys and payload.
+fn from_spill_df(spill_df: DataFrame, params: &EquiJoinParams) -> (DataFrame, DataFrame) {
+    let height = spill_df.height();
+    let mut payload = spill_df.take_columns();
+    let keys = payload.drain(..params.left_key_selectors.len()).collect();
+    unsafe {
+        (
+            DataFrame::new_no_checks(height, keys),
+            DataFrame::new_no_checks(height, payload),
+        )
+    }
 }
 
 fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
@@ -421,6 +463,52 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // If we may spill, the key columns of morsels[i] are kept in key_dfs[i],
+    // as the hash keys can't be turned back into columns.
+    key_dfs: Vec<DataFrame>,
+    bytes: usize,
+    spiller: Option<PartitionedSpiller>,
+}
+
+impl LocalBuilder {
+    /// Writes all morsels to disk, partitioned.
+    fn spill_morsels(&mut self) -> PolarsResult<()> {
+        let num_partitions = self.sketch_per_p.len();
+        for (i, ((seq, payload, _keys), key_df)) in self
+            .morsels
+            .drain(..)
+            .zip(self.key_dfs.drain(..))
+            .enumerate()
+        {
+            let spill_df = to_spill_df(&key_df, &payload);
+            let spiller = match &mut self.spiller {
+                Some(spiller) => spiller,
+                slot @ None => {
+                    slot.insert(PartitionedSpiller::new(spill_df.schema(), num_partitions)?)
+                },
+            };
+
+            for p in 0..num_partitions {
+                let start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let stop = self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs = &self.morsel_idxs_values_per_p[p][start..stop];
+                if p_morsel_idxs.is_empty() {
+                    continue;
+                }
+                let p_df = unsafe { spill_df.take_slice_unchecked_impl(p_morsel_idxs, false) };
+                spiller.spill(p, &p_df, seq.to_u64())?;
+            }
+        }
+
+        for vp in &mut self.morsel_idxs_values_per_p {
+            vp.clear();
+        }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.bytes = 0;
+        Ok(())
+    }
 }
 
 struct BuildState {
@@ -440,6 +528,9 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                key_dfs: Vec::new(),
+                bytes: 0,
+                spiller: None,
             })
             .collect();
         Self {
@@ -465,16 +556,16 @@ impl BuildState {
             key_selectors = &params.right_key_selectors;
         };
 
+        let spill_budget = params
+            .spill_budget
+            .map(|budget| budget / state.num_pipelines);
+
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
-                key_selectors,
-                params,
-                &state.in_memory_exec_state,
-            )
-            .await?;
+            let key_df =
+                select_key_df(morsel.df(), key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = hash_keys_from_df(&key_df, params);
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
 
@@ -488,7 +579,16 @@ impl BuildState {
             local
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-            local.morsels.push((morsel.seq(), payload, hash_keys));
+            if let Some(budget) = spill_budget {
+                local.bytes += payload.estimated_size() + key_df.estimated_size();
+                local.key_dfs.push(key_df);
+                local.morsels.push((morsel.seq(), payload, hash_keys));
+                if local.bytes > budget {
+                    local.spill_morsels()?;
+                }
+            } else {
+                local.morsels.push((morsel.seq(), payload, hash_keys));
+            }
         }
         Ok(())
     }
@@ -588,10 +688,48 @@ impl BuildState {
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            spill_state: None,
+        }
+    }
+
+    /// Decides which partitions are kept in memory for probing, the others
+    /// are joined one at a time from disk after the probe side is done.
+    fn choose_resident_partitions(
+        &self,
+        spilled_per_local: &[Vec<SpilledPartition>],
+        budget: usize,
+    ) -> Vec<bool> {
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let mut bytes_per_p = vec![0; num_partitions];
+        for l in &self.local_builders {
+            let offsets_len = l.morsel_idxs_offsets_per_p.len();
+            let in_memory_rows_per_p = &l.morsel_idxs_offsets_per_p[offsets_len - num_partitions..];
+            let in_memory_rows: usize = in_memory_rows_per_p.iter().sum();
+            for (p, rows) in in_memory_rows_per_p.iter().enumerate() {
+                bytes_per_p[p] += l.bytes * rows / in_memory_rows.max(1);
+            }
+        }
+        for l_spilled in spilled_per_local {
+            for (p, spilled) in l_spilled.iter().enumerate() {
+                bytes_per_p[p] += spilled.spilled_bytes();
+            }
         }
+
+        let mut total_bytes = 0;
+        bytes_per_p
+            .into_iter()
+            .map(|bytes| {
+                total_bytes += bytes;
+                total_bytes <= budget
+            })
+            .collect()
     }
 
-    fn finalize_unordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
+    fn finalize_unordered(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeState> {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
             &params.left_payload_schema
@@ -599,6 +737,27 @@ impl BuildState {
             &params.right_payload_schema
         };
 
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let spilled_per_local = self
+            .local_builders
+            .iter_mut()
+            .filter_map(|b| b.spiller.take())
+            .map(|spiller| spiller.finish())
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let is_resident = match params.spill_budget {
+            Some(budget) if !spilled_per_local.is_empty() => {
+                self.choose_resident_partitions(&spilled_per_local, budget)
+            },
+            _ => vec![true; num_partitions],
+        };
+        if config::verbose() && !spilled_per_local.is_empty() {
+            eprintln!(
+                "[equi-join]: build side spilled, {} of {} partitions are kept in memory",
+                is_resident.iter().filter(|r| **r).count(),
+                num_partitions
+            );
+        }
+
         // To reduce maximum memory usage we want to drop the morsels
         // as soon as they're processed, so we move into Arcs. The drops might
         // also be expensive, so instead of directly dropping we put that on
@@ -610,9 +769,12 @@ impl BuildState {
             .collect_vec();
         let (morsel_drop_q_send, morsel_drop_q_recv) =
             async_channel::bounded(morsels_per_local_builder.len());
-        let num_partitions = self.local_builders[0].sketch_per_p.len();
         let local_builders = &self.local_builders;
         let probe_tables: SparseInitVec<ProbeTable> = SparseInitVec::with_capacity(num_partitions);
+        let spilled_build_per_p: SparseInitVec<Option<Vec<SpilledPartition>>> =
+            SparseInitVec::with_capacity(num_partitions);
+        let spilled_per_local = &spilled_per_local;
+        let is_resident = &is_resident;
 
         async_executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
@@ -624,6 +786,7 @@ impl BuildState {
                 let morsel_drop_q_send = morsel_drop_q_send.clone();
                 let morsel_drop_q_recv = morsel_drop_q_recv.clone();
                 let probe_tables = &probe_tables;
+                let spilled_build_per_p = &spilled_build_per_p;
                 join_handles.push(s.spawn_task(TaskPriority::High, async move {
                     // Extract from outer arc and drop outer arc.
                     let morsels_per_local_builder =
@@ -640,11 +803,15 @@ impl BuildState {
                             l.morsel_idxs_offsets_per_p[offsets_len - num_partitions + p];
                     }
 
-                    // Allocate hash table and payload builder.
+                    // Allocate hash table and payload builder, unless this
+                    // partition doesn't stay in memory.
                     let mut p_table = table.new_empty();
-                    p_table.reserve(sketch.estimate() * 5 / 4);
                     let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
-                    p_payload.reserve(payload_rows);
+                    let mut p_spiller = None;
+                    if is_resident[p] {
+                        p_table.reserve(sketch.estimate() * 5 / 4);
+                        p_payload.reserve(payload_rows);
+                    }
 
                     // Build.
                     let mut skip_drop_attempt = false;
@@ -655,7 +822,7 @@ impl BuildState {
                         }
 
                         for (i, morsel) in l_morsels.iter().enumerate() {
-                            let (_mseq, payload, keys) = morsel;
+                            let (mseq, payload, keys) = morsel;
                             unsafe {
                                 let p_morsel_idxs_start =
                                     l.morsel_idxs_offsets_per_p[i * num_partitions + p];
@@ -663,12 +830,28 @@ impl BuildState {
                                     l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
                                 let p_morsel_idxs = &l.morsel_idxs_values_per_p[p]
                                     [p_morsel_idxs_start..p_morsel_idxs_stop];
-                                p_table.insert_keys_subset(keys, p_morsel_idxs, track_unmatchable);
-                                p_payload.gather_extend(
-                                    payload,
-                                    p_morsel_idxs,
-                                    ShareStrategy::Never,
-                                );
+                                if is_resident[p] {
+                                    p_table.insert_keys_subset(
+                                        keys,
+                                        p_morsel_idxs,
+                                        track_unmatchable,
+                                    );
+                                    p_payload.gather_extend(
+                                        payload,
+                                        p_morsel_idxs,
+                                        ShareStrategy::Never,
+                                    );
+                                } else if !p_morsel_idxs.is_empty() {
+                                    let spill_df = to_spill_df(&l.key_dfs[i], payload);
+                                    let spiller = match &mut p_spiller {
+                                        Some(spiller) => spiller,
+                                        slot @ None => slot
+                                            .insert(PartitionedSpiller::new(spill_df.schema(), 1)?),
+                                    };
+                                    let p_df =
+                                        spill_df.take_slice_unchecked_impl(p_morsel_idxs, false);
+                                    spiller.spill(0, &p_df, mseq.to_u64())?;
+                                }
                             }
                         }
 
@@ -683,6 +866,33 @@ impl BuildState {
                         }
                     }
 
+                    // Merge back the spilled morsels, or collect the spill
+                    // files if this partition is processed later.
+                    let spilled_build = if is_resident[p] {
+                        for l_spilled in spilled_per_local {
+                            for spilled_morsel in l_spilled[p].read()? {
+                                let (_seq, spill_df) = spilled_morsel?;
+                                let (key_df, payload) = from_spill_df(spill_df, params);
+                                let keys = hash_keys_from_df(&key_df, params);
+                                let idxs = (0..keys.len() as IdxSize).collect_vec();
+                                unsafe {
+                                    p_table.insert_keys_subset(&keys, &idxs, track_unmatchable)
+                                };
+                                p_payload.extend(&payload, ShareStrategy::Never);
+                            }
+                        }
+                        None
+                    } else {
+                        let mut spilled_build = Vec::new();
+                        if let Some(spiller) = p_spiller {
+                            spilled_build.extend(spiller.finish()?);
+                        }
+                        // The spill files of the other partitions are shared
+                        // through the spill directory, we only borrow ours.
+                        spilled_build.extend(spilled_per_local.iter().map(|l| l[p].clone()));
+                        Some(spilled_build)
+                    };
+
                     // We're done, help others out by doing drops.
                     drop(morsel_drop_q_send); // So we don't deadlock trying to receive from ourselves.
                     while let Ok(l_morsels) = morsel_drop_q_recv.recv().await {
@@ -700,6 +910,8 @@ impl BuildState {
                         )
                         .ok()
                         .unwrap();
+                    spilled_build_per_p.try_set(p, spilled_build).ok().unwrap();
+                    PolarsResult::Ok(())
                 }));
             }
 
@@ -712,17 +924,28 @@ impl BuildState {
 
             polars_io::pl_async::get_runtime().block_on(async move {
                 for handle in join_handles {
-                    handle.await;
+                    handle.await?;
                 }
+                PolarsResult::Ok(())
+            })
+        })?;
+
+        let spilled_build_per_p = spilled_build_per_p.try_assume_init().ok().unwrap();
+        let spill_state = spilled_build_per_p
+            .iter()
+            .any(|s| s.is_some())
+            .then(|| SpillState {
+                build_per_p: spilled_build_per_p,
+                probe: Mutex::default(),
             });
-        });
 
-        ProbeState {
+        Ok(ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
-        }
+            spill_state,
+        })
     }
 }
 
@@ -732,6 +955,21 @@ struct ProbeTable {
     seq_ids: Vec<IdxSize>,
 }
 
+/// The partitions of an unordered join which didn't fit in memory. These are
+/// joined one at a time once the probe side is done.
+struct SpillState {
+    // Per partition the spilled build side, or None if it's kept in memory.
+    build_per_p: Vec<Option<Vec<SpilledPartition>>>,
+    // The spilled probe side, with an entry per partition for each probe task.
+    probe: Mutex<Vec<Vec<SpilledPartition>>>,
+}
+
+impl SpillState {
+    fn is_spilled(&self, p: usize) -> bool {
+        self.build_per_p[p].is_some()
+    }
+}
+
 struct ProbeState {
     table_per_partition: Vec<ProbeTable>,
     max_seq_sent: MorselSeq,
@@ -739,15 +977,19 @@ struct ProbeState {
 
     // For unordered joins we relabel output morsels to speed up the linearizer.
     unordered_morsel_seq: AtomicU64,
+
+    spill_state: Option<SpillState>,
 }
 
 impl ProbeState {
     /// Returns the max morsel sequence sent.
+    #[allow(clippy::too_many_arguments)]
     async fn partition_and_probe(
         mut recv: Receiver<Morsel>,
         mut send: Sender<Morsel>,
         partitions: &[ProbeTable],
         unordered_morsel_seq: &AtomicU64,
+        spill_state: Option<&SpillState>,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
@@ -779,6 +1021,7 @@ impl ProbeState {
 
         let mut build_out = DataFrameBuilder::new(build_payload_schema.clone());
         let mut probe_out = DataFrameBuilder::new(probe_payload_schema.clone());
+        let mut spiller: Option<PartitionedSpiller> = None;
 
         // A simple estimate used to size reserves.
         let mut selectivity_estimate = 1.0;
@@ -793,8 +1036,8 @@ impl ProbeState {
                 continue;
             }
 
-            let hash_keys =
-                select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+            let key_df = select_key_df(&df, key_selectors, &state.in_memory_exec_state).await?;
+            let hash_keys = hash_keys_from_df(&key_df, params);
             let mut payload = select_payload(df, payload_selector);
             let mut payload_rechunked = false; // We don't eagerly rechunk because there might be no matches.
             let mut total_matches = 0;
@@ -916,7 +1159,33 @@ impl ProbeState {
                         emit_unmatched,
                     );
 
-                    for (p, idxs_in_p) in partitions.iter().zip(&partition_idxs) {
+                    let mut spill_df = None;
+                    for (p_idx, (p, idxs_in_p)) in
+                        partitions.iter().zip(&partition_idxs).enumerate()
+                    {
+                        if spill_state.is_some_and(|ss| ss.is_spilled(p_idx)) {
+                            // Defer probing this partition until its build side
+                            // is loaded back from disk.
+                            if !idxs_in_p.is_empty() {
+                                if !payload_rechunked {
+                                    payload.rechunk_mut();
+                                    payload_rechunked = true;
+                                }
+                                let spill_df =
+                                    spill_df.get_or_insert_with(|| to_spill_df(&key_df, &payload));
+                                let spiller = match &mut spiller {
+                                    Some(spiller) => spiller,
+                                    slot @ None => slot.insert(PartitionedSpiller::new(
+                                        spill_df.schema(),
+                                        partitioner.num_partitions(),
+                                    )?),
+                                };
+                                let p_df = spill_df.take_slice_unchecked_impl(idxs_in_p, false);
+                                spiller.spill(p_idx, &p_df, in_seq.to_u64())?;
+                            }
+                            continue;
+                        }
+
                         let mut offset = 0;
                         while offset < idxs_in_p.len() {
                             let matches_before_limit = probe_limit - probe_match.len() as IdxSize;
@@ -998,6 +1267,11 @@ impl ProbeState {
             selectivity_estimate_confidence = (selectivity_estimate_confidence + 0.1).min(0.8);
         }
 
+        if let (Some(spiller), Some(spill_state)) = (spiller, spill_state) {
+            let spilled = spiller.finish()?;
+            spill_state.probe.lock().push(spilled);
+        }
+
         Ok(max_seq)
     }
 
@@ -1154,10 +1428,267 @@ impl EmitUnmatchedState {
     }
 }
 
+/// Joins the partitions which were spilled to disk one at a time, after the
+/// in-memory partitions were probed.
+struct JoinSpilledState {
+    // The in-memory partitions, kept around to emit their unmatched rows later.
+    partitions: Vec<ProbeTable>,
+    spilled_build_per_p: Vec<Option<Vec<SpilledPartition>>>,
+    spilled_probe: Vec<Vec<SpilledPartition>>,
+    active_partition_idx: usize,
+    // The loaded build side of the active partition.
+    active_table: Option<ProbeTable>,
+    // How many spilled probe DataFrames of the active partition were joined.
+    probe_frames_done: usize,
+    offset_in_unmatched: usize,
+    morsel_seq: MorselSeq,
+}
+
+impl JoinSpilledState {
+    fn new(probe_state: &mut ProbeState) -> Option<Self> {
+        let spill_state = probe_state.spill_state.take()?;
+        Some(Self {
+            partitions: core::mem::take(&mut probe_state.table_per_partition),
+            spilled_build_per_p: spill_state.build_per_p,
+            spilled_probe: spill_state.probe.into_inner(),
+            active_partition_idx: 0,
+            active_table: None,
+            probe_frames_done: 0,
+            offset_in_unmatched: 0,
+            morsel_seq: probe_state.max_seq_sent.successor(),
+        })
+    }
+
+    fn is_done(&self) -> bool {
+        self.active_partition_idx >= self.spilled_build_per_p.len()
+    }
+
+    fn load_build(
+        spilled_build: &[SpilledPartition],
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeTable> {
+        let payload_schema = if params.left_is_build.unwrap() {
+            &params.left_payload_schema
+        } else {
+            &params.right_payload_schema
+        };
+        let track_unmatchable = params.emit_unmatched_build();
+
+        let mut p_table = table.new_empty();
+        let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
+        p_payload.reserve(spilled_build.iter().map(|s| s.num_rows()).sum());
+        for spilled in spilled_build {
+            for spilled_morsel in spilled.read()? {
+                let (_seq, spill_df) = spilled_morsel?;
+                let (key_df, payload) = from_spill_df(spill_df, params);
+                let keys = hash_keys_from_df(&key_df, params);
+                let idxs = (0..keys.len() as IdxSize).collect_vec();
+                unsafe { p_table.insert_keys_subset(&keys, &idxs, track_unmatchable) };
+                p_payload.extend(&payload, ShareStrategy::Never);
+            }
+        }
+        Ok(ProbeTable {
+            hash_table: p_table,
+            payload: p_payload.freeze(),
+            seq_ids: Vec::new(),
+        })
+    }
+
+    fn new_morsel(
+        morsel_seq: &mut MorselSeq,
+        build_out: &mut DataFrameBuilder,
+        probe_out: &mut DataFrameBuilder,
+        params: &EquiJoinParams,
+        source_token: &SourceToken,
+    ) -> Morsel {
+        let mut build_df = build_out.freeze_reset();
+        let mut probe_df = probe_out.freeze_reset();
+        let out_df = unsafe {
+            if params.left_is_build.unwrap() {
+                build_df.hstack_mut_unchecked(probe_df.get_columns());
+                build_df
+            } else {
+                probe_df.hstack_mut_unchecked(build_df.get_columns());
+                probe_df
+            }
+        };
+        let morsel = Morsel::new(
+            postprocess_join(out_df, params),
+            *morsel_seq,
+            source_token.clone(),
+        );
+        *morsel_seq = morsel_seq.successor();
+        morsel
+    }
+
+    // TODO: join multiple spilled partitions in parallel.
+    async fn join_spilled(
+        &mut self,
+        mut send: Sender<Morsel>,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<()> {
+        let (build_payload_schema, probe_payload_schema) = if params.left_is_build.unwrap() {
+            (&params.left_payload_schema, &params.right_payload_schema)
+        } else {
+            (&params.right_payload_schema, &params.left_payload_schema)
+        };
+        let mark_matches = params.emit_unmatched_build();
+        let emit_unmatched = params.emit_unmatched_probe();
+        let probe_limit = get_ideal_morsel_size() as IdxSize;
+
+        let wait_group = WaitGroup::default();
+        let source_token = SourceToken::new();
+        let mut build_out = DataFrameBuilder::new(build_payload_schema.clone());
+        let mut probe_out = DataFrameBuilder::new(probe_payload_schema.clone());
+        let mut table_match = Vec::new();
+        let mut probe_match = Vec::new();
+        let mut unmarked_idxs = Vec::new();
+
+        while let Some(spilled_build) = self.spilled_build_per_p.get(self.active_partition_idx) {
+            let p_idx = self.active_partition_idx;
+            let Some(spilled_build) = spilled_build else {
+                self.active_partition_idx += 1;
+                continue;
+            };
+
+            let p = match self.active_table.take() {
+                Some(p) => p,
+                None => Self::load_build(spilled_build, params, table)?,
+            };
+
+            // Probe with the spilled probe side of this partition.
+            let mut frame_idx = 0;
+            for spilled_probe in &self.spilled_probe {
+                for spilled_morsel in spilled_probe[p_idx].read()? {
+                    if frame_idx < self.probe_frames_done {
+                        frame_idx += 1;
+                        continue;
+                    }
+
+                    let (_seq, spill_df) = spilled_morsel?;
+                    let (key_df, payload) = from_spill_df(spill_df, params);
+                    let hash_keys = hash_keys_from_df(&key_df, params);
+                    let idxs = (0..hash_keys.len() as IdxSize).collect_vec();
+                    let mut offset = 0;
+                    while offset < idxs.len() {
+                        let matches_before_limit = probe_limit - probe_match.len() as IdxSize;
+                        table_match.clear();
+                        offset += unsafe {
+                            p.hash_table.probe_subset(
+                                &hash_keys,
+                                &idxs[offset..],
+                                &mut table_match,
+                                &mut probe_match,
+                                mark_matches,
+                                emit_unmatched,
+                                matches_before_limit,
+                            )
+                        } as usize;
+
+                        unsafe {
+                            if emit_unmatched {
+                                build_out.opt_gather_extend(
+                                    &p.payload,
+                                    &table_match,
+                                    ShareStrategy::Always,
+                                );
+                            } else {
+                                build_out.gather_extend(
+                                    &p.payload,
+                                    &table_match,
+                                    ShareStrategy::Always,
+                                );
+                            }
+                        }
+
+                        if probe_match.len() >= probe_limit as usize || offset == idxs.len() {
+                            if probe_match.is_empty() {
+                                continue;
+                            }
+                            unsafe {
+                                probe_out.gather_extend(
+                                    &payload,
+                                    &probe_match,
+                                    ShareStrategy::Always,
+                                )
+                            };
+                            probe_match.clear();
+                            let out_morsel = Self::new_morsel(
+                                &mut self.morsel_seq,
+                                &mut build_out,
+                                &mut probe_out,
+                                params,
+                                &source_token,
+                            );
+                            if send.send(out_morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    frame_idx += 1;
+                    self.probe_frames_done = frame_idx;
+                    if source_token.stop_requested() {
+                        self.active_table = Some(p);
+                        return Ok(());
+                    }
+                }
+            }
+
+            // Emit the unmatched rows of the build side of this partition.
+            if params.emit_unmatched_build() {
+                loop {
+                    self.offset_in_unmatched += p.hash_table.unmarked_keys(
+                        &mut unmarked_idxs,
+                        self.offset_in_unmatched as IdxSize,
+                        probe_limit,
+                    ) as usize;
+                    if unmarked_idxs.is_empty() {
+                        break;
+                    }
+
+                    unsafe {
+                        build_out.gather_extend(&p.payload, &unmarked_idxs, ShareStrategy::Never);
+                        let len = build_out.len();
+                        let null_probe = DataFrame::full_null(probe_payload_schema, len);
+                        probe_out.extend(&null_probe, ShareStrategy::Always);
+                    }
+                    let mut morsel = Self::new_morsel(
+                        &mut self.morsel_seq,
+                        &mut build_out,
+                        &mut probe_out,
+                        params,
+                        &source_token,
+                    );
+                    morsel.set_consume_token(wait_group.token());
+                    if send.send(morsel).await.is_err() {
+                        return Ok(());
+                    }
+
+                    wait_group.wait().await;
+                    if source_token.stop_requested() {
+                        self.active_table = Some(p);
+                        return Ok(());
+                    }
+                }
+            }
+
+            self.active_partition_idx += 1;
+            self.probe_frames_done = 0;
+            self.offset_in_unmatched = 0;
+        }
+
+        Ok(())
+    }
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
     Probe(ProbeState),
+    JoinSpilled(JoinSpilledState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
     Done,
@@ -1180,6 +1711,7 @@ impl EquiJoinNode {
         left_key_selectors: Vec<StreamExpr>,
         right_key_selectors: Vec<StreamExpr>,
         args: JoinArgs,
+        spill_memory_budget: Option<usize>,
         num_pipelines: usize,
     ) -> PolarsResult<Self> {
         let left_is_build = match args.maintain_order {
@@ -1227,6 +1759,24 @@ impl EquiJoinNode {
             EquiJoinState::Sample(SampleState::default())
         };
 
+        // Spilling partitions of the build side to disk requires us to join
+        // them out of order, so a join with maintain_order keeps its entire
+        // build side in memory regardless of the memory budget.
+        let spill_budget = spill_memory_budget.filter(|_| {
+            !preserve_order_build
+                && !preserve_order_probe
+                && can_spill(&left_input_schema)
+                && can_spill(&right_input_schema)
+        });
+        if config::verbose()
+            && spill_memory_budget.is_some()
+            && (preserve_order_build || preserve_order_probe)
+        {
+            eprintln!(
+                "[equi-join]: maintain_order is set, the build side will not be spilled to disk"
+            );
+        }
+
         let left_payload_schema = Arc::new(select_schema(&left_input_schema, &left_payload_select));
         let right_payload_schema =
             Arc::new(select_schema(&right_input_schema, &right_payload_select));
@@ -1246,6 +1796,7 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                spill_budget,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1292,7 +1843,7 @@ impl ComputeNode for EquiJoinNode {
                 let probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
-                    build_state.finalize_unordered(&self.params, &*self.table)
+                    build_state.finalize_unordered(&self.params, &*self.table)?
                 };
                 self.state = EquiJoinState::Probe(probe_state);
             }
@@ -1303,7 +1854,9 @@ impl ComputeNode for EquiJoinNode {
         if let EquiJoinState::Probe(probe_state) = &mut self.state {
             let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
             if samples_consumed && recv[probe_idx] == PortState::Done {
-                if self.params.emit_unmatched_build() {
+                if let Some(join_spilled_state) = JoinSpilledState::new(probe_state) {
+                    self.state = EquiJoinState::JoinSpilled(join_spilled_state);
+                } else if self.params.emit_unmatched_build() {
                     if self.params.preserve_order_build {
                         let unmatched = probe_state.ordered_unmatched(&self.params);
                         let src = InMemorySourceNode::new(
@@ -1325,6 +1878,23 @@ impl ComputeNode for EquiJoinNode {
             }
         }
 
+        // If we are done joining the spilled partitions, emit the unmatched
+        // keys of the in-memory partitions if necessary.
+        if let EquiJoinState::JoinSpilled(join_spilled_state) = &mut self.state {
+            if join_spilled_state.is_done() {
+                if self.params.emit_unmatched_build() {
+                    self.state = EquiJoinState::EmitUnmatchedBuild(EmitUnmatchedState {
+                        partitions: core::mem::take(&mut join_spilled_state.partitions),
+                        active_partition_idx: 0,
+                        offset_in_active_p: 0,
+                        morsel_seq: join_spilled_state.morsel_seq,
+                    });
+                } else {
+                    self.state = EquiJoinState::Done;
+                }
+            }
+        }
+
         // Finally, check if we are done emitting unmatched keys.
         if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
             if emit_state.active_partition_idx >= emit_state.partitions.len() {
@@ -1372,7 +1942,7 @@ impl ComputeNode for EquiJoinNode {
                 }
                 recv[build_idx] = PortState::Done;
             },
-            EquiJoinState::EmitUnmatchedBuild(_) => {
+            EquiJoinState::JoinSpilled(_) | EquiJoinState::EmitUnmatchedBuild(_) => {
                 send[0] = PortState::Ready;
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
@@ -1502,6 +2072,7 @@ impl ComputeNode for EquiJoinNode {
                                 send,
                                 &probe_state.table_per_partition,
                                 &probe_state.unordered_morsel_seq,
+                                probe_state.spill_state.as_ref(),
                                 partitioner.clone(),
                                 &self.params,
                                 state,
@@ -1518,6 +2089,15 @@ impl ComputeNode for EquiJoinNode {
                     Ok(())
                 }));
             },
+            EquiJoinState::JoinSpilled(join_spilled_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(
+                    TaskPriority::Low,
+                    join_spilled_state.join_spilled(send, &self.params, &*self.table),
+                ));
+            },
             EquiJoinState::EmitUnmatchedBuild(emit_state) => {
                 assert!(recv_ports[build_idx].is_none());
                 assert!(recv_ports[probe_idx].is_none());
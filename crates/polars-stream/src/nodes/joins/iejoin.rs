@@ -0,0 +1,405 @@
+use std::sync::Arc;
+
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_core::scalar::Scalar;
+use polars_ops::frame::{IEJoinOptions, InequalityOperator};
+use polars_utils::itertools::Itertools;
+
+use crate::async_primitives::connector::Sender;
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::joins::InMemoryJoiner;
+use crate::utils::spill::{PartitionedSpiller, SpilledPartition};
+
+/// The range of the non-null values of a join key.
+struct KeyRange {
+    min: Scalar,
+    max: Scalar,
+}
+
+impl KeyRange {
+    /// Computes the ranges of the given keys, returns None if any key only
+    /// contains nulls, in which case no row can match.
+    fn of_keys(keys: &[Column]) -> PolarsResult<Option<Vec<Self>>> {
+        let mut ranges = Vec::with_capacity(keys.len());
+        for key in keys {
+            let min = key.min_reduce()?;
+            if min.is_null() {
+                return Ok(None);
+            }
+            let max = key.max_reduce()?;
+            ranges.push(Self { min, max });
+        }
+        Ok(Some(ranges))
+    }
+}
+
+/// Whether `l op r` may hold for some values l and r in the given ranges.
+fn ranges_may_match(left: &[KeyRange], right: &[KeyRange], ops: &[InequalityOperator]) -> bool {
+    left.iter().zip(right).zip(ops).all(|((l, r), op)| {
+        let (l_min, l_max) = (l.min.value(), l.max.value());
+        let (r_min, r_max) = (r.min.value(), r.max.value());
+        match op {
+            InequalityOperator::Lt => l_min < r_max,
+            InequalityOperator::LtEq => l_min <= r_max,
+            InequalityOperator::Gt => l_max > r_min,
+            InequalityOperator::GtEq => l_max >= r_min,
+        }
+    })
+}
+
+async fn evaluate_keys(
+    selectors: &[StreamExpr],
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Column>> {
+    let mut keys = Vec::with_capacity(selectors.len());
+    for selector in selectors {
+        keys.push(selector.evaluate(df, state).await?);
+    }
+    Ok(keys)
+}
+
+enum BlockData {
+    InMemory(DataFrame),
+    /// Stored in the spilled partition, tagged with the block index.
+    Spilled,
+}
+
+/// A contiguous block of rows of the build side.
+struct Block {
+    ranges: Vec<KeyRange>,
+    data: BlockData,
+}
+
+struct BuildState {
+    buffered: DataFrame,
+    blocks: Vec<Block>,
+    in_memory_bytes: usize,
+    spiller: Option<PartitionedSpiller>,
+}
+
+impl BuildState {
+    fn add_block(
+        &mut self,
+        df: DataFrame,
+        keys: &[Column],
+        memory_budget: usize,
+    ) -> PolarsResult<()> {
+        let Some(ranges) = KeyRange::of_keys(keys)? else {
+            return Ok(());
+        };
+        let bytes = df.estimated_size();
+        let data = if self.in_memory_bytes + bytes > memory_budget {
+            let spiller = match &mut self.spiller {
+                Some(spiller) => spiller,
+                slot @ None => slot.insert(PartitionedSpiller::new(df.schema(), 1)?),
+            };
+            spiller.spill(0, &df, self.blocks.len() as u64)?;
+            BlockData::Spilled
+        } else {
+            self.in_memory_bytes += bytes;
+            BlockData::InMemory(df)
+        };
+        self.blocks.push(Block { ranges, data });
+        Ok(())
+    }
+}
+
+struct ProbeState {
+    blocks: Vec<Block>,
+    spilled: Option<SpilledPartition>,
+}
+
+impl ProbeState {
+    /// Joins a batch of probe morsels with all blocks whose key ranges
+    /// overlap, returns false if the receiver is closed.
+    ///
+    /// Spilled blocks are read once per batch, rather than once per morsel.
+    async fn join_batch(
+        &self,
+        batch: &[(Morsel, Vec<KeyRange>)],
+        params: &IEJoinParams,
+        send: &mut Sender<Morsel>,
+    ) -> PolarsResult<bool> {
+        let mut spilled_out = vec![Vec::new(); batch.len()];
+        if let Some(spilled) = &self.spilled {
+            let any_match = self.blocks.iter().any(|block| {
+                matches!(block.data, BlockData::Spilled)
+                    && batch
+                        .iter()
+                        .any(|(_, ranges)| params.may_match(ranges, &block.ranges))
+            });
+            if any_match {
+                for spilled_block in spilled.read()? {
+                    let (block_idx, df) = spilled_block?;
+                    let block = &self.blocks[block_idx as usize];
+                    for ((morsel, ranges), out) in batch.iter().zip(&mut spilled_out) {
+                        if params.may_match(ranges, &block.ranges) {
+                            out.push((params.joiner)(morsel.df().clone(), df.clone())?);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Morsels are sent in order of the batch to keep their sequence ids
+        // increasing.
+        for ((morsel, ranges), spilled_out) in batch.iter().zip(spilled_out) {
+            for block in &self.blocks {
+                let BlockData::InMemory(df) = &block.data else {
+                    continue;
+                };
+                if params.may_match(ranges, &block.ranges) {
+                    let out = (params.joiner)(morsel.df().clone(), df.clone())?;
+                    if !send_output(out, morsel, send).await {
+                        return Ok(false);
+                    }
+                }
+            }
+            for out in spilled_out {
+                if !send_output(out, morsel, send).await {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+async fn send_output(out: DataFrame, morsel: &Morsel, send: &mut Sender<Morsel>) -> bool {
+    if out.height() == 0 {
+        return true;
+    }
+    let morsel = Morsel::new(out, morsel.seq(), morsel.source_token().clone());
+    send.send(morsel).await.is_ok()
+}
+
+struct IEJoinParams {
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    operators: Vec<InequalityOperator>,
+    joiner: InMemoryJoiner,
+    memory_budget: usize,
+}
+
+impl IEJoinParams {
+    fn may_match(&self, left: &[KeyRange], right: &[KeyRange]) -> bool {
+        ranges_may_match(left, right, &self.operators)
+    }
+}
+
+enum IEJoinState {
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+/// An inequality join which splits the right (build) side into blocks, and
+/// joins every left morsel with the blocks whose key ranges may match.
+///
+/// The build side is expected to be sorted by its first key so the blocks
+/// have narrow key ranges. Blocks exceeding the memory budget are spilled to
+/// disk, in which case left morsels are joined in batches.
+pub struct IEJoinNode {
+    params: IEJoinParams,
+    state: IEJoinState,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        options: &IEJoinOptions,
+        joiner: InMemoryJoiner,
+        memory_budget: usize,
+    ) -> Self {
+        let operators = std::iter::once(options.operator1)
+            .chain(options.operator2)
+            .collect_vec();
+        assert!(left_key_selectors.len() == operators.len());
+        assert!(right_key_selectors.len() == operators.len());
+        Self {
+            params: IEJoinParams {
+                left_key_selectors,
+                right_key_selectors,
+                operators,
+                joiner,
+                memory_budget,
+            },
+            state: IEJoinState::Build(BuildState {
+                buffered: DataFrame::empty_with_schema(&right_input_schema),
+                blocks: Vec::new(),
+                in_memory_bytes: 0,
+                spiller: None,
+            }),
+        }
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "iejoin"
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        true
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Are we done?
+        if send[0] == PortState::Done || recv[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // Transition to probe?
+        if recv[1] == PortState::Done {
+            if let IEJoinState::Build(build) = &mut self.state {
+                let df = std::mem::take(&mut build.buffered);
+                if df.height() > 0 {
+                    let keys = self
+                        .params
+                        .right_key_selectors
+                        .iter()
+                        .map(|s| s.evaluate_blocking(&df, &state.in_memory_exec_state))
+                        .try_collect_vec()?;
+                    build.add_block(df, &keys, self.params.memory_budget)?;
+                }
+                let spilled = match build.spiller.take() {
+                    Some(spiller) => spiller.finish()?.pop(),
+                    None => None,
+                };
+                let blocks = std::mem::take(&mut build.blocks);
+                if config::verbose() {
+                    eprintln!(
+                        "[IEJoinNode]: build side has {} blocks, {} of which spilled",
+                        blocks.len(),
+                        blocks
+                            .iter()
+                            .filter(|b| matches!(b.data, BlockData::Spilled))
+                            .count()
+                    );
+                }
+                self.state = if blocks.is_empty() {
+                    IEJoinState::Done
+                } else {
+                    IEJoinState::Probe(ProbeState { blocks, spilled })
+                };
+            }
+        }
+
+        match &self.state {
+            IEJoinState::Build(_) => {
+                recv[1] = PortState::Ready;
+                recv[0] = PortState::Blocked;
+                send[0] = PortState::Blocked;
+            },
+            IEJoinState::Probe(_) => {
+                recv[1] = PortState::Done;
+                core::mem::swap(&mut recv[0], &mut send[0]);
+            },
+            IEJoinState::Done => {
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+        let params = &self.params;
+        match &mut self.state {
+            IEJoinState::Build(build) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                // The build side is received in order, so the blocks keep
+                // the sort order of the input.
+                let mut recv = recv_ports[1].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let block_size = get_ideal_morsel_size();
+                    while let Ok(morsel) = recv.recv().await {
+                        build.buffered.vstack_mut_owned(morsel.into_df())?;
+                        while build.buffered.height() >= block_size {
+                            let df = build.buffered.slice(0, block_size);
+                            build.buffered = build
+                                .buffered
+                                .slice(block_size as i64, build.buffered.height());
+                            let keys = evaluate_keys(
+                                &params.right_key_selectors,
+                                &df,
+                                &state.in_memory_exec_state,
+                            )
+                            .await?;
+                            build.add_block(df, &keys, params.memory_budget)?;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            IEJoinState::Probe(probe) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+
+                // Without spilled blocks there's no reason to batch.
+                let batch_bytes_limit = if probe.spilled.is_some() {
+                    params.memory_budget / receivers.len()
+                } else {
+                    0
+                };
+                for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+                    let probe = &*probe;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut batch = Vec::new();
+                        let mut batch_bytes = 0;
+                        while let Ok(mut morsel) = recv.recv().await {
+                            // Batched morsels are held on to while receiving
+                            // more, so they must not block their source.
+                            drop(morsel.take_consume_token());
+                            let keys = evaluate_keys(
+                                &params.left_key_selectors,
+                                morsel.df(),
+                                &state.in_memory_exec_state,
+                            )
+                            .await?;
+                            let Some(ranges) = KeyRange::of_keys(&keys)? else {
+                                continue;
+                            };
+                            batch_bytes += morsel.df().estimated_size();
+                            batch.push((morsel, ranges));
+                            if batch_bytes >= batch_bytes_limit {
+                                if !probe.join_batch(&batch, params, &mut send).await? {
+                                    return Ok(());
+                                }
+                                batch.clear();
+                                batch_bytes = 0;
+                            }
+                        }
+                        probe.join_batch(&batch, params, &mut send).await?;
+                        Ok(())
+                    }));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub mod rle;
 pub mod rle_id;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
 pub mod streaming_slice;
 pub mod top_k;
 pub mod with_row_index;
@@ -0,0 +1,500 @@
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::config;
+use polars_core::prelude::row_encode::_get_rows_encoded;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_utils::IdxSize;
+use polars_utils::itertools::Itertools;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Sender;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::utils::spill::{PartitionedSpiller, SpilledPartition, SpilledPartitionReader};
+
+/// The maximum number of sorted runs that are merged at once. If there are
+/// more runs they are first merged into larger runs on disk.
+const MAX_MERGE_FAN_IN: usize = 64;
+
+const SEQ_COL_NAME: &str = "__POLARS_SORT_SEQ";
+const ROW_COL_NAME: &str = "__POLARS_SORT_ROW";
+
+struct SortParams {
+    key_cols: Vec<PlSmallStr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    output_schema: Arc<Schema>,
+}
+
+impl SortParams {
+    /// Row-encodes the sort keys of the DataFrame such that comparing the
+    /// encoded rows bytewise gives the sort order.
+    fn encode_keys(&self, df: &DataFrame) -> PolarsResult<BinaryArray<i64>> {
+        let keys = self
+            .key_cols
+            .iter()
+            .map(|name| df.column(name).cloned())
+            .try_collect_vec()?;
+        Ok(_get_rows_encoded(&keys, &self.descending, &self.nulls_last)?.into_array())
+    }
+
+    fn sort(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let keys = self.encode_keys(&df)?;
+        let mut idxs = (0..df.height() as IdxSize).collect_vec();
+        idxs.sort_by(|l, r| keys.value(*l as usize).cmp(keys.value(*r as usize)));
+        Ok(unsafe { df.take_slice_unchecked(&idxs) })
+    }
+}
+
+enum SortedRun {
+    InMemory(DataFrame),
+    Spilled(SpilledPartition),
+}
+
+impl SortedRun {
+    fn open(self, params: &SortParams) -> PolarsResult<Option<RunCursor>> {
+        let chunks = match self {
+            SortedRun::InMemory(df) => RunChunks::InMemory { df, offset: 0 },
+            SortedRun::Spilled(spilled) => RunChunks::Spilled(Box::new(spilled.read()?)),
+        };
+        let mut cursor = RunCursor {
+            chunks,
+            df: DataFrame::empty(),
+            keys: BinaryArray::new_empty(ArrowDataType::LargeBinary),
+            offset: 0,
+        };
+        Ok(cursor.load_next(params)?.then_some(cursor))
+    }
+}
+
+enum RunChunks {
+    InMemory { df: DataFrame, offset: usize },
+    Spilled(Box<SpilledPartitionReader>),
+}
+
+impl RunChunks {
+    fn next(&mut self) -> Option<PolarsResult<DataFrame>> {
+        match self {
+            RunChunks::InMemory { df, offset } => {
+                if *offset >= df.height() {
+                    return None;
+                }
+                let chunk = df.slice(*offset as i64, get_ideal_morsel_size());
+                *offset += chunk.height();
+                Some(Ok(chunk))
+            },
+            RunChunks::Spilled(reader) => reader.next().map(|r| r.map(|(_, df)| df)),
+        }
+    }
+}
+
+/// The position in a sorted run, only a single chunk of the run is loaded
+/// at a time.
+struct RunCursor {
+    chunks: RunChunks,
+    df: DataFrame,
+    keys: BinaryArray<i64>,
+    offset: usize,
+}
+
+impl RunCursor {
+    fn is_exhausted(&self) -> bool {
+        self.offset >= self.df.height()
+    }
+
+    /// Loads the next non-empty chunk, returns false if the run is exhausted.
+    fn load_next(&mut self, params: &SortParams) -> PolarsResult<bool> {
+        while let Some(df) = self.chunks.next() {
+            let df = df?;
+            if df.height() > 0 {
+                self.keys = params.encode_keys(&df)?;
+                self.df = df;
+                self.offset = 0;
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn last_key(&self) -> &[u8] {
+        self.keys.value(self.keys.len() - 1)
+    }
+
+    /// The end of the rows in the loaded chunk with a key <= bound.
+    fn upper_bound(&self, bound: &[u8]) -> usize {
+        let (mut lo, mut hi) = (self.offset, self.df.height());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.keys.value(mid) <= bound {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+/// Merges the next sorted batch of rows from the given runs.
+///
+/// Every step emits all rows with a key smaller than or equal to the smallest
+/// last key of the loaded chunks. All rows that aren't loaded yet are at least
+/// as large as that bound, so this emits the rows in sorted order.
+fn merge_step(
+    cursors: &mut Vec<RunCursor>,
+    params: &SortParams,
+) -> PolarsResult<Option<DataFrame>> {
+    for cursor in cursors.iter_mut() {
+        if cursor.is_exhausted() {
+            cursor.load_next(params)?;
+        }
+    }
+    cursors.retain(|c| !c.is_exhausted());
+    let Some(bound_cursor) = cursors.iter().min_by(|l, r| l.last_key().cmp(r.last_key())) else {
+        return Ok(None);
+    };
+    let bound = bound_cursor.last_key().to_vec();
+
+    let ranges = cursors
+        .iter()
+        .map(|c| (c.offset, c.upper_bound(&bound)))
+        .collect_vec();
+    let contributing = ranges.iter().filter(|(start, end)| start < end).count();
+
+    let mut dfs = Vec::with_capacity(contributing);
+    let mut keyed_idxs = Vec::new();
+    for (cursor, (start, end)) in cursors.iter().zip(&ranges) {
+        if start == end {
+            continue;
+        }
+        if contributing > 1 {
+            let base = keyed_idxs.len();
+            keyed_idxs.extend(
+                (*start..*end).map(|i| (cursor.keys.value(i), (base + i - start) as IdxSize)),
+            );
+        }
+        dfs.push(cursor.df.slice(*start as i64, end - start));
+    }
+
+    let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+    if contributing > 1 {
+        // The stable sort detects the sorted runs and merges them.
+        keyed_idxs.sort_by(|l, r| l.0.cmp(r.0));
+        let idxs = keyed_idxs.into_iter().map(|(_, i)| i).collect_vec();
+        df = unsafe { df.take_slice_unchecked(&idxs) };
+    }
+
+    for (cursor, (_, end)) in cursors.iter_mut().zip(ranges) {
+        cursor.offset = end;
+    }
+    Ok(Some(df))
+}
+
+/// Per-pipeline buffer of unsorted morsels, which are sorted into a run once
+/// they exceed the memory budget.
+#[derive(Default)]
+struct LocalRunBuilder {
+    buffered: Vec<DataFrame>,
+    bytes: usize,
+    runs: Vec<SortedRun>,
+}
+
+impl LocalRunBuilder {
+    fn sort_buffered(&mut self, params: &SortParams) -> PolarsResult<Option<DataFrame>> {
+        self.bytes = 0;
+        if self.buffered.is_empty() {
+            return Ok(None);
+        }
+        let df = accumulate_dataframes_vertical_unchecked(self.buffered.drain(..));
+        params.sort(df).map(Some)
+    }
+
+    fn spill_run(&mut self, params: &SortParams) -> PolarsResult<()> {
+        let Some(df) = self.sort_buffered(params)? else {
+            return Ok(());
+        };
+        let mut spiller = PartitionedSpiller::new(df.schema(), 1)?;
+        let morsel_size = get_ideal_morsel_size();
+        for offset in (0..df.height()).step_by(morsel_size) {
+            spiller.spill(0, &df.slice(offset as i64, morsel_size), 0)?;
+        }
+        self.runs
+            .push(SortedRun::Spilled(spiller.finish()?.pop().unwrap()));
+        Ok(())
+    }
+}
+
+struct MergeState {
+    runs: Vec<SortedRun>,
+    cursors: Option<Vec<RunCursor>>,
+    pending: Option<DataFrame>,
+    morsel_seq: MorselSeq,
+    done: bool,
+}
+
+impl MergeState {
+    /// Merges runs on disk until few enough remain to merge them at once.
+    fn reduce_runs(&mut self, params: &SortParams) -> PolarsResult<()> {
+        while self.runs.len() > MAX_MERGE_FAN_IN {
+            let mut cursors = self
+                .runs
+                .drain(..MAX_MERGE_FAN_IN)
+                .map(|run| run.open(params))
+                .filter_map(Result::transpose)
+                .try_collect_vec()?;
+
+            let mut spiller = None;
+            while let Some(df) = merge_step(&mut cursors, params)? {
+                let spiller = match &mut spiller {
+                    Some(spiller) => spiller,
+                    None => spiller.insert(PartitionedSpiller::new(df.schema(), 1)?),
+                };
+                spiller.spill(0, &df, 0)?;
+            }
+            if let Some(spiller) = spiller {
+                self.runs
+                    .push(SortedRun::Spilled(spiller.finish()?.pop().unwrap()));
+            }
+        }
+        Ok(())
+    }
+
+    async fn merge(&mut self, mut send: Sender<Morsel>, params: &SortParams) -> PolarsResult<()> {
+        if self.cursors.is_none() {
+            self.reduce_runs(params)?;
+            let cursors = self
+                .runs
+                .drain(..)
+                .map(|run| run.open(params))
+                .filter_map(Result::transpose)
+                .try_collect_vec()?;
+            self.cursors = Some(cursors);
+        }
+        let cursors = self.cursors.as_mut().unwrap();
+
+        let morsel_size = get_ideal_morsel_size();
+        let wait_group = WaitGroup::default();
+        let source_token = SourceToken::new();
+        loop {
+            let df = match self.pending.take() {
+                Some(df) => df,
+                None => match merge_step(cursors, params)? {
+                    Some(df) => df,
+                    None => {
+                        self.done = true;
+                        return Ok(());
+                    },
+                },
+            };
+
+            let (out, rest) = df.split_at(morsel_size.min(df.height()) as i64);
+            if rest.height() > 0 {
+                self.pending = Some(rest);
+            }
+
+            let out = out.select(params.output_schema.iter_names_cloned())?;
+            let mut morsel = Morsel::new(out, self.morsel_seq, source_token.clone());
+            self.morsel_seq = self.morsel_seq.successor();
+            morsel.set_consume_token(wait_group.token());
+            if send.send(morsel).await.is_err() {
+                return Ok(());
+            }
+
+            wait_group.wait().await;
+            if source_token.stop_requested() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+enum SortState {
+    Sink(Vec<LocalRunBuilder>),
+    Source(MergeState),
+    Done,
+}
+
+/// An external merge sort. Sorted runs which exceed the memory budget are
+/// spilled to disk, after which all runs are merged.
+pub struct SortNode {
+    params: SortParams,
+    budget_per_local: usize,
+    state: SortState,
+}
+
+impl SortNode {
+    pub fn new(
+        key_cols: Vec<PlSmallStr>,
+        mut descending: Vec<bool>,
+        mut nulls_last: Vec<bool>,
+        maintain_order: bool,
+        output_schema: Arc<Schema>,
+        memory_budget: usize,
+        num_pipelines: usize,
+    ) -> Self {
+        let mut key_cols = key_cols;
+        if maintain_order {
+            // Break ties by the position in the input.
+            for name in [SEQ_COL_NAME, ROW_COL_NAME] {
+                key_cols.push(PlSmallStr::from_static(name));
+                descending.push(false);
+                nulls_last.push(false);
+            }
+        }
+
+        Self {
+            params: SortParams {
+                key_cols,
+                descending,
+                nulls_last,
+                maintain_order,
+                output_schema,
+            },
+            budget_per_local: memory_budget / num_pipelines,
+            state: SortState::Sink(
+                (0..num_pipelines)
+                    .map(|_| LocalRunBuilder::default())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, sort what's left in memory and start merging.
+            SortState::Sink(locals) if recv[0] == PortState::Done => {
+                let mut runs = Vec::new();
+                for local in locals {
+                    if let Some(df) = local.sort_buffered(&self.params)? {
+                        runs.push(SortedRun::InMemory(df));
+                    }
+                    runs.append(&mut local.runs);
+                }
+
+                if config::verbose() {
+                    let num_spilled = runs
+                        .iter()
+                        .filter(|r| matches!(r, SortedRun::Spilled(_)))
+                        .count();
+                    eprintln!(
+                        "[SortNode]: merging {} sorted runs, {} of which spilled",
+                        runs.len(),
+                        num_spilled
+                    );
+                }
+
+                self.state = SortState::Source(MergeState {
+                    runs,
+                    cursors: None,
+                    pending: None,
+                    morsel_seq: MorselSeq::default(),
+                    done: false,
+                });
+            },
+            SortState::Source(merge) if merge.done => {
+                self.state = SortState::Done;
+            },
+            // Nothing to change.
+            _ => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            SortState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let params = &self.params;
+        match &mut self.state {
+            SortState::Sink(locals) => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let budget = self.budget_per_local;
+                for (mut recv, local) in receivers.into_iter().zip(locals) {
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let (mut df, seq, _, _) = morsel.into_inner();
+                            if df.height() == 0 {
+                                continue;
+                            }
+
+                            if params.maintain_order {
+                                let height = df.height();
+                                let seq_col = Column::new_scalar(
+                                    PlSmallStr::from_static(SEQ_COL_NAME),
+                                    Scalar::from(seq.to_u64()),
+                                    height,
+                                );
+                                let row_col = IdxCa::from_vec(
+                                    PlSmallStr::from_static(ROW_COL_NAME),
+                                    (0..height as IdxSize).collect(),
+                                )
+                                .into_column();
+                                unsafe { df.hstack_mut_unchecked(&[seq_col, row_col]) };
+                            }
+
+                            local.bytes += df.estimated_size();
+                            local.buffered.push(df);
+                            if local.bytes > budget {
+                                local.spill_run(params)?;
+                            }
+                        }
+
+                        Ok(())
+                    }));
+                }
+            },
+            SortState::Source(merge) => {
+                assert!(recv_ports[0].is_none());
+                let send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    merge.merge(send, params).await
+                }));
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
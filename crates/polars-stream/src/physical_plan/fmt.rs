@@ -31,10 +31,12 @@ impl NodeStyle {
             | K::NegativeSlice { .. }
             | K::InMemorySink { .. }
             | K::Sort { .. }
+            | K::ExternalSort { .. }
             | K::GroupBy { .. }
             | K::EquiJoin { .. }
             | K::SemiAntiJoin { .. }
             | K::InMemoryJoin { .. }
+            | K::IEJoin { .. }
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
@@ -261,6 +263,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -285,6 +289,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -317,6 +323,16 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        PhysNodeKind::ExternalSort {
+            input,
+            key_cols,
+            descending: _,
+            nulls_last: _,
+            maintain_order: _,
+        } => (
+            format!("external-sort\\n{}", key_cols.join(", ")),
+            from_ref(input),
+        ),
         PhysNodeKind::TopK {
             input,
             k,
@@ -473,6 +489,21 @@ fn visualize_plan_rec(
             right_on,
             args,
         }
+        | PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        }
+        | PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            ..
+        }
         | PhysNodeKind::SemiAntiJoin {
             input_left,
             input_right,
@@ -484,6 +515,8 @@ fn visualize_plan_rec(
             let label = match phys_sm[node_key].kind {
                 PhysNodeKind::EquiJoin { .. } => "equi-join",
                 PhysNodeKind::InMemoryJoin { .. } => "in-memory-join",
+                PhysNodeKind::AsOfJoin { .. } => "asof-join",
+                PhysNodeKind::IEJoin { .. } => "iejoin",
                 PhysNodeKind::CrossJoin { .. } => "cross-join",
                 PhysNodeKind::SemiAntiJoin {
                     output_bool: false, ..
@@ -0,0 +1,116 @@
+use std::sync::{Arc, Mutex};
+
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::PolarsResult;
+use polars_plan::dsl::Expr;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::execute::StreamingExecutionState;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::io_sources::batch::GetBatchFn;
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+
+/// Arguments passed to every call of the anonymous scan function.
+pub struct AnonymousScanParams {
+    pub file_schema: SchemaRef,
+    pub output_schema: Option<SchemaRef>,
+    pub projection: Option<Arc<[PlSmallStr]>>,
+    pub n_rows: Option<usize>,
+    /// Only set if the function allows predicate pushdown.
+    pub predicate: Option<Expr>,
+}
+
+impl AnonymousScanParams {
+    fn to_args(&self) -> AnonymousScanArgs {
+        AnonymousScanArgs {
+            n_rows: self.n_rows,
+            with_columns: self.projection.clone(),
+            schema: self.file_schema.clone(),
+            output_schema: self.output_schema.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+struct AnonymousScanState {
+    buffered: Option<DataFrame>,
+    exhausted: bool,
+}
+
+/// Wraps an anonymous scan function into a reader for the multiscan.
+///
+/// The data is requested with [`AnonymousScan::next_batch`] if the function allows batched
+/// scans, otherwise with a single call to [`AnonymousScan::scan`]. In both cases the output is
+/// split into morsel-sized batches.
+pub fn anonymous_scan_to_reader_builder(
+    function: Arc<dyn AnonymousScan>,
+    name: &str,
+    params: AnonymousScanParams,
+) -> PolarsResult<Arc<dyn FileReaderBuilder>> {
+    let name = PlSmallStr::from_str(name);
+    let reader_schema = match &params.projection {
+        Some(projection) => Arc::new(params.file_schema.try_project(projection.iter())?),
+        None => params.file_schema.clone(),
+    };
+    let batched = function.allows_batched_scan();
+    let morsel_size = get_ideal_morsel_size();
+    let state = Mutex::new(AnonymousScanState::default());
+
+    let get_batch_fn = Box::new(move |_state: &StreamingExecutionState| {
+        let mut state = state.lock().unwrap();
+        loop {
+            if let Some(df) = state.buffered.take() {
+                if df.height() > morsel_size {
+                    let (head, tail) = df.split_at(morsel_size as i64);
+                    state.buffered = Some(tail);
+                    return Ok(Some(head));
+                }
+                return Ok(Some(df));
+            }
+
+            if state.exhausted {
+                return Ok(None);
+            }
+
+            let df = if batched {
+                function.next_batch(params.to_args())?
+            } else {
+                state.exhausted = true;
+                Some(function.scan(params.to_args())?)
+            };
+            let Some(mut df) = df else {
+                state.exhausted = true;
+                return Ok(None);
+            };
+
+            // The function may not have applied the projection.
+            if let Some(projection) = &params.projection {
+                if df.get_column_names().into_iter().ne(projection.iter()) {
+                    df = df.select(projection.iter().cloned())?;
+                }
+            }
+            state.buffered = Some(df);
+        }
+    }) as GetBatchFn;
+
+    use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
+    use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchState};
+
+    let reader = BatchFnReader {
+        name: name.clone(),
+        output_schema: Some(reader_schema),
+        get_batch_state: Some(GetBatchState::from(get_batch_fn)),
+        execution_state: None,
+        verbose: config::verbose(),
+    };
+
+    Ok(Arc::new(BatchFnReaderBuilder {
+        name,
+        reader: Mutex::new(Some(reader)),
+        execution_state: Default::default(),
+    }) as Arc<dyn FileReaderBuilder>)
+}
@@ -1,2 +1,3 @@
+pub mod anonymous_scan;
 #[cfg(feature = "python")]
 pub mod python_dataset;
@@ -42,6 +42,7 @@ impl ExprCache {
 
 struct LowerExprContext<'a> {
     prepare_visualization: bool,
+    spill_memory_budget: Option<usize>,
     expr_arena: &'a mut Arena<AExpr>,
     phys_sm: &'a mut SlotMap<PhysNodeKey, PhysNode>,
     cache: &'a mut ExprCache,
@@ -51,6 +52,7 @@ impl<'a> From<LowerExprContext<'a>> for StreamingLowerIRContext {
     fn from(value: LowerExprContext<'a>) -> Self {
         Self {
             prepare_visualization: value.prepare_visualization,
+            spill_memory_budget: value.spill_memory_budget,
         }
     }
 }
@@ -58,6 +60,7 @@ impl<'a> From<&LowerExprContext<'a>> for StreamingLowerIRContext {
     fn from(value: &LowerExprContext<'a>) -> Self {
         Self {
             prepare_visualization: value.prepare_visualization,
+            spill_memory_budget: value.spill_memory_budget,
         }
     }
 }
@@ -780,9 +783,7 @@ fn lower_exprs_with_ctx(
                     ctx.expr_arena,
                     ctx.phys_sm,
                     ctx.cache,
-                    StreamingLowerIRContext {
-                        prepare_visualization: ctx.prepare_visualization,
-                    },
+                    StreamingLowerIRContext::from(&*ctx),
                 )?;
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(tmp_count_name)));
                 input_streams.insert(stream);
@@ -842,9 +843,7 @@ fn lower_exprs_with_ctx(
                     ctx.expr_arena,
                     ctx.phys_sm,
                     ctx.cache,
-                    StreamingLowerIRContext {
-                        prepare_visualization: ctx.prepare_visualization,
-                    },
+                    StreamingLowerIRContext::from(&*ctx),
                 )?;
 
                 let value = ExprIR::new(
@@ -1648,6 +1647,7 @@ pub fn lower_exprs(
         phys_sm,
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
+        spill_memory_budget: ctx.spill_memory_budget,
     };
     let node_exprs = exprs.iter().map(|e| e.node()).collect_vec();
     let (transformed_input, transformed_exprs) =
@@ -1675,6 +1675,7 @@ pub fn build_select_stream(
         phys_sm,
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
+        spill_memory_budget: ctx.spill_memory_budget,
     };
     build_select_stream_with_ctx(input, exprs, &mut ctx)
 }
@@ -1694,6 +1695,7 @@ pub fn build_length_preserving_select_stream(
         phys_sm,
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
+        spill_memory_budget: ctx.spill_memory_budget,
     };
     let already_length_preserving = exprs
         .iter()
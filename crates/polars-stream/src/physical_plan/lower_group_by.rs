@@ -407,7 +407,7 @@ fn try_build_streaming_group_by(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> Option<PolarsResult<PhysStream>> {
-    if apply.is_some() || maintain_order {
+    if apply.is_some() || maintain_order || options.grouping_sets.is_some() {
         return None; // TODO
     }
 
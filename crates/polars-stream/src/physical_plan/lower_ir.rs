@@ -9,18 +9,21 @@ use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
+use polars_ops::frame::JoinArgs;
 use polars_plan::constants::get_literal_name;
 use polars_plan::dsl::default_values::DefaultFieldValues;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
-    ExtraColumnsPolicy, FileScanIR, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR,
-    SinkTypeIR,
+    ExtraColumnsPolicy, FileScanIR, FileSinkType, JoinTypeOptionsIR, PartitionSinkTypeIR,
+    PartitionVariantIR, ScanSources, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
+use polars_plan::plans::prune::prune;
 use polars_plan::plans::{AExpr, FunctionIR, IR, IRAggExpr, LiteralValue, write_ir_non_recursive};
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
+use polars_utils::mmap::MemSlice;
 use polars_utils::slice_enum::Slice;
 use polars_utils::unique_id::UniqueId;
 use polars_utils::{IdxSize, unique_column_name};
@@ -37,6 +40,56 @@ use crate::physical_plan::lower_expr::{
 };
 use crate::physical_plan::lower_group_by::build_group_by_stream;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
+use crate::utils::spill::can_spill;
+
+/// Whether the join is an as-of join on plain columns, which the streaming
+/// as-of join node supports.
+fn is_streamable_asof_join(
+    args: &JoinArgs,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    #[cfg(feature = "asof_join")]
+    {
+        let is_col =
+            |e: &[ExprIR]| e.len() == 1 && matches!(expr_arena.get(e[0].node()), AExpr::Column(_));
+        matches!(args.how, polars_ops::frame::JoinType::AsOf(_))
+            && is_col(left_on)
+            && is_col(right_on)
+    }
+    #[cfg(not(feature = "asof_join"))]
+    {
+        let _ = (args, left_on, right_on, expr_arena);
+        false
+    }
+}
+
+/// Whether the join is an inequality join on elementwise keys, which the
+/// streaming IEJoin node supports.
+fn is_streamable_iejoin(
+    args: &JoinArgs,
+    options: &Option<JoinTypeOptionsIR>,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+    expr_cache: &mut ExprCache,
+) -> bool {
+    #[cfg(feature = "iejoin")]
+    {
+        matches!(args.how, polars_ops::frame::JoinType::IEJoin)
+            && matches!(options, Some(JoinTypeOptionsIR::IEJoin(_)))
+            && left_on
+                .iter()
+                .chain(right_on)
+                .all(|e| is_elementwise_rec_cached(e.node(), expr_arena, expr_cache))
+    }
+    #[cfg(not(feature = "iejoin"))]
+    {
+        let _ = (args, options, left_on, right_on, expr_arena, expr_cache);
+        false
+    }
+}
 
 /// Creates a new PhysStream which outputs a slice of the input stream.
 pub fn build_slice_stream(
@@ -119,6 +172,8 @@ fn build_filter_stream(
 #[derive(Debug, Clone, Copy)]
 pub struct StreamingLowerIRContext {
     pub prepare_visualization: bool,
+    /// See [`spill_memory_budget`](crate::utils::spill::spill_memory_budget).
+    pub spill_memory_budget: Option<usize>,
 }
 
 #[recursive::recursive]
@@ -505,6 +560,58 @@ pub fn lower_ir(
                 }));
             }
 
+            // Without a limit no rows can be discarded, so if a memory budget
+            // is set we use an external merge sort which can spill to disk.
+            // The budget has to be set explicitly, the external sort is slower
+            // than the in-memory sort for data that fits in memory.
+            if slice.is_none()
+                && limit == u64::MAX
+                && ctx.spill_memory_budget.is_some()
+                && can_spill(&cur_out_schema)
+            {
+                // Materialize the sort keys which aren't plain columns.
+                let input_schema = phys_sm[stream.node].output_schema.clone();
+                let mut key_cols = Vec::with_capacity(by_column.len());
+                let mut key_exprs = Vec::new();
+                for e in &by_column {
+                    match expr_arena.get(e.node()) {
+                        AExpr::Column(name) if input_schema.contains(name) => {
+                            key_cols.push(name.clone())
+                        },
+                        _ => {
+                            let name = unique_column_name();
+                            key_exprs.push(e.with_alias(name.clone()));
+                            key_cols.push(name);
+                        },
+                    }
+                }
+                if !key_exprs.is_empty() {
+                    let mut selectors = input_schema
+                        .iter_names_cloned()
+                        .map(|name| {
+                            let node = expr_arena.add(AExpr::Column(name.clone()));
+                            ExprIR::new(node, OutputName::ColumnLhs(name))
+                        })
+                        .collect_vec();
+                    selectors.extend(key_exprs);
+                    stream = build_length_preserving_select_stream(
+                        stream, &selectors, expr_arena, phys_sm, expr_cache, ctx,
+                    )?;
+                }
+
+                let node = phys_sm.insert(PhysNode {
+                    output_schema,
+                    kind: PhysNodeKind::ExternalSort {
+                        input: stream,
+                        key_cols,
+                        descending: sort_options.descending,
+                        nulls_last: sort_options.nulls_last,
+                        maintain_order: sort_options.maintain_order,
+                    },
+                });
+                return Ok(PhysStream::first(node));
+            }
+
             stream = PhysStream::first(phys_sm.insert(PhysNode {
                 output_schema: Arc::new(cur_out_schema),
                 kind: PhysNodeKind::Sort {
@@ -567,18 +674,25 @@ pub fn lower_ir(
 
         v @ IR::Scan { .. } => {
             let IR::Scan {
-                sources: scan_sources,
+                sources: mut scan_sources,
                 file_info,
                 mut hive_parts,
-                output_schema: _,
+                output_schema: scan_output_schema,
                 scan_type,
-                predicate,
+                mut predicate,
                 unified_scan_args,
             } = v.clone()
             else {
                 unreachable!();
             };
 
+            // Anonymous scans don't read from the sources, give the multiscan a single empty
+            // in-memory source rather than a path that doesn't exist. File paths and hive
+            // partitions are rejected when building the scan.
+            if let FileScanIR::Anonymous { .. } = &*scan_type {
+                scan_sources = ScanSources::Buffers(Arc::from([MemSlice::default()]));
+            }
+
             if scan_sources.is_empty()
                 || unified_scan_args
                     .pre_slice
@@ -615,6 +729,22 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro {
+                        options: polars_io::avro::AvroScanOptions {},
+                        metadata: first_metadata,
+                    } => Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder {
+                        first_metadata: first_metadata.clone(),
+                    }) as Arc<dyn FileReaderBuilder>,
+
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc {
+                        options: polars_io::orc::OrcScanOptions {},
+                        metadata: first_metadata,
+                    } => Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder {
+                        first_metadata: first_metadata.clone(),
+                    }) as Arc<dyn FileReaderBuilder>,
+
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => {
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
@@ -625,6 +755,13 @@ pub fn lower_ir(
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
                     },
 
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { options } => {
+                        Arc::new(crate::nodes::io_sources::json::builder::JsonReaderBuilder {
+                            options: Arc::new(options.clone()),
+                        }) as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object: _,
@@ -642,7 +779,37 @@ pub fn lower_ir(
                         python_dataset_scan_to_reader_builder(expanded_scan)
                     },
 
-                    FileScanIR::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScanIR::Anonymous { function, options } => {
+                        use crate::physical_plan::io::anonymous_scan::{
+                            AnonymousScanParams, anonymous_scan_to_reader_builder,
+                        };
+
+                        // A pushed down predicate is applied by the function, otherwise the
+                        // multiscan applies it.
+                        let function_predicate = if function.allows_predicate_pushdown() {
+                            predicate.take().map(|p| p.to_expr(expr_arena))
+                        } else {
+                            None
+                        };
+                        // The multiscan also applies the slice, in case the function returns
+                        // more rows.
+                        let n_rows = match &unified_scan_args.pre_slice {
+                            Some(Slice::Positive { offset: 0, len }) => Some(*len),
+                            _ => None,
+                        };
+
+                        anonymous_scan_to_reader_builder(
+                            function.clone(),
+                            options.fmt_str,
+                            AnonymousScanParams {
+                                file_schema: file_info.schema.clone(),
+                                output_schema: scan_output_schema,
+                                projection: unified_scan_args.projection.clone(),
+                                n_rows,
+                                predicate: function_predicate,
+                            },
+                        )?
+                    },
                 };
 
                 {
@@ -947,6 +1114,29 @@ pub fn lower_ir(
             let right_on = right_on.clone();
             let args = options.args.clone();
             let options = options.options.clone();
+            let is_streamable_iejoin =
+                is_streamable_iejoin(&args, &options, &left_on, &right_on, expr_arena, expr_cache);
+            let input_right = match &options {
+                // Sorting the build side by the first key gives its blocks
+                // narrow key ranges, allowing probes to skip most of them.
+                #[cfg(feature = "iejoin")]
+                Some(JoinTypeOptionsIR::IEJoin(ie_options)) if is_streamable_iejoin => {
+                    use polars_ops::frame::InequalityOperator;
+                    let descending = matches!(
+                        ie_options.operator1,
+                        InequalityOperator::Gt | InequalityOperator::GtEq
+                    );
+                    ir_arena.add(IR::Sort {
+                        input: input_right,
+                        by_column: vec![right_on[0].clone()],
+                        slice: None,
+                        sort_options: polars_core::prelude::SortMultipleOptions::default()
+                            .with_order_descending(descending)
+                            .with_nulls_last(true),
+                    })
+                },
+                _ => input_right,
+            };
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
             if (args.how.is_equi() || args.how.is_semi_anti()) && !args.validation.needs_checks() {
@@ -1026,6 +1216,43 @@ pub fn lower_ir(
                     stream = build_slice_stream(stream, offset, len, phys_sm);
                 }
                 return Ok(stream);
+            } else if is_streamable_asof_join(&args, &left_on, &right_on, expr_arena) {
+                let mut args = args;
+                let slice = args.slice.take();
+                let node = phys_sm.insert(PhysNode::new(
+                    output_schema,
+                    PhysNodeKind::AsOfJoin {
+                        input_left: phys_left,
+                        input_right: phys_right,
+                        left_on,
+                        right_on,
+                        args,
+                    },
+                ));
+                let mut stream = PhysStream::first(node);
+                if let Some((offset, len)) = slice {
+                    stream = build_slice_stream(stream, offset, len, phys_sm);
+                }
+                return Ok(stream);
+            } else if is_streamable_iejoin {
+                let mut args = args;
+                let slice = args.slice.take();
+                let node = phys_sm.insert(PhysNode::new(
+                    output_schema,
+                    PhysNodeKind::IEJoin {
+                        input_left: phys_left,
+                        input_right: phys_right,
+                        left_on,
+                        right_on,
+                        args,
+                        options,
+                    },
+                ));
+                let mut stream = PhysStream::first(node);
+                if let Some((offset, len)) = slice {
+                    stream = build_slice_stream(stream, offset, len, phys_sm);
+                }
+                return Ok(stream);
             } else {
                 PhysNodeKind::InMemoryJoin {
                     input_left: phys_left,
@@ -1192,6 +1419,69 @@ pub fn lower_ir(
 
             return Ok(stream);
         },
+        IR::RecursiveUnion {
+            anchor,
+            recursive,
+            id,
+            options,
+        } => {
+            // Every iteration depends on the rows of the previous one, so we fall back to the
+            // in-memory engine for the recursive part.
+            let (recursive, id, options) = (*recursive, *id, options.clone());
+            let phys_anchor = lower_ir!(*anchor)?;
+
+            let lmdf = Arc::new(LateMaterializedDataFrame::default());
+            let mut lp_arena = Arena::default();
+            let mut rec_expr_arena = Arena::default();
+            let [recursive] = prune(
+                &[recursive],
+                ir_arena,
+                expr_arena,
+                &mut lp_arena,
+                &mut rec_expr_arena,
+            )
+            .try_into()
+            .unwrap();
+            let anchor_lp_node = lp_arena.add(lmdf.clone().as_ir_node(output_schema.clone()));
+            let union_lp_node = lp_arena.add(IR::RecursiveUnion {
+                anchor: anchor_lp_node,
+                recursive,
+                id,
+                options,
+            });
+            let executor = Mutex::new(create_physical_plan(
+                union_lp_node,
+                &mut lp_arena,
+                &mut rec_expr_arena,
+                None,
+            )?);
+
+            let format_str = ctx.prepare_visualization.then(|| {
+                let mut buffer = String::new();
+                write_ir_non_recursive(
+                    &mut buffer,
+                    ir_arena.get(node),
+                    expr_arena,
+                    phys_sm[phys_anchor.node].output_schema.as_ref(),
+                    0,
+                )
+                .unwrap();
+                buffer
+            });
+            PhysNodeKind::InMemoryMap {
+                input: phys_anchor,
+                map: Arc::new(move |df| {
+                    lmdf.set_materialized_dataframe(df);
+                    let mut state = ExecutionState::new();
+                    executor.lock().execute(&mut state)
+                }),
+                format_str,
+            }
+        },
+        IR::WorkTableScan { name, .. } => polars_bail!(
+            InvalidOperation:
+            "work table '{}' can only be scanned by the recursive input of its recursive union", name
+        ),
         IR::ExtContext { .. } => todo!(),
         IR::Invalid => unreachable!(),
     };
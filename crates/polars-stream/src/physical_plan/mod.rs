@@ -193,6 +193,16 @@ pub enum PhysNodeKind {
         sort_options: SortMultipleOptions,
     },
 
+    /// An external merge sort on the given key columns, which spills sorted
+    /// runs to disk.
+    ExternalSort {
+        input: PhysStream,
+        key_cols: Vec<PlSmallStr>,
+        descending: Vec<bool>,
+        nulls_last: Vec<bool>,
+        maintain_order: bool,
+    },
+
     TopK {
         input: PhysStream,
         k: PhysStream,
@@ -318,6 +328,27 @@ pub enum PhysNodeKind {
 
         key: PlSmallStr,
     },
+
+    /// An as-of join of two inputs sorted by their (single column) key.
+    AsOfJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+    },
+
+    /// An inequality join, which partitions the right input into blocks with
+    /// known key ranges and streams the left input against them. The right
+    /// input should be sorted by its first key for the blocks to be selective.
+    IEJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+        options: Option<JoinTypeOptionsIR>,
+    },
 }
 
 fn visit_node_inputs_mut(
@@ -356,6 +387,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
+            | PhysNodeKind::ExternalSort { input, .. }
             | PhysNodeKind::Multiplexer { input }
             | PhysNodeKind::Rle(input)
             | PhysNodeKind::RleId(input)
@@ -376,6 +408,16 @@ fn visit_node_inputs_mut(
                 input_right,
                 ..
             }
+            | PhysNodeKind::AsOfJoin {
+                input_left,
+                input_right,
+                ..
+            }
+            | PhysNodeKind::IEJoin {
+                input_left,
+                input_right,
+                ..
+            }
             | PhysNodeKind::EquiJoin {
                 input_left,
                 input_right,
@@ -10,7 +10,8 @@ use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
 use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
-use polars_plan::dsl::{JoinOptionsIR, PartitionVariantIR, ScanSources};
+use polars_ops::frame::JoinArgs;
+use polars_plan::dsl::{JoinOptionsIR, JoinTypeOptionsIR, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
 use polars_plan::prelude::{FileType, FunctionFlags};
@@ -34,8 +35,10 @@ use crate::nodes::io_sinks::partition::PerPartitionSortBy;
 use crate::nodes::io_sources::multi_scan::config::MultiScanConfig;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+use crate::nodes::joins::InMemoryJoiner;
 use crate::physical_plan::lower_expr::compute_output_schema;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
+use crate::utils::spill::can_spill;
 
 fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
     arena.iter(node).any(|(_n, ae)| match ae {
@@ -69,12 +72,14 @@ struct GraphConversionContext<'a> {
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     expr_conversion_state: ExpressionConversionState,
     num_pipelines: usize,
+    spill_memory_budget: Option<usize>,
 }
 
 pub fn physical_plan_to_graph(
     root: PhysNodeKey,
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &mut Arena<AExpr>,
+    spill_memory_budget: Option<usize>,
 ) -> PolarsResult<(Graph, SecondaryMap<PhysNodeKey, GraphNodeKey>)> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -85,6 +90,7 @@ pub fn physical_plan_to_graph(
         phys_to_graph: SecondaryMap::with_capacity(phys_sm.len()),
         expr_conversion_state: ExpressionConversionState::new(false),
         num_pipelines,
+        spill_memory_budget,
     };
 
     to_graph_rec(root, &mut ctx)?;
@@ -316,11 +322,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        avro_writer_options.clone(),
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -487,6 +505,28 @@ fn to_graph_rec<'a>(
             )
         },
 
+        ExternalSort {
+            input,
+            key_cols,
+            descending,
+            nulls_last,
+            maintain_order,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::sort::SortNode::new(
+                    key_cols.clone(),
+                    descending.clone(),
+                    nulls_last.clone(),
+                    *maintain_order,
+                    node.output_schema.clone(),
+                    ctx.spill_memory_budget.unwrap_or(usize::MAX),
+                    ctx.num_pipelines,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         TopK {
             input,
             k,
@@ -710,6 +750,7 @@ fn to_graph_rec<'a>(
 
             ctx.graph.add_node(
                 nodes::group_by::GroupByNode::new(
+                    input_schema.clone(),
                     key_schema,
                     key_selectors,
                     grouper,
@@ -717,6 +758,7 @@ fn to_graph_rec<'a>(
                     grouped_reductions,
                     node.output_schema.clone(),
                     PlRandomState::default(),
+                    ctx.spill_memory_budget,
                     ctx.num_pipelines,
                 ),
                 [(input_key, input.port)],
@@ -736,46 +778,131 @@ fn to_graph_rec<'a>(
             let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
             let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
 
-            let mut lp_arena = Arena::default();
-            let left_lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let right_lmdf = Arc::new(LateMaterializedDataFrame::default());
-
-            let left_node = lp_arena.add(left_lmdf.clone().as_ir_node(left_input_schema.clone()));
-            let right_node =
-                lp_arena.add(right_lmdf.clone().as_ir_node(right_input_schema.clone()));
-            let join_node = lp_arena.add(IR::Join {
-                input_left: left_node,
-                input_right: right_node,
-                schema: node.output_schema.clone(),
-                left_on: left_on.clone(),
-                right_on: right_on.clone(),
-                options: Arc::new(JoinOptionsIR {
-                    allow_parallel: true,
-                    force_parallel: false,
-                    args: args.clone(),
-                    options: options.clone(),
-                    rows_left: (None, 0),
-                    rows_right: (None, 0),
-                }),
-            });
-
-            let executor = Mutex::new(create_physical_plan(
-                join_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                None,
-            )?);
-
+            let joiner = build_in_memory_joiner(
+                left_input_schema.clone(),
+                right_input_schema.clone(),
+                node.output_schema.clone(),
+                left_on,
+                right_on,
+                args,
+                options,
+                ctx,
+            )?;
             ctx.graph.add_node(
                 nodes::joins::in_memory::InMemoryJoinNode::new(
                     left_input_schema,
                     right_input_schema,
-                    Arc::new(move |left, right| {
-                        left_lmdf.set_materialized_dataframe(left);
-                        right_lmdf.set_materialized_dataframe(right);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    joiner,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+            let polars_ops::frame::JoinType::AsOf(asof_options) = &args.how else {
+                unreachable!()
+            };
+            let key_name = |e: &ExprIR| match ctx.expr_arena.get(e.node()) {
+                AExpr::Column(name) => name.clone(),
+                _ => unreachable!(),
+            };
+            let left_key = key_name(&left_on[0]);
+            let right_key = key_name(&right_on[0]);
+
+            // The node checks the sortedness of its inputs itself, the
+            // joiner only ever sees sorted morsels.
+            let mut joiner_args = args.clone();
+            if let polars_ops::frame::JoinType::AsOf(options) = &mut joiner_args.how {
+                options.check_sortedness = false;
+            }
+            let joiner = build_in_memory_joiner(
+                left_input_schema,
+                right_input_schema.clone(),
+                node.output_schema.clone(),
+                left_on,
+                right_on,
+                &joiner_args,
+                &None,
+                ctx,
+            )?;
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    right_input_schema,
+                    left_key,
+                    right_key,
+                    asof_options,
+                    joiner,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+        #[cfg(not(feature = "asof_join"))]
+        AsOfJoin { .. } => unreachable!(),
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+            let Some(JoinTypeOptionsIR::IEJoin(ie_options)) = options else {
+                unreachable!()
+            };
+
+            let left_key_selectors = left_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &left_input_schema))
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &right_input_schema))
+                .try_collect_vec()?;
+            let memory_budget = match ctx.spill_memory_budget {
+                Some(budget) if can_spill(&right_input_schema) => budget,
+                _ => usize::MAX,
+            };
+            let joiner = build_in_memory_joiner(
+                left_input_schema,
+                right_input_schema.clone(),
+                node.output_schema.clone(),
+                left_on,
+                right_on,
+                args,
+                options,
+                ctx,
+            )?;
+            ctx.graph.add_node(
+                nodes::joins::iejoin::IEJoinNode::new(
+                    right_input_schema,
+                    left_key_selectors,
+                    right_key_selectors,
+                    ie_options,
+                    joiner,
+                    memory_budget,
                 ),
                 [
                     (left_input_key, input_left.port),
@@ -783,6 +910,8 @@ fn to_graph_rec<'a>(
                 ],
             )
         },
+        #[cfg(not(feature = "iejoin"))]
+        IEJoin { .. } => unreachable!(),
 
         EquiJoin {
             input_left,
@@ -873,6 +1002,7 @@ fn to_graph_rec<'a>(
                         left_key_selectors,
                         right_key_selectors,
                         args,
+                        ctx.spill_memory_budget,
                         ctx.num_pipelines,
                     )?,
                     [
@@ -1138,3 +1268,55 @@ fn to_graph_rec<'a>(
     ctx.phys_to_graph.insert(phys_node_key, graph_key);
     Ok(graph_key)
 }
+
+/// Builds a function which joins two DataFrames using the in-memory engine.
+#[allow(clippy::too_many_arguments)]
+fn build_in_memory_joiner(
+    left_input_schema: Arc<Schema>,
+    right_input_schema: Arc<Schema>,
+    output_schema: Arc<Schema>,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    args: &JoinArgs,
+    options: &Option<JoinTypeOptionsIR>,
+    ctx: &mut GraphConversionContext<'_>,
+) -> PolarsResult<InMemoryJoiner> {
+    let mut lp_arena = Arena::default();
+    let left_lmdf = Arc::new(LateMaterializedDataFrame::default());
+    let right_lmdf = Arc::new(LateMaterializedDataFrame::default());
+
+    let left_node = lp_arena.add(left_lmdf.clone().as_ir_node(left_input_schema));
+    let right_node = lp_arena.add(right_lmdf.clone().as_ir_node(right_input_schema));
+    let join_node = lp_arena.add(IR::Join {
+        input_left: left_node,
+        input_right: right_node,
+        schema: output_schema,
+        left_on: left_on.to_vec(),
+        right_on: right_on.to_vec(),
+        options: Arc::new(JoinOptionsIR {
+            allow_parallel: true,
+            force_parallel: false,
+            args: args.clone(),
+            options: options.clone(),
+            rows_left: (None, 0),
+            rows_right: (None, 0),
+        }),
+    });
+
+    // Executors consume their inputs and planning consumes the IR, so we plan
+    // a new executor for every call to allow joining multiple times (e.g. once
+    // per morsel).
+    let expr_arena = ctx.expr_arena.clone();
+    Ok(Arc::new(move |left, right| {
+        let mut executor = create_physical_plan(
+            join_node,
+            &mut lp_arena.clone(),
+            &mut expr_arena.clone(),
+            None,
+        )?;
+        left_lmdf.set_materialized_dataframe(left);
+        right_lmdf.set_materialized_dataframe(right);
+        let mut state = ExecutionState::new();
+        executor.execute(&mut state)
+    }))
+}
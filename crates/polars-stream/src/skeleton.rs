@@ -12,6 +12,7 @@ use slotmap::{SecondaryMap, SlotMap};
 
 use crate::graph::{Graph, GraphNodeKey};
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
+use crate::utils::spill::spill_memory_budget;
 
 /// Executes the IR with the streaming engine.
 ///
@@ -42,6 +43,7 @@ pub fn visualize_physical_plan(
 
     let ctx = StreamingLowerIRContext {
         prepare_visualization: true,
+        spill_memory_budget: spill_memory_budget()?,
     };
     let root_phys_node =
         crate::physical_plan::build_physical_plan(node, ir_arena, expr_arena, &mut phys_sm, ctx)?;
@@ -77,6 +79,7 @@ impl StreamingQuery {
         let mut phys_sm = SlotMap::with_capacity_and_key(ir_arena.len());
         let ctx = StreamingLowerIRContext {
             prepare_visualization: false,
+            spill_memory_budget: spill_memory_budget()?,
         };
         let root_phys_node = crate::physical_plan::build_physical_plan(
             node,
@@ -91,8 +94,12 @@ impl StreamingQuery {
             std::fs::write(visual_path, visualization).unwrap();
         }
 
-        let (mut graph, phys_to_graph) =
-            crate::physical_plan::physical_plan_to_graph(root_phys_node, &phys_sm, expr_arena)?;
+        let (mut graph, phys_to_graph) = crate::physical_plan::physical_plan_to_graph(
+            root_phys_node,
+            &phys_sm,
+            expr_arena,
+            ctx.spill_memory_budget,
+        )?;
 
         let top_ir = ir_arena.get(node).clone();
 
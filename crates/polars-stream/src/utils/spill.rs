@@ -0,0 +1,236 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use arrow::datatypes::ArrowSchema;
+use arrow::io::ipc::read::{StreamReader, StreamState, read_stream_metadata};
+use arrow::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow::record_batch::RecordBatch;
+use polars_core::config;
+use polars_core::prelude::{CompatLevel, DataFrame, Schema, SchemaExt};
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+
+/// The amount of memory (in bytes) a single blocking streaming operator may
+/// use for its buffered state before it starts spilling it to disk, if any.
+///
+/// The budget is set with `POLARS_STREAMING_MEMORY_BUDGET`, setting
+/// `POLARS_FORCE_OOC=1` makes operators spill as much as possible. Without
+/// either nothing is spilled: sorts use the in-memory sort node, and group-bys
+/// and equi-joins keep all their state in memory.
+///
+/// Not every operator can spill within the budget. Equi-joins with
+/// `maintain_order` set keep their whole build side in memory, as spilled
+/// partitions are joined out of order, and data containing objects is never
+/// spilled.
+///
+/// This is resolved once per query and passed down through the lowering and
+/// graph conversion contexts.
+pub fn spill_memory_budget() -> PolarsResult<Option<usize>> {
+    if std::env::var("POLARS_FORCE_OOC").as_deref() == Ok("1") {
+        return Ok(Some(0));
+    }
+    let Ok(budget) = std::env::var("POLARS_STREAMING_MEMORY_BUDGET") else {
+        return Ok(None);
+    };
+    budget.parse().map(Some).map_err(|_| {
+        polars_err!(
+            InvalidOperation: "invalid value for POLARS_STREAMING_MEMORY_BUDGET: '{budget}', expected a number of bytes"
+        )
+    })
+}
+
+/// Whether data of this schema can be written to a spill file.
+pub fn can_spill(schema: &Schema) -> bool {
+    !schema.iter_values().any(|dt| dt.is_object())
+}
+
+/// A temporary directory containing spill files, removed when dropped.
+struct SpillDir {
+    path: PathBuf,
+}
+
+impl SpillDir {
+    fn new() -> PolarsResult<Self> {
+        static SPILL_DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
+        let path = POLARS_TEMP_DIR_BASE_PATH.join(format!(
+            "spill-{}-{}",
+            std::process::id(),
+            SPILL_DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&path)?;
+        if config::verbose() {
+            eprintln!("[spill]: created spill directory {}", path.display());
+        }
+        Ok(Self { path })
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        // Errors are ignored, at worst we leave some files behind in the
+        // temporary directory.
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Writes DataFrames with a common schema to a set of on-disk partitions.
+///
+/// Each written DataFrame is tagged with a u64 (typically a morsel sequence
+/// id) which is returned alongside it when reading the partition back.
+pub struct PartitionedSpiller {
+    dir: Arc<SpillDir>,
+    arrow_schema: Arc<ArrowSchema>,
+    writers: Vec<Option<StreamWriter<BufWriter<File>>>>,
+    tags_per_p: Vec<Vec<u64>>,
+    rows_per_p: Vec<usize>,
+    bytes_per_p: Vec<usize>,
+}
+
+impl PartitionedSpiller {
+    pub fn new(schema: &Schema, num_partitions: usize) -> PolarsResult<Self> {
+        if !can_spill(schema) {
+            polars_bail!(ComputeError: "cannot spill data of type Object to disk");
+        }
+        Ok(Self {
+            dir: Arc::new(SpillDir::new()?),
+            arrow_schema: Arc::new(schema.to_arrow(CompatLevel::newest())),
+            writers: (0..num_partitions).map(|_| None).collect(),
+            tags_per_p: vec![Vec::new(); num_partitions],
+            rows_per_p: vec![0; num_partitions],
+            bytes_per_p: vec![0; num_partitions],
+        })
+    }
+
+    /// Appends the DataFrame to the given partition.
+    pub fn spill(&mut self, partition: usize, df: &DataFrame, tag: u64) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        let writer = match &mut self.writers[partition] {
+            Some(writer) => writer,
+            slot @ None => {
+                let path = self.dir.path.join(format!("{partition}.arrows"));
+                let file = BufWriter::new(File::create(path)?);
+                let mut writer = StreamWriter::new(file, WriteOptions { compression: None });
+                writer.start(&self.arrow_schema, None)?;
+                slot.insert(writer)
+            },
+        };
+
+        // Every DataFrame is written as a single record batch so tags line up
+        // with batches when reading back.
+        let arrays = df
+            .get_columns()
+            .iter()
+            .map(|c| c.clone().rechunk_to_arrow(CompatLevel::newest()))
+            .collect();
+        let batch = RecordBatch::new(df.height(), self.arrow_schema.clone(), arrays);
+        writer.write(&batch, None)?;
+
+        self.tags_per_p[partition].push(tag);
+        self.rows_per_p[partition] += df.height();
+        self.bytes_per_p[partition] += df.estimated_size();
+        Ok(())
+    }
+
+    /// Finishes all partition files, returning a handle per partition.
+    pub fn finish(self) -> PolarsResult<Vec<SpilledPartition>> {
+        let dir = self.dir;
+        self.writers
+            .into_iter()
+            .zip(self.tags_per_p)
+            .zip(self.rows_per_p)
+            .zip(self.bytes_per_p)
+            .enumerate()
+            .map(|(p, (((writer, tags), num_rows), bytes))| {
+                let path = match writer {
+                    Some(mut writer) => {
+                        writer.finish()?;
+                        writer
+                            .into_inner()
+                            .into_inner()
+                            .map_err(|e| e.into_error())?;
+                        Some(dir.path.join(format!("{p}.arrows")))
+                    },
+                    None => None,
+                };
+                Ok(SpilledPartition {
+                    _dir: dir.clone(),
+                    path,
+                    tags,
+                    num_rows,
+                    bytes,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A single partition of spilled DataFrames.
+#[derive(Clone)]
+pub struct SpilledPartition {
+    // Keeps the spill directory alive while this partition may still be read.
+    _dir: Arc<SpillDir>,
+    path: Option<PathBuf>,
+    tags: Vec<u64>,
+    num_rows: usize,
+    bytes: usize,
+}
+
+impl SpilledPartition {
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The estimated in-memory size of the spilled DataFrames.
+    pub fn spilled_bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Reads the spilled DataFrames back in the order they were written,
+    /// together with their tags.
+    pub fn read(&self) -> PolarsResult<SpilledPartitionReader> {
+        let reader = match &self.path {
+            Some(path) => {
+                let mut file = BufReader::new(File::open(path)?);
+                let metadata = read_stream_metadata(&mut file)?;
+                Some(StreamReader::new(file, metadata, None))
+            },
+            None => None,
+        };
+        Ok(SpilledPartitionReader {
+            _dir: self._dir.clone(),
+            reader,
+            tags: self.tags.clone().into_iter(),
+        })
+    }
+}
+
+/// Iterator over the tagged DataFrames of a [`SpilledPartition`].
+pub struct SpilledPartitionReader {
+    _dir: Arc<SpillDir>,
+    reader: Option<StreamReader<BufReader<File>>>,
+    tags: std::vec::IntoIter<u64>,
+}
+
+impl Iterator for SpilledPartitionReader {
+    type Item = PolarsResult<(u64, DataFrame)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tag = self.tags.next()?;
+        let batch = match self.reader.as_mut()?.next()? {
+            Ok(StreamState::Some(batch)) => batch,
+            Ok(StreamState::Waiting) => {
+                return Some(Err(
+                    polars_err!(ComputeError: "unexpected end of spill file"),
+                ));
+            },
+            Err(e) => return Some(Err(e)),
+        };
+        Some(Ok((tag, DataFrame::from(batch))))
+    }
+}
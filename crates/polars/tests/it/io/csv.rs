@@ -859,6 +859,74 @@ fn test_utf8() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_encodings() -> PolarsResult<()> {
+    let mut df = df![
+        "naïve" => ["café", "€5", "œuvre"],
+        "n" => [1i64, 2, 3],
+    ]?;
+
+    for (encoding, include_bom) in [
+        (CsvEncoding::Windows1252, false),
+        (CsvEncoding::Utf16Le, true),
+        (CsvEncoding::Utf16Be, false),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .include_bom(include_bom)
+            .with_encoding(encoding)
+            .finish(&mut df)?;
+
+        let out = CsvReadOptions::default()
+            .map_parse_options(|parse_options| parse_options.with_encoding(encoding))
+            .into_reader_with_file_handle(Cursor::new(buf))
+            .finish()?;
+        assert!(out.equals(&df));
+    }
+
+    // '€' has no Latin-1 encoding.
+    let mut buf: Vec<u8> = Vec::new();
+    assert!(
+        CsvWriter::new(&mut buf)
+            .with_encoding(CsvEncoding::Latin1)
+            .finish(&mut df)
+            .is_err()
+    );
+
+    let file = Cursor::new(b"a,b\ncaf\xe9,\xa3\n");
+    let out = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_encoding(CsvEncoding::Latin1))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    assert!(out.equals(&df!["a" => ["café"], "b" => ["£"]]?));
+
+    Ok(())
+}
+
+#[test]
+fn test_multi_char_separator_and_escape_char() -> PolarsResult<()> {
+    let csv = "a||b||c\r\n1||x\\|||\"q\\\"\nr\"\r\n2||||z\\\\\r\n";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options
+                .with_multi_char_separator(Some("||".into()))
+                .with_escape_char(Some(b'\\'))
+                .with_line_terminator(Some("\r\n".into()))
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    let expected = df![
+        "a" => [1i64, 2],
+        "b" => [Some("x|"), None],
+        "c" => ["q\"\nr", "z\\"],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    Ok(())
+}
+
 #[test]
 fn test_header_inference() -> PolarsResult<()> {
     let csv = r#"not_a_header,really,even_if,it_looks_like_one
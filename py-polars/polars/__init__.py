@@ -197,6 +197,7 @@ from polars.io import (
     read_json,
     read_ndjson,
     read_ods,
+    read_orc,
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
@@ -205,6 +206,7 @@ from polars.io import (
     scan_iceberg,
     scan_ipc,
     scan_ndjson,
+    scan_orc,
     scan_parquet,
     scan_pyarrow_dataset,
 )
@@ -309,6 +311,7 @@ __all__ = [
     "read_json",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
@@ -317,6 +320,7 @@ __all__ = [
     "scan_iceberg",
     "scan_ipc",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
     "Catalog",
@@ -850,6 +850,7 @@ class PyLazyFrame:
         parallel: Any,
         low_memory: bool,
         use_statistics: bool,
+        decryption_key_retriever: Any | None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_ipc(
@@ -869,6 +870,23 @@ class PyLazyFrame:
         include_file_paths: str | None,
     ) -> PyLazyFrame: ...
     @staticmethod
+    def new_from_orc(
+        source: Any | None,
+        sources: Any,
+        n_rows: int | None,
+        cache: bool,
+        rechunk: bool,
+        row_index: tuple[str, int] | None,
+        cloud_options: dict[str, Any] | None,
+        credential_provider: Any | None,
+        hive_partitioning: bool | None,
+        hive_schema: Any | None,
+        try_parse_hive_dates: bool,
+        retries: int,
+        file_cache_ttl: int | None,
+        include_file_paths: str | None,
+    ) -> PyLazyFrame: ...
+    @staticmethod
     def new_from_dataset_object(dataset_object: Any) -> PyLazyFrame: ...
     @staticmethod
     def scan_from_python_function_arrow_schema(
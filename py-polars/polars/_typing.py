@@ -108,7 +108,7 @@ ColumnNameOrSelector: TypeAlias = Union[str, SelectorType]
 # User-facing string literal types
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
-AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate", "zstd"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
@@ -116,8 +116,13 @@ ColumnMapping: TypeAlias = tuple[Literal["iceberg-column-mapping"], "pa.Schema"]
 DefaultFieldValues: TypeAlias = tuple[
     Literal["iceberg"], dict[int, Union["Series", str]]
 ]
-DeletionFiles: TypeAlias = tuple[
-    Literal["iceberg-position-delete"], dict[int, list[str]]
+DeletionFiles: TypeAlias = Union[
+    tuple[Literal["iceberg-position-delete"], dict[int, list[str]]],
+    tuple[
+        Literal["iceberg-equality-delete"],
+        tuple[dict[int, list[str]], dict[int, list[tuple[str, list[int]]]]],
+    ],
+    tuple[Literal["delta-deletion-vector"], tuple[str, dict[int, dict[str, Any]]]],
 ]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
@@ -138,6 +143,15 @@ ParallelStrategy: TypeAlias = Literal[
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+ParquetEncoding: TypeAlias = Literal[
+    "plain",
+    "dictionary",
+    "delta_binary_packed",
+    "delta_length_byte_array",
+    "delta_byte_array",
+    "byte_stream_split",
+    "auto",
+]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
@@ -424,6 +438,7 @@ __all__ = [
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
+    "ParquetEncoding",
     "PartitioningScheme",
     "PivotAgg",
     "PolarsDataType",
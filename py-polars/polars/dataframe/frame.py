@@ -3151,7 +3151,7 @@ class DataFrame:
         ----------
         file
             File path or writable file-like object to which the data will be written.
-        compression : {'uncompressed', 'snappy', 'deflate'}
+        compression : {'uncompressed', 'snappy', 'deflate', 'zstd'}
             Compression method. Defaults to "uncompressed".
         name
             Schema name. Defaults to empty string.
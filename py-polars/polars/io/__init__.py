@@ -9,6 +9,7 @@ from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
 from polars.io.json import read_json
 from polars.io.ndjson import read_ndjson, scan_ndjson
+from polars.io.orc import read_orc, scan_orc
 from polars.io.parquet import (
     read_parquet,
     read_parquet_metadata,
@@ -50,6 +51,7 @@ __all__ = [
     "read_json",
     "read_ndjson",
     "read_ods",
+    "read_orc",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
@@ -58,6 +60,7 @@ __all__ = [
     "scan_iceberg",
     "scan_ipc",
     "scan_ndjson",
+    "scan_orc",
     "scan_parquet",
     "scan_pyarrow_dataset",
     "ScanCastOptions",
@@ -20,6 +20,7 @@ if TYPE_CHECKING:
     from deltalake import DeltaTable
 
     from polars import DataFrame, DataType, LazyFrame
+    from polars._typing import DeletionFiles
     from polars.io.cloud import CredentialProviderFunction
 
 
@@ -369,7 +370,7 @@ def scan_delta(
         and table_protocol.reader_features is not None
     ):
         missing_features = {*table_protocol.reader_features}.difference(
-            SUPPORTED_READER_FEATURES
+            {*SUPPORTED_READER_FEATURES, "deletionVectors"}
         )
         if len(missing_features) > 0:
             msg = f"The table has set these reader features: {missing_features} but these are not yet supported by the polars delta scanner."
@@ -405,6 +406,13 @@ def scan_delta(
     if dl_tbl.table_uri.startswith("lakefs://"):
         file_uris = [file_uri.replace("lakefs://", "s3://") for file_uri in file_uris]
 
+    deletion_files = None
+    if (
+        table_protocol.reader_features is not None
+        and "deletionVectors" in table_protocol.reader_features
+    ):
+        deletion_files = _get_deletion_vectors(dl_tbl, file_uris)
+
     return scan_parquet(
         file_uris,
         schema=main_schema,
@@ -416,9 +424,64 @@ def scan_delta(
         storage_options=storage_options,
         credential_provider=credential_provider_builder,  # type: ignore[arg-type]
         rechunk=rechunk or False,
+        _deletion_files=deletion_files,
     )
 
 
+def _get_deletion_vectors(
+    dl_tbl: DeltaTable, file_uris: list[str]
+) -> DeletionFiles | None:
+    """Get the deletion vector descriptors, keyed by the index of the data file."""
+    from urllib.parse import unquote
+
+    from polars import DataFrame
+    from polars.functions import col
+
+    add_actions = DataFrame(dl_tbl.get_add_actions(flatten=True))
+
+    fields = ["storageType", "pathOrInlineDv", "offset", "sizeInBytes", "cardinality"]
+
+    if f"deletionVector.{fields[0]}" not in add_actions.columns:
+        return None
+
+    # The add action paths are relative to the table root (or absolute), and can be
+    # percent-encoded.
+    descriptors = {
+        unquote(path): descriptor
+        for path, descriptor in zip(
+            add_actions.get_column("path"),
+            add_actions.select(
+                col(f"deletionVector.{field}").alias(field) for field in fields
+            ).iter_rows(named=True),
+        )
+    }
+
+    vectors: dict[int, dict[str, Any]] = {}
+    for i, file_uri in enumerate(file_uris):
+        parts = unquote(file_uri).split("/")
+        descriptor = next(
+            (
+                descriptors[path]
+                for path in ("/".join(parts[j:]) for j in range(len(parts)))
+                if path in descriptors
+            ),
+            None,
+        )
+
+        if descriptor is None:
+            msg = f"no add action found for the data file {file_uri!r}"
+            raise ValueError(msg)
+
+        if descriptor["storageType"] is not None:
+            vectors[i] = descriptor
+
+    table_uri = dl_tbl.table_uri
+    if table_uri.startswith("lakefs://"):
+        table_uri = table_uri.replace("lakefs://", "s3://")
+
+    return ("delta-deletion-vector", (table_uri, vectors))
+
+
 def _resolve_delta_lake_uri(table_uri: str | Path, *, strict: bool = True) -> str:
     resolved_uri = str(
         Path(table_uri).expanduser().resolve(strict)
@@ -18,6 +18,7 @@ if TYPE_CHECKING:
     import pyarrow as pa
     from pyiceberg.table import Table
 
+    from polars._typing import DeletionFiles
     from polars.lazyframe.frame import LazyFrame
 
 
@@ -168,6 +169,7 @@ class IcebergDataset:
             projected_iceberg_schema,
         )
         deletion_files: dict[int, list[str]] = {}
+        equality_deletion_files: dict[int, list[tuple[str, list[int]]]] = {}
 
         if reader_override != "pyiceberg" and not fallback_reason:
             from pyiceberg.manifest import DataFileContent, FileFormat
@@ -192,26 +194,32 @@ class IcebergDataset:
                     )
                     break
 
-                if file_info.delete_files:
-                    deletion_files[i] = []
-
-                    for deletion_file in file_info.delete_files:
-                        if deletion_file.content != DataFileContent.POSITION_DELETES:
-                            fallback_reason = (
-                                "unsupported deletion file type: "
-                                f"{deletion_file.content}"
-                            )
-                            break
-
-                        if deletion_file.file_format != FileFormat.PARQUET:
-                            fallback_reason = (
-                                "unsupported deletion file format: "
-                                f"{deletion_file.file_format}"
+                for deletion_file in file_info.delete_files:
+                    if deletion_file.file_format != FileFormat.PARQUET:
+                        fallback_reason = (
+                            "unsupported deletion file format: "
+                            f"{deletion_file.file_format}"
+                        )
+                        break
+
+                    if deletion_file.content == DataFileContent.POSITION_DELETES:
+                        deletion_files.setdefault(i, []).append(
+                            deletion_file.file_path
+                        )
+                    elif deletion_file.content == DataFileContent.EQUALITY_DELETES:
+                        equality_deletion_files.setdefault(i, []).append(
+                            (
+                                deletion_file.file_path,
+                                list(deletion_file.equality_ids),
                             )
-                            break
+                        )
+                    else:
+                        fallback_reason = (
+                            f"unsupported deletion file type: {deletion_file.content}"
+                        )
+                        break
 
-                        deletion_files[i].append(deletion_file.file_path)
-                        total_deletion_files += 1
+                    total_deletion_files += 1
 
                 if fallback_reason:
                     break
@@ -247,6 +255,16 @@ class IcebergDataset:
 
             from polars.io.parquet.functions import scan_parquet
 
+            # Equality deletes are carried together with the position deletes.
+            deletion_files_arg: DeletionFiles = (
+                (
+                    "iceberg-equality-delete",
+                    (deletion_files, equality_deletion_files),
+                )
+                if equality_deletion_files
+                else ("iceberg-position-delete", deletion_files)
+            )
+
             return scan_parquet(
                 sources,
                 cast_options=ScanCastOptions._default_iceberg(),
@@ -259,7 +277,7 @@ class IcebergDataset:
                     schema_to_pyarrow(iceberg_schema),
                 ),
                 _default_values=("iceberg", missing_field_defaults.finish()),
-                _deletion_files=("iceberg-position-delete", deletion_files),
+                _deletion_files=deletion_files_arg,
             ), snapshot_id_key
 
         elif reader_override == "native":
@@ -1,7 +1,10 @@
 from __future__ import annotations
 
 from collections.abc import Mapping, Sequence
-from typing import Any
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    from polars._typing import ParquetEncoding
 
 
 def _parquet_field_overwrites_dict_to_dict_list(
@@ -46,6 +49,18 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.required is not None:
         d["required"] = pqo.required
 
+    # Bloom filter
+    if pqo.bloom_filter:
+        bloom_filter: dict[str, Any] = {}
+        if pqo.bloom_filter_ndv is not None:
+            bloom_filter["ndv"] = pqo.bloom_filter_ndv
+        if pqo.bloom_filter_fpp is not None:
+            bloom_filter["fpp"] = pqo.bloom_filter_fpp
+        d["bloom_filter"] = bloom_filter
+
+    if pqo.encoding is not None:
+        d["encoding"] = pqo.encoding
+
     return d
 
 
@@ -108,6 +123,14 @@ class ParquetFieldOverwrites:
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
     required: bool | None = None  #: Is the field not allowed to have missing values
+    bloom_filter: bool = False  #: Write a bloom filter for every row group of the field
+    bloom_filter_ndv: (
+        int | None
+    ) = None  #: Expected number of distinct values per row group, sizes the bloom filter
+    bloom_filter_fpp: float | None = None  #: False positive probability of the bloom filter
+    encoding: (
+        ParquetEncoding | None
+    ) = None  #: Encoding of the data pages. `'auto'` picks it from the values of every row group
 
     def __init__(
         self,
@@ -122,6 +145,10 @@ class ParquetFieldOverwrites:
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
         required: bool | None = None,
+        bloom_filter: bool = False,
+        bloom_filter_ndv: int | None = None,
+        bloom_filter_fpp: float | None = None,
+        encoding: ParquetEncoding | None = None,
     ) -> None:
         self.name = name
 
@@ -138,3 +165,7 @@ class ParquetFieldOverwrites:
         else:
             self.metadata = metadata
         self.required = required
+        self.bloom_filter = bloom_filter
+        self.bloom_filter_ndv = bloom_filter_ndv
+        self.bloom_filter_fpp = bloom_filter_fpp
+        self.encoding = encoding
@@ -34,6 +34,7 @@ with contextlib.suppress(ImportError):
     from polars._plr import read_parquet_metadata as _read_parquet_metadata
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
     from typing import Literal
 
     from polars import DataFrame, DataType, LazyFrame
@@ -424,6 +425,7 @@ def scan_parquet(
     allow_missing_columns: bool | None = None,
     extra_columns: Literal["ignore", "raise"] = "raise",
     cast_options: ScanCastOptions | None = None,
+    decryption_key_retriever: Callable[[bytes], bytes] | None = None,
     _column_mapping: ColumnMapping | None = None,
     _default_values: DefaultFieldValues | None = None,
     _deletion_files: DeletionFiles | None = None,
@@ -559,6 +561,14 @@ def scan_parquet(
         Configuration for column type-casting during scans. Useful for datasets
         containing files that have differing schemas.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    decryption_key_retriever
+        Read files that use Parquet modular encryption. This function is called with
+        the key metadata that the file stores for the footer key and the column keys,
+        and must return the AES key (of 16, 24 or 32 bytes) as `bytes`.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -597,6 +607,13 @@ def scan_parquet(
         msg = "The `cast_options` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if decryption_key_retriever is not None:
+        msg = (
+            "the `decryption_key_retriever` parameter of `scan_parquet` is considered"
+            " unstable."
+        )
+        issue_unstable_warning(msg)
+
     if allow_missing_columns is not None:
         issue_deprecation_warning(
             "the parameter `allow_missing_columns` for `scan_parquet` is deprecated. "
@@ -632,6 +649,7 @@ def scan_parquet(
         parallel=parallel,
         low_memory=low_memory,
         use_statistics=use_statistics,
+        decryption_key_retriever=decryption_key_retriever,
         scan_options=ScanOptions(
             row_index=(
                 (row_index_name, row_index_offset)
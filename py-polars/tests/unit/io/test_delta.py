@@ -759,3 +759,47 @@ endpoint_url = http://localhost:333
 
     with pytest.raises(OSError, match="http://localhost:333"):
         pl.DataFrame({"x": 1}).write_delta("s3://.../...", mode="append")
+
+
+def test_scan_delta_deletion_vectors_by_path() -> None:
+    from polars.io.delta import _get_deletion_vectors
+
+    class _Table:
+        table_uri = "s3://bucket/table"
+
+        def get_add_actions(self, flatten: bool) -> dict[str, list[Any]]:
+            # In another order than the data files.
+            return {
+                "path": ["a=2/part-0.parquet", "a%3D1/part-0.parquet"],
+                "deletionVector.storageType": ["i", None],
+                "deletionVector.pathOrInlineDv": ["dv", None],
+                "deletionVector.offset": [None, None],
+                "deletionVector.sizeInBytes": [10, None],
+                "deletionVector.cardinality": [2, None],
+            }
+
+    file_uris = [
+        "s3://bucket/table/a=1/part-0.parquet",
+        "s3://bucket/table/a=2/part-0.parquet",
+    ]
+
+    assert _get_deletion_vectors(_Table(), file_uris) == (  # type: ignore[arg-type]
+        "delta-deletion-vector",
+        (
+            "s3://bucket/table",
+            {
+                1: {
+                    "storageType": "i",
+                    "pathOrInlineDv": "dv",
+                    "offset": None,
+                    "sizeInBytes": 10,
+                    "cardinality": 2,
+                }
+            },
+        ),
+    )
+
+    with pytest.raises(ValueError, match="no add action found"):
+        _get_deletion_vectors(  # type: ignore[arg-type]
+            _Table(), ["s3://bucket/table/a=3/part-0.parquet"]
+        )
@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+from decimal import Decimal
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+pa_orc = pytest.importorskip("pyarrow.orc")
+
+
+def write_orc(df: pl.DataFrame, path: Path, **kwargs: object) -> None:
+    # The ORC writer of pyarrow does not support the view types.
+    table = df.to_arrow(compat_level=pl.CompatLevel.oldest())
+    pa_orc.write_table(table, path, **kwargs)
+
+
+@pytest.fixture
+def example_df() -> pl.DataFrame:
+    n = 300
+    return pl.DataFrame(
+        {
+            "bool": [i % 3 == 0 for i in range(n)],
+            "i8": pl.Series([i % 100 - 50 for i in range(n)], dtype=pl.Int8),
+            "i32": pl.Series([i * 1_000 for i in range(n)], dtype=pl.Int32),
+            "i64": [None if i % 7 == 0 else i - 150 for i in range(n)],
+            "f64": [i / 4 for i in range(n)],
+            "str": [None if i % 11 == 0 else f"v{i % 5}" for i in range(n)],
+            "date": [date(2020, 1, 1 + i % 28) for i in range(n)],
+            "datetime": pl.Series(
+                [datetime(1960 + i % 80, 6, 1, 12, 30, i % 60, i) for i in range(n)],
+                dtype=pl.Datetime("ns"),
+            ),
+            "decimal": pl.Series(
+                [Decimal(i) / 100 for i in range(n)], dtype=pl.Decimal(10, 2)
+            ),
+            "list": [None if i % 13 == 0 else list(range(i % 4)) for i in range(n)],
+            "struct": [{"a": i, "b": f"s{i}"} for i in range(n)],
+        }
+    )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", ["uncompressed", "zlib", "snappy", "zstd"])
+def test_scan_orc_compression(
+    example_df: pl.DataFrame, compression: str, tmp_path: Path
+) -> None:
+    path = tmp_path / "data.orc"
+    write_orc(example_df, path, compression=compression)
+
+    assert_frame_equal(pl.scan_orc(path).collect(), example_df)
+    assert_frame_equal(pl.read_orc(path), example_df)
+
+
+@pytest.mark.write_disk
+def test_scan_orc_dictionary_encoding(example_df: pl.DataFrame, tmp_path: Path) -> None:
+    path = tmp_path / "data.orc"
+    # A threshold of 1 makes the writer dictionary encode every string column.
+    write_orc(example_df, path, dictionary_key_size_threshold=1.0)
+
+    assert_frame_equal(pl.scan_orc(path).collect(), example_df)
+
+
+@pytest.mark.write_disk
+def test_scan_orc_stripes(example_df: pl.DataFrame, tmp_path: Path) -> None:
+    path = tmp_path / "data.orc"
+    write_orc(example_df, path, stripe_size=1024, batch_size=50)
+    assert pa_orc.ORCFile(path).nstripes > 1
+
+    lf = pl.scan_orc(path)
+    assert_frame_equal(lf.collect(), example_df)
+
+    assert_frame_equal(
+        lf.select("list", "str").slice(120, 100).collect(),
+        example_df.select("list", "str").slice(120, 100),
+    )
+    assert_frame_equal(
+        lf.filter((pl.col("i64") >= 100) | (pl.col("i64") < -100)).collect(),
+        example_df.filter((pl.col("i64") >= 100) | (pl.col("i64") < -100)),
+    )
+    assert_frame_equal(
+        lf.select(pl.col("str", "list").null_count()).collect(),
+        example_df.select(pl.col("str", "list").null_count()),
+    )
+    assert_frame_equal(
+        pl.scan_orc(path, n_rows=75, row_index_name="idx").collect(),
+        example_df.head(75).with_row_index("idx"),
+    )
+
+
+@pytest.mark.write_disk
+def test_scan_orc_multiple_files(example_df: pl.DataFrame, tmp_path: Path) -> None:
+    write_orc(example_df.head(100), tmp_path / "a.orc")
+    write_orc(example_df.tail(200), tmp_path / "b.orc")
+
+    out = pl.scan_orc(tmp_path / "*.orc", include_file_paths="path").collect()
+    assert_frame_equal(out.drop("path"), example_df)
+    assert out["path"].unique_counts().to_list() == [100, 200]
@@ -1,8 +1,10 @@
 from __future__ import annotations
 
+import base64
 import decimal
 import functools
 import io
+import json
 import warnings
 from datetime import date, datetime, time, timezone
 from decimal import Decimal
@@ -3529,3 +3531,83 @@ def test_literal_predicate_23901() -> None:
 
     f.seek(0)
     assert_frame_equal(pl.scan_parquet(f).filter(pl.lit(1) == 1).collect(), df)
+
+
+@pytest.mark.parametrize("algorithm", ["AES_GCM_V1", "AES_GCM_CTR_V1"])
+@pytest.mark.parametrize("plaintext_footer", [False, True])
+def test_scan_parquet_decryption_pyarrow(
+    algorithm: str, plaintext_footer: bool
+) -> None:
+    pe = pytest.importorskip("pyarrow.parquet.encryption")
+
+    class KmsClient(pe.KmsClient):  # type: ignore[misc]
+        """Wraps the data keys with base64 only, so that the keys can be recovered."""
+
+        def __init__(self, _config: Any) -> None:
+            pe.KmsClient.__init__(self)
+
+        def wrap_key(self, key_bytes: bytes, master_key_identifier: str) -> bytes:
+            return base64.b64encode(key_bytes)
+
+        def unwrap_key(self, wrapped_key: bytes, master_key_identifier: str) -> bytes:
+            return base64.b64decode(wrapped_key)
+
+    # Only column 'b' is encrypted with its own key, the others are not encrypted.
+    encryption_config = pe.EncryptionConfiguration(
+        footer_key="footer_key",
+        column_keys={"column_key": ["b"]},
+        encryption_algorithm=algorithm,
+        plaintext_footer=plaintext_footer,
+        double_wrapping=False,
+    )
+    properties = pe.CryptoFactory(KmsClient).file_encryption_properties(
+        pe.KmsConnectionConfig(), encryption_config
+    )
+
+    df = pl.DataFrame(
+        {
+            "a": range(1000),
+            "b": [None if i % 7 == 0 else f"v{i}" for i in range(1000)],
+            "c": [list(range(i % 3)) for i in range(1000)],
+        }
+    )
+    f = io.BytesIO()
+    pq.write_table(
+        df.to_arrow(), f, encryption_properties=properties, data_page_size=1024
+    )
+
+    # The key metadata is the key material of pyarrow, which holds the wrapped key.
+    master_key_ids: set[str] = set()
+
+    def retrieve_key(key_metadata: bytes) -> bytes:
+        key_material = json.loads(key_metadata)
+        master_key_ids.add(key_material["masterKeyID"])
+        return base64.b64decode(key_material["wrappedDEK"])
+
+    f.seek(0)
+    lf = pl.scan_parquet(f, decryption_key_retriever=retrieve_key)
+    assert_frame_equal(lf.collect(), df)
+    assert master_key_ids == {"footer_key", "column_key"}
+    assert_frame_equal(
+        lf.filter(pl.col("a") == 42).collect(), df.filter(pl.col("a") == 42)
+    )
+
+    f.seek(0)
+    with pytest.raises(ComputeError):
+        pl.scan_parquet(f).select("b").collect()
+    f.seek(0)
+    if plaintext_footer:
+        # The columns that are not encrypted can be read without the keys.
+        assert_frame_equal(
+            pl.scan_parquet(f).select("a", "c").collect(), df.select("a", "c")
+        )
+    else:
+        with pytest.raises(ComputeError):
+            pl.scan_parquet(f).select("a").collect()
+
+    def missing_key(key_metadata: bytes) -> bytes:
+        raise KeyError
+
+    f.seek(0)
+    with pytest.raises(ComputeError, match="failed to retrieve key"):
+        pl.scan_parquet(f, decryption_key_retriever=missing_key).collect()
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import subprocess
 import sys
+import zlib
 from typing import TYPE_CHECKING
 
 import pytest
@@ -450,3 +451,224 @@ def test_scan_row_deletion_skips_file_with_all_rows_deleted(
 
     assert_frame_equal(q.slice(10).collect(), expect.drop("index"))
     assert_frame_equal(q.with_row_index().slice(10).collect(), expect)
+
+
+_Z85_ALPHABET = (
+    "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+    ".-:+=^!/*?&<>()[]{}@%$#"
+)
+
+
+def _z85_encode(data: bytes) -> str:
+    data += b"\x00" * (-len(data) % 4)
+    out = []
+
+    for i in range(0, len(data), 4):
+        value = int.from_bytes(data[i : i + 4], "big")
+        chunk = []
+        for _ in range(5):
+            value, digit = divmod(value, 85)
+            chunk.append(_Z85_ALPHABET[digit])
+        out.extend(reversed(chunk))
+
+    return "".join(out)
+
+
+def _serialize_deletion_vector(positions: list[int]) -> bytes:
+    """Serializes positions below 2^16 as a Delta `RoaringBitmapArray`."""
+    out = (1681511377).to_bytes(4, "little")
+    # One 32-bit bitmap with high bits 0.
+    out += (1).to_bytes(8, "little") + (0).to_bytes(4, "little")
+    # A portable roaring bitmap without run containers, with a single array container.
+    out += (12346).to_bytes(4, "little") + (1).to_bytes(4, "little")
+    out += (0).to_bytes(2, "little") + (len(positions) - 1).to_bytes(2, "little")
+    out += (0).to_bytes(4, "little")
+    out += b"".join(x.to_bytes(2, "little") for x in positions)
+    return out
+
+
+def _write_deletion_vector_file(path: Path, dvs: list[bytes]) -> list[int]:
+    """Writes a deletion vector file, returns the offsets of the deletion vectors."""
+    out = b"\x01"
+    offsets = []
+
+    for dv in dvs:
+        offsets.append(len(out))
+        # Size, data and checksum.
+        out += len(dv).to_bytes(4, "big") + dv + zlib.crc32(dv).to_bytes(4, "big")
+
+    path.parent.mkdir(exist_ok=True, parents=True)
+    path.write_bytes(out)
+    return offsets
+
+
+@pytest.mark.write_disk
+def test_scan_delta_deletion_vectors(data_files_path: Path, tmp_path: Path) -> None:
+    inline_dv = _serialize_deletion_vector([1, 2])
+
+    # Stored relative to the table root in a file named by its UUID.
+    relative_dv = _serialize_deletion_vector([0, 1, 2])
+    uuid = bytes(range(16))
+    _write_deletion_vector_file(
+        tmp_path / "ab" / "deletion_vector_00010203-0405-0607-0809-0a0b0c0d0e0f.bin",
+        [relative_dv],
+    )
+
+    # Stored at an absolute path, after another deletion vector.
+    absolute_dv = _serialize_deletion_vector([2, 3])
+    absolute_path = tmp_path / "dvs.bin"
+    _, absolute_offset = _write_deletion_vector_file(
+        absolute_path, [_serialize_deletion_vector([0]), absolute_dv]
+    )
+
+    deletion_files = (
+        "delta-deletion-vector",
+        (
+            str(tmp_path),
+            {
+                0: {
+                    "storageType": "i",
+                    "pathOrInlineDv": _z85_encode(inline_dv),
+                    "offset": None,
+                    "sizeInBytes": len(inline_dv),
+                    "cardinality": 2,
+                },
+                1: {
+                    "storageType": "u",
+                    "pathOrInlineDv": "ab" + _z85_encode(uuid),
+                    "offset": 1,
+                    "sizeInBytes": len(relative_dv),
+                    "cardinality": 3,
+                },
+                4: {
+                    "storageType": "p",
+                    "pathOrInlineDv": str(absolute_path),
+                    "offset": absolute_offset,
+                    "sizeInBytes": len(absolute_dv),
+                    "cardinality": 2,
+                },
+            },
+        ),
+    )
+
+    q = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=deletion_files,  # type: ignore[arg-type]
+        hive_partitioning=False,
+    ).with_row_index()
+
+    assert q.select(pl.len()).collect().item() == 18
+
+    expect = pl.DataFrame(
+        {
+            "index": range(18),
+            "physical_index": [
+                0, 3, 4,
+                8, 9,
+                10, 11, 12, 13, 14,
+                15, 16, 17, 18, 19,
+                20, 21, 24
+            ],
+        },
+        schema={"index": pl.get_index_type(), "physical_index": pl.UInt32},
+    )  # fmt: skip
+
+    assert_frame_equal(q.collect(), expect)
+    assert_frame_equal(q.slice(4, 5).collect(), expect.slice(4, 5))
+    assert_frame_equal(q.tail(3).collect(), expect.tail(3))
+
+    # The cardinality must match the deletion vector.
+    deletion_files[1][1][0]["cardinality"] = 3
+
+    q = pl.scan_parquet(
+        data_files_path,
+        _deletion_files=deletion_files,  # type: ignore[arg-type]
+        hive_partitioning=False,
+    )
+
+    with pytest.raises(pl.exceptions.ComputeError, match="contains 2 rows, expected 3"):
+        q.collect()
+
+
+def _write_parquet_with_field_ids(
+    df: pl.DataFrame, field_ids: list[int], path: Path
+) -> str:
+    import pyarrow.parquet as pq
+
+    tbl = df.to_arrow()
+    schema = tbl.schema
+
+    for i, field_id in enumerate(field_ids):
+        schema = schema.set(
+            i, schema.field(i).with_metadata({b"PARQUET:field_id": str(field_id)})
+        )
+
+    pq.write_table(tbl.cast(schema), path)
+    return str(path)
+
+
+@pytest.mark.write_disk
+def test_scan_iceberg_equality_deletes(tmp_path: Path) -> None:
+    # Field IDs: 1 = id, 2 = name.
+    data_files = [
+        _write_parquet_with_field_ids(
+            pl.DataFrame(
+                {"id": range(i, i + 5), "name": [f"n{x % 3}" for x in range(5)]}
+            ),
+            [1, 2],
+            tmp_path / f"data_{i}.parquet",
+        )
+        for i in [0, 5]
+    ]
+
+    position_deletes = str(tmp_path / "pos.parquet")
+    pl.DataFrame({"file_path": ["", ""], "pos": [0, 4]}).write_parquet(
+        position_deletes
+    )
+
+    # The column names of the delete files do not need to match the data files, the
+    # columns are matched by field ID.
+    delete_by_id = _write_parquet_with_field_ids(
+        pl.DataFrame({"key": pl.Series([2, 8, 100], dtype=pl.Int32)}),
+        [1],
+        tmp_path / "eq_id.parquet",
+    )
+    delete_by_id_and_name = _write_parquet_with_field_ids(
+        pl.DataFrame({"other": ["n0", "n0"], "key": [3, 6]}),
+        [2, 1],
+        tmp_path / "eq_id_name.parquet",
+    )
+
+    deletion_files = (
+        "iceberg-equality-delete",
+        (
+            {0: [position_deletes]},
+            {
+                0: [(delete_by_id, [1]), (delete_by_id_and_name, [1, 2])],
+                1: [(delete_by_id, [1]), (delete_by_id_and_name, [1, 2])],
+            },
+        ),
+    )
+
+    q = pl.scan_parquet(
+        data_files,
+        _deletion_files=deletion_files,  # type: ignore[arg-type]
+    ).with_row_index()
+
+    assert q.select(pl.len()).collect().item() == 5
+
+    # * 0, 4: Position deletes
+    # * 2, 8: Deleted by id
+    # * 3: Deleted by (id, name), 6 is kept as its name is n1
+    expect = pl.DataFrame(
+        {
+            "index": range(5),
+            "id": [1, 5, 6, 7, 9],
+            "name": ["n1", "n0", "n1", "n2", "n1"],
+        },
+        schema_overrides={"index": pl.get_index_type()},
+    )
+
+    assert_frame_equal(q.collect(), expect)
+    assert_frame_equal(q.slice(2, 3).collect(), expect.slice(2, 3))
+    assert_frame_equal(q.tail(2).collect(), expect.tail(2))
@@ -292,6 +292,33 @@ def test_streaming_group_by_ooc_q3(
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.write_disk
+def test_streaming_group_by_spill_memory_budget(
+    tmp_path: Path,
+    monkeypatch: Any,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "1000")
+
+    n = 100_000
+    lf = pl.LazyFrame(
+        {
+            "k": np.arange(n) % 5_000,
+            "s": [str(i) for i in range(n)],
+            "v": np.arange(n),
+        }
+    )
+    q = lf.group_by("k").agg(
+        pl.col("v").sum(), pl.col("s").min(), pl.col("v").mean().alias("m")
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
 def test_streaming_group_by_struct_key() -> None:
     df = pl.DataFrame(
         {"A": [1, 2, 3, 2], "B": ["google", "ms", "apple", "ms"], "C": [2, 3, 4, 3]}
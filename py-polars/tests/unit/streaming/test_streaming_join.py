@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from datetime import datetime
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import JoinStrategy, MaintainOrderJoin
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -386,3 +386,260 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full", "semi", "anti"])
+@pytest.mark.parametrize("force_ooc", [True, False])
+def test_streaming_join_spill(
+    how: JoinStrategy, force_ooc: bool, tmp_path: Path, monkeypatch: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    if force_ooc:
+        monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    else:
+        monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "10000")
+
+    np.random.seed(0)
+    left = pl.LazyFrame(
+        {
+            "a": np.random.randint(0, 2_000, 20_000),
+            "b": [str(i) for i in range(20_000)],
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "a": np.random.randint(1_000, 3_000, 10_000),
+            "c": np.arange(10_000),
+        }
+    )
+    q = left.join(right, on="a", how=how)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("maintain_order", ["left_right", "right_left"])
+def test_streaming_join_spill_maintain_order(
+    maintain_order: MaintainOrderJoin,
+    tmp_path: Path,
+    monkeypatch: Any,
+    capfd: pytest.CaptureFixture[str],
+) -> None:
+    # Ordered joins don't spill, they keep their build side in memory.
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    np.random.seed(0)
+    left = pl.LazyFrame(
+        {
+            "a": np.random.randint(0, 2_000, 20_000),
+            "b": [str(i) for i in range(20_000)],
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "a": np.random.randint(1_000, 3_000, 10_000),
+            "c": np.arange(10_000),
+        }
+    )
+    q = left.join(right, on="a", maintain_order=maintain_order)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    captured = capfd.readouterr().err
+    assert "the build side will not be spilled to disk" in captured
+    assert "build side spilled" not in captured
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("by", [None, "g"])
+@pytest.mark.parametrize("tolerance", [None, 5])
+@pytest.mark.parametrize("allow_exact_matches", [True, False])
+def test_streaming_join_asof(
+    strategy: Literal["backward", "forward", "nearest"],
+    by: str | None,
+    tolerance: int | None,
+    allow_exact_matches: bool,
+) -> None:
+    np.random.seed(0)
+    n = 250_000
+    left = pl.LazyFrame(
+        {
+            "t": np.sort(np.random.randint(0, 1_000_000, n)),
+            "g": np.random.randint(0, 10, n),
+            "a": np.arange(n),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "t": np.sort(np.random.randint(0, 1_000_000, n // 2)),
+            "g": np.random.randint(0, 10, n // 2),
+            "b": np.arange(n // 2),
+        }
+    )
+
+    q = left.join_asof(
+        right,
+        on="t",
+        by=by,
+        strategy=strategy,
+        tolerance=tolerance,
+        allow_exact_matches=allow_exact_matches,
+        check_sortedness=False,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("check_sortedness", [True, False])
+def test_streaming_join_asof_by_sorted_within_groups(
+    strategy: Literal["backward", "forward", "nearest"],
+    check_sortedness: bool,
+) -> None:
+    # Each group is sorted by 't', but the inputs as a whole are not.
+    n = 100_000
+    left = pl.LazyFrame(
+        {
+            "g": np.repeat([0, 1, 2], n),
+            "t": np.tile(np.arange(n), 3),
+            "a": np.arange(3 * n),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "g": np.repeat([0, 1, 2], n // 2),
+            "t": np.tile(np.arange(0, n, 2), 3),
+            "b": np.arange(3 * (n // 2)),
+        }
+    )
+
+    q = left.join_asof(
+        right,
+        on="t",
+        by="g",
+        strategy=strategy,
+        check_sortedness=check_sortedness,
+    )
+    if check_sortedness:
+        with pytest.warns(UserWarning, match="cannot be checked"):
+            expected = q.collect(engine="in-memory")
+    else:
+        expected = q.collect(engine="in-memory")
+    # The streaming engine checks the sortedness within each group itself.
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert_frame_equal(
+        q.slice(1_000, 10_000).collect(engine="streaming"),
+        expected.slice(1_000, 10_000),
+    )
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_streaming_join_asof_by_group_ends(
+    strategy: Literal["backward", "forward", "nearest"],
+) -> None:
+    # Group 0 only appears at the start of the right side, so left rows of
+    # it wait for the entire right side to be seen.
+    n = 250_000
+    left = pl.LazyFrame(
+        {
+            "t": np.arange(n),
+            "g": np.arange(n) % 3,
+            "a": np.arange(n),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "t": np.arange(0, n, 2),
+            "g": np.where(np.arange(n // 2) < 100, 0, 1 + np.arange(n // 2) % 2),
+            "b": np.arange(n // 2),
+        }
+    )
+
+    q = left.join_asof(right, on="t", by="g", strategy=strategy)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_join_asof_by_multiple_keys() -> None:
+    left = pl.LazyFrame(
+        {
+            "g": ["x", "y", "x", "y", None, "x"],
+            "h": [1, 1, 2, 1, 1, 1],
+            "t": [1, 1, 2, 2, 3, 3],
+            "a": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "g": ["y", "x", "x", None, "y"],
+            "h": [1, 1, 2, 1, 1],
+            "t": [0, 1, 1, 2, 2],
+            "b": [10, 20, 30, 40, 50],
+        }
+    )
+
+    q = left.join_asof(right, on="t", by=["g", "h"], check_sortedness=False)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_join_asof_unsorted() -> None:
+    left = pl.LazyFrame({"t": [3, 1, 2], "a": [1, 2, 3]})
+    right = pl.LazyFrame({"t": [1, 2, 3], "b": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        left.join_asof(right, on="t").collect(engine="streaming")
+
+    # With 'by' groups each group has to be sorted.
+    left = pl.LazyFrame({"g": [1, 2, 1], "t": [2, 1, 3], "a": [1, 2, 3]})
+    right = pl.LazyFrame({"g": [1, 1, 2], "t": [2, 1, 3], "b": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        left.join_asof(right, on="t", by="g").collect(engine="streaming")
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    "predicates",
+    [
+        [pl.col("t") >= pl.col("start"), pl.col("t") < pl.col("end")],
+        [pl.col("t") > pl.col("start"), pl.col("t") <= pl.col("end")],
+        [pl.col("t") < pl.col("start")],
+    ],
+)
+@pytest.mark.parametrize("spill", [True, False])
+def test_streaming_iejoin(
+    predicates: list[pl.Expr], spill: bool, tmp_path: Path, monkeypatch: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    if spill:
+        monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "10000")
+
+    np.random.seed(0)
+    events = pl.LazyFrame(
+        {
+            "t": np.random.randint(0, 100_000, 5_000),
+            "a": np.arange(5_000),
+        }
+    )
+    start = np.random.randint(0, 100_000, 2_000)
+    sessions = pl.LazyFrame(
+        {
+            "start": start,
+            "end": start + np.random.randint(0, 500, 2_000),
+            "b": np.arange(2_000),
+        }
+    )
+    if len(predicates) == 1:
+        events = events.head(500)
+        sessions = sessions.head(500)
+
+    q = events.join_where(sessions, *predicates)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
@@ -2,7 +2,7 @@ from __future__ import annotations
 
 from collections import Counter
 from datetime import datetime
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import numpy as np
 import pytest
@@ -183,3 +183,64 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("maintain_order", [True, False])
+def test_streaming_external_sort(
+    maintain_order: bool, tmp_path: Path, monkeypatch: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "10000")
+
+    np.random.seed(0)
+    n = 50_000
+    lf = pl.LazyFrame(
+        {
+            "a": np.random.randint(0, 100, n),
+            "b": pl.Series([str(i) for i in np.random.randint(0, 1_000, n)]),
+            "c": pl.Series(np.random.randint(0, 5, n)).replace(0, None),
+            "idx": np.arange(n),
+        }
+    )
+
+    q = lf.sort(
+        ["c", "a", "b"],
+        descending=[True, False, True],
+        nulls_last=True,
+        maintain_order=maintain_order,
+    )
+    out = q.collect(engine="streaming")
+    expected = q.collect(engine="in-memory")
+    if maintain_order:
+        assert_frame_equal(out, expected)
+    else:
+        assert_frame_equal(out.drop("idx"), expected.drop("idx"))
+
+    q = lf.sort(pl.col("a") % 7, pl.col("b").str.len_chars(), maintain_order=True)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.write_disk
+def test_streaming_external_sort_requires_memory_budget(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    monkeypatch.delenv("POLARS_STREAMING_MEMORY_BUDGET", raising=False)
+    monkeypatch.delenv("POLARS_FORCE_OOC", raising=False)
+
+    lf = pl.LazyFrame({"a": np.arange(10_000)[::-1], "b": np.arange(10_000) % 7})
+    q = lf.sort("b", "a")
+    expected = q.collect(engine="in-memory")
+
+    # Without a memory budget the in-memory sort is used.
+    capfd.readouterr()
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert "[SortNode]" not in capfd.readouterr().err
+
+    monkeypatch.setenv("POLARS_STREAMING_MEMORY_BUDGET", "10000")
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert "[SortNode]: merging" in capfd.readouterr().err